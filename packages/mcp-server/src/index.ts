@@ -83,6 +83,16 @@ function deriveTokenVaultPda(escrowPda: PublicKey): [PublicKey, number] {
   );
 }
 
+function deriveConfigPda(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("config")], ESCROW_PROGRAM_ID);
+}
+
+// Config is optional on create and release instructions; pass it whenever it has been initialized
+async function configIfInitialized(): Promise<PublicKey | null> {
+  const [configPda] = deriveConfigPda();
+  return (await connection.getAccountInfo(configPda)) ? configPda : null;
+}
+
 function text(content: string) {
   return { content: [{ type: "text" as const, text: content }] };
 }
@@ -231,11 +241,15 @@ server.tool(
   "Accept an escrow task as the executor. Status changes from Created to Active.",
   {
     escrow_id: z.string().describe("Escrow PDA address"),
+    terms: z
+      .string()
+      .optional()
+      .describe("Terms the escrow was created with, acknowledged on-chain (omit if none)"),
   },
-  async ({ escrow_id }) => {
+  async ({ escrow_id, terms }) => {
     try {
       const e = requireEscrow();
-      const signature = await e.accept(escrow_id);
+      const signature = await e.accept(escrow_id, terms);
 
       return json({
         escrowId: escrow_id,
@@ -425,6 +439,7 @@ server.tool(
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: new PublicKey("SysvarRent111111111111111111111111"),
+          config: await configIfInitialized(),
         })
         .rpc();
 
@@ -502,6 +517,7 @@ server.tool(
           recipientTokenAccount,
           feeTokenAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
+          config: await configIfInitialized(),
         })
         .rpc();
 
@@ -649,6 +665,7 @@ server.tool(
           arbiter: arbiterPubkey,
           feeRecipient: feeRecipientPubkey,
           systemProgram: SystemProgram.programId,
+          config: await configIfInitialized(),
         })
         .rpc();
 
@@ -717,6 +734,7 @@ server.tool(
           creator: w.publicKey,
           recipient: escrowData.recipient as PublicKey,
           feeRecipient: escrowData.feeRecipient as PublicKey,
+          config: await configIfInitialized(),
         })
         .rpc();
 
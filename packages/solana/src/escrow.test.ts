@@ -0,0 +1,60 @@
+import { describe, it, expect, vi } from 'vitest';
+import { Connection, Keypair, PublicKey } from '@solana/web3.js';
+import { createHash } from 'crypto';
+import { SolanaEscrow, hashTerms } from './escrow';
+
+// Stands in for the Anchor program so instruction args can be inspected offline
+function stubProgram(escrow: SolanaEscrow) {
+  const builder = {
+    accounts: vi.fn(() => builder),
+    rpc: vi.fn(async () => 'signature'),
+  };
+  const acceptTask = vi.fn(() => builder);
+  vi.spyOn(escrow as any, 'getProgram').mockReturnValue({ methods: { acceptTask } });
+  return { acceptTask, builder };
+}
+
+function newEscrow(): { escrow: SolanaEscrow; signer: Keypair } {
+  const signer = Keypair.generate();
+  const escrow = new SolanaEscrow(signer as any, new Connection('http://127.0.0.1:8899'));
+  return { escrow, signer };
+}
+
+describe('hashTerms', () => {
+  it('hashes terms with sha256', () => {
+    const expected = Array.from(createHash('sha256').update('Build feature X').digest());
+    expect(hashTerms('Build feature X')).toEqual(expected);
+  });
+
+  it('is all zeros when there are no terms', () => {
+    expect(hashTerms()).toEqual(Array(32).fill(0));
+    expect(hashTerms('')).toEqual(Array(32).fill(0));
+  });
+});
+
+describe('SolanaEscrow.accept', () => {
+  const escrowId = Keypair.generate().publicKey.toBase58();
+
+  it('acknowledges the hash of the given terms', async () => {
+    const { escrow, signer } = newEscrow();
+    const { acceptTask, builder } = stubProgram(escrow);
+
+    const sig = await escrow.accept(escrowId, 'Build feature X');
+
+    expect(sig).toBe('signature');
+    expect(acceptTask).toHaveBeenCalledWith(hashTerms('Build feature X'));
+    expect(builder.accounts).toHaveBeenCalledWith({
+      escrowAccount: new PublicKey(escrowId),
+      recipient: signer.publicKey,
+    });
+  });
+
+  it('acknowledges the zero hash for an escrow without terms', async () => {
+    const { escrow } = newEscrow();
+    const { acceptTask } = stubProgram(escrow);
+
+    await escrow.accept(escrowId);
+
+    expect(acceptTask).toHaveBeenCalledWith(Array(32).fill(0));
+  });
+});
@@ -5,7 +5,7 @@
  *
  * Flow:
  * 1. Creator calls create(amount, recipient, terms)
- * 2. Recipient calls accept(escrowId)
+ * 2. Recipient calls accept(escrowId, terms)
  * 3. After work completed: release(escrowId)
  * 4. If dispute: dispute(escrowId, reason)
  *
@@ -58,6 +58,26 @@ export interface CreateEscrowParams {
   arbiter?: string;
   /** Optional auto-release timestamp (Date or Unix ms). If set, anyone can release after this time. Must be after deadline. */
   autoReleaseAt?: Date | number;
+  /** Seconds after the deadline the recipient keeps before the creator can refund. Default: 0 */
+  refundGraceSeconds?: number;
+  /** Seconds after auto-release during which the creator can still dispute. Default: 0 (pay out immediately) */
+  postReleaseWindowSeconds?: number;
+  /** Earliest time (Date or Unix ms) the recipient may accept. Default: no restriction */
+  notBefore?: Date | number;
+  /** Task category tag. Default: 0 */
+  category?: number;
+  /** Off-chain task description URI (max 128 bytes) */
+  metadataUri?: string;
+  /** Auto-release as a delay in seconds after the deadline, instead of autoReleaseAt. Default: 0 (disabled) */
+  autoReleaseAfterDeadline?: number;
+  /** Time (Date or Unix ms) by which the recipient must accept. Default: no limit */
+  acceptDeadline?: Date | number;
+  /** Charge the fee on top of the amount instead of deducting it from the payout. Default: false */
+  feePaidByCreator?: boolean;
+  /** Bonus in SOL paid to the recipient if work is released before the deadline. Default: 0 */
+  earlyBonus?: number;
+  /** Time (Date or Unix ms) after which disputes can no longer be opened. Must not be before deadline. Default: no limit */
+  disputeDeadline?: Date | number;
 }
 
 /**
@@ -70,6 +90,23 @@ export interface SolanaEscrowOptions {
   feeRecipient?: string;
 }
 
+/**
+ * Convert a Date or Unix ms timestamp to on-chain Unix seconds (0 when unset)
+ */
+function toUnixSeconds(value?: Date | number): BN {
+  if (value === undefined) return new BN(0);
+  return new BN(Math.floor((value instanceof Date ? value.getTime() : value) / 1000));
+}
+
+/**
+ * Hash of an escrow's terms as stored on-chain (all zeros when there are none)
+ */
+export function hashTerms(terms?: string): number[] {
+  return terms
+    ? Array.from(createHash('sha256').update(terms).digest())
+    : Array(32).fill(0);
+}
+
 /**
  * Derive PDA for an escrow account
  */
@@ -87,6 +124,35 @@ export function deriveEscrowPda(
   );
 }
 
+/**
+ * Derive PDA for the protocol config account
+ */
+export function deriveConfigPda(
+  programId: PublicKey = ESCROW_PROGRAM_ID,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from('config')], programId);
+}
+
+/**
+ * Derive PDA for the receipt holding an escrow's arbitration deposit
+ */
+export function deriveBondReceiptPda(
+  escrowPda: PublicKey,
+  programId: PublicKey = ESCROW_PROGRAM_ID,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from('bond'), escrowPda.toBuffer()], programId);
+}
+
+/**
+ * Derive PDA for a creator's escrow counter
+ */
+export function deriveCounterPda(
+  creator: PublicKey,
+  programId: PublicKey = ESCROW_PROGRAM_ID,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from('counter'), creator.toBuffer()], programId);
+}
+
 /**
  * Derive PDA for a reputation account
  */
@@ -100,6 +166,19 @@ export function deriveReputationPda(
   );
 }
 
+/**
+ * Derive PDA for the tombstone left when an agent's reputation is closed
+ */
+export function deriveReputationTombstonePda(
+  agent: PublicKey,
+  programId: PublicKey = ESCROW_PROGRAM_ID,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('reputation_tombstone'), agent.toBuffer()],
+    programId,
+  );
+}
+
 /**
  * Derive PDA for a milestone escrow account
  */
@@ -301,15 +380,9 @@ export class SolanaEscrow {
     const program = this.getProgram();
 
     const amountLamports = new BN(Math.round(params.amount * LAMPORTS_PER_SOL));
-    const deadlineUnix = new BN(
-      Math.floor(
-        (params.deadline instanceof Date ? params.deadline.getTime() : params.deadline) / 1000,
-      ),
-    );
+    const deadlineUnix = toUnixSeconds(params.deadline);
 
-    const termsHash = params.terms
-      ? Array.from(createHash('sha256').update(params.terms).digest())
-      : Array(32).fill(0);
+    const termsHash = hashTerms(params.terms);
 
     // Random escrow ID to avoid collisions
     const escrowIdBytes = new Uint8Array(8);
@@ -325,13 +398,7 @@ export class SolanaEscrow {
 
     const [escrowPda] = deriveEscrowPda(this.signer.publicKey, escrowId);
 
-    const autoReleaseUnix = params.autoReleaseAt
-      ? new BN(
-          Math.floor(
-            (params.autoReleaseAt instanceof Date ? params.autoReleaseAt.getTime() : params.autoReleaseAt) / 1000,
-          ),
-        )
-      : new BN(0);
+    const autoReleaseUnix = toUnixSeconds(params.autoReleaseAt);
 
     // Derive reputation PDAs (optional accounts - pass if they exist)
     const [creatorRepPda] = deriveReputationPda(this.signer.publicKey);
@@ -342,13 +409,14 @@ export class SolanaEscrow {
     const recipientRepExists = await this.accountExists(recipientRepPda);
 
     // Build accounts object with optional reputation accounts
-    const accounts: Record<string, PublicKey> = {
+    const accounts: Record<string, PublicKey | null> = {
       escrowAccount: escrowPda,
       creator: this.signer.publicKey,
       recipient: recipientPubkey,
       arbiter: arbiterPubkey,
       feeRecipient: feeRecipientPubkey,
       systemProgram: SystemProgram.programId,
+      config: await this.configIfInitialized(),
     };
 
     if (creatorRepExists) {
@@ -359,7 +427,25 @@ export class SolanaEscrow {
     }
 
     await program.methods
-      .createEscrow(escrowId, amountLamports, deadlineUnix, termsHash, feeBasisPoints, autoReleaseUnix)
+      .createEscrow(
+        escrowId,
+        amountLamports,
+        deadlineUnix,
+        termsHash,
+        feeBasisPoints,
+        autoReleaseUnix,
+        new BN(params.refundGraceSeconds ?? 0),
+        0, // referrer_fee_bps: no referrer
+        new BN(params.postReleaseWindowSeconds ?? 0),
+        toUnixSeconds(params.notBefore),
+        params.category ?? 0,
+        params.metadataUri ?? '',
+        new BN(params.autoReleaseAfterDeadline ?? 0),
+        toUnixSeconds(params.acceptDeadline),
+        params.feePaidByCreator ?? false,
+        new BN(Math.round((params.earlyBonus ?? 0) * LAMPORTS_PER_SOL)),
+        toUnixSeconds(params.disputeDeadline),
+      )
       .accounts(accounts)
       .rpc();
 
@@ -378,18 +464,47 @@ export class SolanaEscrow {
     }
   }
 
+  /**
+   * The config PDA if it has been initialized, or null to leave out the
+   * optional config account on create and release instructions
+   */
+  private async configIfInitialized(): Promise<PublicKey | null> {
+    const [configPda] = deriveConfigPda();
+    return (await this.accountExists(configPda)) ? configPda : null;
+  }
+
+  /**
+   * Accounts a creator-side refund must name: the sponsor that funded the
+   * escrow, or the creator's refund destination if it was changed
+   */
+  private refundAccounts(escrowData: any): Record<string, PublicKey> {
+    const sponsor = escrowData.sponsor as PublicKey;
+    if (!sponsor.equals(PublicKey.default)) {
+      return { sponsor };
+    }
+    const refundDestination = escrowData.refundDestination as PublicKey;
+    if (!refundDestination.equals(escrowData.creator as PublicKey)) {
+      return { refundDestination };
+    }
+    return {};
+  }
+
   /**
    * Accept a task as the executor
    *
+   * The program only accepts if `terms` hashes to the escrow's terms hash, so
+   * the executor attests to the exact agreement the creator posted.
+   *
    * @param escrowId - The escrow PDA address
+   * @param terms - The terms the escrow was created with (omit if it had none)
    * @returns Transaction signature
    */
-  async accept(escrowId: string): Promise<string> {
+  async accept(escrowId: string, terms?: string): Promise<string> {
     const program = this.getProgram();
     const escrowPubkey = new PublicKey(escrowId);
 
     const sig = await program.methods
-      .acceptTask()
+      .acceptTask(hashTerms(terms))
       .accounts({
         escrowAccount: escrowPubkey,
         recipient: this.signer.publicKey,
@@ -426,11 +541,13 @@ export class SolanaEscrow {
     const recipientRepExists = await this.accountExists(recipientRepPda);
 
     // Build accounts object with optional reputation accounts
-    const accounts: Record<string, PublicKey> = {
+    const accounts: Record<string, PublicKey | null> = {
       escrowAccount: escrowPubkey,
       creator: this.signer.publicKey,
       recipient: recipientPubkey,
       feeRecipient: escrowData.feeRecipient as PublicKey,
+      config: await this.configIfInitialized(),
+      escrowCounter: deriveCounterPda(creatorPubkey)[0],
     };
 
     if (creatorRepExists) {
@@ -460,12 +577,15 @@ export class SolanaEscrow {
   async refund(escrowId: string): Promise<string> {
     const program = this.getProgram();
     const escrowPubkey = new PublicKey(escrowId);
+    const escrowData = await program.account.escrowAccount.fetch(escrowPubkey);
 
     const sig = await program.methods
       .requestRefund()
       .accounts({
         escrowAccount: escrowPubkey,
         creator: this.signer.publicKey,
+        escrowCounter: deriveCounterPda(this.signer.publicKey)[0],
+        ...this.refundAccounts(escrowData),
       })
       .rpc();
 
@@ -495,12 +615,14 @@ export class SolanaEscrow {
     const recipientRepExists = await this.accountExists(recipientRepPda);
 
     // Build accounts object with optional reputation accounts
-    const accounts: Record<string, PublicKey> = {
+    const accounts: Record<string, PublicKey | null> = {
       escrowAccount: escrowPubkey,
       caller: this.signer.publicKey,
       creator: creatorPubkey,
       recipient: recipientPubkey,
       feeRecipient: escrowData.feeRecipient as PublicKey,
+      config: await this.configIfInitialized(),
+      escrowCounter: deriveCounterPda(creatorPubkey)[0],
     };
 
     if (creatorRepExists) {
@@ -581,7 +703,7 @@ export class SolanaEscrow {
     const accounts = await program.account.escrowAccount.all([
       {
         memcmp: {
-          offset: 8, // After discriminator: creator is first field
+          offset: 8 + 1, // After discriminator + version byte: creator
           bytes: this.signer.publicKey.toBase58(),
         },
       },
@@ -599,7 +721,7 @@ export class SolanaEscrow {
     const accounts = await program.account.escrowAccount.all([
       {
         memcmp: {
-          offset: 8 + 32, // After discriminator + creator: recipient is second field
+          offset: 8 + 1 + 32, // After discriminator + version byte + creator: recipient
           bytes: this.signer.publicKey.toBase58(),
         },
       },
@@ -624,6 +746,7 @@ export class SolanaEscrow {
       .accounts({
         reputationAccount: reputationPda,
         agent: agentPubkey,
+        tombstone: deriveReputationTombstonePda(agentPubkey)[0],
         payer: this.signer.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -676,9 +799,10 @@ export class SolanaEscrow {
    *
    * @param escrowId - The escrow PDA address
    * @param winner - 'creator' or 'recipient'
+   * @param resolution - Optional resolution note; its SHA256 hash is emitted in the DisputeResolved event
    * @returns Transaction signature
    */
-  async resolveDispute(escrowId: string, winner: DisputeWinner): Promise<string> {
+  async resolveDispute(escrowId: string, winner: DisputeWinner, resolution?: string): Promise<string> {
     const program = this.getProgram();
     const escrowPubkey = new PublicKey(escrowId);
     const escrowData = await program.account.escrowAccount.fetch(escrowPubkey);
@@ -698,15 +822,25 @@ export class SolanaEscrow {
       creator: creatorPubkey,
       recipient: recipientPubkey,
       feeRecipient: feeRecipientPubkey,
+      config: deriveConfigPda()[0],
+      escrowCounter: deriveCounterPda(creatorPubkey)[0],
+      ...(winner === 'creator' ? this.refundAccounts(escrowData) : {}),
     };
 
     if (creatorRepExists) accounts.creatorReputation = creatorRepPda;
     if (recipientRepExists) accounts.recipientReputation = recipientRepPda;
+    if ((escrowData.arbitrationFee as BN).gtn(0)) {
+      accounts.bondReceipt = deriveBondReceiptPda(escrowPubkey)[0];
+    }
 
     const winnerEnum = winner === 'creator' ? { creator: {} } : { recipient: {} };
 
+    const resolutionHash = resolution
+      ? Array.from(createHash('sha256').update(resolution).digest())
+      : Array(32).fill(0);
+
     const sig = await program.methods
-      .resolveDispute(winnerEnum)
+      .resolveDispute(winnerEnum, resolutionHash)
       .accounts(accounts)
       .rpc();
 
@@ -737,9 +871,7 @@ export class SolanaEscrow {
       ),
     );
 
-    const termsHash = params.terms
-      ? Array.from(createHash('sha256').update(params.terms).digest())
-      : Array(32).fill(0);
+    const termsHash = hashTerms(params.terms);
 
     const escrowIdBytes = new Uint8Array(8);
     crypto.getRandomValues(escrowIdBytes);
@@ -769,6 +901,7 @@ export class SolanaEscrow {
         arbiter: arbiterPubkey,
         feeRecipient: feeRecipientPubkey,
         systemProgram: SystemProgram.programId,
+        config: await this.configIfInitialized(),
       })
       .rpc();
 
@@ -815,6 +948,7 @@ export class SolanaEscrow {
         creator: this.signer.publicKey,
         recipient: escrowData.recipient as PublicKey,
         feeRecipient: escrowData.feeRecipient as PublicKey,
+        config: await this.configIfInitialized(),
       })
       .rpc();
 
@@ -826,14 +960,18 @@ export class SolanaEscrow {
    *
    * @param escrowId - The milestone escrow PDA address
    * @param milestoneIndex - Index of the milestone to dispute (0-9)
+   * @param reason - Optional reason for the dispute (max 64 bytes)
    * @returns Transaction signature
    */
-  async disputeMilestone(escrowId: string, milestoneIndex: number): Promise<string> {
+  async disputeMilestone(escrowId: string, milestoneIndex: number, reason = ''): Promise<string> {
     const program = this.getProgram();
     const escrowPubkey = new PublicKey(escrowId);
 
+    const reasonBytes = Buffer.alloc(64);
+    reasonBytes.write(reason.substring(0, 64));
+
     const sig = await program.methods
-      .disputeMilestone(milestoneIndex)
+      .disputeMilestone(milestoneIndex, Array.from(reasonBytes))
       .accounts({
         escrowAccount: escrowPubkey,
         disputer: this.signer.publicKey,
@@ -927,7 +1065,7 @@ export class SolanaEscrow {
     const accounts = await program.account.milestoneEscrowAccount.all([
       {
         memcmp: {
-          offset: 8, // After discriminator: creator is first field
+          offset: 8 + 1, // After discriminator + version byte: creator
           bytes: this.signer.publicKey.toBase58(),
         },
       },
@@ -34,6 +34,97 @@ export type Escrow = {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "creatorReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Creator's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "recipientReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Recipient's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "referrer",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "sponsor",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Funds the escrow on the creator's behalf (optional - refunds go back to the sponsor)"
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to enforce configured limits)"
+          ]
+        },
+        {
+          "name": "arbiterAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Arbiter's registry account (optional - required if config sets a minimum stake)"
+          ]
+        },
+        {
+          "name": "arbiterReputation",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Arbiter's reputation account (optional - required if config sets a minimum case count)"
+          ]
+        },
+        {
+          "name": "escrowCounter",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Creator's escrow counter (optional - pass to draw a guaranteed-unique escrow_id;",
+            "required if config caps active escrows per creator)"
+          ]
+        },
+        {
+          "name": "globalStats",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol-wide stats (optional - pass to count this escrow in the aggregates)"
+          ]
+        },
+        {
+          "name": "refundDestination",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
         }
       ],
       "args": [
@@ -61,30 +152,88 @@ export type Escrow = {
         {
           "name": "feeBasisPoints",
           "type": "u16"
+        },
+        {
+          "name": "autoReleaseAt",
+          "type": "i64"
+        },
+        {
+          "name": "refundGraceSeconds",
+          "type": "i64"
+        },
+        {
+          "name": "referrerFeeBps",
+          "type": "u16"
+        },
+        {
+          "name": "postReleaseWindowSeconds",
+          "type": "i64"
+        },
+        {
+          "name": "notBefore",
+          "type": "i64"
+        },
+        {
+          "name": "category",
+          "type": "u16"
+        },
+        {
+          "name": "metadataUri",
+          "type": "string"
+        },
+        {
+          "name": "autoReleaseAfterDeadline",
+          "type": "i64"
+        },
+        {
+          "name": "acceptDeadline",
+          "type": "i64"
+        },
+        {
+          "name": "feePaidByCreator",
+          "type": "bool"
+        },
+        {
+          "name": "earlyBonusLamports",
+          "type": "u64"
+        },
+        {
+          "name": "disputeDeadline",
+          "type": "i64"
         }
       ]
     },
     {
-      "name": "acceptTask",
+      "name": "initEscrowCounter",
       "accounts": [
         {
-          "name": "escrowAccount",
+          "name": "escrowCounter",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "recipient",
-          "isMut": false,
+          "name": "creator",
+          "isMut": true,
           "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": []
     },
     {
-      "name": "releasePayment",
+      "name": "initAuditLog",
       "accounts": [
         {
           "name": "escrowAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
           "isMut": true,
           "isSigner": false
         },
@@ -93,6 +242,102 @@ export type Escrow = {
           "isMut": true,
           "isSigner": true
         },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createEscrowBatch",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "arbiter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to enforce configured limits)"
+          ]
+        },
+        {
+          "name": "arbiterAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Arbiter's registry account (optional - required if config sets a minimum stake)"
+          ]
+        },
+        {
+          "name": "escrowCounter",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Creator's escrow counter (optional - pass to draw guaranteed-unique escrow_ids;",
+            "required if config caps active escrows per creator)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "escrows",
+          "type": {
+            "vec": {
+              "defined": "BatchEscrowParams"
+            }
+          }
+        },
+        {
+          "name": "feeBasisPoints",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "autoRelease",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "caller",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Anyone can trigger auto-release and collects the crank reward"
+          ]
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "recipient",
           "isMut": true,
@@ -102,28 +347,126 @@ export type Escrow = {
           "name": "feeRecipient",
           "isMut": true,
           "isSigner": false
+        },
+        {
+          "name": "creatorReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Creator's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "recipientReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Recipient's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to honour the pause switch and pay the crank reward)"
+          ]
+        },
+        {
+          "name": "escrowCounter",
+          "isMut": true,
+          "isSigner": false
         }
       ],
       "args": []
     },
     {
-      "name": "requestRefund",
+      "name": "claimAutoRelease",
       "accounts": [
         {
           "name": "escrowAccount",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "must sign unless the delegate does"
+          ]
+        },
+        {
+          "name": "delegate",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Recipient's delegate key (optional - signs in place of the recipient)"
+          ]
+        },
         {
           "name": "creator",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Creator's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "recipientReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Recipient's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to honour the pause switch)"
+          ]
+        },
+        {
+          "name": "escrowCounter",
+          "isMut": true,
+          "isSigner": false
         }
       ],
       "args": []
     },
     {
-      "name": "dispute",
+      "name": "creatorInactivityRelease",
       "accounts": [
         {
           "name": "escrowAccount",
@@ -131,25 +474,41 @@ export type Escrow = {
           "isSigner": false
         },
         {
-          "name": "disputer",
-          "isMut": false,
+          "name": "recipient",
+          "isMut": true,
           "isSigner": true
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "reason",
-          "type": {
-            "array": [
-              "u8",
-              64
-            ]
-          }
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowCounter",
+          "isMut": true,
+          "isSigner": false
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "resolveDispute",
+      "name": "finalizeEscrow",
       "accounts": [
         {
           "name": "escrowAccount",
@@ -157,9 +516,12 @@ export type Escrow = {
           "isSigner": false
         },
         {
-          "name": "arbiter",
+          "name": "caller",
           "isMut": false,
-          "isSigner": true
+          "isSigner": true,
+          "docs": [
+            "Anyone can finalize once the post-release window has elapsed"
+          ]
         },
         {
           "name": "creator",
@@ -175,132 +537,11728 @@ export type Escrow = {
           "name": "feeRecipient",
           "isMut": true,
           "isSigner": false
+        },
+        {
+          "name": "creatorReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Creator's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "recipientReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Recipient's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to honour the pause switch)"
+          ]
+        },
+        {
+          "name": "escrowCounter",
+          "isMut": true,
+          "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "winner",
+      "args": []
+    },
+    {
+      "name": "acceptTask",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Escrow's audit log (optional - pass to record this step on-chain)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "termsAck",
           "type": {
-            "defined": "DisputeWinner"
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "releasePayment",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Creator's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "recipientReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Recipient's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to honour the pause switch and apply the reputation fee discount)"
+          ]
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Second approver (optional - required if the escrow has a cosigner)"
+          ]
+        },
+        {
+          "name": "payout",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "ratingReceipt",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Rating receipt (optional - pass to let the creator rate the recipient afterwards)"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalStats",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol-wide stats (optional - pass to count this escrow in the aggregates)"
+          ]
+        },
+        {
+          "name": "escrowCounter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Escrow's audit log (optional - pass to record this step on-chain)"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "rateRecipient",
+      "accounts": [
+        {
+          "name": "ratingReceipt",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Closing the receipt is what stops the same escrow being rated twice"
+          ],
+          "relations": [
+            "creator"
+          ]
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipientReputation",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "stars",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "releaseAndRate",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Creator's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "recipientReputation",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Recipient's reputation account, which receives the rating"
+          ]
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to honour the pause switch and apply the reputation fee discount)"
+          ]
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Second approver (optional - required if the escrow has a cosigner)"
+          ]
+        },
+        {
+          "name": "escrowCounter",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "stars",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "releaseWithTip",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Creator's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "recipientReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Recipient's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Second approver (optional - required if the escrow has a cosigner)"
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to honour the pause switch)"
+          ]
+        },
+        {
+          "name": "escrowCounter",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "tip",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "trustedFastRelease",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorReputation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientReputation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Second approver (optional - required if the escrow has a cosigner)"
+          ]
+        },
+        {
+          "name": "escrowCounter",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "requestRefund",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "sponsor",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "refundDestination",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "escrowCounter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Escrow's audit log (optional - pass to record this step on-chain)"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "requestPartialRefund",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "sponsor",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "refundDestination",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "escrowCounter",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "refundAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "amendTerms",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Both parties must sign to change the agreed scope"
+          ]
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newTermsHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "reassignTask",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Both parties must sign to hand the task to another worker"
+          ]
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newRecipient",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "cancelAutoRelease",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Auto-release protects the recipient, so they must co-sign its removal"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "requestRelease",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "delegate",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Recipient's delegate key (optional - signs in place of the recipient)"
+          ]
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Escrow's audit log (optional - pass to record this step on-chain)"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "submitWork",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "delegate",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Recipient's delegate key (optional - signs in place of the recipient)"
+          ]
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Escrow's audit log (optional - pass to record this step on-chain)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "workHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "setDelegate",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "delegate",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "acknowledgeTerms",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "termsAck",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "migrateEscrow",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "discriminator and creator are validated in the handler"
+          ]
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Creator pays the extra rent"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateTokenEscrow",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "discriminator and creator are validated in the handler"
+          ]
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Creator pays the extra rent"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateMilestoneEscrow",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "discriminator and creator are validated in the handler"
+          ]
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Creator pays the extra rent"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateReputation",
+      "accounts": [
+        {
+          "name": "reputationAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "discriminator is validated in the handler"
+          ]
+        },
+        {
+          "name": "agent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Pays the extra rent"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "splitEscrow",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to honour the pause switch and the active escrow cap)"
+          ]
+        },
+        {
+          "name": "escrowCounter",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Creator's escrow counter (optional - pass to draw a guaranteed-unique new_escrow_id;",
+            "required if config caps active escrows per creator)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "newEscrowId",
+          "type": "u64"
+        },
+        {
+          "name": "splitAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "dispute",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "disputer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "disputerReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Disputer's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "globalStats",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol-wide stats (optional - pass to count this escrow in the aggregates)"
+          ]
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Escrow's audit log (optional - pass to record this step on-chain)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "reason",
+          "type": {
+            "array": [
+              "u8",
+              64
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "resolveDispute",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "arbiter",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Receives the arbitration fee, if one was deposited"
+          ]
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sponsor",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "refundDestination",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "creatorReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Creator's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "recipientReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Recipient's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "arbiterAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Arbiter's registry account (optional - pass to track resolved cases)"
+          ]
+        },
+        {
+          "name": "escrowCounter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Protocol config (splits a losing party's arbitration deposit)"
+          ]
+        },
+        {
+          "name": "bondReceipt",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Escrow's bond receipt (optional - required if an arbitration fee was deposited)"
+          ]
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Escrow's audit log (optional - pass to record this step on-chain)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "winner",
+          "type": {
+            "defined": "DisputeWinner"
+          }
+        },
+        {
+          "name": "resolutionHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "resolveDisputesBatch",
+      "accounts": [
+        {
+          "name": "arbiter",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Receives the arbitration fees, if any were deposited"
+          ]
+        },
+        {
+          "name": "arbiterAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Arbiter's registry account (optional - pass to track resolved cases)"
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Protocol config (splits a losing party's arbitration deposit)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "resolutions",
+          "type": {
+            "vec": {
+              "defined": "BatchResolution"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "assignArbiter",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Both parties must sign so neither can pick the arbiter alone"
+          ]
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Protocol config (enforces the minimum arbiter stake and case count)"
+          ]
+        },
+        {
+          "name": "arbiterAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Arbiter's registry account (optional - required if config sets a minimum stake)"
+          ]
+        },
+        {
+          "name": "arbiterReputation",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Arbiter's reputation account (optional - required if config sets a minimum case count)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "arbiter",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "assignRandomArbiter",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Both parties must sign so neither can stack the pool alone"
+          ]
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Protocol config (enforces the minimum arbiter stake and case count)"
+          ]
+        },
+        {
+          "name": "arbiterReputation",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Drawn arbiter's reputation account (optional - required if config sets a",
+            "minimum case count). Checked in the handler, since the draw isn't known",
+            "until the pool has been read."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "requestArbitration",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondReceipt",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Holds the deposit until the dispute is settled"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Either party can put up the arbitration fee"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "arbitrationFee",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "claimBond",
+      "accounts": [
+        {
+          "name": "bondReceipt",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Closing the receipt returns the deposit together with its rent"
+          ],
+          "relations": [
+            "payer"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createTokenEscrow",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "arbiter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "creatorTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to enforce configured limits)"
+          ]
+        },
+        {
+          "name": "mintAllowlist",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Mint allowlist (optional - pass to restrict which mints can back escrows)"
+          ]
+        },
+        {
+          "name": "mintFeeConfig",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Per-mint fee table (optional - required when fee_basis_points is USE_MINT_FEE_BPS)"
+          ]
+        },
+        {
+          "name": "creatorReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Creator's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "recipientReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Recipient's reputation account (optional - pass if tracking reputation)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "escrowId",
+          "type": "u64"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "deadline",
+          "type": "i64"
+        },
+        {
+          "name": "termsHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "feeBasisPoints",
+          "type": "u16"
+        },
+        {
+          "name": "autoReleaseAt",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "acceptTokenTask",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "releaseTokenPayment",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "May be the creator's own token account when the creator is also the",
+            "fee recipient. Every transfer debits the vault and the creator's",
+            "system account only receives rent, so nothing is borrowed twice."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to honour the pause switch)"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "releaseTokenStaged",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "feeTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to honour the pause switch)"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "finalizeTokenRelease",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to honour the pause switch)"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "refundTokenEscrow",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "creatorTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recipientReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Recipient's reputation account (optional - pass to record a missed deadline)"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "recoverFrozenTokenEscrow",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "creatorTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientTokenAccount",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The account release_token_payment would pay into"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "disputeToken",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "disputer",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "reason",
+          "type": {
+            "array": [
+              "u8",
+              64
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "resolveTokenDispute",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "arbiter",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "winner",
+          "type": {
+            "defined": "DisputeWinner"
+          }
+        }
+      ]
+    },
+    {
+      "name": "resolveTokenDisputeSplit",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "arbiter",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "recipientBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "resolveTokenDisputeAdvanced",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "arbiter",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "recipientAmount",
+          "type": "u64"
+        },
+        {
+          "name": "refundAmount",
+          "type": "u64"
+        },
+        {
+          "name": "chargeFee",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "autoReleaseToken",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "caller",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Anyone can trigger auto-release"
+          ]
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to honour the pause switch and pay the crank reward)"
+          ]
+        },
+        {
+          "name": "callerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Caller's token account (optional - pass to collect the crank reward)"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createMilestoneEscrow",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "arbiter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to enforce configured limits)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "escrowId",
+          "type": "u64"
+        },
+        {
+          "name": "deadline",
+          "type": "i64"
+        },
+        {
+          "name": "termsHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "feeBasisPoints",
+          "type": "u16"
+        },
+        {
+          "name": "milestones",
+          "type": {
+            "vec": {
+              "defined": "MilestoneInput"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "acceptMilestoneTask",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptMilestones",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "indices",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "releaseMilestone",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to honour the pause switch)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "milestoneIndex",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "disputeMilestone",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "disputer",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "milestoneIndex",
+          "type": "u8"
+        },
+        {
+          "name": "reason",
+          "type": {
+            "array": [
+              "u8",
+              64
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "resolveMilestoneDispute",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "arbiter",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Creator's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "recipientReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Recipient's reputation account (optional - pass if tracking reputation)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "milestoneIndex",
+          "type": "u8"
+        },
+        {
+          "name": "winner",
+          "type": {
+            "defined": "DisputeWinner"
+          }
+        }
+      ]
+    },
+    {
+      "name": "refundMilestoneEscrow",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createMilestoneEscrowCompact",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to enforce configured limits)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "escrowId",
+          "type": "u64"
+        },
+        {
+          "name": "deadline",
+          "type": "i64"
+        },
+        {
+          "name": "termsHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "feeBasisPoints",
+          "type": "u16"
+        },
+        {
+          "name": "milestones",
+          "type": {
+            "vec": {
+              "defined": "MilestoneInput"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "acceptCompactMilestoneTask",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "releaseCompactMilestone",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to honour the pause switch)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "milestoneIndex",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "refundCompactMilestoneEscrow",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "getClaimable",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": "ClaimableAmounts"
+      }
+    },
+    {
+      "name": "createHybridEscrow",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "arbiter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "creatorTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to enforce configured limits)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "escrowId",
+          "type": "u64"
+        },
+        {
+          "name": "lamportAmount",
+          "type": "u64"
+        },
+        {
+          "name": "tokenAmount",
+          "type": "u64"
+        },
+        {
+          "name": "deadline",
+          "type": "i64"
+        },
+        {
+          "name": "termsHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "feeBasisPoints",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "acceptHybridTask",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "releaseHybrid",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to honour the pause switch)"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "refundHybrid",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "creatorTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createRecurringEscrow",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to enforce configured limits)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "escrowId",
+          "type": "u64"
+        },
+        {
+          "name": "amountPerPeriod",
+          "type": "u64"
+        },
+        {
+          "name": "periodSeconds",
+          "type": "i64"
+        },
+        {
+          "name": "deposit",
+          "type": "u64"
+        },
+        {
+          "name": "feeBasisPoints",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "releasePeriod",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to honour the pause switch)"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "topUpRecurring",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "cancelRecurring",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initReputation",
+      "accounts": [
+        {
+          "name": "reputationAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "agent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tombstone",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initReputationBatch",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "agents",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "reputationCommitment",
+      "accounts": [
+        {
+          "name": "reputationAccount",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "array": [
+          "u8",
+          32
+        ]
+      }
+    },
+    {
+      "name": "reputationScore",
+      "accounts": [
+        {
+          "name": "reputationAccount",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": "u64"
+    },
+    {
+      "name": "closeReputation",
+      "accounts": [
+        {
+          "name": "reputationAccount",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "agent"
+          ]
+        },
+        {
+          "name": "agent",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tombstone",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "mergeReputation",
+      "accounts": [
+        {
+          "name": "oldReputation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newReputation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oldAgent",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "newAgent",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tombstone",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initConfig",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Upgradeable loader state naming the program's upgrade authority"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": "ConfigParams"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateConfig",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "admin"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": "ConfigParams"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPaused",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "admin"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "redirectFees",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "admin"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "newFeeRecipient",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "sweepDust",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "admin"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "orphan",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initFeeVault",
+      "accounts": [
+        {
+          "name": "feeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "admin"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawFees",
+      "accounts": [
+        {
+          "name": "feeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "admin"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initGlobalStats",
+      "accounts": [
+        {
+          "name": "globalStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "admin"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "forceComplete",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "admin"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sponsor",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "refundDestination",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "bondReceipt",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Escrow's bond receipt (optional - required if an arbitration fee was deposited)"
+          ]
+        },
+        {
+          "name": "escrowCounter",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "winner",
+          "type": {
+            "defined": "DisputeWinner"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initMintAllowlist",
+      "accounts": [
+        {
+          "name": "mintAllowlist",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "admin"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addMint",
+      "accounts": [
+        {
+          "name": "mintAllowlist",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "admin"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "mint",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "removeMint",
+      "accounts": [
+        {
+          "name": "mintAllowlist",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "admin"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "mint",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "initMintFeeConfig",
+      "accounts": [
+        {
+          "name": "mintFeeConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "admin"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setMintFee",
+      "accounts": [
+        {
+          "name": "mintFeeConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "admin"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "mint",
+          "type": "publicKey"
+        },
+        {
+          "name": "feeBasisPoints",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "registerArbiter",
+      "accounts": [
+        {
+          "name": "arbiterAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "stake",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "deregisterArbiter",
+      "accounts": [
+        {
+          "name": "arbiterAccount",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "authority"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "EscrowAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "Layout version stamped at creation (see ACCOUNT_VERSION)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "creator",
+            "docs": [
+              "Creator (task poster) pubkey"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "recipient",
+            "docs": [
+              "Recipient (task executor) pubkey"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Amount in lamports held in escrow"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "status",
+            "docs": [
+              "Current status"
+            ],
+            "type": {
+              "defined": "EscrowStatus"
+            }
+          },
+          {
+            "name": "deadline",
+            "docs": [
+              "Deadline as Unix timestamp (seconds)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "termsHash",
+            "docs": [
+              "SHA256 hash of terms/agreement"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "arbiter",
+            "docs": [
+              "Arbiter pubkey (for dispute resolution)"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "feeBasisPoints",
+            "docs": [
+              "Fee in basis points (e.g. 150 = 1.5%)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "feeRecipient",
+            "docs": [
+              "Fee recipient (treasury) pubkey"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Creation timestamp (Unix seconds)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "escrowId",
+            "docs": [
+              "Unique escrow ID"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "disputeReason",
+            "docs": [
+              "Dispute reason (truncated to 64 bytes)"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          },
+          {
+            "name": "autoReleaseAt",
+            "docs": [
+              "Auto-release timestamp (0 = disabled, >0 = unix timestamp when anyone can release)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "refundGraceSeconds",
+            "docs": [
+              "Seconds after deadline before an active escrow can be refunded (0 = immediately)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "referrer",
+            "docs": [
+              "Referrer pubkey (default = no referrer)"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "referrerFeeBps",
+            "docs": [
+              "Referrer fee in basis points, paid out of the escrow on release"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "postReleaseWindowSeconds",
+            "docs": [
+              "Seconds after auto-release during which the creator can still dispute (0 = pay out immediately)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "releasedAt",
+            "docs": [
+              "Auto-release timestamp while payout is held for the post-release window (0 = not released)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "notBefore",
+            "docs": [
+              "Earliest timestamp at which the recipient can accept (0 = immediately)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "prevTermsHash",
+            "docs": [
+              "Terms hash replaced by the last amend_terms (zeroed = never amended)"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "releaseRequested",
+            "docs": [
+              "Set once the recipient signals the work is done via request_release"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "category",
+            "docs": [
+              "Marketplace-defined category tag for off-chain analytics (no on-chain meaning)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "cosigner",
+            "docs": [
+              "Second approver who must co-sign creator releases (default = not required)"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "sponsor",
+            "docs": [
+              "Third party that funded the escrow; refunds go back to them (default = creator funded)"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "arbitrationFee",
+            "docs": [
+              "Arbitration fee deposited after a dispute, held by its BondReceipt and",
+              "paid to the arbiter on resolution"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "arbitrationFeePayer",
+            "docs": [
+              "Party that deposited the arbitration fee (default = none deposited)"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "termsAckedAt",
+            "docs": [
+              "When the recipient acknowledged the terms before accepting (0 = never)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "metadataUri",
+            "docs": [
+              "Off-chain metadata URI (Arweave/IPFS/...), zero-padded UTF-8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                128
+              ]
+            }
+          },
+          {
+            "name": "disputeCount",
+            "docs": [
+              "Times this escrow has entered Disputed"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "acceptedAt",
+            "docs": [
+              "When the recipient accepted the task (0 = not yet accepted)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "delegate",
+            "docs": [
+              "Hot key allowed to act for the recipient; payouts still go to the recipient (default = none)"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "acceptDeadline",
+            "docs": [
+              "Latest time the recipient may accept; the work deadline still governs refunds (0 = deadline)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "feePaidByCreator",
+            "docs": [
+              "Creator funded the protocol fee on top of `amount`, so the recipient is paid in full"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "workHash",
+            "docs": [
+              "Hash of the delivered work recorded by the recipient via submit_work (zeroed = not submitted)"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "earlyBonusLamports",
+            "docs": [
+              "Creator-prefunded bonus for releasing early; decays linearly to zero at the deadline"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "disputeDeadline",
+            "docs": [
+              "Last moment a new dispute may be opened; bounds the recipient's liability after delivery (0 = unbounded)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "refundDestination",
+            "docs": [
+              "Wallet that receives request_refund payouts for creator-funded escrows (defaults to creator)"
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "EscrowCounter",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "docs": [
+              "Creator whose escrow ids this counter issues"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "nextId",
+            "docs": [
+              "Next escrow id to use"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "activeEscrows",
+            "docs": [
+              "SOL escrows opened with this counter that have not yet closed"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TokenEscrowAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "Layout version stamped at creation (see ACCOUNT_VERSION)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "creator",
+            "docs": [
+              "Creator (task poster) pubkey"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "recipient",
+            "docs": [
+              "Recipient (task executor) pubkey"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "SPL token mint address"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Amount in token smallest units"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "status",
+            "docs": [
+              "Current status"
+            ],
+            "type": {
+              "defined": "EscrowStatus"
+            }
+          },
+          {
+            "name": "deadline",
+            "docs": [
+              "Deadline as Unix timestamp (seconds)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "termsHash",
+            "docs": [
+              "SHA256 hash of terms/agreement"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "arbiter",
+            "docs": [
+              "Arbiter pubkey (for dispute resolution)"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "feeBasisPoints",
+            "docs": [
+              "Fee in basis points (e.g. 10 = 0.1%)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "feeRecipient",
+            "docs": [
+              "Fee recipient (treasury) pubkey"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Creation timestamp (Unix seconds)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "escrowId",
+            "docs": [
+              "Unique escrow ID"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "disputeReason",
+            "docs": [
+              "Dispute reason (truncated to 64 bytes)"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          },
+          {
+            "name": "autoReleaseAt",
+            "docs": [
+              "Auto-release timestamp (0 = disabled)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "acceptedAt",
+            "docs": [
+              "When the recipient accepted the task (0 = not yet accepted)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "frozenReportedAt",
+            "docs": [
+              "When the creator first reported the recipient's token account frozen (0 = never)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "stagedAmount",
+            "docs": [
+              "Recipient share awaiting finalize_token_release after a staged release (0 = not staged)"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "HybridEscrowAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "Layout version stamped at creation (see ACCOUNT_VERSION)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "creator",
+            "docs": [
+              "Creator (task poster) pubkey"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "recipient",
+            "docs": [
+              "Recipient (task executor) pubkey"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "SPL token mint of the bonus leg"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "lamportAmount",
+            "docs": [
+              "Base payment in lamports held in this account"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "tokenAmount",
+            "docs": [
+              "Token bonus in smallest units held in the vault"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "status",
+            "docs": [
+              "Current status"
+            ],
+            "type": {
+              "defined": "EscrowStatus"
+            }
+          },
+          {
+            "name": "deadline",
+            "docs": [
+              "Deadline as Unix timestamp (seconds)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "termsHash",
+            "docs": [
+              "SHA256 hash of terms/agreement"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "arbiter",
+            "docs": [
+              "Arbiter pubkey (for dispute resolution)"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "feeBasisPoints",
+            "docs": [
+              "Fee in basis points, charged on both legs"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "feeRecipient",
+            "docs": [
+              "Fee recipient (treasury) pubkey"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Creation timestamp (Unix seconds)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "escrowId",
+            "docs": [
+              "Unique escrow ID"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RecurringEscrowAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "Layout version stamped at creation (see ACCOUNT_VERSION)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "creator",
+            "docs": [
+              "Creator (subscriber) pubkey"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "recipient",
+            "docs": [
+              "Recipient (service provider) pubkey"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "amountPerPeriod",
+            "docs": [
+              "Lamports released to the recipient each period"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "periodSeconds",
+            "docs": [
+              "Length of one period in seconds"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "nextReleaseTs",
+            "docs": [
+              "Earliest time the next period can be released"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "balance",
+            "docs": [
+              "Prepaid lamports not yet released (excludes rent)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "periodsReleased",
+            "docs": [
+              "Periods released so far"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "feeBasisPoints",
+            "docs": [
+              "Fee in basis points, charged on each released period"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "feeRecipient",
+            "docs": [
+              "Fee recipient (treasury) pubkey"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Creation timestamp (Unix seconds)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "escrowId",
+            "docs": [
+              "Unique escrow ID"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MilestoneEscrowAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "Layout version stamped at creation (see ACCOUNT_VERSION)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "recipient",
+            "type": "publicKey"
+          },
+          {
+            "name": "totalAmount",
+            "type": "u64"
+          },
+          {
+            "name": "releasedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": "EscrowStatus"
+            }
+          },
+          {
+            "name": "deadline",
+            "type": "i64"
+          },
+          {
+            "name": "termsHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "arbiter",
+            "type": "publicKey"
+          },
+          {
+            "name": "feeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "feeRecipient",
+            "type": "publicKey"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "escrowId",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "milestoneCount",
+            "type": "u8"
+          },
+          {
+            "name": "milestones",
+            "type": {
+              "array": [
+                {
+                  "defined": "Milestone"
+                },
+                10
+              ]
+            }
+          },
+          {
+            "name": "disputeReason",
+            "docs": [
+              "Dispute reason (truncated to 64 bytes)"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          },
+          {
+            "name": "lastDisputeTs",
+            "docs": [
+              "Timestamp of the last milestone dispute resolution (0 = never)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "disputeCount",
+            "docs": [
+              "Milestone disputes opened on this escrow"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "acceptedAt",
+            "docs": [
+              "When the recipient accepted the task (0 = not yet accepted)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "milestoneDisputedBy",
+            "docs": [
+              "Who opened the dispute on each milestone, by index (default = never disputed).",
+              "Kept out of `Milestone` so the milestones array keeps its original layout."
+            ],
+            "type": {
+              "array": [
+                "publicKey",
+                10
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CompactMilestoneEscrowAccount",
+      "docs": [
+        "Milestone escrow sized to its milestones instead of `MAX_MILESTONES`,",
+        "for escrows with only a few. Supports accept, release and refund; use",
+        "`MilestoneEscrowAccount` when milestone disputes are needed."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "Layout version stamped at creation (see ACCOUNT_VERSION)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "recipient",
+            "type": "publicKey"
+          },
+          {
+            "name": "totalAmount",
+            "type": "u64"
+          },
+          {
+            "name": "releasedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": "EscrowStatus"
+            }
+          },
+          {
+            "name": "deadline",
+            "type": "i64"
+          },
+          {
+            "name": "termsHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "feeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "feeRecipient",
+            "type": "publicKey"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "escrowId",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "acceptedAt",
+            "docs": [
+              "When the recipient accepted the task (0 = not yet accepted)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "milestones",
+            "docs": [
+              "Exactly the milestones the escrow was created with"
+            ],
+            "type": {
+              "vec": {
+                "defined": "Milestone"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReputationAccount",
+      "docs": [
+        "Fields are only ever appended; migrate_reputation grows accounts created",
+        "under an older layout."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "agent",
+            "docs": [
+              "Agent's public key"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "escrowsCreated",
+            "docs": [
+              "Number of escrows created"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "escrowsCompleted",
+            "docs": [
+              "Number of escrows completed as creator"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "escrowsReceived",
+            "docs": [
+              "Number of escrows received as recipient"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "tasksCompleted",
+            "docs": [
+              "Number of tasks completed as recipient"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "disputesInitiated",
+            "docs": [
+              "Number of disputes initiated"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "disputesWon",
+            "docs": [
+              "Number of disputes won"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "disputesLost",
+            "docs": [
+              "Number of disputes lost"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "totalVolumeLamports",
+            "docs": [
+              "Total volume in lamports"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastActivity",
+            "docs": [
+              "Last activity timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "tipsReceived",
+            "docs": [
+              "Total tips received as recipient, in lamports"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "ratingSum",
+            "docs": [
+              "Sum of 1-5 star ratings received as recipient"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "ratingCount",
+            "docs": [
+              "Number of ratings received as recipient"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "deadlinesMissed",
+            "docs": [
+              "Escrows refunded to the creator after this agent, as recipient, let the deadline pass"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReputationTombstone",
+      "docs": [
+        "Left behind when an agent's reputation is closed or merged away, so the",
+        "same wallet can't initialize a fresh record and shed its history"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "agent",
+            "docs": [
+              "Agent whose reputation was closed"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "closedAt",
+            "docs": [
+              "When the reputation account was closed"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RatingReceipt",
+      "docs": [
+        "Left behind by a release so the creator can rate the recipient once.",
+        "Closed when the rating is submitted."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "escrow",
+            "docs": [
+              "Released escrow this receipt was issued for"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "creator",
+            "docs": [
+              "Creator entitled to rate"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "recipient",
+            "docs": [
+              "Recipient being rated"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "releasedAt",
+            "docs": [
+              "Release timestamp; the rating window runs from here"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BondReceipt",
+      "docs": [
+        "Holds an escrow's arbitration deposit apart from the escrow, so the",
+        "deposit outlives the escrow closing. Created by request_arbitration."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "escrow",
+            "docs": [
+              "Disputed escrow the deposit was made for"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Party that put up the deposit"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Deposit in lamports (held in this account on top of rent)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "outcome",
+            "docs": [
+              "What the settlement decided for the deposit"
+            ],
+            "type": {
+              "defined": "BondOutcome"
+            }
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "docs": [
+              "Admin allowed to update the config"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "maxAutoReleaseHorizon",
+            "docs": [
+              "Max seconds from creation that auto_release_at may be set (0 = unbounded)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "minArbiterStake",
+            "docs": [
+              "Minimum stake (lamports) a registered arbiter must hold to be chosen (0 = not required)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "feeDiscountMinTasks",
+            "docs": [
+              "Completed tasks a recipient needs for the reputation fee discount (0 = disabled)"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "minFeeBps",
+            "docs": [
+              "Minimum protocol fee in basis points every new escrow must charge (0 = no floor)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "maxDeadlineHorizon",
+            "docs": [
+              "Max seconds from creation that a deadline may be set (0 = unbounded)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "crankRewardLamports",
+            "docs": [
+              "Reward paid to whoever cranks an auto-release, carved out of the protocol",
+              "fee (token escrows pay the same figure in token base units; 0 = disabled)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minMilestoneAmount",
+            "docs": [
+              "Smallest amount (lamports) a single milestone may hold (0 = no minimum)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "paused",
+            "docs": [
+              "Emergency switch: blocks creates and releases, refunds stay open"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "trustedMinTasks",
+            "docs": [
+              "Completed escrows each side of a pair needs for trusted_fast_release (0 = disabled)"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "forceCompleteDelay",
+            "docs": [
+              "Seconds past an escrow's deadline before the admin may force_complete it (0 = disabled)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "creatorInactivityWindow",
+            "docs": [
+              "Seconds past the deadline after which the recipient of a delivered but",
+              "unreleased escrow may pay themselves (0 = disabled; meant to be weeks)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "maxActivePerCreator",
+            "docs": [
+              "Open escrows a creator may have at once; enforced through their escrow counter (0 = unlimited)"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "minArbiterCases",
+            "docs": [
+              "Disputes (won + lost) a chosen arbiter's reputation must show at creation (0 = no minimum)"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "bondToArbiterBps",
+            "docs": [
+              "Share (bps) of a losing party's arbitration deposit the arbiter keeps; the rest goes to",
+              "the winner (0 = disabled, the arbiter keeps the whole deposit)"
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "GlobalStats",
+      "docs": [
+        "Protocol-wide aggregates for SOL escrows. Handlers only update it when a",
+        "client passes the PDA, so the totals cover opted-in traffic only."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "totalEscrowsCreated",
+            "docs": [
+              "Escrows created"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalVolumeLamports",
+            "docs": [
+              "Lamports paid out through release_payment (fees included)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalDisputes",
+            "docs": [
+              "Disputes opened"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalFeesCollected",
+            "docs": [
+              "Protocol fees (lamports) charged on release"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeVault",
+      "docs": [
+        "Protocol-wide SOL fee vault. Escrows opt in by naming this PDA as their",
+        "fee_recipient, so every release credits it directly; the config admin",
+        "sweeps the balance with withdraw_fees."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MintAllowlist",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mints",
+            "docs": [
+              "Mints approved to back token escrows"
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MintFeeConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "fees",
+            "docs": [
+              "Default protocol fee per mint, applied when a creator passes USE_MINT_FEE_BPS"
+            ],
+            "type": {
+              "vec": {
+                "defined": "MintFee"
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ArbiterAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "docs": [
+              "Arbiter's public key"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "stake",
+            "docs": [
+              "Staked bond in lamports (held in this account on top of rent)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "casesResolved",
+            "docs": [
+              "Number of disputes resolved"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "registeredAt",
+            "docs": [
+              "Registration timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "lastActiveAt",
+            "docs": [
+              "Last registration or resolution timestamp (starts the deregister cooldown)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AuditLog",
+      "docs": [
+        "Append-only on-chain trail for a single SOL escrow. Starts empty and is",
+        "grown one entry at a time with realloc; rent for the full capacity is",
+        "deposited up front so appends never need a payer. Outlives the escrow."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "escrow",
+            "docs": [
+              "Escrow this log belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "entries",
+            "docs": [
+              "Recorded entries, oldest first"
+            ],
+            "type": {
+              "vec": {
+                "defined": "AuditEntry"
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "BatchEscrowParams",
+      "docs": [
+        "Per-escrow parameters for `create_escrow_batch`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "escrowId",
+            "type": "u64"
+          },
+          {
+            "name": "recipient",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "deadline",
+            "type": "i64"
+          },
+          {
+            "name": "termsHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "autoReleaseAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MilestoneInput",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "descriptionHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ClaimableAmounts",
+      "docs": [
+        "Returned via return data by `get_claimable`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "claimable",
+            "docs": [
+              "Sum of milestones still pending release"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "released",
+            "docs": [
+              "Total released so far"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ConfigParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxAutoReleaseHorizon",
+            "type": "i64"
+          },
+          {
+            "name": "minArbiterStake",
+            "type": "u64"
+          },
+          {
+            "name": "feeDiscountMinTasks",
+            "type": "u32"
+          },
+          {
+            "name": "minFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "maxDeadlineHorizon",
+            "type": "i64"
+          },
+          {
+            "name": "crankRewardLamports",
+            "type": "u64"
+          },
+          {
+            "name": "minMilestoneAmount",
+            "type": "u64"
+          },
+          {
+            "name": "trustedMinTasks",
+            "type": "u32"
+          },
+          {
+            "name": "forceCompleteDelay",
+            "type": "i64"
+          },
+          {
+            "name": "creatorInactivityWindow",
+            "type": "i64"
+          },
+          {
+            "name": "maxActivePerCreator",
+            "type": "u32"
+          },
+          {
+            "name": "minArbiterCases",
+            "type": "u32"
+          },
+          {
+            "name": "bondToArbiterBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BatchResolution",
+      "docs": [
+        "Outcome of one escrow in a batch resolution"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "winner",
+            "type": {
+              "defined": "DisputeWinner"
+            }
+          },
+          {
+            "name": "resolutionHash",
+            "docs": [
+              "Hash of the off-chain ruling, as passed to resolve_dispute"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "Milestone",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": "MilestoneStatus"
+            }
+          },
+          {
+            "name": "descriptionHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "MintFee",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "feeBasisPoints",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AuditEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "timestamp",
+            "type": "i64"
+          },
+          {
+            "name": "action",
+            "docs": [
+              "AuditAction discriminant"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "actor",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReputationChangeReason",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "EscrowCreated"
+          },
+          {
+            "name": "EscrowReceived"
+          },
+          {
+            "name": "EscrowCompleted"
+          },
+          {
+            "name": "TaskCompleted"
+          },
+          {
+            "name": "DisputeInitiated"
+          },
+          {
+            "name": "DisputeWon"
+          },
+          {
+            "name": "DisputeLost"
+          },
+          {
+            "name": "TipReceived"
+          },
+          {
+            "name": "RatingReceived"
+          },
+          {
+            "name": "DeadlineMissed"
+          },
+          {
+            "name": "Merged"
+          }
+        ]
+      }
+    },
+    {
+      "name": "DisputeWinner",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Creator"
+          },
+          {
+            "name": "Recipient"
+          }
+        ]
+      }
+    },
+    {
+      "name": "EscrowStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Created"
+          },
+          {
+            "name": "Active"
+          },
+          {
+            "name": "Completed"
+          },
+          {
+            "name": "Disputed"
+          },
+          {
+            "name": "Refunded"
+          },
+          {
+            "name": "Cancelled"
+          },
+          {
+            "name": "Resolved"
+          },
+          {
+            "name": "Completing"
+          },
+          {
+            "name": "Resolving"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MilestoneStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Pending"
+          },
+          {
+            "name": "Released"
+          },
+          {
+            "name": "Disputed"
+          },
+          {
+            "name": "Accepted"
+          },
+          {
+            "name": "Refunded"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BondOutcome",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Pending"
+          },
+          {
+            "name": "Refundable"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AuditAction",
+      "docs": [
+        "Lifecycle step recorded in an AuditLog entry"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Created"
+          },
+          {
+            "name": "Accepted"
+          },
+          {
+            "name": "WorkSubmitted"
+          },
+          {
+            "name": "ReleaseRequested"
+          },
+          {
+            "name": "Released"
+          },
+          {
+            "name": "Disputed"
+          },
+          {
+            "name": "Resolved"
+          },
+          {
+            "name": "Refunded"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "ReputationUpdated",
+      "fields": [
+        {
+          "name": "agent",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "reason",
+          "type": {
+            "defined": "ReputationChangeReason"
+          },
+          "index": false
+        },
+        {
+          "name": "escrowsCreated",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "escrowsCompleted",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "escrowsReceived",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "tasksCompleted",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "disputesInitiated",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "disputesWon",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "disputesLost",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "totalVolumeLamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "tipsReceived",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "ratingSum",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "ratingCount",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "deadlinesMissed",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EscrowCreated",
+      "fields": [
+        {
+          "name": "escrow",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipient",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "deadline",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "category",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TaskAccepted",
+      "fields": [
+        {
+          "name": "escrow",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipient",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "acceptedAt",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "DisputeOpened",
+      "fields": [
+        {
+          "name": "escrow",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "disputer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "disputeCount",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "DisputeResolved",
+      "fields": [
+        {
+          "name": "escrow",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "arbiter",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "winner",
+          "type": {
+            "defined": "DisputeWinner"
+          },
+          "index": false
+        },
+        {
+          "name": "resolutionHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ReleaseRequested",
+      "fields": [
+        {
+          "name": "escrow",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipient",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "WorkSubmitted",
+      "fields": [
+        {
+          "name": "escrow",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipient",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "workHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AutoReleased",
+      "fields": [
+        {
+          "name": "escrow",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "caller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipient",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ForceCompleted",
+      "fields": [
+        {
+          "name": "escrow",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "winner",
+          "type": {
+            "defined": "DisputeWinner"
+          },
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TokenEscrowRefunded",
+      "fields": [
+        {
+          "name": "escrow",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "EscrowStatus"
+          },
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "InvalidStatus",
+      "msg": "Escrow is not in the expected status for this operation"
+    },
+    {
+      "code": 6001,
+      "name": "UnauthorizedCreator",
+      "msg": "Only the creator can perform this action"
+    },
+    {
+      "code": 6002,
+      "name": "UnauthorizedRecipient",
+      "msg": "Only the recipient can perform this action"
+    },
+    {
+      "code": 6003,
+      "name": "UnauthorizedArbiter",
+      "msg": "Only the arbiter can resolve disputes"
+    },
+    {
+      "code": 6004,
+      "name": "DeadlineNotReached",
+      "msg": "Deadline has not passed yet"
+    },
+    {
+      "code": 6005,
+      "name": "DeadlineExpired",
+      "msg": "Deadline has already passed"
+    },
+    {
+      "code": 6006,
+      "name": "FeeTooHigh",
+      "msg": "Fee basis points exceeds maximum (1000 = 10%, protocol + referrer)"
+    },
+    {
+      "code": 6007,
+      "name": "ZeroAmount",
+      "msg": "Amount must be greater than zero"
+    },
+    {
+      "code": 6008,
+      "name": "Overflow",
+      "msg": "Arithmetic overflow"
+    },
+    {
+      "code": 6009,
+      "name": "UnauthorizedDisputer",
+      "msg": "Only creator or recipient can dispute"
+    },
+    {
+      "code": 6010,
+      "name": "InvalidAutoRelease",
+      "msg": "Auto-release timestamp must be after deadline"
+    },
+    {
+      "code": 6011,
+      "name": "AutoReleaseNotEnabled",
+      "msg": "Auto-release is not enabled for this escrow"
+    },
+    {
+      "code": 6012,
+      "name": "AutoReleaseNotReady",
+      "msg": "Auto-release timestamp has not been reached yet"
+    },
+    {
+      "code": 6013,
+      "name": "TooManyMilestones",
+      "msg": "Too many milestones (max 10)"
+    },
+    {
+      "code": 6014,
+      "name": "MilestoneAmountMismatch",
+      "msg": "Milestone amounts must sum to total amount"
+    },
+    {
+      "code": 6015,
+      "name": "InvalidMilestoneIndex",
+      "msg": "Invalid milestone index"
+    },
+    {
+      "code": 6016,
+      "name": "MilestoneAlreadyReleased",
+      "msg": "Milestone already released"
+    },
+    {
+      "code": 6017,
+      "name": "MilestoneNotPending",
+      "msg": "Milestone is not in pending status"
+    },
+    {
+      "code": 6018,
+      "name": "RefundExceedsAmount",
+      "msg": "Refund amount exceeds escrowed amount"
+    },
+    {
+      "code": 6019,
+      "name": "InvalidRefundGrace",
+      "msg": "Refund grace period cannot be negative"
+    },
+    {
+      "code": 6020,
+      "name": "InvalidReferrer",
+      "msg": "Referrer account does not match the escrow referrer"
+    },
+    {
+      "code": 6021,
+      "name": "InvalidPostReleaseWindow",
+      "msg": "Post-release window cannot be negative"
+    },
+    {
+      "code": 6022,
+      "name": "PostReleaseWindowClosed",
+      "msg": "Post-release dispute window has closed"
+    },
+    {
+      "code": 6023,
+      "name": "PostReleaseWindowOpen",
+      "msg": "Post-release dispute window has not elapsed yet"
+    },
+    {
+      "code": 6024,
+      "name": "AutoReleaseTooFar",
+      "msg": "Auto-release timestamp exceeds the configured maximum horizon"
+    },
+    {
+      "code": 6025,
+      "name": "UnauthorizedAdmin",
+      "msg": "Only the config admin can perform this action"
+    },
+    {
+      "code": 6026,
+      "name": "InvalidConfig",
+      "msg": "Invalid config value"
+    },
+    {
+      "code": 6027,
+      "name": "SelfEscrow",
+      "msg": "Creator, recipient and arbiter must be distinct parties"
+    },
+    {
+      "code": 6028,
+      "name": "InsufficientArbiterStake",
+      "msg": "Arbiter is not registered with the minimum required stake"
+    },
+    {
+      "code": 6029,
+      "name": "ArbiterCooldownActive",
+      "msg": "Arbiter deregistration cooldown has not elapsed"
+    },
+    {
+      "code": 6030,
+      "name": "MilestoneDisputed",
+      "msg": "Milestone is under dispute"
+    },
+    {
+      "code": 6031,
+      "name": "NotYetStarted",
+      "msg": "Escrow cannot be accepted before its start time"
+    },
+    {
+      "code": 6032,
+      "name": "InvalidStartTime",
+      "msg": "Start time must be before the deadline"
+    },
+    {
+      "code": 6033,
+      "name": "InvalidBatchSize",
+      "msg": "Batch must contain between 1 and 8 escrows"
+    },
+    {
+      "code": 6034,
+      "name": "BatchAccountMismatch",
+      "msg": "Remaining accounts do not match the batch escrow PDAs"
+    },
+    {
+      "code": 6035,
+      "name": "MintNotAllowed",
+      "msg": "Mint is not on the allowlist"
+    },
+    {
+      "code": 6036,
+      "name": "MintAlreadyAllowed",
+      "msg": "Mint is already on the allowlist"
+    },
+    {
+      "code": 6037,
+      "name": "MintAllowlistFull",
+      "msg": "Mint allowlist is full"
+    },
+    {
+      "code": 6038,
+      "name": "DisputeCooldown",
+      "msg": "A milestone dispute was resolved too recently"
+    },
+    {
+      "code": 6039,
+      "name": "InvalidSplit",
+      "msg": "Split basis points cannot exceed 10000"
+    },
+    {
+      "code": 6040,
+      "name": "EscrowIdMismatch",
+      "msg": "Escrow id does not match the creator's counter"
+    },
+    {
+      "code": 6041,
+      "name": "ConflictedArbiter",
+      "msg": "Arbiter cannot be a party to the escrow"
+    },
+    {
+      "code": 6042,
+      "name": "FeeTooLow",
+      "msg": "Fee is below the configured minimum"
+    },
+    {
+      "code": 6043,
+      "name": "PayoutNotAuthorized",
+      "msg": "Recipient must sign to redirect the payout"
+    },
+    {
+      "code": 6044,
+      "name": "DeadlineTooFar",
+      "msg": "Deadline exceeds the configured maximum horizon"
+    },
+    {
+      "code": 6045,
+      "name": "TermsHashMismatch",
+      "msg": "Acknowledged terms hash does not match the escrow terms"
+    },
+    {
+      "code": 6046,
+      "name": "ArbiterAlreadyAssigned",
+      "msg": "Escrow already has an arbiter"
+    },
+    {
+      "code": 6047,
+      "name": "InvalidArbiter",
+      "msg": "Arbiter must be a real account"
+    },
+    {
+      "code": 6048,
+      "name": "AccountNotOrphaned",
+      "msg": "Account still holds live program data"
+    },
+    {
+      "code": 6049,
+      "name": "CosignerRequired",
+      "msg": "Release requires the escrow cosigner's signature"
+    },
+    {
+      "code": 6050,
+      "name": "InvalidSponsor",
+      "msg": "Sponsor account does not match the escrow sponsor"
+    },
+    {
+      "code": 6051,
+      "name": "MilestoneCountMismatch",
+      "msg": "Milestone count does not match the populated milestones"
+    },
+    {
+      "code": 6052,
+      "name": "ArbitrationAlreadyRequested",
+      "msg": "Arbitration fee has already been deposited"
+    },
+    {
+      "code": 6053,
+      "name": "TermsNotAcknowledged",
+      "msg": "Recipient has not acknowledged the escrow terms"
+    },
+    {
+      "code": 6054,
+      "name": "AmountBelowMinimum",
+      "msg": "Milestone amount is below the configured minimum"
+    },
+    {
+      "code": 6055,
+      "name": "ProgramPaused",
+      "msg": "Program is paused"
+    },
+    {
+      "code": 6056,
+      "name": "InvalidRating",
+      "msg": "Rating must be between 1 and 5 stars"
+    },
+    {
+      "code": 6057,
+      "name": "RatingWindowClosed",
+      "msg": "Rating window has closed"
+    },
+    {
+      "code": 6058,
+      "name": "AlreadyReleased",
+      "msg": "Escrow has already been released"
+    },
+    {
+      "code": 6059,
+      "name": "MetadataUriTooLong",
+      "msg": "Metadata URI is too long"
+    },
+    {
+      "code": 6060,
+      "name": "InvalidArbiterPool",
+      "msg": "Arbiter pool is empty, unsorted, too large or contains an invalid entry"
+    },
+    {
+      "code": 6061,
+      "name": "VaultBalanceMismatch",
+      "msg": "Token vault holds less than the escrowed amount"
+    },
+    {
+      "code": 6062,
+      "name": "NotTrustedPair",
+      "msg": "Creator and recipient do not meet the trusted pair criteria"
+    },
+    {
+      "code": 6063,
+      "name": "ForceCompleteLocked",
+      "msg": "force_complete is disabled or the escrow has not been stuck long enough"
+    },
+    {
+      "code": 6064,
+      "name": "MintFeeConfigFull",
+      "msg": "Mint fee config is full"
+    },
+    {
+      "code": 6065,
+      "name": "MintFeeNotConfigured",
+      "msg": "No fee is configured for this mint"
+    },
+    {
+      "code": 6066,
+      "name": "InvalidDelegate",
+      "msg": "Signer is not the recipient's delegate"
+    },
+    {
+      "code": 6067,
+      "name": "InvalidAcceptDeadline",
+      "msg": "Accept deadline must not be after the work deadline"
+    },
+    {
+      "code": 6068,
+      "name": "AcceptDeadlinePassed",
+      "msg": "The accept deadline has passed"
+    },
+    {
+      "code": 6069,
+      "name": "RecipientAccountNotFrozen",
+      "msg": "Recipient token account is not frozen"
+    },
+    {
+      "code": 6070,
+      "name": "FrozenRecoveryNotReady",
+      "msg": "Recipient token account has not been frozen long enough"
+    },
+    {
+      "code": 6071,
+      "name": "DuplicateMilestoneIndex",
+      "msg": "Milestone index appears more than once"
+    },
+    {
+      "code": 6072,
+      "name": "UnsupportedVersion",
+      "msg": "Account layout version is not supported by this program"
+    },
+    {
+      "code": 6073,
+      "name": "SplitAmountTooLarge",
+      "msg": "Split amount must be less than the escrow amount"
+    },
+    {
+      "code": 6074,
+      "name": "InvalidPeriod",
+      "msg": "Period length must be greater than zero"
+    },
+    {
+      "code": 6075,
+      "name": "PeriodNotReached",
+      "msg": "The next period has not started yet"
+    },
+    {
+      "code": 6076,
+      "name": "RecurringBalanceExhausted",
+      "msg": "Prepaid balance does not cover another period"
+    },
+    {
+      "code": 6077,
+      "name": "ReleaseNotRequested",
+      "msg": "Recipient has not requested release"
+    },
+    {
+      "code": 6078,
+      "name": "InactivityWindowOpen",
+      "msg": "Creator inactivity release is disabled or the window has not elapsed"
+    },
+    {
+      "code": 6079,
+      "name": "MintMismatch",
+      "msg": "Token account mint does not match the escrow mint"
+    },
+    {
+      "code": 6080,
+      "name": "TooManyActiveEscrows",
+      "msg": "Creator has reached the maximum number of active escrows"
+    },
+    {
+      "code": 6081,
+      "name": "ReputationSelfMerge",
+      "msg": "Cannot merge a reputation account into itself"
+    },
+    {
+      "code": 6082,
+      "name": "InsufficientReputation",
+      "msg": "Arbiter does not have the required dispute track record"
+    },
+    {
+      "code": 6083,
+      "name": "AuditLogFull",
+      "msg": "Audit log has reached its maximum number of entries"
+    },
+    {
+      "code": 6084,
+      "name": "InvalidFeeRecipient",
+      "msg": "Fee recipient must be set when a fee is charged"
+    },
+    {
+      "code": 6085,
+      "name": "InvalidDisputeDeadline",
+      "msg": "Dispute deadline must not be before the work deadline"
+    },
+    {
+      "code": 6086,
+      "name": "DisputeWindowClosed",
+      "msg": "The dispute window for this escrow has closed"
+    },
+    {
+      "code": 6087,
+      "name": "InvalidRefundDestination",
+      "msg": "Refund destination is missing or does not match the escrow"
+    },
+    {
+      "code": 6088,
+      "name": "ReputationClosed",
+      "msg": "This agent's reputation was closed and cannot be re-initialized"
+    },
+    {
+      "code": 6089,
+      "name": "NotUpgradeAuthority",
+      "msg": "Only the program's upgrade authority can initialize the config"
+    },
+    {
+      "code": 6090,
+      "name": "InvalidBondReceipt",
+      "msg": "Bond receipt is missing or does not match the escrow"
+    },
+    {
+      "code": 6091,
+      "name": "BondNotRefundable",
+      "msg": "Arbitration deposit cannot be claimed back"
+    }
+  ]
+};
+
+export const IDL: Escrow = {
+  "version": "0.1.0",
+  "name": "escrow",
+  "instructions": [
+    {
+      "name": "createEscrow",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "arbiter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "creatorReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Creator's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "recipientReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Recipient's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "referrer",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "sponsor",
+          "isMut": true,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Funds the escrow on the creator's behalf (optional - refunds go back to the sponsor)"
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to enforce configured limits)"
+          ]
+        },
+        {
+          "name": "arbiterAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Arbiter's registry account (optional - required if config sets a minimum stake)"
+          ]
+        },
+        {
+          "name": "arbiterReputation",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Arbiter's reputation account (optional - required if config sets a minimum case count)"
+          ]
+        },
+        {
+          "name": "escrowCounter",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Creator's escrow counter (optional - pass to draw a guaranteed-unique escrow_id;",
+            "required if config caps active escrows per creator)"
+          ]
+        },
+        {
+          "name": "globalStats",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol-wide stats (optional - pass to count this escrow in the aggregates)"
+          ]
+        },
+        {
+          "name": "refundDestination",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "escrowId",
+          "type": "u64"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "deadline",
+          "type": "i64"
+        },
+        {
+          "name": "termsHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "feeBasisPoints",
+          "type": "u16"
+        },
+        {
+          "name": "autoReleaseAt",
+          "type": "i64"
+        },
+        {
+          "name": "refundGraceSeconds",
+          "type": "i64"
+        },
+        {
+          "name": "referrerFeeBps",
+          "type": "u16"
+        },
+        {
+          "name": "postReleaseWindowSeconds",
+          "type": "i64"
+        },
+        {
+          "name": "notBefore",
+          "type": "i64"
+        },
+        {
+          "name": "category",
+          "type": "u16"
+        },
+        {
+          "name": "metadataUri",
+          "type": "string"
+        },
+        {
+          "name": "autoReleaseAfterDeadline",
+          "type": "i64"
+        },
+        {
+          "name": "acceptDeadline",
+          "type": "i64"
+        },
+        {
+          "name": "feePaidByCreator",
+          "type": "bool"
+        },
+        {
+          "name": "earlyBonusLamports",
+          "type": "u64"
+        },
+        {
+          "name": "disputeDeadline",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "initEscrowCounter",
+      "accounts": [
+        {
+          "name": "escrowCounter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initAuditLog",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createEscrowBatch",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "arbiter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to enforce configured limits)"
+          ]
+        },
+        {
+          "name": "arbiterAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Arbiter's registry account (optional - required if config sets a minimum stake)"
+          ]
+        },
+        {
+          "name": "escrowCounter",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Creator's escrow counter (optional - pass to draw guaranteed-unique escrow_ids;",
+            "required if config caps active escrows per creator)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "escrows",
+          "type": {
+            "vec": {
+              "defined": "BatchEscrowParams"
+            }
+          }
+        },
+        {
+          "name": "feeBasisPoints",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "autoRelease",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "caller",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Anyone can trigger auto-release and collects the crank reward"
+          ]
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Creator's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "recipientReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Recipient's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to honour the pause switch and pay the crank reward)"
+          ]
+        },
+        {
+          "name": "escrowCounter",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimAutoRelease",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "must sign unless the delegate does"
+          ]
+        },
+        {
+          "name": "delegate",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Recipient's delegate key (optional - signs in place of the recipient)"
+          ]
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Creator's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "recipientReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Recipient's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to honour the pause switch)"
+          ]
+        },
+        {
+          "name": "escrowCounter",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "creatorInactivityRelease",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrowCounter",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "finalizeEscrow",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "caller",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Anyone can finalize once the post-release window has elapsed"
+          ]
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Creator's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "recipientReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Recipient's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to honour the pause switch)"
+          ]
+        },
+        {
+          "name": "escrowCounter",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptTask",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Escrow's audit log (optional - pass to record this step on-chain)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "termsAck",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "releasePayment",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Creator's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "recipientReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Recipient's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to honour the pause switch and apply the reputation fee discount)"
+          ]
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Second approver (optional - required if the escrow has a cosigner)"
+          ]
+        },
+        {
+          "name": "payout",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "ratingReceipt",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Rating receipt (optional - pass to let the creator rate the recipient afterwards)"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalStats",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol-wide stats (optional - pass to count this escrow in the aggregates)"
+          ]
+        },
+        {
+          "name": "escrowCounter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Escrow's audit log (optional - pass to record this step on-chain)"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "rateRecipient",
+      "accounts": [
+        {
+          "name": "ratingReceipt",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Closing the receipt is what stops the same escrow being rated twice"
+          ],
+          "relations": [
+            "creator"
+          ]
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipientReputation",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "stars",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "releaseAndRate",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Creator's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "recipientReputation",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Recipient's reputation account, which receives the rating"
+          ]
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to honour the pause switch and apply the reputation fee discount)"
+          ]
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Second approver (optional - required if the escrow has a cosigner)"
+          ]
+        },
+        {
+          "name": "escrowCounter",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "stars",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "releaseWithTip",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Creator's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "recipientReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Recipient's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Second approver (optional - required if the escrow has a cosigner)"
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to honour the pause switch)"
+          ]
+        },
+        {
+          "name": "escrowCounter",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "tip",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "trustedFastRelease",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorReputation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientReputation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Second approver (optional - required if the escrow has a cosigner)"
+          ]
+        },
+        {
+          "name": "escrowCounter",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "requestRefund",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "sponsor",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "refundDestination",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "escrowCounter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Escrow's audit log (optional - pass to record this step on-chain)"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "requestPartialRefund",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "sponsor",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "refundDestination",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "escrowCounter",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "refundAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "amendTerms",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Both parties must sign to change the agreed scope"
+          ]
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newTermsHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "reassignTask",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Both parties must sign to hand the task to another worker"
+          ]
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newRecipient",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "cancelAutoRelease",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Auto-release protects the recipient, so they must co-sign its removal"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "requestRelease",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "delegate",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Recipient's delegate key (optional - signs in place of the recipient)"
+          ]
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Escrow's audit log (optional - pass to record this step on-chain)"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "submitWork",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "delegate",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Recipient's delegate key (optional - signs in place of the recipient)"
+          ]
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Escrow's audit log (optional - pass to record this step on-chain)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "workHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "setDelegate",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "delegate",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "acknowledgeTerms",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "termsAck",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "migrateEscrow",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "discriminator and creator are validated in the handler"
+          ]
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Creator pays the extra rent"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateTokenEscrow",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "discriminator and creator are validated in the handler"
+          ]
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Creator pays the extra rent"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateMilestoneEscrow",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "discriminator and creator are validated in the handler"
+          ]
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Creator pays the extra rent"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateReputation",
+      "accounts": [
+        {
+          "name": "reputationAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "discriminator is validated in the handler"
+          ]
+        },
+        {
+          "name": "agent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Pays the extra rent"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "splitEscrow",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to honour the pause switch and the active escrow cap)"
+          ]
+        },
+        {
+          "name": "escrowCounter",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Creator's escrow counter (optional - pass to draw a guaranteed-unique new_escrow_id;",
+            "required if config caps active escrows per creator)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "newEscrowId",
+          "type": "u64"
+        },
+        {
+          "name": "splitAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "dispute",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "disputer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "disputerReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Disputer's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "globalStats",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol-wide stats (optional - pass to count this escrow in the aggregates)"
+          ]
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Escrow's audit log (optional - pass to record this step on-chain)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "reason",
+          "type": {
+            "array": [
+              "u8",
+              64
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "resolveDispute",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "arbiter",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Receives the arbitration fee, if one was deposited"
+          ]
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sponsor",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "refundDestination",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "creatorReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Creator's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "recipientReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Recipient's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "arbiterAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Arbiter's registry account (optional - pass to track resolved cases)"
+          ]
+        },
+        {
+          "name": "escrowCounter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Protocol config (splits a losing party's arbitration deposit)"
+          ]
+        },
+        {
+          "name": "bondReceipt",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Escrow's bond receipt (optional - required if an arbitration fee was deposited)"
+          ]
+        },
+        {
+          "name": "auditLog",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Escrow's audit log (optional - pass to record this step on-chain)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "winner",
+          "type": {
+            "defined": "DisputeWinner"
+          }
+        },
+        {
+          "name": "resolutionHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "resolveDisputesBatch",
+      "accounts": [
+        {
+          "name": "arbiter",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Receives the arbitration fees, if any were deposited"
+          ]
+        },
+        {
+          "name": "arbiterAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Arbiter's registry account (optional - pass to track resolved cases)"
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Protocol config (splits a losing party's arbitration deposit)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "resolutions",
+          "type": {
+            "vec": {
+              "defined": "BatchResolution"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "assignArbiter",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Both parties must sign so neither can pick the arbiter alone"
+          ]
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Protocol config (enforces the minimum arbiter stake and case count)"
+          ]
+        },
+        {
+          "name": "arbiterAccount",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Arbiter's registry account (optional - required if config sets a minimum stake)"
+          ]
+        },
+        {
+          "name": "arbiterReputation",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Arbiter's reputation account (optional - required if config sets a minimum case count)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "arbiter",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "assignRandomArbiter",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Both parties must sign so neither can stack the pool alone"
+          ]
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Protocol config (enforces the minimum arbiter stake and case count)"
+          ]
+        },
+        {
+          "name": "arbiterReputation",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Drawn arbiter's reputation account (optional - required if config sets a",
+            "minimum case count). Checked in the handler, since the draw isn't known",
+            "until the pool has been read."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "requestArbitration",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondReceipt",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Holds the deposit until the dispute is settled"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Either party can put up the arbitration fee"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "arbitrationFee",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "claimBond",
+      "accounts": [
+        {
+          "name": "bondReceipt",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Closing the receipt returns the deposit together with its rent"
+          ],
+          "relations": [
+            "payer"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createTokenEscrow",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "arbiter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "creatorTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to enforce configured limits)"
+          ]
+        },
+        {
+          "name": "mintAllowlist",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Mint allowlist (optional - pass to restrict which mints can back escrows)"
+          ]
+        },
+        {
+          "name": "mintFeeConfig",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Per-mint fee table (optional - required when fee_basis_points is USE_MINT_FEE_BPS)"
+          ]
+        },
+        {
+          "name": "creatorReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Creator's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "recipientReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Recipient's reputation account (optional - pass if tracking reputation)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "escrowId",
+          "type": "u64"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "deadline",
+          "type": "i64"
+        },
+        {
+          "name": "termsHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "feeBasisPoints",
+          "type": "u16"
+        },
+        {
+          "name": "autoReleaseAt",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "acceptTokenTask",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "releaseTokenPayment",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "May be the creator's own token account when the creator is also the",
+            "fee recipient. Every transfer debits the vault and the creator's",
+            "system account only receives rent, so nothing is borrowed twice."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to honour the pause switch)"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "releaseTokenStaged",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "feeTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to honour the pause switch)"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "finalizeTokenRelease",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to honour the pause switch)"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "refundTokenEscrow",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "creatorTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "recipientReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Recipient's reputation account (optional - pass to record a missed deadline)"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "recoverFrozenTokenEscrow",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "creatorTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientTokenAccount",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "The account release_token_payment would pay into"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "disputeToken",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "disputer",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "reason",
+          "type": {
+            "array": [
+              "u8",
+              64
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "resolveTokenDispute",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "arbiter",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "winner",
+          "type": {
+            "defined": "DisputeWinner"
+          }
+        }
+      ]
+    },
+    {
+      "name": "resolveTokenDisputeSplit",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "arbiter",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "recipientBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "resolveTokenDisputeAdvanced",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "arbiter",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "recipientAmount",
+          "type": "u64"
+        },
+        {
+          "name": "refundAmount",
+          "type": "u64"
+        },
+        {
+          "name": "chargeFee",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "autoReleaseToken",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "caller",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Anyone can trigger auto-release"
+          ]
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to honour the pause switch and pay the crank reward)"
+          ]
+        },
+        {
+          "name": "callerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Caller's token account (optional - pass to collect the crank reward)"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createMilestoneEscrow",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "arbiter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to enforce configured limits)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "escrowId",
+          "type": "u64"
+        },
+        {
+          "name": "deadline",
+          "type": "i64"
+        },
+        {
+          "name": "termsHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "feeBasisPoints",
+          "type": "u16"
+        },
+        {
+          "name": "milestones",
+          "type": {
+            "vec": {
+              "defined": "MilestoneInput"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "acceptMilestoneTask",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptMilestones",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "indices",
+          "type": "bytes"
+        }
+      ]
+    },
+    {
+      "name": "releaseMilestone",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to honour the pause switch)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "milestoneIndex",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "disputeMilestone",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "disputer",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "milestoneIndex",
+          "type": "u8"
+        },
+        {
+          "name": "reason",
+          "type": {
+            "array": [
+              "u8",
+              64
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "resolveMilestoneDispute",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "arbiter",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Creator's reputation account (optional - pass if tracking reputation)"
+          ]
+        },
+        {
+          "name": "recipientReputation",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Recipient's reputation account (optional - pass if tracking reputation)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "milestoneIndex",
+          "type": "u8"
+        },
+        {
+          "name": "winner",
+          "type": {
+            "defined": "DisputeWinner"
+          }
+        }
+      ]
+    },
+    {
+      "name": "refundMilestoneEscrow",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createMilestoneEscrowCompact",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to enforce configured limits)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "escrowId",
+          "type": "u64"
+        },
+        {
+          "name": "deadline",
+          "type": "i64"
+        },
+        {
+          "name": "termsHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "feeBasisPoints",
+          "type": "u16"
+        },
+        {
+          "name": "milestones",
+          "type": {
+            "vec": {
+              "defined": "MilestoneInput"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "acceptCompactMilestoneTask",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "releaseCompactMilestone",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to honour the pause switch)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "milestoneIndex",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "refundCompactMilestoneEscrow",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "getClaimable",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": "ClaimableAmounts"
+      }
+    },
+    {
+      "name": "createHybridEscrow",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "arbiter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "creatorTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to enforce configured limits)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "escrowId",
+          "type": "u64"
+        },
+        {
+          "name": "lamportAmount",
+          "type": "u64"
+        },
+        {
+          "name": "tokenAmount",
+          "type": "u64"
+        },
+        {
+          "name": "deadline",
+          "type": "i64"
+        },
+        {
+          "name": "termsHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "feeBasisPoints",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "acceptHybridTask",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "releaseHybrid",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipientTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to honour the pause switch)"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "refundHybrid",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "creatorTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createRecurringEscrow",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to enforce configured limits)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "escrowId",
+          "type": "u64"
+        },
+        {
+          "name": "amountPerPeriod",
+          "type": "u64"
+        },
+        {
+          "name": "periodSeconds",
+          "type": "i64"
+        },
+        {
+          "name": "deposit",
+          "type": "u64"
+        },
+        {
+          "name": "feeBasisPoints",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "releasePeriod",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Protocol config (optional - pass to honour the pause switch)"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "topUpRecurring",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "cancelRecurring",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initReputation",
+      "accounts": [
+        {
+          "name": "reputationAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "agent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tombstone",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initReputationBatch",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "agents",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "reputationCommitment",
+      "accounts": [
+        {
+          "name": "reputationAccount",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "array": [
+          "u8",
+          32
+        ]
+      }
+    },
+    {
+      "name": "reputationScore",
+      "accounts": [
+        {
+          "name": "reputationAccount",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": "u64"
+    },
+    {
+      "name": "closeReputation",
+      "accounts": [
+        {
+          "name": "reputationAccount",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "agent"
+          ]
+        },
+        {
+          "name": "agent",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tombstone",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "mergeReputation",
+      "accounts": [
+        {
+          "name": "oldReputation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newReputation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oldAgent",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "newAgent",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tombstone",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initConfig",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Upgradeable loader state naming the program's upgrade authority"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": "ConfigParams"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateConfig",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "admin"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": "ConfigParams"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPaused",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "admin"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "redirectFees",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "admin"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "newFeeRecipient",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "sweepDust",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "admin"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "orphan",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initFeeVault",
+      "accounts": [
+        {
+          "name": "feeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "admin"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawFees",
+      "accounts": [
+        {
+          "name": "feeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "admin"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initGlobalStats",
+      "accounts": [
+        {
+          "name": "globalStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "admin"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "forceComplete",
+      "accounts": [
+        {
+          "name": "escrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "admin"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sponsor",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "refundDestination",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "bondReceipt",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Escrow's bond receipt (optional - required if an arbitration fee was deposited)"
+          ]
+        },
+        {
+          "name": "escrowCounter",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "winner",
+          "type": {
+            "defined": "DisputeWinner"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initMintAllowlist",
+      "accounts": [
+        {
+          "name": "mintAllowlist",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "admin"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addMint",
+      "accounts": [
+        {
+          "name": "mintAllowlist",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "admin"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "mint",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "removeMint",
+      "accounts": [
+        {
+          "name": "mintAllowlist",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "admin"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "mint",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "initMintFeeConfig",
+      "accounts": [
+        {
+          "name": "mintFeeConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "admin"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setMintFee",
+      "accounts": [
+        {
+          "name": "mintFeeConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "admin"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "mint",
+          "type": "publicKey"
+        },
+        {
+          "name": "feeBasisPoints",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "registerArbiter",
+      "accounts": [
+        {
+          "name": "arbiterAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "stake",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "deregisterArbiter",
+      "accounts": [
+        {
+          "name": "arbiterAccount",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "authority"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "EscrowAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "Layout version stamped at creation (see ACCOUNT_VERSION)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "creator",
+            "docs": [
+              "Creator (task poster) pubkey"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "recipient",
+            "docs": [
+              "Recipient (task executor) pubkey"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Amount in lamports held in escrow"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "status",
+            "docs": [
+              "Current status"
+            ],
+            "type": {
+              "defined": "EscrowStatus"
+            }
+          },
+          {
+            "name": "deadline",
+            "docs": [
+              "Deadline as Unix timestamp (seconds)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "termsHash",
+            "docs": [
+              "SHA256 hash of terms/agreement"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "arbiter",
+            "docs": [
+              "Arbiter pubkey (for dispute resolution)"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "feeBasisPoints",
+            "docs": [
+              "Fee in basis points (e.g. 150 = 1.5%)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "feeRecipient",
+            "docs": [
+              "Fee recipient (treasury) pubkey"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Creation timestamp (Unix seconds)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "escrowId",
+            "docs": [
+              "Unique escrow ID"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "disputeReason",
+            "docs": [
+              "Dispute reason (truncated to 64 bytes)"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          },
+          {
+            "name": "autoReleaseAt",
+            "docs": [
+              "Auto-release timestamp (0 = disabled, >0 = unix timestamp when anyone can release)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "refundGraceSeconds",
+            "docs": [
+              "Seconds after deadline before an active escrow can be refunded (0 = immediately)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "referrer",
+            "docs": [
+              "Referrer pubkey (default = no referrer)"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "referrerFeeBps",
+            "docs": [
+              "Referrer fee in basis points, paid out of the escrow on release"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "postReleaseWindowSeconds",
+            "docs": [
+              "Seconds after auto-release during which the creator can still dispute (0 = pay out immediately)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "releasedAt",
+            "docs": [
+              "Auto-release timestamp while payout is held for the post-release window (0 = not released)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "notBefore",
+            "docs": [
+              "Earliest timestamp at which the recipient can accept (0 = immediately)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "prevTermsHash",
+            "docs": [
+              "Terms hash replaced by the last amend_terms (zeroed = never amended)"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "releaseRequested",
+            "docs": [
+              "Set once the recipient signals the work is done via request_release"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "category",
+            "docs": [
+              "Marketplace-defined category tag for off-chain analytics (no on-chain meaning)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "cosigner",
+            "docs": [
+              "Second approver who must co-sign creator releases (default = not required)"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "sponsor",
+            "docs": [
+              "Third party that funded the escrow; refunds go back to them (default = creator funded)"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "arbitrationFee",
+            "docs": [
+              "Arbitration fee deposited after a dispute, held by its BondReceipt and",
+              "paid to the arbiter on resolution"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "arbitrationFeePayer",
+            "docs": [
+              "Party that deposited the arbitration fee (default = none deposited)"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "termsAckedAt",
+            "docs": [
+              "When the recipient acknowledged the terms before accepting (0 = never)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "metadataUri",
+            "docs": [
+              "Off-chain metadata URI (Arweave/IPFS/...), zero-padded UTF-8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                128
+              ]
+            }
+          },
+          {
+            "name": "disputeCount",
+            "docs": [
+              "Times this escrow has entered Disputed"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "acceptedAt",
+            "docs": [
+              "When the recipient accepted the task (0 = not yet accepted)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "delegate",
+            "docs": [
+              "Hot key allowed to act for the recipient; payouts still go to the recipient (default = none)"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "acceptDeadline",
+            "docs": [
+              "Latest time the recipient may accept; the work deadline still governs refunds (0 = deadline)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "feePaidByCreator",
+            "docs": [
+              "Creator funded the protocol fee on top of `amount`, so the recipient is paid in full"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "workHash",
+            "docs": [
+              "Hash of the delivered work recorded by the recipient via submit_work (zeroed = not submitted)"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "earlyBonusLamports",
+            "docs": [
+              "Creator-prefunded bonus for releasing early; decays linearly to zero at the deadline"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "disputeDeadline",
+            "docs": [
+              "Last moment a new dispute may be opened; bounds the recipient's liability after delivery (0 = unbounded)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "refundDestination",
+            "docs": [
+              "Wallet that receives request_refund payouts for creator-funded escrows (defaults to creator)"
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "EscrowCounter",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "docs": [
+              "Creator whose escrow ids this counter issues"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "nextId",
+            "docs": [
+              "Next escrow id to use"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "activeEscrows",
+            "docs": [
+              "SOL escrows opened with this counter that have not yet closed"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TokenEscrowAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "Layout version stamped at creation (see ACCOUNT_VERSION)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "creator",
+            "docs": [
+              "Creator (task poster) pubkey"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "recipient",
+            "docs": [
+              "Recipient (task executor) pubkey"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "SPL token mint address"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Amount in token smallest units"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "status",
+            "docs": [
+              "Current status"
+            ],
+            "type": {
+              "defined": "EscrowStatus"
+            }
+          },
+          {
+            "name": "deadline",
+            "docs": [
+              "Deadline as Unix timestamp (seconds)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "termsHash",
+            "docs": [
+              "SHA256 hash of terms/agreement"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "arbiter",
+            "docs": [
+              "Arbiter pubkey (for dispute resolution)"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "feeBasisPoints",
+            "docs": [
+              "Fee in basis points (e.g. 10 = 0.1%)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "feeRecipient",
+            "docs": [
+              "Fee recipient (treasury) pubkey"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Creation timestamp (Unix seconds)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "escrowId",
+            "docs": [
+              "Unique escrow ID"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "disputeReason",
+            "docs": [
+              "Dispute reason (truncated to 64 bytes)"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          },
+          {
+            "name": "autoReleaseAt",
+            "docs": [
+              "Auto-release timestamp (0 = disabled)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "acceptedAt",
+            "docs": [
+              "When the recipient accepted the task (0 = not yet accepted)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "frozenReportedAt",
+            "docs": [
+              "When the creator first reported the recipient's token account frozen (0 = never)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "stagedAmount",
+            "docs": [
+              "Recipient share awaiting finalize_token_release after a staged release (0 = not staged)"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "HybridEscrowAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "Layout version stamped at creation (see ACCOUNT_VERSION)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "creator",
+            "docs": [
+              "Creator (task poster) pubkey"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "recipient",
+            "docs": [
+              "Recipient (task executor) pubkey"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "SPL token mint of the bonus leg"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "lamportAmount",
+            "docs": [
+              "Base payment in lamports held in this account"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "tokenAmount",
+            "docs": [
+              "Token bonus in smallest units held in the vault"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "status",
+            "docs": [
+              "Current status"
+            ],
+            "type": {
+              "defined": "EscrowStatus"
+            }
+          },
+          {
+            "name": "deadline",
+            "docs": [
+              "Deadline as Unix timestamp (seconds)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "termsHash",
+            "docs": [
+              "SHA256 hash of terms/agreement"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "arbiter",
+            "docs": [
+              "Arbiter pubkey (for dispute resolution)"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "feeBasisPoints",
+            "docs": [
+              "Fee in basis points, charged on both legs"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "feeRecipient",
+            "docs": [
+              "Fee recipient (treasury) pubkey"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Creation timestamp (Unix seconds)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "escrowId",
+            "docs": [
+              "Unique escrow ID"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RecurringEscrowAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "Layout version stamped at creation (see ACCOUNT_VERSION)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "creator",
+            "docs": [
+              "Creator (subscriber) pubkey"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "recipient",
+            "docs": [
+              "Recipient (service provider) pubkey"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "amountPerPeriod",
+            "docs": [
+              "Lamports released to the recipient each period"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "periodSeconds",
+            "docs": [
+              "Length of one period in seconds"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "nextReleaseTs",
+            "docs": [
+              "Earliest time the next period can be released"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "balance",
+            "docs": [
+              "Prepaid lamports not yet released (excludes rent)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "periodsReleased",
+            "docs": [
+              "Periods released so far"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "feeBasisPoints",
+            "docs": [
+              "Fee in basis points, charged on each released period"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "feeRecipient",
+            "docs": [
+              "Fee recipient (treasury) pubkey"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Creation timestamp (Unix seconds)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "escrowId",
+            "docs": [
+              "Unique escrow ID"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MilestoneEscrowAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "Layout version stamped at creation (see ACCOUNT_VERSION)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "recipient",
+            "type": "publicKey"
+          },
+          {
+            "name": "totalAmount",
+            "type": "u64"
+          },
+          {
+            "name": "releasedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": "EscrowStatus"
+            }
+          },
+          {
+            "name": "deadline",
+            "type": "i64"
+          },
+          {
+            "name": "termsHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "arbiter",
+            "type": "publicKey"
+          },
+          {
+            "name": "feeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "feeRecipient",
+            "type": "publicKey"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "escrowId",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "milestoneCount",
+            "type": "u8"
+          },
+          {
+            "name": "milestones",
+            "type": {
+              "array": [
+                {
+                  "defined": "Milestone"
+                },
+                10
+              ]
+            }
+          },
+          {
+            "name": "disputeReason",
+            "docs": [
+              "Dispute reason (truncated to 64 bytes)"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          },
+          {
+            "name": "lastDisputeTs",
+            "docs": [
+              "Timestamp of the last milestone dispute resolution (0 = never)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "disputeCount",
+            "docs": [
+              "Milestone disputes opened on this escrow"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "acceptedAt",
+            "docs": [
+              "When the recipient accepted the task (0 = not yet accepted)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "milestoneDisputedBy",
+            "docs": [
+              "Who opened the dispute on each milestone, by index (default = never disputed).",
+              "Kept out of `Milestone` so the milestones array keeps its original layout."
+            ],
+            "type": {
+              "array": [
+                "publicKey",
+                10
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CompactMilestoneEscrowAccount",
+      "docs": [
+        "Milestone escrow sized to its milestones instead of `MAX_MILESTONES`,",
+        "for escrows with only a few. Supports accept, release and refund; use",
+        "`MilestoneEscrowAccount` when milestone disputes are needed."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "Layout version stamped at creation (see ACCOUNT_VERSION)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "recipient",
+            "type": "publicKey"
+          },
+          {
+            "name": "totalAmount",
+            "type": "u64"
+          },
+          {
+            "name": "releasedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": "EscrowStatus"
+            }
+          },
+          {
+            "name": "deadline",
+            "type": "i64"
+          },
+          {
+            "name": "termsHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "feeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "feeRecipient",
+            "type": "publicKey"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "escrowId",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "acceptedAt",
+            "docs": [
+              "When the recipient accepted the task (0 = not yet accepted)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "milestones",
+            "docs": [
+              "Exactly the milestones the escrow was created with"
+            ],
+            "type": {
+              "vec": {
+                "defined": "Milestone"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReputationAccount",
+      "docs": [
+        "Fields are only ever appended; migrate_reputation grows accounts created",
+        "under an older layout."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "agent",
+            "docs": [
+              "Agent's public key"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "escrowsCreated",
+            "docs": [
+              "Number of escrows created"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "escrowsCompleted",
+            "docs": [
+              "Number of escrows completed as creator"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "escrowsReceived",
+            "docs": [
+              "Number of escrows received as recipient"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "tasksCompleted",
+            "docs": [
+              "Number of tasks completed as recipient"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "disputesInitiated",
+            "docs": [
+              "Number of disputes initiated"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "disputesWon",
+            "docs": [
+              "Number of disputes won"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "disputesLost",
+            "docs": [
+              "Number of disputes lost"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "totalVolumeLamports",
+            "docs": [
+              "Total volume in lamports"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastActivity",
+            "docs": [
+              "Last activity timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "tipsReceived",
+            "docs": [
+              "Total tips received as recipient, in lamports"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "ratingSum",
+            "docs": [
+              "Sum of 1-5 star ratings received as recipient"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "ratingCount",
+            "docs": [
+              "Number of ratings received as recipient"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "deadlinesMissed",
+            "docs": [
+              "Escrows refunded to the creator after this agent, as recipient, let the deadline pass"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReputationTombstone",
+      "docs": [
+        "Left behind when an agent's reputation is closed or merged away, so the",
+        "same wallet can't initialize a fresh record and shed its history"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "agent",
+            "docs": [
+              "Agent whose reputation was closed"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "closedAt",
+            "docs": [
+              "When the reputation account was closed"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RatingReceipt",
+      "docs": [
+        "Left behind by a release so the creator can rate the recipient once.",
+        "Closed when the rating is submitted."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "escrow",
+            "docs": [
+              "Released escrow this receipt was issued for"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "creator",
+            "docs": [
+              "Creator entitled to rate"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "recipient",
+            "docs": [
+              "Recipient being rated"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "releasedAt",
+            "docs": [
+              "Release timestamp; the rating window runs from here"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BondReceipt",
+      "docs": [
+        "Holds an escrow's arbitration deposit apart from the escrow, so the",
+        "deposit outlives the escrow closing. Created by request_arbitration."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "escrow",
+            "docs": [
+              "Disputed escrow the deposit was made for"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Party that put up the deposit"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Deposit in lamports (held in this account on top of rent)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "outcome",
+            "docs": [
+              "What the settlement decided for the deposit"
+            ],
+            "type": {
+              "defined": "BondOutcome"
+            }
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "docs": [
+              "Admin allowed to update the config"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "maxAutoReleaseHorizon",
+            "docs": [
+              "Max seconds from creation that auto_release_at may be set (0 = unbounded)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "minArbiterStake",
+            "docs": [
+              "Minimum stake (lamports) a registered arbiter must hold to be chosen (0 = not required)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "feeDiscountMinTasks",
+            "docs": [
+              "Completed tasks a recipient needs for the reputation fee discount (0 = disabled)"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "minFeeBps",
+            "docs": [
+              "Minimum protocol fee in basis points every new escrow must charge (0 = no floor)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "maxDeadlineHorizon",
+            "docs": [
+              "Max seconds from creation that a deadline may be set (0 = unbounded)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "crankRewardLamports",
+            "docs": [
+              "Reward paid to whoever cranks an auto-release, carved out of the protocol",
+              "fee (token escrows pay the same figure in token base units; 0 = disabled)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minMilestoneAmount",
+            "docs": [
+              "Smallest amount (lamports) a single milestone may hold (0 = no minimum)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "paused",
+            "docs": [
+              "Emergency switch: blocks creates and releases, refunds stay open"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "trustedMinTasks",
+            "docs": [
+              "Completed escrows each side of a pair needs for trusted_fast_release (0 = disabled)"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "forceCompleteDelay",
+            "docs": [
+              "Seconds past an escrow's deadline before the admin may force_complete it (0 = disabled)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "creatorInactivityWindow",
+            "docs": [
+              "Seconds past the deadline after which the recipient of a delivered but",
+              "unreleased escrow may pay themselves (0 = disabled; meant to be weeks)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "maxActivePerCreator",
+            "docs": [
+              "Open escrows a creator may have at once; enforced through their escrow counter (0 = unlimited)"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "minArbiterCases",
+            "docs": [
+              "Disputes (won + lost) a chosen arbiter's reputation must show at creation (0 = no minimum)"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "bondToArbiterBps",
+            "docs": [
+              "Share (bps) of a losing party's arbitration deposit the arbiter keeps; the rest goes to",
+              "the winner (0 = disabled, the arbiter keeps the whole deposit)"
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "GlobalStats",
+      "docs": [
+        "Protocol-wide aggregates for SOL escrows. Handlers only update it when a",
+        "client passes the PDA, so the totals cover opted-in traffic only."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "totalEscrowsCreated",
+            "docs": [
+              "Escrows created"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalVolumeLamports",
+            "docs": [
+              "Lamports paid out through release_payment (fees included)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalDisputes",
+            "docs": [
+              "Disputes opened"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalFeesCollected",
+            "docs": [
+              "Protocol fees (lamports) charged on release"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
           }
-        }
-      ]
+        ]
+      }
+    },
+    {
+      "name": "FeeVault",
+      "docs": [
+        "Protocol-wide SOL fee vault. Escrows opt in by naming this PDA as their",
+        "fee_recipient, so every release credits it directly; the config admin",
+        "sweeps the balance with withdraw_fees."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MintAllowlist",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mints",
+            "docs": [
+              "Mints approved to back token escrows"
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MintFeeConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "fees",
+            "docs": [
+              "Default protocol fee per mint, applied when a creator passes USE_MINT_FEE_BPS"
+            ],
+            "type": {
+              "vec": {
+                "defined": "MintFee"
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ArbiterAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "docs": [
+              "Arbiter's public key"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "stake",
+            "docs": [
+              "Staked bond in lamports (held in this account on top of rent)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "casesResolved",
+            "docs": [
+              "Number of disputes resolved"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "registeredAt",
+            "docs": [
+              "Registration timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "lastActiveAt",
+            "docs": [
+              "Last registration or resolution timestamp (starts the deregister cooldown)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AuditLog",
+      "docs": [
+        "Append-only on-chain trail for a single SOL escrow. Starts empty and is",
+        "grown one entry at a time with realloc; rent for the full capacity is",
+        "deposited up front so appends never need a payer. Outlives the escrow."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "escrow",
+            "docs": [
+              "Escrow this log belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "entries",
+            "docs": [
+              "Recorded entries, oldest first"
+            ],
+            "type": {
+              "vec": {
+                "defined": "AuditEntry"
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     }
   ],
-  "accounts": [
+  "types": [
     {
-      "name": "escrowAccount",
+      "name": "BatchEscrowParams",
+      "docs": [
+        "Per-escrow parameters for `create_escrow_batch`"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "creator",
+            "name": "escrowId",
+            "type": "u64"
+          },
+          {
+            "name": "recipient",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "deadline",
+            "type": "i64"
+          },
+          {
+            "name": "termsHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "autoReleaseAt",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MilestoneInput",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "descriptionHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ClaimableAmounts",
+      "docs": [
+        "Returned via return data by `get_claimable`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "claimable",
             "docs": [
-              "Creator (task poster) pubkey"
+              "Sum of milestones still pending release"
             ],
-            "type": "publicKey"
+            "type": "u64"
+          },
+          {
+            "name": "released",
+            "docs": [
+              "Total released so far"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ConfigParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxAutoReleaseHorizon",
+            "type": "i64"
+          },
+          {
+            "name": "minArbiterStake",
+            "type": "u64"
+          },
+          {
+            "name": "feeDiscountMinTasks",
+            "type": "u32"
+          },
+          {
+            "name": "minFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "maxDeadlineHorizon",
+            "type": "i64"
+          },
+          {
+            "name": "crankRewardLamports",
+            "type": "u64"
+          },
+          {
+            "name": "minMilestoneAmount",
+            "type": "u64"
+          },
+          {
+            "name": "trustedMinTasks",
+            "type": "u32"
+          },
+          {
+            "name": "forceCompleteDelay",
+            "type": "i64"
+          },
+          {
+            "name": "creatorInactivityWindow",
+            "type": "i64"
+          },
+          {
+            "name": "maxActivePerCreator",
+            "type": "u32"
+          },
+          {
+            "name": "minArbiterCases",
+            "type": "u32"
+          },
+          {
+            "name": "bondToArbiterBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BatchResolution",
+      "docs": [
+        "Outcome of one escrow in a batch resolution"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "winner",
+            "type": {
+              "defined": "DisputeWinner"
+            }
           },
           {
-            "name": "recipient",
+            "name": "resolutionHash",
             "docs": [
-              "Recipient (task executor) pubkey"
+              "Hash of the off-chain ruling, as passed to resolve_dispute"
             ],
-            "type": "publicKey"
-          },
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "Milestone",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
             "name": "amount",
-            "docs": [
-              "Amount in lamports held in escrow"
-            ],
             "type": "u64"
           },
           {
             "name": "status",
-            "docs": [
-              "Current status"
-            ],
             "type": {
-              "defined": "EscrowStatus"
+              "defined": "MilestoneStatus"
             }
           },
           {
-            "name": "deadline",
-            "docs": [
-              "Deadline as Unix timestamp (seconds)"
-            ],
-            "type": "i64"
-          },
-          {
-            "name": "termsHash",
-            "docs": [
-              "SHA256 hash of terms/agreement"
-            ],
+            "name": "descriptionHash",
             "type": {
               "array": [
                 "u8",
                 32
               ]
             }
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "MintFee",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "arbiter",
-            "docs": [
-              "Arbiter pubkey (for dispute resolution)"
-            ],
+            "name": "mint",
             "type": "publicKey"
           },
           {
             "name": "feeBasisPoints",
-            "docs": [
-              "Fee in basis points (e.g. 150 = 1.5%)"
-            ],
             "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AuditEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "timestamp",
+            "type": "i64"
           },
           {
-            "name": "feeRecipient",
+            "name": "action",
             "docs": [
-              "Fee recipient (treasury) pubkey"
+              "AuditAction discriminant"
             ],
+            "type": "u8"
+          },
+          {
+            "name": "actor",
             "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReputationChangeReason",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "EscrowCreated"
           },
           {
-            "name": "createdAt",
-            "docs": [
-              "Creation timestamp (Unix seconds)"
-            ],
-            "type": "i64"
+            "name": "EscrowReceived"
           },
           {
-            "name": "escrowId",
-            "docs": [
-              "Unique escrow ID"
-            ],
-            "type": "u64"
+            "name": "EscrowCompleted"
           },
           {
-            "name": "bump",
-            "docs": [
-              "PDA bump seed"
-            ],
-            "type": "u8"
+            "name": "TaskCompleted"
           },
           {
-            "name": "disputeReason",
-            "docs": [
-              "Dispute reason (truncated to 64 bytes)"
-            ],
-            "type": {
-              "array": [
-                "u8",
-                64
-              ]
-            }
+            "name": "DisputeInitiated"
+          },
+          {
+            "name": "DisputeWon"
+          },
+          {
+            "name": "DisputeLost"
+          },
+          {
+            "name": "TipReceived"
+          },
+          {
+            "name": "RatingReceived"
+          },
+          {
+            "name": "DeadlineMissed"
+          },
+          {
+            "name": "Merged"
           }
         ]
       }
-    }
-  ],
-  "types": [
+    },
     {
       "name": "DisputeWinner",
       "type": {
@@ -340,410 +12298,442 @@ export type Escrow = {
           },
           {
             "name": "Resolved"
+          },
+          {
+            "name": "Completing"
+          },
+          {
+            "name": "Resolving"
           }
         ]
       }
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "InvalidStatus",
-      "msg": "Escrow is not in the expected status for this operation"
     },
     {
-      "code": 6001,
-      "name": "UnauthorizedCreator",
-      "msg": "Only the creator can perform this action"
-    },
-    {
-      "code": 6002,
-      "name": "UnauthorizedRecipient",
-      "msg": "Only the recipient can perform this action"
-    },
-    {
-      "code": 6003,
-      "name": "UnauthorizedArbiter",
-      "msg": "Only the arbiter can resolve disputes"
-    },
-    {
-      "code": 6004,
-      "name": "DeadlineNotReached",
-      "msg": "Deadline has not passed yet"
-    },
-    {
-      "code": 6005,
-      "name": "DeadlineExpired",
-      "msg": "Deadline has already passed"
-    },
-    {
-      "code": 6006,
-      "name": "FeeTooHigh",
-      "msg": "Fee basis points exceeds maximum (1000 = 10%)"
-    },
-    {
-      "code": 6007,
-      "name": "ZeroAmount",
-      "msg": "Amount must be greater than zero"
+      "name": "MilestoneStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Pending"
+          },
+          {
+            "name": "Released"
+          },
+          {
+            "name": "Disputed"
+          },
+          {
+            "name": "Accepted"
+          },
+          {
+            "name": "Refunded"
+          }
+        ]
+      }
     },
     {
-      "code": 6008,
-      "name": "Overflow",
-      "msg": "Arithmetic overflow"
+      "name": "BondOutcome",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Pending"
+          },
+          {
+            "name": "Refundable"
+          }
+        ]
+      }
     },
     {
-      "code": 6009,
-      "name": "UnauthorizedDisputer",
-      "msg": "Only creator or recipient can dispute"
+      "name": "AuditAction",
+      "docs": [
+        "Lifecycle step recorded in an AuditLog entry"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Created"
+          },
+          {
+            "name": "Accepted"
+          },
+          {
+            "name": "WorkSubmitted"
+          },
+          {
+            "name": "ReleaseRequested"
+          },
+          {
+            "name": "Released"
+          },
+          {
+            "name": "Disputed"
+          },
+          {
+            "name": "Resolved"
+          },
+          {
+            "name": "Refunded"
+          }
+        ]
+      }
     }
-  ]
-};
-
-export const IDL: Escrow = {
-  "version": "0.1.0",
-  "name": "escrow",
-  "instructions": [
+  ],
+  "events": [
     {
-      "name": "createEscrow",
-      "accounts": [
+      "name": "ReputationUpdated",
+      "fields": [
         {
-          "name": "escrowAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "agent",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "creator",
-          "isMut": true,
-          "isSigner": true
+          "name": "reason",
+          "type": {
+            "defined": "ReputationChangeReason"
+          },
+          "index": false
         },
         {
-          "name": "recipient",
-          "isMut": false,
-          "isSigner": false
+          "name": "escrowsCreated",
+          "type": "u32",
+          "index": false
         },
         {
-          "name": "arbiter",
-          "isMut": false,
-          "isSigner": false
+          "name": "escrowsCompleted",
+          "type": "u32",
+          "index": false
         },
         {
-          "name": "feeRecipient",
-          "isMut": false,
-          "isSigner": false
+          "name": "escrowsReceived",
+          "type": "u32",
+          "index": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
+          "name": "tasksCompleted",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "disputesInitiated",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "disputesWon",
+          "type": "u32",
+          "index": false
+        },
         {
-          "name": "escrowId",
-          "type": "u64"
+          "name": "disputesLost",
+          "type": "u32",
+          "index": false
         },
         {
-          "name": "amount",
-          "type": "u64"
+          "name": "totalVolumeLamports",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "deadline",
-          "type": "i64"
+          "name": "tipsReceived",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "termsHash",
-          "type": {
-            "array": [
-              "u8",
-              32
-            ]
-          }
+          "name": "ratingSum",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "feeBasisPoints",
-          "type": "u16"
-        }
-      ]
-    },
-    {
-      "name": "acceptTask",
-      "accounts": [
+          "name": "ratingCount",
+          "type": "u32",
+          "index": false
+        },
         {
-          "name": "escrowAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "deadlinesMissed",
+          "type": "u32",
+          "index": false
         },
         {
-          "name": "recipient",
-          "isMut": false,
-          "isSigner": true
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
         }
-      ],
-      "args": []
+      ]
     },
     {
-      "name": "releasePayment",
-      "accounts": [
+      "name": "EscrowCreated",
+      "fields": [
         {
-          "name": "escrowAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "escrow",
+          "type": "publicKey",
+          "index": false
         },
         {
           "name": "creator",
-          "isMut": true,
-          "isSigner": true
+          "type": "publicKey",
+          "index": false
         },
         {
           "name": "recipient",
-          "isMut": true,
-          "isSigner": false
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "feeRecipient",
-          "isMut": true,
-          "isSigner": false
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "deadline",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "category",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
         }
-      ],
-      "args": []
+      ]
     },
     {
-      "name": "requestRefund",
-      "accounts": [
+      "name": "TaskAccepted",
+      "fields": [
         {
-          "name": "escrowAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "escrow",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "creator",
-          "isMut": true,
-          "isSigner": true
+          "name": "recipient",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "acceptedAt",
+          "type": "i64",
+          "index": false
         }
-      ],
-      "args": []
+      ]
     },
     {
-      "name": "dispute",
-      "accounts": [
+      "name": "DisputeOpened",
+      "fields": [
         {
-          "name": "escrowAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "escrow",
+          "type": "publicKey",
+          "index": false
         },
         {
           "name": "disputer",
-          "isMut": false,
-          "isSigner": true
-        }
-      ],
-      "args": [
+          "type": "publicKey",
+          "index": false
+        },
         {
-          "name": "reason",
-          "type": {
-            "array": [
-              "u8",
-              64
-            ]
-          }
+          "name": "disputeCount",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
         }
       ]
     },
     {
-      "name": "resolveDispute",
-      "accounts": [
+      "name": "DisputeResolved",
+      "fields": [
         {
-          "name": "escrowAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "escrow",
+          "type": "publicKey",
+          "index": false
         },
         {
           "name": "arbiter",
-          "isMut": false,
-          "isSigner": true
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "creator",
-          "isMut": true,
-          "isSigner": false
+          "name": "winner",
+          "type": {
+            "defined": "DisputeWinner"
+          },
+          "index": false
         },
         {
-          "name": "recipient",
-          "isMut": true,
-          "isSigner": false
+          "name": "resolutionHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
         },
         {
-          "name": "feeRecipient",
-          "isMut": true,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "winner",
-          "type": {
-            "defined": "DisputeWinner"
-          }
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
         }
       ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "escrowAccount",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "creator",
-            "docs": [
-              "Creator (task poster) pubkey"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "recipient",
-            "docs": [
-              "Recipient (task executor) pubkey"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "amount",
-            "docs": [
-              "Amount in lamports held in escrow"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "status",
-            "docs": [
-              "Current status"
-            ],
-            "type": {
-              "defined": "EscrowStatus"
-            }
-          },
-          {
-            "name": "deadline",
-            "docs": [
-              "Deadline as Unix timestamp (seconds)"
-            ],
-            "type": "i64"
-          },
-          {
-            "name": "termsHash",
-            "docs": [
-              "SHA256 hash of terms/agreement"
-            ],
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "arbiter",
-            "docs": [
-              "Arbiter pubkey (for dispute resolution)"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "feeBasisPoints",
-            "docs": [
-              "Fee in basis points (e.g. 150 = 1.5%)"
-            ],
-            "type": "u16"
-          },
-          {
-            "name": "feeRecipient",
-            "docs": [
-              "Fee recipient (treasury) pubkey"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "createdAt",
-            "docs": [
-              "Creation timestamp (Unix seconds)"
-            ],
-            "type": "i64"
-          },
-          {
-            "name": "escrowId",
-            "docs": [
-              "Unique escrow ID"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "bump",
-            "docs": [
-              "PDA bump seed"
-            ],
-            "type": "u8"
-          },
-          {
-            "name": "disputeReason",
-            "docs": [
-              "Dispute reason (truncated to 64 bytes)"
-            ],
-            "type": {
-              "array": [
-                "u8",
-                64
-              ]
-            }
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
+    },
     {
-      "name": "DisputeWinner",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Creator"
-          },
-          {
-            "name": "Recipient"
-          }
-        ]
-      }
+      "name": "ReleaseRequested",
+      "fields": [
+        {
+          "name": "escrow",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipient",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "EscrowStatus",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Created"
-          },
-          {
-            "name": "Active"
-          },
-          {
-            "name": "Completed"
-          },
-          {
-            "name": "Disputed"
+      "name": "WorkSubmitted",
+      "fields": [
+        {
+          "name": "escrow",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipient",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "workHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
           },
-          {
-            "name": "Refunded"
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AutoReleased",
+      "fields": [
+        {
+          "name": "escrow",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "caller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipient",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ForceCompleted",
+      "fields": [
+        {
+          "name": "escrow",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "winner",
+          "type": {
+            "defined": "DisputeWinner"
           },
-          {
-            "name": "Cancelled"
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TokenEscrowRefunded",
+      "fields": [
+        {
+          "name": "escrow",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "EscrowStatus"
           },
-          {
-            "name": "Resolved"
-          }
-        ]
-      }
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
     }
   ],
   "errors": [
@@ -780,7 +12770,7 @@ export const IDL: Escrow = {
     {
       "code": 6006,
       "name": "FeeTooHigh",
-      "msg": "Fee basis points exceeds maximum (1000 = 10%)"
+      "msg": "Fee basis points exceeds maximum (1000 = 10%, protocol + referrer)"
     },
     {
       "code": 6007,
@@ -796,6 +12786,416 @@ export const IDL: Escrow = {
       "code": 6009,
       "name": "UnauthorizedDisputer",
       "msg": "Only creator or recipient can dispute"
+    },
+    {
+      "code": 6010,
+      "name": "InvalidAutoRelease",
+      "msg": "Auto-release timestamp must be after deadline"
+    },
+    {
+      "code": 6011,
+      "name": "AutoReleaseNotEnabled",
+      "msg": "Auto-release is not enabled for this escrow"
+    },
+    {
+      "code": 6012,
+      "name": "AutoReleaseNotReady",
+      "msg": "Auto-release timestamp has not been reached yet"
+    },
+    {
+      "code": 6013,
+      "name": "TooManyMilestones",
+      "msg": "Too many milestones (max 10)"
+    },
+    {
+      "code": 6014,
+      "name": "MilestoneAmountMismatch",
+      "msg": "Milestone amounts must sum to total amount"
+    },
+    {
+      "code": 6015,
+      "name": "InvalidMilestoneIndex",
+      "msg": "Invalid milestone index"
+    },
+    {
+      "code": 6016,
+      "name": "MilestoneAlreadyReleased",
+      "msg": "Milestone already released"
+    },
+    {
+      "code": 6017,
+      "name": "MilestoneNotPending",
+      "msg": "Milestone is not in pending status"
+    },
+    {
+      "code": 6018,
+      "name": "RefundExceedsAmount",
+      "msg": "Refund amount exceeds escrowed amount"
+    },
+    {
+      "code": 6019,
+      "name": "InvalidRefundGrace",
+      "msg": "Refund grace period cannot be negative"
+    },
+    {
+      "code": 6020,
+      "name": "InvalidReferrer",
+      "msg": "Referrer account does not match the escrow referrer"
+    },
+    {
+      "code": 6021,
+      "name": "InvalidPostReleaseWindow",
+      "msg": "Post-release window cannot be negative"
+    },
+    {
+      "code": 6022,
+      "name": "PostReleaseWindowClosed",
+      "msg": "Post-release dispute window has closed"
+    },
+    {
+      "code": 6023,
+      "name": "PostReleaseWindowOpen",
+      "msg": "Post-release dispute window has not elapsed yet"
+    },
+    {
+      "code": 6024,
+      "name": "AutoReleaseTooFar",
+      "msg": "Auto-release timestamp exceeds the configured maximum horizon"
+    },
+    {
+      "code": 6025,
+      "name": "UnauthorizedAdmin",
+      "msg": "Only the config admin can perform this action"
+    },
+    {
+      "code": 6026,
+      "name": "InvalidConfig",
+      "msg": "Invalid config value"
+    },
+    {
+      "code": 6027,
+      "name": "SelfEscrow",
+      "msg": "Creator, recipient and arbiter must be distinct parties"
+    },
+    {
+      "code": 6028,
+      "name": "InsufficientArbiterStake",
+      "msg": "Arbiter is not registered with the minimum required stake"
+    },
+    {
+      "code": 6029,
+      "name": "ArbiterCooldownActive",
+      "msg": "Arbiter deregistration cooldown has not elapsed"
+    },
+    {
+      "code": 6030,
+      "name": "MilestoneDisputed",
+      "msg": "Milestone is under dispute"
+    },
+    {
+      "code": 6031,
+      "name": "NotYetStarted",
+      "msg": "Escrow cannot be accepted before its start time"
+    },
+    {
+      "code": 6032,
+      "name": "InvalidStartTime",
+      "msg": "Start time must be before the deadline"
+    },
+    {
+      "code": 6033,
+      "name": "InvalidBatchSize",
+      "msg": "Batch must contain between 1 and 8 escrows"
+    },
+    {
+      "code": 6034,
+      "name": "BatchAccountMismatch",
+      "msg": "Remaining accounts do not match the batch escrow PDAs"
+    },
+    {
+      "code": 6035,
+      "name": "MintNotAllowed",
+      "msg": "Mint is not on the allowlist"
+    },
+    {
+      "code": 6036,
+      "name": "MintAlreadyAllowed",
+      "msg": "Mint is already on the allowlist"
+    },
+    {
+      "code": 6037,
+      "name": "MintAllowlistFull",
+      "msg": "Mint allowlist is full"
+    },
+    {
+      "code": 6038,
+      "name": "DisputeCooldown",
+      "msg": "A milestone dispute was resolved too recently"
+    },
+    {
+      "code": 6039,
+      "name": "InvalidSplit",
+      "msg": "Split basis points cannot exceed 10000"
+    },
+    {
+      "code": 6040,
+      "name": "EscrowIdMismatch",
+      "msg": "Escrow id does not match the creator's counter"
+    },
+    {
+      "code": 6041,
+      "name": "ConflictedArbiter",
+      "msg": "Arbiter cannot be a party to the escrow"
+    },
+    {
+      "code": 6042,
+      "name": "FeeTooLow",
+      "msg": "Fee is below the configured minimum"
+    },
+    {
+      "code": 6043,
+      "name": "PayoutNotAuthorized",
+      "msg": "Recipient must sign to redirect the payout"
+    },
+    {
+      "code": 6044,
+      "name": "DeadlineTooFar",
+      "msg": "Deadline exceeds the configured maximum horizon"
+    },
+    {
+      "code": 6045,
+      "name": "TermsHashMismatch",
+      "msg": "Acknowledged terms hash does not match the escrow terms"
+    },
+    {
+      "code": 6046,
+      "name": "ArbiterAlreadyAssigned",
+      "msg": "Escrow already has an arbiter"
+    },
+    {
+      "code": 6047,
+      "name": "InvalidArbiter",
+      "msg": "Arbiter must be a real account"
+    },
+    {
+      "code": 6048,
+      "name": "AccountNotOrphaned",
+      "msg": "Account still holds live program data"
+    },
+    {
+      "code": 6049,
+      "name": "CosignerRequired",
+      "msg": "Release requires the escrow cosigner's signature"
+    },
+    {
+      "code": 6050,
+      "name": "InvalidSponsor",
+      "msg": "Sponsor account does not match the escrow sponsor"
+    },
+    {
+      "code": 6051,
+      "name": "MilestoneCountMismatch",
+      "msg": "Milestone count does not match the populated milestones"
+    },
+    {
+      "code": 6052,
+      "name": "ArbitrationAlreadyRequested",
+      "msg": "Arbitration fee has already been deposited"
+    },
+    {
+      "code": 6053,
+      "name": "TermsNotAcknowledged",
+      "msg": "Recipient has not acknowledged the escrow terms"
+    },
+    {
+      "code": 6054,
+      "name": "AmountBelowMinimum",
+      "msg": "Milestone amount is below the configured minimum"
+    },
+    {
+      "code": 6055,
+      "name": "ProgramPaused",
+      "msg": "Program is paused"
+    },
+    {
+      "code": 6056,
+      "name": "InvalidRating",
+      "msg": "Rating must be between 1 and 5 stars"
+    },
+    {
+      "code": 6057,
+      "name": "RatingWindowClosed",
+      "msg": "Rating window has closed"
+    },
+    {
+      "code": 6058,
+      "name": "AlreadyReleased",
+      "msg": "Escrow has already been released"
+    },
+    {
+      "code": 6059,
+      "name": "MetadataUriTooLong",
+      "msg": "Metadata URI is too long"
+    },
+    {
+      "code": 6060,
+      "name": "InvalidArbiterPool",
+      "msg": "Arbiter pool is empty, unsorted, too large or contains an invalid entry"
+    },
+    {
+      "code": 6061,
+      "name": "VaultBalanceMismatch",
+      "msg": "Token vault holds less than the escrowed amount"
+    },
+    {
+      "code": 6062,
+      "name": "NotTrustedPair",
+      "msg": "Creator and recipient do not meet the trusted pair criteria"
+    },
+    {
+      "code": 6063,
+      "name": "ForceCompleteLocked",
+      "msg": "force_complete is disabled or the escrow has not been stuck long enough"
+    },
+    {
+      "code": 6064,
+      "name": "MintFeeConfigFull",
+      "msg": "Mint fee config is full"
+    },
+    {
+      "code": 6065,
+      "name": "MintFeeNotConfigured",
+      "msg": "No fee is configured for this mint"
+    },
+    {
+      "code": 6066,
+      "name": "InvalidDelegate",
+      "msg": "Signer is not the recipient's delegate"
+    },
+    {
+      "code": 6067,
+      "name": "InvalidAcceptDeadline",
+      "msg": "Accept deadline must not be after the work deadline"
+    },
+    {
+      "code": 6068,
+      "name": "AcceptDeadlinePassed",
+      "msg": "The accept deadline has passed"
+    },
+    {
+      "code": 6069,
+      "name": "RecipientAccountNotFrozen",
+      "msg": "Recipient token account is not frozen"
+    },
+    {
+      "code": 6070,
+      "name": "FrozenRecoveryNotReady",
+      "msg": "Recipient token account has not been frozen long enough"
+    },
+    {
+      "code": 6071,
+      "name": "DuplicateMilestoneIndex",
+      "msg": "Milestone index appears more than once"
+    },
+    {
+      "code": 6072,
+      "name": "UnsupportedVersion",
+      "msg": "Account layout version is not supported by this program"
+    },
+    {
+      "code": 6073,
+      "name": "SplitAmountTooLarge",
+      "msg": "Split amount must be less than the escrow amount"
+    },
+    {
+      "code": 6074,
+      "name": "InvalidPeriod",
+      "msg": "Period length must be greater than zero"
+    },
+    {
+      "code": 6075,
+      "name": "PeriodNotReached",
+      "msg": "The next period has not started yet"
+    },
+    {
+      "code": 6076,
+      "name": "RecurringBalanceExhausted",
+      "msg": "Prepaid balance does not cover another period"
+    },
+    {
+      "code": 6077,
+      "name": "ReleaseNotRequested",
+      "msg": "Recipient has not requested release"
+    },
+    {
+      "code": 6078,
+      "name": "InactivityWindowOpen",
+      "msg": "Creator inactivity release is disabled or the window has not elapsed"
+    },
+    {
+      "code": 6079,
+      "name": "MintMismatch",
+      "msg": "Token account mint does not match the escrow mint"
+    },
+    {
+      "code": 6080,
+      "name": "TooManyActiveEscrows",
+      "msg": "Creator has reached the maximum number of active escrows"
+    },
+    {
+      "code": 6081,
+      "name": "ReputationSelfMerge",
+      "msg": "Cannot merge a reputation account into itself"
+    },
+    {
+      "code": 6082,
+      "name": "InsufficientReputation",
+      "msg": "Arbiter does not have the required dispute track record"
+    },
+    {
+      "code": 6083,
+      "name": "AuditLogFull",
+      "msg": "Audit log has reached its maximum number of entries"
+    },
+    {
+      "code": 6084,
+      "name": "InvalidFeeRecipient",
+      "msg": "Fee recipient must be set when a fee is charged"
+    },
+    {
+      "code": 6085,
+      "name": "InvalidDisputeDeadline",
+      "msg": "Dispute deadline must not be before the work deadline"
+    },
+    {
+      "code": 6086,
+      "name": "DisputeWindowClosed",
+      "msg": "The dispute window for this escrow has closed"
+    },
+    {
+      "code": 6087,
+      "name": "InvalidRefundDestination",
+      "msg": "Refund destination is missing or does not match the escrow"
+    },
+    {
+      "code": 6088,
+      "name": "ReputationClosed",
+      "msg": "This agent's reputation was closed and cannot be re-initialized"
+    },
+    {
+      "code": 6089,
+      "name": "NotUpgradeAuthority",
+      "msg": "Only the program's upgrade authority can initialize the config"
+    },
+    {
+      "code": 6090,
+      "name": "InvalidBondReceipt",
+      "msg": "Bond receipt is missing or does not match the escrow"
+    },
+    {
+      "code": 6091,
+      "name": "BondNotRefundable",
+      "msg": "Arbitration deposit cannot be claimed back"
     }
   ]
 };
@@ -67,8 +67,13 @@ export {
   DEFAULT_FEE_PERCENT,
   MAX_FEE_PERCENT,
   AION_TREASURY,
+  hashTerms,
   deriveEscrowPda,
+  deriveConfigPda,
+  deriveBondReceiptPda,
+  deriveCounterPda,
   deriveReputationPda,
+  deriveReputationTombstonePda,
   deriveMilestoneEscrowPda,
   type EscrowConfig,
   type EscrowState,
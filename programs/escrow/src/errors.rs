@@ -14,7 +14,7 @@ pub enum EscrowError {
     DeadlineNotReached,
     #[msg("Deadline has already passed")]
     DeadlineExpired,
-    #[msg("Fee basis points exceeds maximum (1000 = 10%)")]
+    #[msg("Fee basis points exceeds maximum (1000 = 10%, protocol + referrer)")]
     FeeTooHigh,
     #[msg("Amount must be greater than zero")]
     ZeroAmount,
@@ -38,4 +38,155 @@ pub enum EscrowError {
     MilestoneAlreadyReleased,
     #[msg("Milestone is not in pending status")]
     MilestoneNotPending,
+    #[msg("Refund amount exceeds escrowed amount")]
+    RefundExceedsAmount,
+    #[msg("Refund grace period cannot be negative")]
+    InvalidRefundGrace,
+    #[msg("Referrer account does not match the escrow referrer")]
+    InvalidReferrer,
+    #[msg("Post-release window cannot be negative")]
+    InvalidPostReleaseWindow,
+    #[msg("Post-release dispute window has closed")]
+    PostReleaseWindowClosed,
+    #[msg("Post-release dispute window has not elapsed yet")]
+    PostReleaseWindowOpen,
+    #[msg("Auto-release timestamp exceeds the configured maximum horizon")]
+    AutoReleaseTooFar,
+    #[msg("Only the config admin can perform this action")]
+    UnauthorizedAdmin,
+    #[msg("Invalid config value")]
+    InvalidConfig,
+    #[msg("Creator, recipient and arbiter must be distinct parties")]
+    SelfEscrow,
+    #[msg("Arbiter is not registered with the minimum required stake")]
+    InsufficientArbiterStake,
+    #[msg("Arbiter deregistration cooldown has not elapsed")]
+    ArbiterCooldownActive,
+    #[msg("Milestone is under dispute")]
+    MilestoneDisputed,
+    #[msg("Escrow cannot be accepted before its start time")]
+    NotYetStarted,
+    #[msg("Start time must be before the deadline")]
+    InvalidStartTime,
+    #[msg("Batch must contain between 1 and 8 escrows")]
+    InvalidBatchSize,
+    #[msg("Remaining accounts do not match the batch escrow PDAs")]
+    BatchAccountMismatch,
+    #[msg("Mint is not on the allowlist")]
+    MintNotAllowed,
+    #[msg("Mint is already on the allowlist")]
+    MintAlreadyAllowed,
+    #[msg("Mint allowlist is full")]
+    MintAllowlistFull,
+    #[msg("A milestone dispute was resolved too recently")]
+    DisputeCooldown,
+    #[msg("Split basis points cannot exceed 10000")]
+    InvalidSplit,
+    #[msg("Escrow id does not match the creator's counter")]
+    EscrowIdMismatch,
+    #[msg("Arbiter cannot be a party to the escrow")]
+    ConflictedArbiter,
+    #[msg("Fee is below the configured minimum")]
+    FeeTooLow,
+    #[msg("Recipient must sign to redirect the payout")]
+    PayoutNotAuthorized,
+    #[msg("Deadline exceeds the configured maximum horizon")]
+    DeadlineTooFar,
+    #[msg("Acknowledged terms hash does not match the escrow terms")]
+    TermsHashMismatch,
+    #[msg("Escrow already has an arbiter")]
+    ArbiterAlreadyAssigned,
+    #[msg("Arbiter must be a real account")]
+    InvalidArbiter,
+    #[msg("Account still holds live program data")]
+    AccountNotOrphaned,
+    #[msg("Release requires the escrow cosigner's signature")]
+    CosignerRequired,
+    #[msg("Sponsor account does not match the escrow sponsor")]
+    InvalidSponsor,
+    #[msg("Milestone count does not match the populated milestones")]
+    MilestoneCountMismatch,
+    #[msg("Arbitration fee has already been deposited")]
+    ArbitrationAlreadyRequested,
+    #[msg("Recipient has not acknowledged the escrow terms")]
+    TermsNotAcknowledged,
+    #[msg("Milestone amount is below the configured minimum")]
+    AmountBelowMinimum,
+    #[msg("Program is paused")]
+    ProgramPaused,
+    #[msg("Rating must be between 1 and 5 stars")]
+    InvalidRating,
+    #[msg("Rating window has closed")]
+    RatingWindowClosed,
+    /// Only reachable while a released escrow is still open, i.e. an
+    /// auto-release held for its post-release window. A normal release closes
+    /// the account, so a retry fails with AccountNotInitialized instead.
+    #[msg("Escrow has already been released")]
+    AlreadyReleased,
+    #[msg("Metadata URI is too long")]
+    MetadataUriTooLong,
+    #[msg("Arbiter pool is empty, unsorted, too large or contains an invalid entry")]
+    InvalidArbiterPool,
+    #[msg("Token vault holds less than the escrowed amount")]
+    VaultBalanceMismatch,
+    #[msg("Creator and recipient do not meet the trusted pair criteria")]
+    NotTrustedPair,
+    #[msg("force_complete is disabled or the escrow has not been stuck long enough")]
+    ForceCompleteLocked,
+    #[msg("Mint fee config is full")]
+    MintFeeConfigFull,
+    #[msg("No fee is configured for this mint")]
+    MintFeeNotConfigured,
+    #[msg("Signer is not the recipient's delegate")]
+    InvalidDelegate,
+    #[msg("Accept deadline must not be after the work deadline")]
+    InvalidAcceptDeadline,
+    #[msg("The accept deadline has passed")]
+    AcceptDeadlinePassed,
+    #[msg("Recipient token account is not frozen")]
+    RecipientAccountNotFrozen,
+    #[msg("Recipient token account has not been frozen long enough")]
+    FrozenRecoveryNotReady,
+    #[msg("Milestone index appears more than once")]
+    DuplicateMilestoneIndex,
+    #[msg("Account layout version is not supported by this program")]
+    UnsupportedVersion,
+    #[msg("Split amount must be less than the escrow amount")]
+    SplitAmountTooLarge,
+    #[msg("Period length must be greater than zero")]
+    InvalidPeriod,
+    #[msg("The next period has not started yet")]
+    PeriodNotReached,
+    #[msg("Prepaid balance does not cover another period")]
+    RecurringBalanceExhausted,
+    #[msg("Recipient has not requested release")]
+    ReleaseNotRequested,
+    #[msg("Creator inactivity release is disabled or the window has not elapsed")]
+    InactivityWindowOpen,
+    #[msg("Token account mint does not match the escrow mint")]
+    MintMismatch,
+    #[msg("Creator has reached the maximum number of active escrows")]
+    TooManyActiveEscrows,
+    #[msg("Cannot merge a reputation account into itself")]
+    ReputationSelfMerge,
+    #[msg("Arbiter does not have the required dispute track record")]
+    InsufficientReputation,
+    #[msg("Audit log has reached its maximum number of entries")]
+    AuditLogFull,
+    #[msg("Fee recipient must be set when a fee is charged")]
+    InvalidFeeRecipient,
+    #[msg("Dispute deadline must not be before the work deadline")]
+    InvalidDisputeDeadline,
+    #[msg("The dispute window for this escrow has closed")]
+    DisputeWindowClosed,
+    #[msg("Refund destination is missing or does not match the escrow")]
+    InvalidRefundDestination,
+    #[msg("This agent's reputation was closed and cannot be re-initialized")]
+    ReputationClosed,
+    #[msg("Only the program's upgrade authority can initialize the config")]
+    NotUpgradeAuthority,
+    #[msg("Bond receipt is missing or does not match the escrow")]
+    InvalidBondReceipt,
+    #[msg("Arbitration deposit cannot be claimed back")]
+    BondNotRefundable,
 }
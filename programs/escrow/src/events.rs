@@ -0,0 +1,159 @@
+use anchor_lang::prelude::*;
+use crate::state::{EscrowStatus, ReputationAccount};
+use crate::instructions::resolve_dispute::DisputeWinner;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ReputationChangeReason {
+    EscrowCreated,
+    EscrowReceived,
+    EscrowCompleted,
+    TaskCompleted,
+    DisputeInitiated,
+    DisputeWon,
+    DisputeLost,
+    TipReceived,
+    RatingReceived,
+    DeadlineMissed,
+    Merged,
+}
+
+/// Emitted whenever a reputation account is mutated. Carries the counters
+/// as they stand after the change so indexers can stream deltas.
+#[event]
+pub struct ReputationUpdated {
+    pub agent: Pubkey,
+    pub reason: ReputationChangeReason,
+    pub escrows_created: u32,
+    pub escrows_completed: u32,
+    pub escrows_received: u32,
+    pub tasks_completed: u32,
+    pub disputes_initiated: u32,
+    pub disputes_won: u32,
+    pub disputes_lost: u32,
+    pub total_volume_lamports: u64,
+    pub tips_received: u64,
+    pub rating_sum: u64,
+    pub rating_count: u32,
+    pub deadlines_missed: u32,
+    pub timestamp: i64,
+}
+
+impl ReputationUpdated {
+    pub fn new(reputation: &ReputationAccount, reason: ReputationChangeReason) -> Self {
+        ReputationUpdated {
+            agent: reputation.agent,
+            reason,
+            escrows_created: reputation.escrows_created,
+            escrows_completed: reputation.escrows_completed,
+            escrows_received: reputation.escrows_received,
+            tasks_completed: reputation.tasks_completed,
+            disputes_initiated: reputation.disputes_initiated,
+            disputes_won: reputation.disputes_won,
+            disputes_lost: reputation.disputes_lost,
+            total_volume_lamports: reputation.total_volume_lamports,
+            tips_received: reputation.tips_received,
+            rating_sum: reputation.rating_sum,
+            rating_count: reputation.rating_count,
+            deadlines_missed: reputation.deadlines_missed,
+            timestamp: reputation.last_activity,
+        }
+    }
+}
+
+/// Emitted when a SOL escrow is created. `category` is an opaque
+/// marketplace tag carried for analytics.
+#[event]
+pub struct EscrowCreated {
+    pub escrow: Pubkey,
+    pub creator: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub deadline: i64,
+    pub category: u16,
+    pub timestamp: i64,
+}
+
+/// Emitted when a recipient accepts a SOL, token or milestone escrow.
+/// `accepted_at` is the start point for deadline and SLA measurements.
+#[event]
+pub struct TaskAccepted {
+    pub escrow: Pubkey,
+    pub recipient: Pubkey,
+    pub accepted_at: i64,
+}
+
+/// Emitted when a SOL escrow or one of a milestone escrow's milestones is
+/// disputed. `dispute_count` is the escrow's running total, for spotting
+/// serial disputers.
+#[event]
+pub struct DisputeOpened {
+    pub escrow: Pubkey,
+    pub disputer: Pubkey,
+    pub dispute_count: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted when an arbiter resolves a SOL escrow dispute. The escrow account
+/// closes on resolution, so the arbiter's reasoning hash lives here.
+#[event]
+pub struct DisputeResolved {
+    pub escrow: Pubkey,
+    pub arbiter: Pubkey,
+    pub winner: DisputeWinner,
+    pub resolution_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+/// Emitted when a recipient signals that the work is done and asks the
+/// creator to release payment. No funds move.
+#[event]
+pub struct ReleaseRequested {
+    pub escrow: Pubkey,
+    pub recipient: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a recipient records a hash of the delivered work. Creators
+/// and arbiters compare it against the agreed terms.
+#[event]
+pub struct WorkSubmitted {
+    pub escrow: Pubkey,
+    pub recipient: Pubkey,
+    pub work_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+/// Emitted when a permissionless auto-release is cranked, for SOL and token
+/// escrows alike. `caller` is whoever submitted the transaction.
+#[event]
+pub struct AutoReleased {
+    pub escrow: Pubkey,
+    pub caller: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin force-settles a stuck escrow. This bypasses both
+/// parties, so indexers should surface it prominently.
+#[event]
+pub struct ForceCompleted {
+    pub escrow: Pubkey,
+    pub admin: Pubkey,
+    pub winner: DisputeWinner,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a token escrow's vault is returned to the creator. `status`
+/// is Cancelled for an unaccepted escrow and Refunded once the deadline
+/// passed on an accepted one.
+#[event]
+pub struct TokenEscrowRefunded {
+    pub escrow: Pubkey,
+    pub creator: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub status: EscrowStatus,
+    pub timestamp: i64,
+}
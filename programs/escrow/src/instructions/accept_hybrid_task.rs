@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::util::is_expired;
+
+#[derive(Accounts)]
+pub struct AcceptHybridTask<'info> {
+    #[account(
+        mut,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.recipient == recipient.key() @ EscrowError::UnauthorizedRecipient,
+        constraint = escrow_account.status == EscrowStatus::Created @ EscrowError::InvalidStatus,
+    )]
+    pub escrow_account: Account<'info, HybridEscrowAccount>,
+
+    pub recipient: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptHybridTask>) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(
+        !is_expired(ctx.accounts.escrow_account.deadline, clock.unix_timestamp),
+        EscrowError::DeadlineExpired
+    );
+
+    let escrow = &mut ctx.accounts.escrow_account;
+    escrow.status.transition_to(EscrowStatus::Active)?;
+
+    Ok(())
+}
@@ -1,11 +1,14 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::EscrowError;
+use crate::util::is_expired;
+use crate::events::TaskAccepted;
 
 #[derive(Accounts)]
 pub struct AcceptMilestoneTask<'info> {
     #[account(
         mut,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
         constraint = escrow_account.recipient == recipient.key() @ EscrowError::UnauthorizedRecipient,
         constraint = escrow_account.status == EscrowStatus::Created @ EscrowError::InvalidStatus,
     )]
@@ -17,12 +20,19 @@ pub struct AcceptMilestoneTask<'info> {
 pub fn handler(ctx: Context<AcceptMilestoneTask>) -> Result<()> {
     let clock = Clock::get()?;
     require!(
-        clock.unix_timestamp < ctx.accounts.escrow_account.deadline,
+        !is_expired(ctx.accounts.escrow_account.deadline, clock.unix_timestamp),
         EscrowError::DeadlineExpired
     );
 
     let escrow = &mut ctx.accounts.escrow_account;
-    escrow.status = EscrowStatus::Active;
+    escrow.status.transition_to(EscrowStatus::Active)?;
+    escrow.accepted_at = clock.unix_timestamp;
+
+    emit!(TaskAccepted {
+        escrow: escrow.key(),
+        recipient: escrow.recipient,
+        accepted_at: escrow.accepted_at,
+    });
 
     Ok(())
 }
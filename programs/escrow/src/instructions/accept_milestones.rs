@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::util::{check_unique_indices, is_expired};
+
+/// Partial-scope alternative to `accept_milestone_task`: the recipient
+/// commits to the listed milestones and the rest are refunded to the creator.
+#[derive(Accounts)]
+pub struct AcceptMilestones<'info> {
+    #[account(
+        mut,
+        seeds = [b"milestone_escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
+        bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.recipient == recipient.key() @ EscrowError::UnauthorizedRecipient,
+        constraint = escrow_account.status == EscrowStatus::Created @ EscrowError::InvalidStatus,
+    )]
+    pub escrow_account: Account<'info, MilestoneEscrowAccount>,
+
+    pub recipient: Signer<'info>,
+
+    /// CHECK: validated against escrow_account.creator (receives the declined milestones)
+    #[account(
+        mut,
+        constraint = escrow_account.creator == creator.key() @ EscrowError::UnauthorizedCreator
+    )]
+    pub creator: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptMilestones>, indices: Vec<u8>) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(
+        !is_expired(ctx.accounts.escrow_account.deadline, clock.unix_timestamp),
+        EscrowError::DeadlineExpired
+    );
+    require!(!indices.is_empty(), EscrowError::InvalidMilestoneIndex);
+    check_unique_indices(&indices)?;
+
+    let escrow = &mut ctx.accounts.escrow_account;
+    let count = escrow.milestone_count as usize;
+
+    for &index in indices.iter() {
+        let index = index as usize;
+        require!(index < count, EscrowError::InvalidMilestoneIndex);
+        require!(
+            escrow.milestones[index].status == MilestoneStatus::Pending,
+            EscrowError::InvalidMilestoneIndex
+        );
+        escrow.milestones[index].status = MilestoneStatus::Accepted;
+    }
+
+    // Everything not accepted is refunded now
+    let mut refunded: u64 = 0;
+    for milestone in escrow.milestones[..count].iter_mut() {
+        if milestone.status == MilestoneStatus::Pending {
+            milestone.status = MilestoneStatus::Refunded;
+            refunded = refunded.checked_add(milestone.amount).ok_or(EscrowError::Overflow)?;
+        }
+    }
+    escrow.total_amount = escrow.total_amount.checked_sub(refunded).ok_or(EscrowError::Overflow)?;
+    escrow.validate_milestones()?;
+    escrow.status.transition_to(EscrowStatus::Active)?;
+
+    if refunded > 0 {
+        let escrow_info = ctx.accounts.escrow_account.to_account_info();
+        **escrow_info.try_borrow_mut_lamports()? -= refunded;
+        **ctx.accounts.creator.try_borrow_mut_lamports()? += refunded;
+    }
+
+    Ok(())
+}
@@ -1,28 +1,64 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::EscrowError;
+use crate::util::{is_expired, append_audit_entry};
+use crate::events::TaskAccepted;
 
 #[derive(Accounts)]
 pub struct AcceptTask<'info> {
     #[account(
         mut,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
         constraint = escrow_account.recipient == recipient.key() @ EscrowError::UnauthorizedRecipient,
         constraint = escrow_account.status == EscrowStatus::Created @ EscrowError::InvalidStatus,
     )]
     pub escrow_account: Account<'info, EscrowAccount>,
 
     pub recipient: Signer<'info>,
+
+    /// Escrow's audit log (optional - pass to record this step on-chain)
+    #[account(
+        mut,
+        seeds = [b"audit_log", escrow_account.key().as_ref()],
+        bump = audit_log.bump,
+    )]
+    pub audit_log: Option<Account<'info, AuditLog>>,
 }
 
-pub fn handler(ctx: Context<AcceptTask>) -> Result<()> {
+pub fn handler(ctx: Context<AcceptTask>, terms_ack: [u8; 32]) -> Result<()> {
+    // Recipient attests to the exact agreement the creator posted
+    require!(
+        terms_ack == ctx.accounts.escrow_account.terms_hash,
+        EscrowError::TermsHashMismatch
+    );
+
     let clock = Clock::get()?;
     require!(
-        clock.unix_timestamp < ctx.accounts.escrow_account.deadline,
+        !is_expired(ctx.accounts.escrow_account.deadline, clock.unix_timestamp),
         EscrowError::DeadlineExpired
     );
+    require!(
+        !is_expired(ctx.accounts.escrow_account.accept_by(), clock.unix_timestamp),
+        EscrowError::AcceptDeadlinePassed
+    );
+    require!(
+        clock.unix_timestamp >= ctx.accounts.escrow_account.not_before,
+        EscrowError::NotYetStarted
+    );
 
     let escrow = &mut ctx.accounts.escrow_account;
-    escrow.status = EscrowStatus::Active;
+    escrow.status.transition_to(EscrowStatus::Active)?;
+    escrow.accepted_at = clock.unix_timestamp;
+
+    emit!(TaskAccepted {
+        escrow: escrow.key(),
+        recipient: escrow.recipient,
+        accepted_at: escrow.accepted_at,
+    });
+
+    if let Some(log) = &mut ctx.accounts.audit_log {
+        append_audit_entry(log, AuditAction::Accepted, ctx.accounts.recipient.key(), clock.unix_timestamp)?;
+    }
 
     Ok(())
 }
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::util::is_expired;
+
+#[derive(Accounts)]
+pub struct AcknowledgeTerms<'info> {
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
+        bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.recipient == recipient.key() @ EscrowError::UnauthorizedRecipient,
+        constraint = escrow_account.status == EscrowStatus::Created @ EscrowError::InvalidStatus,
+    )]
+    pub escrow_account: Account<'info, EscrowAccount>,
+
+    pub recipient: Signer<'info>,
+}
+
+/// Records that the recipient has seen the terms and started engaging,
+/// without accepting. Lets them dispute the escrow while it is still Created.
+pub fn handler(ctx: Context<AcknowledgeTerms>, terms_ack: [u8; 32]) -> Result<()> {
+    let escrow = &mut ctx.accounts.escrow_account;
+    require!(terms_ack == escrow.terms_hash, EscrowError::TermsHashMismatch);
+
+    let clock = Clock::get()?;
+    require!(!is_expired(escrow.deadline, clock.unix_timestamp), EscrowError::DeadlineExpired);
+
+    escrow.terms_acked_at = clock.unix_timestamp;
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+
+#[derive(Accounts)]
+pub struct UpdateMintAllowlist<'info> {
+    #[account(
+        mut,
+        seeds = [b"mint_allowlist"],
+        bump = mint_allowlist.bump,
+    )]
+    pub mint_allowlist: Account<'info, MintAllowlist>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ EscrowError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UpdateMintAllowlist>, mint: Pubkey) -> Result<()> {
+    let allowlist = &mut ctx.accounts.mint_allowlist;
+    require!(!allowlist.mints.contains(&mint), EscrowError::MintAlreadyAllowed);
+    require!(allowlist.mints.len() < MAX_ALLOWED_MINTS, EscrowError::MintAllowlistFull);
+
+    allowlist.mints.push(mint);
+
+    Ok(())
+}
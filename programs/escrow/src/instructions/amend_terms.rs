@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+
+#[derive(Accounts)]
+pub struct AmendTerms<'info> {
+    #[account(
+        mut,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.creator == creator.key() @ EscrowError::UnauthorizedCreator,
+        constraint = escrow_account.recipient == recipient.key() @ EscrowError::UnauthorizedRecipient,
+        constraint = escrow_account.status == EscrowStatus::Active @ EscrowError::InvalidStatus,
+    )]
+    pub escrow_account: Account<'info, EscrowAccount>,
+
+    /// Both parties must sign to change the agreed scope
+    pub creator: Signer<'info>,
+
+    pub recipient: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AmendTerms>, new_terms_hash: [u8; 32]) -> Result<()> {
+    let escrow = &mut ctx.accounts.escrow_account;
+    escrow.prev_terms_hash = escrow.terms_hash;
+    escrow.terms_hash = new_terms_hash;
+
+    Ok(())
+}
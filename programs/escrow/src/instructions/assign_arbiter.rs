@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+
+#[derive(Accounts)]
+#[instruction(arbiter: Pubkey)]
+pub struct AssignArbiter<'info> {
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
+        bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.creator == creator.key() @ EscrowError::UnauthorizedCreator,
+        constraint = escrow_account.recipient == recipient.key() @ EscrowError::UnauthorizedRecipient,
+        constraint = escrow_account.status == EscrowStatus::Disputed @ EscrowError::InvalidStatus,
+        constraint = escrow_account.arbiter == Pubkey::default() @ EscrowError::ArbiterAlreadyAssigned,
+    )]
+    pub escrow_account: Account<'info, EscrowAccount>,
+
+    /// Both parties must sign so neither can pick the arbiter alone
+    pub creator: Signer<'info>,
+
+    pub recipient: Signer<'info>,
+
+    /// Protocol config (enforces the minimum arbiter stake and case count)
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Arbiter's registry account (optional - required if config sets a minimum stake)
+    #[account(
+        seeds = [b"arbiter", arbiter.as_ref()],
+        bump = arbiter_account.bump,
+    )]
+    pub arbiter_account: Option<Account<'info, ArbiterAccount>>,
+
+    /// Arbiter's reputation account (optional - required if config sets a minimum case count)
+    #[account(
+        seeds = [b"reputation", arbiter.as_ref()],
+        bump = arbiter_reputation.bump,
+    )]
+    pub arbiter_reputation: Option<Account<'info, ReputationAccount>>,
+}
+
+pub fn handler(ctx: Context<AssignArbiter>, arbiter: Pubkey) -> Result<()> {
+    require!(arbiter != Pubkey::default(), EscrowError::InvalidArbiter);
+
+    let escrow = &ctx.accounts.escrow_account;
+    require!(
+        arbiter != escrow.creator && arbiter != escrow.recipient,
+        EscrowError::ConflictedArbiter
+    );
+
+    // Same stake requirement as choosing an arbiter at creation
+    let config = &ctx.accounts.config;
+    if config.min_arbiter_stake > 0 {
+        let arbiter_account = ctx.accounts.arbiter_account.as_ref()
+            .ok_or(EscrowError::InsufficientArbiterStake)?;
+        require!(
+            arbiter_account.stake >= config.min_arbiter_stake,
+            EscrowError::InsufficientArbiterStake
+        );
+    }
+
+    // ...and must have sat through enough disputes of their own
+    if config.min_arbiter_cases > 0 {
+        let arbiter_reputation = ctx.accounts.arbiter_reputation.as_ref()
+            .ok_or(EscrowError::InsufficientReputation)?;
+        require!(
+            arbiter_reputation.dispute_cases() >= config.min_arbiter_cases,
+            EscrowError::InsufficientReputation
+        );
+    }
+
+    let escrow = &mut ctx.accounts.escrow_account;
+    escrow.arbiter = arbiter;
+
+    Ok(())
+}
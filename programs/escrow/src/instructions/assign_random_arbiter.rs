@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::util::arbiter_pool_index;
+
+/// Candidate ArbiterAccount PDAs are passed as remaining accounts, sorted by
+/// authority in strictly ascending order so each pool has one canonical form
+#[derive(Accounts)]
+pub struct AssignRandomArbiter<'info> {
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
+        bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.creator == creator.key() @ EscrowError::UnauthorizedCreator,
+        constraint = escrow_account.recipient == recipient.key() @ EscrowError::UnauthorizedRecipient,
+        constraint = escrow_account.status == EscrowStatus::Disputed @ EscrowError::InvalidStatus,
+        constraint = escrow_account.arbiter == Pubkey::default() @ EscrowError::ArbiterAlreadyAssigned,
+    )]
+    pub escrow_account: Account<'info, EscrowAccount>,
+
+    /// Both parties must sign so neither can stack the pool alone
+    pub creator: Signer<'info>,
+
+    pub recipient: Signer<'info>,
+
+    /// Protocol config (enforces the minimum arbiter stake and case count)
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Drawn arbiter's reputation account (optional - required if config sets a
+    /// minimum case count). Checked in the handler, since the draw isn't known
+    /// until the pool has been read.
+    pub arbiter_reputation: Option<Account<'info, ReputationAccount>>,
+}
+
+/// Draws the arbiter from the supplied pool of registered arbiters using
+/// `arbiter_pool_index`, so the pick is reproducible from the escrow alone.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, AssignRandomArbiter<'info>>,
+) -> Result<()> {
+    let pool = ctx.remaining_accounts;
+    require!(
+        !pool.is_empty() && pool.len() <= MAX_ARBITER_POOL,
+        EscrowError::InvalidArbiterPool
+    );
+
+    let escrow = &ctx.accounts.escrow_account;
+    let min_stake = ctx.accounts.config.min_arbiter_stake;
+
+    let mut authorities = Vec::with_capacity(pool.len());
+    for info in pool.iter() {
+        // Must be a live registry entry at its canonical PDA
+        let arbiter_account = Account::<ArbiterAccount>::try_from(info)
+            .map_err(|_| EscrowError::InvalidArbiterPool)?;
+        let expected = Pubkey::create_program_address(
+            &[b"arbiter", arbiter_account.authority.as_ref(), &[arbiter_account.bump]],
+            ctx.program_id,
+        )
+        .map_err(|_| EscrowError::InvalidArbiterPool)?;
+        require!(info.key() == expected, EscrowError::InvalidArbiterPool);
+
+        let authority = arbiter_account.authority;
+        if let Some(prev) = authorities.last() {
+            require!(authority > *prev, EscrowError::InvalidArbiterPool);
+        }
+        require!(
+            authority != escrow.creator && authority != escrow.recipient,
+            EscrowError::ConflictedArbiter
+        );
+        require!(arbiter_account.stake >= min_stake, EscrowError::InsufficientArbiterStake);
+
+        authorities.push(authority);
+    }
+
+    let index = arbiter_pool_index(&escrow.key(), escrow.escrow_id, authorities.len());
+
+    let chosen = authorities[index];
+
+    // Same case-count requirement as choosing an arbiter at creation
+    let min_cases = ctx.accounts.config.min_arbiter_cases;
+    if min_cases > 0 {
+        let arbiter_reputation = ctx.accounts.arbiter_reputation.as_ref()
+            .ok_or(EscrowError::InsufficientReputation)?;
+        require!(arbiter_reputation.agent == chosen, EscrowError::InsufficientReputation);
+        require!(
+            arbiter_reputation.dispute_cases() >= min_cases,
+            EscrowError::InsufficientReputation
+        );
+    }
+
+    let escrow = &mut ctx.accounts.escrow_account;
+    escrow.arbiter = chosen;
+
+    Ok(())
+}
@@ -1,19 +1,23 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::EscrowError;
+use crate::events::AutoReleased;
+use crate::util::{settle_auto_release, ReleaseTargets};
 
 #[derive(Accounts)]
 pub struct AutoRelease<'info> {
     #[account(
         mut,
-        close = creator,
         seeds = [b"escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
         bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.status != EscrowStatus::Completed @ EscrowError::AlreadyReleased,
         constraint = escrow_account.status == EscrowStatus::Active @ EscrowError::InvalidStatus,
     )]
     pub escrow_account: Account<'info, EscrowAccount>,
 
-    /// Anyone can trigger auto-release (no Signer constraint on caller)
+    /// Anyone can trigger auto-release and collects the crank reward
+    #[account(mut)]
     pub caller: Signer<'info>,
 
     /// CHECK: validated against escrow_account.creator
@@ -52,59 +56,62 @@ pub struct AutoRelease<'info> {
         bump = recipient_reputation.bump,
     )]
     pub recipient_reputation: Option<Account<'info, ReputationAccount>>,
-}
-
-pub fn handler(ctx: Context<AutoRelease>) -> Result<()> {
-    let escrow = &ctx.accounts.escrow_account;
-    let amount = escrow.amount;
-
-    // Auto-release must be enabled
-    require!(escrow.auto_release_at != 0, EscrowError::AutoReleaseNotEnabled);
-
-    // Check timestamp
-    let clock = Clock::get()?;
-    require!(clock.unix_timestamp >= escrow.auto_release_at, EscrowError::AutoReleaseNotReady);
-
-    // Calculate fee (same logic as release_payment)
-    let fee = (amount as u128)
-        .checked_mul(escrow.fee_basis_points as u128)
-        .ok_or(EscrowError::Overflow)?
-        .checked_div(10_000)
-        .ok_or(EscrowError::Overflow)? as u64;
-
-    let recipient_amount = amount.checked_sub(fee).ok_or(EscrowError::Overflow)?;
-
-    // Transfer lamports from PDA
-    let escrow_info = ctx.accounts.escrow_account.to_account_info();
-
-    if fee > 0 {
-        **escrow_info.try_borrow_mut_lamports()? -= fee;
-        **ctx.accounts.fee_recipient.try_borrow_mut_lamports()? += fee;
-    }
 
-    **escrow_info.try_borrow_mut_lamports()? -= recipient_amount;
-    **ctx.accounts.recipient.try_borrow_mut_lamports()? += recipient_amount;
+    /// CHECK: validated against escrow_account.referrer (required if referrer_fee_bps > 0)
+    #[account(
+        mut,
+        constraint = escrow_account.referrer == referrer.key() @ EscrowError::InvalidReferrer
+    )]
+    pub referrer: Option<UncheckedAccount<'info>>,
 
-    // Update reputation accounts if provided AND amount >= 0.01 SOL (anti-gaming)
-    const MIN_REPUTATION_AMOUNT: u64 = 10_000_000;
+    /// Protocol config (optional - pass to honour the pause switch and pay the crank reward)
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Option<Account<'info, Config>>,
 
-    if amount >= MIN_REPUTATION_AMOUNT {
-        if let Some(creator_rep) = &mut ctx.accounts.creator_reputation {
-            creator_rep.escrows_completed = creator_rep.escrows_completed.saturating_add(1);
-            creator_rep.total_volume_lamports = creator_rep.total_volume_lamports.saturating_add(amount);
-            creator_rep.last_activity = clock.unix_timestamp;
-        }
+    /// CHECK: creator's escrow counter PDA (freed on close if it has been initialized)
+    #[account(
+        mut,
+        seeds = [b"counter", escrow_account.creator.as_ref()],
+        bump,
+    )]
+    pub escrow_counter: UncheckedAccount<'info>,
+}
 
-        if let Some(recipient_rep) = &mut ctx.accounts.recipient_reputation {
-            recipient_rep.tasks_completed = recipient_rep.tasks_completed.saturating_add(1);
-            recipient_rep.total_volume_lamports = recipient_rep.total_volume_lamports.saturating_add(amount);
-            recipient_rep.last_activity = clock.unix_timestamp;
-        }
+pub fn handler(ctx: Context<AutoRelease>) -> Result<()> {
+    if let Some(config) = &ctx.accounts.config {
+        config.check_not_paused()?;
     }
 
-    // Update status (close transfers remaining rent to creator)
-    let escrow = &mut ctx.accounts.escrow_account;
-    escrow.status = EscrowStatus::Completed;
+    let escrow = &ctx.accounts.escrow_account;
+    let event = AutoReleased {
+        escrow: escrow.key(),
+        caller: ctx.accounts.caller.key(),
+        recipient: escrow.recipient,
+        amount: escrow.amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+
+    // Cranker's reward comes out of the protocol fee
+    let caller: &AccountInfo = &ctx.accounts.caller;
+    settle_auto_release(
+        &mut ctx.accounts.escrow_account,
+        event.timestamp,
+        ReleaseTargets {
+            fee_recipient: &ctx.accounts.fee_recipient,
+            referrer: ctx.accounts.referrer.as_deref(),
+            destination: &ctx.accounts.recipient,
+            cranker: ctx.accounts.config.as_deref().map(|config| (caller, config)),
+        },
+        &ctx.accounts.creator,
+        ctx.accounts.creator_reputation.as_mut(),
+        ctx.accounts.recipient_reputation.as_mut(),
+        &ctx.accounts.escrow_counter,
+    )?;
+
+    emit!(event);
 
     Ok(())
 }
@@ -2,6 +2,8 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer, CloseAccount};
 use crate::state::*;
 use crate::errors::EscrowError;
+use crate::util::calc_fee;
+use crate::events::AutoReleased;
 
 #[derive(Accounts)]
 pub struct AutoReleaseToken<'info> {
@@ -10,6 +12,8 @@ pub struct AutoReleaseToken<'info> {
         close = creator,
         seeds = [b"token_escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
         bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.status != EscrowStatus::Completed @ EscrowError::AlreadyReleased,
         constraint = escrow_account.status == EscrowStatus::Active @ EscrowError::InvalidStatus,
     )]
     pub escrow_account: Account<'info, TokenEscrowAccount>,
@@ -53,9 +57,30 @@ pub struct AutoReleaseToken<'info> {
     pub fee_token_account: Account<'info, TokenAccount>,
 
     pub token_program: Program<'info, Token>,
+
+    /// Protocol config (optional - pass to honour the pause switch and pay the crank reward)
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Option<Account<'info, Config>>,
+
+    /// Caller's token account (optional - pass to collect the crank reward)
+    #[account(
+        mut,
+        constraint = caller_token_account.owner == caller.key(),
+        constraint = caller_token_account.mint == escrow_account.mint,
+    )]
+    pub caller_token_account: Option<Account<'info, TokenAccount>>,
 }
 
 pub fn handler(ctx: Context<AutoReleaseToken>) -> Result<()> {
+    if let Some(config) = &ctx.accounts.config {
+        config.check_not_paused()?;
+    }
+
+    ctx.accounts.escrow_account.status.transition_to(EscrowStatus::Completing)?;
+
     let escrow = &ctx.accounts.escrow_account;
 
     require!(escrow.auto_release_at != 0, EscrowError::AutoReleaseNotEnabled);
@@ -63,13 +88,22 @@ pub fn handler(ctx: Context<AutoReleaseToken>) -> Result<()> {
     let clock = Clock::get()?;
     require!(clock.unix_timestamp >= escrow.auto_release_at, EscrowError::AutoReleaseNotReady);
 
-    let fee = (escrow.amount as u128)
-        .checked_mul(escrow.fee_basis_points as u128)
-        .ok_or(EscrowError::Overflow)?
-        .checked_div(10_000)
-        .ok_or(EscrowError::Overflow)? as u64;
+    emit!(AutoReleased {
+        escrow: escrow.key(),
+        caller: ctx.accounts.caller.key(),
+        recipient: escrow.recipient,
+        amount: escrow.amount,
+        timestamp: clock.unix_timestamp,
+    });
 
-    let recipient_amount = escrow.amount.checked_sub(fee).ok_or(EscrowError::Overflow)?;
+    let (fee, recipient_amount) = calc_fee(escrow.amount, escrow.fee_basis_points)?;
+
+    // Cranker's reward comes out of the protocol fee, if they can receive it
+    let crank_reward = match (&ctx.accounts.config, &ctx.accounts.caller_token_account) {
+        (Some(config), Some(_)) => config.crank_reward(fee),
+        _ => 0,
+    };
+    let fee = fee - crank_reward;
 
     let escrow_id_bytes = escrow.escrow_id.to_le_bytes();
     let seeds = &[
@@ -80,6 +114,23 @@ pub fn handler(ctx: Context<AutoReleaseToken>) -> Result<()> {
     ];
     let signer_seeds = &[&seeds[..]];
 
+    if let Some(caller_token_account) = &ctx.accounts.caller_token_account {
+        if crank_reward > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.vault.to_account_info(),
+                        to: caller_token_account.to_account_info(),
+                        authority: ctx.accounts.escrow_account.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                crank_reward,
+            )?;
+        }
+    }
+
     if fee > 0 {
         token::transfer(
             CpiContext::new_with_signer(
@@ -120,7 +171,7 @@ pub fn handler(ctx: Context<AutoReleaseToken>) -> Result<()> {
     ))?;
 
     let escrow = &mut ctx.accounts.escrow_account;
-    escrow.status = EscrowStatus::Completed;
+    escrow.status.transition_to(EscrowStatus::Completed)?;
 
     Ok(())
 }
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+
+#[derive(Accounts)]
+pub struct CancelAutoRelease<'info> {
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
+        bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.creator == creator.key() @ EscrowError::UnauthorizedCreator,
+        constraint = escrow_account.recipient == recipient.key() @ EscrowError::UnauthorizedRecipient,
+        constraint = escrow_account.status == EscrowStatus::Active @ EscrowError::InvalidStatus,
+    )]
+    pub escrow_account: Account<'info, EscrowAccount>,
+
+    pub creator: Signer<'info>,
+
+    /// Auto-release protects the recipient, so they must co-sign its removal
+    pub recipient: Signer<'info>,
+}
+
+/// Disables auto-release, returning the escrow to manual release only
+pub fn handler(ctx: Context<CancelAutoRelease>) -> Result<()> {
+    let escrow = &mut ctx.accounts.escrow_account;
+    escrow.auto_release_at = 0;
+
+    Ok(())
+}
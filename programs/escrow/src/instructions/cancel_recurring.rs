@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::util::calc_fee;
+
+#[derive(Accounts)]
+pub struct CancelRecurring<'info> {
+    #[account(
+        mut,
+        close = creator,
+        seeds = [b"recurring_escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
+        bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.creator == creator.key() @ EscrowError::UnauthorizedCreator,
+    )]
+    pub escrow_account: Account<'info, RecurringEscrowAccount>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// CHECK: validated against escrow_account.recipient
+    #[account(
+        mut,
+        constraint = escrow_account.recipient == recipient.key() @ EscrowError::UnauthorizedRecipient
+    )]
+    pub recipient: UncheckedAccount<'info>,
+
+    /// CHECK: validated against escrow_account.fee_recipient
+    #[account(
+        mut,
+        constraint = escrow_account.fee_recipient == fee_recipient.key()
+    )]
+    pub fee_recipient: UncheckedAccount<'info>,
+}
+
+/// Ends the subscription. Periods already due are paid to the recipient
+/// first; the unreleased balance and rent go back to the creator on close.
+pub fn handler(ctx: Context<CancelRecurring>) -> Result<()> {
+    let clock = Clock::get()?;
+    let escrow = &ctx.accounts.escrow_account;
+
+    let owed = escrow.due_periods(clock.unix_timestamp)
+        .checked_mul(escrow.amount_per_period)
+        .ok_or(EscrowError::Overflow)?;
+
+    if owed > 0 {
+        let (fee, recipient_amount) = calc_fee(owed, escrow.fee_basis_points)?;
+        let escrow_info = ctx.accounts.escrow_account.to_account_info();
+
+        if fee > 0 {
+            **escrow_info.try_borrow_mut_lamports()? -= fee;
+            **ctx.accounts.fee_recipient.try_borrow_mut_lamports()? += fee;
+        }
+
+        **escrow_info.try_borrow_mut_lamports()? -= recipient_amount;
+        **ctx.accounts.recipient.try_borrow_mut_lamports()? += recipient_amount;
+    }
+
+    Ok(())
+}
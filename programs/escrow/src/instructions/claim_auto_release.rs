@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::util::{settle_auto_release, ReleaseTargets};
+
+#[derive(Accounts)]
+pub struct ClaimAutoRelease<'info> {
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
+        bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.recipient == recipient.key() @ EscrowError::UnauthorizedRecipient,
+        constraint = escrow_account.status != EscrowStatus::Completed @ EscrowError::AlreadyReleased,
+        constraint = escrow_account.status == EscrowStatus::Active @ EscrowError::InvalidStatus,
+    )]
+    pub escrow_account: Account<'info, EscrowAccount>,
+
+    /// CHECK: validated against escrow_account.recipient; receives the payout and
+    /// must sign unless the delegate does
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+
+    /// Recipient's delegate key (optional - signs in place of the recipient)
+    #[account(
+        constraint = escrow_account.delegate == delegate.key() @ EscrowError::InvalidDelegate
+    )]
+    pub delegate: Option<Signer<'info>>,
+
+    /// CHECK: validated against escrow_account.creator (receives rent on close)
+    #[account(
+        mut,
+        constraint = escrow_account.creator == creator.key() @ EscrowError::UnauthorizedCreator
+    )]
+    pub creator: UncheckedAccount<'info>,
+
+    /// CHECK: validated against escrow_account.fee_recipient
+    #[account(
+        mut,
+        constraint = escrow_account.fee_recipient == fee_recipient.key()
+    )]
+    pub fee_recipient: UncheckedAccount<'info>,
+
+    /// Creator's reputation account (optional - pass if tracking reputation)
+    #[account(
+        mut,
+        seeds = [b"reputation", escrow_account.creator.as_ref()],
+        bump = creator_reputation.bump,
+    )]
+    pub creator_reputation: Option<Account<'info, ReputationAccount>>,
+
+    /// Recipient's reputation account (optional - pass if tracking reputation)
+    #[account(
+        mut,
+        seeds = [b"reputation", escrow_account.recipient.as_ref()],
+        bump = recipient_reputation.bump,
+    )]
+    pub recipient_reputation: Option<Account<'info, ReputationAccount>>,
+
+    /// CHECK: validated against escrow_account.referrer (required if referrer_fee_bps > 0)
+    #[account(
+        mut,
+        constraint = escrow_account.referrer == referrer.key() @ EscrowError::InvalidReferrer
+    )]
+    pub referrer: Option<UncheckedAccount<'info>>,
+
+    /// Protocol config (optional - pass to honour the pause switch)
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Option<Account<'info, Config>>,
+
+    /// CHECK: creator's escrow counter PDA (freed on close if it has been initialized)
+    #[account(
+        mut,
+        seeds = [b"counter", escrow_account.creator.as_ref()],
+        bump,
+    )]
+    pub escrow_counter: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<ClaimAutoRelease>) -> Result<()> {
+    if let Some(config) = &ctx.accounts.config {
+        config.check_not_paused()?;
+    }
+
+    let escrow = &ctx.accounts.escrow_account;
+    escrow.check_recipient_or_delegate(
+        ctx.accounts.recipient.is_signer,
+        ctx.accounts.delegate.as_ref().map(|d| d.key()),
+    )?;
+
+    // Same settlement as auto_release, minus the crank reward
+    let clock = Clock::get()?;
+    settle_auto_release(
+        &mut ctx.accounts.escrow_account,
+        clock.unix_timestamp,
+        ReleaseTargets {
+            fee_recipient: &ctx.accounts.fee_recipient,
+            referrer: ctx.accounts.referrer.as_deref(),
+            destination: &ctx.accounts.recipient,
+            cranker: None,
+        },
+        &ctx.accounts.creator,
+        ctx.accounts.creator_reputation.as_mut(),
+        ctx.accounts.recipient_reputation.as_mut(),
+        &ctx.accounts.escrow_counter,
+    )
+}
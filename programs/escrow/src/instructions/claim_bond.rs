@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+
+/// Returns an arbitration deposit to the party that put it up once its
+/// dispute was settled without a ruling (see force_complete). A ruled dispute
+/// pays the deposit out and closes the receipt, so there is nothing to claim.
+#[derive(Accounts)]
+pub struct ClaimBond<'info> {
+    /// Closing the receipt returns the deposit together with its rent
+    #[account(
+        mut,
+        close = payer,
+        seeds = [b"bond", bond_receipt.escrow.as_ref()],
+        bump = bond_receipt.bump,
+        has_one = payer @ EscrowError::InvalidBondReceipt,
+        constraint = bond_receipt.outcome == BondOutcome::Refundable @ EscrowError::BondNotRefundable,
+    )]
+    pub bond_receipt: Account<'info, BondReceipt>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
+
+pub fn handler(_ctx: Context<ClaimBond>) -> Result<()> {
+    // The receipt's close constraint pays the payer
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Closes an agent's reputation account and returns rent to the agent.
+///
+/// The reputation account does not track in-flight escrows, so closing it
+/// while escrows are still open simply means those escrows will no longer
+/// update this agent's counters (the optional reputation accounts are
+/// skipped). A tombstone is left at the agent's address so the wallet can't
+/// re-initialize a clean record afterwards; the agent pays its (smaller) rent
+/// out of the refund.
+#[derive(Accounts)]
+pub struct CloseReputation<'info> {
+    #[account(
+        mut,
+        close = agent,
+        seeds = [b"reputation", agent.key().as_ref()],
+        bump = reputation_account.bump,
+        has_one = agent,
+    )]
+    pub reputation_account: Account<'info, ReputationAccount>,
+
+    #[account(mut)]
+    pub agent: Signer<'info>,
+
+    #[account(
+        init,
+        payer = agent,
+        space = ReputationTombstone::SPACE,
+        seeds = [b"reputation_tombstone", agent.key().as_ref()],
+        bump
+    )]
+    pub tombstone: Account<'info, ReputationTombstone>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CloseReputation>) -> Result<()> {
+    let tombstone = &mut ctx.accounts.tombstone;
+    tombstone.agent = ctx.accounts.agent.key();
+    tombstone.closed_at = Clock::get()?.unix_timestamp;
+    tombstone.bump = ctx.bumps.tombstone;
+
+    Ok(())
+}
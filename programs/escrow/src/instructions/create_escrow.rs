@@ -2,6 +2,8 @@ use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 use crate::state::*;
 use crate::errors::EscrowError;
+use crate::util::{calc_fee, is_expired, pack_metadata_uri, check_fee_recipient};
+use crate::events::{EscrowCreated, ReputationUpdated, ReputationChangeReason};
 
 #[derive(Accounts)]
 #[instruction(escrow_id: u64)]
@@ -19,9 +21,16 @@ pub struct CreateEscrow<'info> {
     pub creator: Signer<'info>,
 
     /// CHECK: Recipient is stored but doesn't sign at creation
+    #[account(
+        constraint = recipient.key() != creator.key() @ EscrowError::SelfEscrow
+    )]
     pub recipient: UncheckedAccount<'info>,
 
-    /// CHECK: Arbiter is stored but doesn't sign at creation
+    /// CHECK: Arbiter is stored but doesn't sign at creation (default = assign on dispute)
+    #[account(
+        constraint = arbiter.key() != creator.key() @ EscrowError::SelfEscrow,
+        constraint = arbiter.key() != recipient.key() @ EscrowError::SelfEscrow,
+    )]
     pub arbiter: UncheckedAccount<'info>,
 
     /// CHECK: Fee recipient is stored but doesn't sign
@@ -44,6 +53,57 @@ pub struct CreateEscrow<'info> {
         bump = recipient_reputation.bump,
     )]
     pub recipient_reputation: Option<Account<'info, ReputationAccount>>,
+
+    /// CHECK: Referrer is stored but doesn't sign (optional - pass to take a referral cut)
+    pub referrer: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Cosigner is stored but doesn't sign at creation (optional - pass to require a second approver on release)
+    pub cosigner: Option<UncheckedAccount<'info>>,
+
+    /// Funds the escrow on the creator's behalf (optional - refunds go back to the sponsor)
+    #[account(mut)]
+    pub sponsor: Option<Signer<'info>>,
+
+    /// Protocol config (optional - pass to enforce configured limits)
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Option<Account<'info, Config>>,
+
+    /// Arbiter's registry account (optional - required if config sets a minimum stake)
+    #[account(
+        seeds = [b"arbiter", arbiter.key().as_ref()],
+        bump = arbiter_account.bump,
+    )]
+    pub arbiter_account: Option<Account<'info, ArbiterAccount>>,
+
+    /// Arbiter's reputation account (optional - required if config sets a minimum case count)
+    #[account(
+        seeds = [b"reputation", arbiter.key().as_ref()],
+        bump = arbiter_reputation.bump,
+    )]
+    pub arbiter_reputation: Option<Account<'info, ReputationAccount>>,
+
+    /// Creator's escrow counter (optional - pass to draw a guaranteed-unique escrow_id;
+    /// required if config caps active escrows per creator)
+    #[account(
+        mut,
+        seeds = [b"counter", creator.key().as_ref()],
+        bump = escrow_counter.bump,
+    )]
+    pub escrow_counter: Option<Account<'info, EscrowCounter>>,
+
+    /// Protocol-wide stats (optional - pass to count this escrow in the aggregates)
+    #[account(
+        mut,
+        seeds = [b"global_stats"],
+        bump = global_stats.bump,
+    )]
+    pub global_stats: Option<Account<'info, GlobalStats>>,
+
+    /// CHECK: Refund destination is stored but doesn't sign (optional - pass to send refunds somewhere other than the creator)
+    pub refund_destination: Option<UncheckedAccount<'info>>,
 }
 
 pub fn handler(
@@ -54,30 +114,167 @@ pub fn handler(
     terms_hash: [u8; 32],
     fee_basis_points: u16,
     auto_release_at: i64,
+    refund_grace_seconds: i64,
+    referrer_fee_bps: u16,
+    post_release_window_seconds: i64,
+    not_before: i64,
+    category: u16,
+    metadata_uri: String,
+    auto_release_after_deadline: i64,
+    accept_deadline: i64,
+    fee_paid_by_creator: bool,
+    early_bonus_lamports: u64,
+    dispute_deadline: i64,
 ) -> Result<()> {
     require!(amount > 0, EscrowError::ZeroAmount);
-    require!(fee_basis_points <= 1000, EscrowError::FeeTooHigh);
+    let metadata_uri = pack_metadata_uri(&metadata_uri)?;
+
+    // Protocol fee and referrer fee together are capped at the global max
+    let total_fee_bps = fee_basis_points.checked_add(referrer_fee_bps).ok_or(EscrowError::Overflow)?;
+    require!(total_fee_bps <= MAX_FEE_BASIS_POINTS, EscrowError::FeeTooHigh);
+    check_fee_recipient(&ctx.accounts.fee_recipient.key(), fee_basis_points)?;
+
+    let referrer = match &ctx.accounts.referrer {
+        Some(referrer) => referrer.key(),
+        None => Pubkey::default(),
+    };
+    if referrer_fee_bps > 0 {
+        require!(referrer != Pubkey::default(), EscrowError::InvalidReferrer);
+    }
+
+    let refund_destination = match &ctx.accounts.refund_destination {
+        Some(destination) => destination.key(),
+        None => ctx.accounts.creator.key(),
+    };
+    require!(refund_destination != Pubkey::default(), EscrowError::InvalidRefundDestination);
 
     let clock = Clock::get()?;
-    require!(deadline > clock.unix_timestamp, EscrowError::DeadlineExpired);
+    require!(!is_expired(deadline, clock.unix_timestamp), EscrowError::DeadlineExpired);
+
+    // Auto-release may instead be given as a delay after the deadline, but not both
+    let auto_release_at = match auto_release_after_deadline {
+        0 => auto_release_at,
+        delay => {
+            require!(auto_release_at == 0 && delay > 0, EscrowError::InvalidAutoRelease);
+            deadline.checked_add(delay).ok_or(EscrowError::Overflow)?
+        }
+    };
 
     // If auto_release_at is set, it must be after the deadline
     if auto_release_at != 0 {
         require!(auto_release_at > deadline, EscrowError::InvalidAutoRelease);
     }
 
-    // Transfer SOL from creator to escrow PDA
+    // Enforce the configured horizons if a config is provided
+    if let Some(config) = &ctx.accounts.config {
+        config.check_not_paused()?;
+        config.check_fee_floor(fee_basis_points)?;
+        config.check_deadline_horizon(deadline, clock.unix_timestamp)?;
+
+        if auto_release_at != 0 && config.max_auto_release_horizon > 0 {
+            require!(
+                auto_release_at <= clock.unix_timestamp.saturating_add(config.max_auto_release_horizon),
+                EscrowError::AutoReleaseTooFar
+            );
+        }
+
+        // Chosen arbiter must be registered with the minimum stake; an
+        // unassigned arbiter is checked later by assign_arbiter
+        if config.min_arbiter_stake > 0 && ctx.accounts.arbiter.key() != Pubkey::default() {
+            let arbiter_account = ctx.accounts.arbiter_account.as_ref()
+                .ok_or(EscrowError::InsufficientArbiterStake)?;
+            require!(
+                arbiter_account.stake >= config.min_arbiter_stake,
+                EscrowError::InsufficientArbiterStake
+            );
+        }
+
+        // ...and must have sat through enough disputes of their own
+        if config.min_arbiter_cases > 0 && ctx.accounts.arbiter.key() != Pubkey::default() {
+            let arbiter_reputation = ctx.accounts.arbiter_reputation.as_ref()
+                .ok_or(EscrowError::InsufficientReputation)?;
+            require!(
+                arbiter_reputation.dispute_cases() >= config.min_arbiter_cases,
+                EscrowError::InsufficientReputation
+            );
+        }
+    }
+
+    // Counter-issued ids are never reused, even after the escrow closes
+    let max_active = ctx.accounts.config.as_ref().map_or(0, |c| c.max_active_per_creator);
+    match &mut ctx.accounts.escrow_counter {
+        Some(counter) => {
+            require!(escrow_id == counter.next_escrow_id(), EscrowError::EscrowIdMismatch);
+            counter.next_id = counter.next_id.checked_add(1).ok_or(EscrowError::Overflow)?;
+            counter.record_opened(max_active)?;
+        }
+        // The active count lives on the counter, so a capped creator must pass it
+        None => require!(max_active == 0, EscrowError::TooManyActiveEscrows),
+    }
+
+    require!(refund_grace_seconds >= 0, EscrowError::InvalidRefundGrace);
+    require!(post_release_window_seconds >= 0, EscrowError::InvalidPostReleaseWindow);
+
+    // Scheduled escrows must become acceptable before they expire
+    if not_before != 0 {
+        require!(not_before < deadline, EscrowError::InvalidStartTime);
+    }
+
+    if accept_deadline != 0 {
+        require!(
+            accept_deadline > clock.unix_timestamp && accept_deadline <= deadline,
+            EscrowError::InvalidAcceptDeadline
+        );
+        if not_before != 0 {
+            require!(not_before < accept_deadline, EscrowError::InvalidStartTime);
+        }
+    }
+
+    // Disputes must stay possible at least until the work is due
+    if dispute_deadline != 0 {
+        require!(dispute_deadline >= deadline, EscrowError::InvalidDisputeDeadline);
+    }
+
+    // A creator-paid fee is funded up front so the recipient gets the full amount
+    let creator_fee = if fee_paid_by_creator {
+        calc_fee(amount, fee_basis_points)?.0
+    } else {
+        0
+    };
+    let funded = amount.checked_add(creator_fee).ok_or(EscrowError::Overflow)?;
+
+    // Transfer SOL from the sponsor (or creator) to escrow PDA; the creator
+    // still owns the escrow and pays its rent either way
+    let funder = match &ctx.accounts.sponsor {
+        Some(sponsor) => sponsor.to_account_info(),
+        None => ctx.accounts.creator.to_account_info(),
+    };
     system_program::transfer(
         CpiContext::new(
             ctx.accounts.system_program.to_account_info(),
             system_program::Transfer {
-                from: ctx.accounts.creator.to_account_info(),
+                from: funder,
                 to: ctx.accounts.escrow_account.to_account_info(),
             },
         ),
-        amount,
+        funded,
     )?;
 
+    // The early-release bonus always comes from the creator; whatever
+    // isn't earned returns to them with the rent on close
+    if early_bonus_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: ctx.accounts.escrow_account.to_account_info(),
+                },
+            ),
+            early_bonus_lamports,
+        )?;
+    }
+
     // Update reputation accounts if provided AND amount >= 0.01 SOL (anti-gaming)
     const MIN_REPUTATION_AMOUNT: u64 = 10_000_000;
 
@@ -85,11 +282,13 @@ pub fn handler(
         if let Some(creator_rep) = &mut ctx.accounts.creator_reputation {
             creator_rep.escrows_created = creator_rep.escrows_created.saturating_add(1);
             creator_rep.last_activity = clock.unix_timestamp;
+            emit!(ReputationUpdated::new(creator_rep, ReputationChangeReason::EscrowCreated));
         }
 
         if let Some(recipient_rep) = &mut ctx.accounts.recipient_reputation {
             recipient_rep.escrows_received = recipient_rep.escrows_received.saturating_add(1);
             recipient_rep.last_activity = clock.unix_timestamp;
+            emit!(ReputationUpdated::new(recipient_rep, ReputationChangeReason::EscrowReceived));
         }
     }
 
@@ -108,6 +307,51 @@ pub fn handler(
     escrow.bump = ctx.bumps.escrow_account;
     escrow.dispute_reason = [0u8; 64];
     escrow.auto_release_at = auto_release_at;
+    escrow.refund_grace_seconds = refund_grace_seconds;
+    escrow.referrer = referrer;
+    escrow.referrer_fee_bps = referrer_fee_bps;
+    escrow.post_release_window_seconds = post_release_window_seconds;
+    escrow.released_at = 0;
+    escrow.not_before = not_before;
+    escrow.prev_terms_hash = [0u8; 32];
+    escrow.release_requested = false;
+    escrow.category = category;
+    escrow.cosigner = match &ctx.accounts.cosigner {
+        Some(cosigner) => cosigner.key(),
+        None => Pubkey::default(),
+    };
+    escrow.sponsor = match &ctx.accounts.sponsor {
+        Some(sponsor) => sponsor.key(),
+        None => Pubkey::default(),
+    };
+    escrow.arbitration_fee = 0;
+    escrow.arbitration_fee_payer = Pubkey::default();
+    escrow.terms_acked_at = 0;
+    escrow.metadata_uri = metadata_uri;
+    escrow.dispute_count = 0;
+    escrow.accepted_at = 0;
+    escrow.delegate = Pubkey::default();
+    escrow.accept_deadline = accept_deadline;
+    escrow.version = ACCOUNT_VERSION;
+    escrow.fee_paid_by_creator = fee_paid_by_creator;
+    escrow.work_hash = [0u8; 32];
+    escrow.early_bonus_lamports = early_bonus_lamports;
+    escrow.dispute_deadline = dispute_deadline;
+    escrow.refund_destination = refund_destination;
+
+    if let Some(stats) = &mut ctx.accounts.global_stats {
+        stats.total_escrows_created = stats.total_escrows_created.saturating_add(1);
+    }
+
+    emit!(EscrowCreated {
+        escrow: escrow.key(),
+        creator: escrow.creator,
+        recipient: escrow.recipient,
+        amount,
+        deadline,
+        category,
+        timestamp: clock.unix_timestamp,
+    });
 
     Ok(())
 }
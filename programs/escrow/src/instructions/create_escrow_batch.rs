@@ -0,0 +1,209 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::util::{is_expired, check_fee_recipient};
+use crate::events::EscrowCreated;
+
+/// Per-escrow parameters for `create_escrow_batch`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BatchEscrowParams {
+    pub escrow_id: u64,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub deadline: i64,
+    pub terms_hash: [u8; 32],
+    pub auto_release_at: i64,
+}
+
+/// Escrow PDAs are passed as writable remaining accounts, one per params entry, in order
+#[derive(Accounts)]
+pub struct CreateEscrowBatch<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// CHECK: Arbiter is stored but doesn't sign at creation
+    #[account(
+        constraint = arbiter.key() != creator.key() @ EscrowError::SelfEscrow
+    )]
+    pub arbiter: UncheckedAccount<'info>,
+
+    /// CHECK: Fee recipient is stored but doesn't sign
+    pub fee_recipient: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Protocol config (optional - pass to enforce configured limits)
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Option<Account<'info, Config>>,
+
+    /// Arbiter's registry account (optional - required if config sets a minimum stake)
+    #[account(
+        seeds = [b"arbiter", arbiter.key().as_ref()],
+        bump = arbiter_account.bump,
+    )]
+    pub arbiter_account: Option<Account<'info, ArbiterAccount>>,
+
+    /// Creator's escrow counter (optional - pass to draw guaranteed-unique escrow_ids;
+    /// required if config caps active escrows per creator)
+    #[account(
+        mut,
+        seeds = [b"counter", creator.key().as_ref()],
+        bump = escrow_counter.bump,
+    )]
+    pub escrow_counter: Option<Account<'info, EscrowCounter>>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateEscrowBatch<'info>>,
+    escrows: Vec<BatchEscrowParams>,
+    fee_basis_points: u16,
+) -> Result<()> {
+    require!(
+        !escrows.is_empty() && escrows.len() <= MAX_BATCH_SIZE,
+        EscrowError::InvalidBatchSize
+    );
+    require!(
+        ctx.remaining_accounts.len() == escrows.len(),
+        EscrowError::BatchAccountMismatch
+    );
+    require!(fee_basis_points <= MAX_FEE_BASIS_POINTS, EscrowError::FeeTooHigh);
+    check_fee_recipient(&ctx.accounts.fee_recipient.key(), fee_basis_points)?;
+
+    let clock = Clock::get()?;
+    let creator_key = ctx.accounts.creator.key();
+    let arbiter_key = ctx.accounts.arbiter.key();
+
+    if let Some(config) = &ctx.accounts.config {
+        config.check_not_paused()?;
+        config.check_fee_floor(fee_basis_points)?;
+
+        // Chosen arbiter must be registered with the minimum stake
+        if config.min_arbiter_stake > 0 {
+            let arbiter_account = ctx.accounts.arbiter_account.as_ref()
+                .ok_or(EscrowError::InsufficientArbiterStake)?;
+            require!(
+                arbiter_account.stake >= config.min_arbiter_stake,
+                EscrowError::InsufficientArbiterStake
+            );
+        }
+    }
+
+    let rent = Rent::get()?.minimum_balance(EscrowAccount::SPACE);
+
+    for (params, escrow_info) in escrows.iter().zip(ctx.remaining_accounts.iter()) {
+        require!(params.amount > 0, EscrowError::ZeroAmount);
+        require!(!is_expired(params.deadline, clock.unix_timestamp), EscrowError::DeadlineExpired);
+        require!(params.recipient != creator_key, EscrowError::SelfEscrow);
+        require!(params.recipient != arbiter_key, EscrowError::SelfEscrow);
+
+        if params.auto_release_at != 0 {
+            require!(params.auto_release_at > params.deadline, EscrowError::InvalidAutoRelease);
+        }
+
+        // Entries draw consecutive counter ids, and each counts against the creator's active cap
+        let max_active = ctx.accounts.config.as_ref().map_or(0, |c| c.max_active_per_creator);
+        match &mut ctx.accounts.escrow_counter {
+            Some(counter) => {
+                require!(params.escrow_id == counter.next_escrow_id(), EscrowError::EscrowIdMismatch);
+                counter.next_id = counter.next_id.checked_add(1).ok_or(EscrowError::Overflow)?;
+                counter.record_opened(max_active)?;
+            }
+            None => require!(max_active == 0, EscrowError::TooManyActiveEscrows),
+        }
+
+        if let Some(config) = &ctx.accounts.config {
+            config.check_deadline_horizon(params.deadline, clock.unix_timestamp)?;
+
+            if params.auto_release_at != 0 && config.max_auto_release_horizon > 0 {
+                require!(
+                    params.auto_release_at <= clock.unix_timestamp.saturating_add(config.max_auto_release_horizon),
+                    EscrowError::AutoReleaseTooFar
+                );
+            }
+        }
+
+        // Each remaining account must be the escrow PDA for its params entry
+        let escrow_id_bytes = params.escrow_id.to_le_bytes();
+        let (expected, bump) = Pubkey::find_program_address(
+            &[b"escrow", creator_key.as_ref(), &escrow_id_bytes],
+            ctx.program_id,
+        );
+        require!(escrow_info.key() == expected, EscrowError::BatchAccountMismatch);
+
+        // Create the PDA funded with rent plus the escrowed amount
+        let lamports = rent.checked_add(params.amount).ok_or(EscrowError::Overflow)?;
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::CreateAccount {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: escrow_info.clone(),
+                },
+                &[&[b"escrow", creator_key.as_ref(), &escrow_id_bytes, &[bump]]],
+            ),
+            lamports,
+            EscrowAccount::SPACE as u64,
+            ctx.program_id,
+        )?;
+
+        let escrow = EscrowAccount {
+            version: ACCOUNT_VERSION,
+            creator: creator_key,
+            recipient: params.recipient,
+            amount: params.amount,
+            status: EscrowStatus::Created,
+            deadline: params.deadline,
+            terms_hash: params.terms_hash,
+            arbiter: arbiter_key,
+            fee_basis_points,
+            fee_recipient: ctx.accounts.fee_recipient.key(),
+            created_at: clock.unix_timestamp,
+            escrow_id: params.escrow_id,
+            bump,
+            dispute_reason: [0u8; 64],
+            auto_release_at: params.auto_release_at,
+            refund_grace_seconds: 0,
+            referrer: Pubkey::default(),
+            referrer_fee_bps: 0,
+            post_release_window_seconds: 0,
+            released_at: 0,
+            not_before: 0,
+            prev_terms_hash: [0u8; 32],
+            release_requested: false,
+            category: 0,
+            cosigner: Pubkey::default(),
+            sponsor: Pubkey::default(),
+            arbitration_fee: 0,
+            arbitration_fee_payer: Pubkey::default(),
+            terms_acked_at: 0,
+            metadata_uri: [0u8; METADATA_URI_LEN],
+            dispute_count: 0,
+            accepted_at: 0,
+            delegate: Pubkey::default(),
+            accept_deadline: 0,
+            fee_paid_by_creator: false,
+            work_hash: [0u8; 32],
+            early_bonus_lamports: 0,
+            dispute_deadline: 0,
+            refund_destination: creator_key,
+        };
+        let mut data = escrow_info.try_borrow_mut_data()?;
+        escrow.try_serialize(&mut &mut data[..])?;
+
+        emit!(EscrowCreated {
+            escrow: escrow_info.key(),
+            creator: creator_key,
+            recipient: params.recipient,
+            amount: params.amount,
+            deadline: params.deadline,
+            category: 0,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Token, TokenAccount, Mint, Transfer};
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::util::{is_expired, check_fee_recipient};
+
+#[derive(Accounts)]
+#[instruction(escrow_id: u64)]
+pub struct CreateHybridEscrow<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = HybridEscrowAccount::SPACE,
+        seeds = [b"hybrid_escrow", creator.key().as_ref(), &escrow_id.to_le_bytes()],
+        bump
+    )]
+    pub escrow_account: Account<'info, HybridEscrowAccount>,
+
+    #[account(
+        init,
+        payer = creator,
+        token::mint = mint,
+        token::authority = escrow_account,
+        seeds = [b"hybrid_vault", escrow_account.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// CHECK: Recipient stored but doesn't sign at creation
+    #[account(
+        constraint = recipient.key() != creator.key() @ EscrowError::SelfEscrow
+    )]
+    pub recipient: UncheckedAccount<'info>,
+
+    /// CHECK: Arbiter stored but doesn't sign at creation
+    #[account(
+        constraint = arbiter.key() != creator.key() @ EscrowError::SelfEscrow,
+        constraint = arbiter.key() != recipient.key() @ EscrowError::SelfEscrow,
+    )]
+    pub arbiter: UncheckedAccount<'info>,
+
+    /// CHECK: Fee recipient stored but doesn't sign
+    pub fee_recipient: UncheckedAccount<'info>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = creator_token_account.owner == creator.key(),
+        constraint = creator_token_account.mint == mint.key(),
+    )]
+    pub creator_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+
+    /// Protocol config (optional - pass to enforce configured limits)
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Option<Account<'info, Config>>,
+}
+
+pub fn handler(
+    ctx: Context<CreateHybridEscrow>,
+    escrow_id: u64,
+    lamport_amount: u64,
+    token_amount: u64,
+    deadline: i64,
+    terms_hash: [u8; 32],
+    fee_basis_points: u16,
+) -> Result<()> {
+    // Both legs must be funded; use a plain SOL or token escrow otherwise
+    require!(lamport_amount > 0 && token_amount > 0, EscrowError::ZeroAmount);
+    require!(fee_basis_points <= MAX_FEE_BASIS_POINTS, EscrowError::FeeTooHigh);
+    check_fee_recipient(&ctx.accounts.fee_recipient.key(), fee_basis_points)?;
+
+    let clock = Clock::get()?;
+    require!(!is_expired(deadline, clock.unix_timestamp), EscrowError::DeadlineExpired);
+
+    if let Some(config) = &ctx.accounts.config {
+        config.check_not_paused()?;
+        config.check_fee_floor(fee_basis_points)?;
+        config.check_deadline_horizon(deadline, clock.unix_timestamp)?;
+    }
+
+    // Transfer SOL leg from creator to escrow PDA
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.creator.to_account_info(),
+                to: ctx.accounts.escrow_account.to_account_info(),
+            },
+        ),
+        lamport_amount,
+    )?;
+
+    // Transfer token leg from creator to vault
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.creator_token_account.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+                authority: ctx.accounts.creator.to_account_info(),
+            },
+        ),
+        token_amount,
+    )?;
+
+    let escrow = &mut ctx.accounts.escrow_account;
+    escrow.creator = ctx.accounts.creator.key();
+    escrow.recipient = ctx.accounts.recipient.key();
+    escrow.mint = ctx.accounts.mint.key();
+    escrow.lamport_amount = lamport_amount;
+    escrow.token_amount = token_amount;
+    escrow.status = EscrowStatus::Created;
+    escrow.deadline = deadline;
+    escrow.terms_hash = terms_hash;
+    escrow.arbiter = ctx.accounts.arbiter.key();
+    escrow.fee_basis_points = fee_basis_points;
+    escrow.fee_recipient = ctx.accounts.fee_recipient.key();
+    escrow.created_at = clock.unix_timestamp;
+    escrow.escrow_id = escrow_id;
+    escrow.bump = ctx.bumps.escrow_account;
+    escrow.version = ACCOUNT_VERSION;
+
+    Ok(())
+}
@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 use crate::state::*;
 use crate::errors::EscrowError;
+use crate::util::{is_expired, check_fee_recipient};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct MilestoneInput {
@@ -25,15 +26,29 @@ pub struct CreateMilestoneEscrow<'info> {
     pub creator: Signer<'info>,
 
     /// CHECK: Recipient stored but doesn't sign at creation
+    #[account(
+        constraint = recipient.key() != creator.key() @ EscrowError::SelfEscrow
+    )]
     pub recipient: UncheckedAccount<'info>,
 
     /// CHECK: Arbiter stored but doesn't sign at creation
+    #[account(
+        constraint = arbiter.key() != creator.key() @ EscrowError::SelfEscrow,
+        constraint = arbiter.key() != recipient.key() @ EscrowError::SelfEscrow,
+    )]
     pub arbiter: UncheckedAccount<'info>,
 
     /// CHECK: Fee recipient stored but doesn't sign
     pub fee_recipient: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Protocol config (optional - pass to enforce configured limits)
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Option<Account<'info, Config>>,
 }
 
 pub fn handler(
@@ -45,10 +60,20 @@ pub fn handler(
     milestones: Vec<MilestoneInput>,
 ) -> Result<()> {
     require!(milestones.len() > 0 && milestones.len() <= MAX_MILESTONES, EscrowError::TooManyMilestones);
-    require!(fee_basis_points <= 1000, EscrowError::FeeTooHigh);
+    require!(fee_basis_points <= MAX_FEE_BASIS_POINTS, EscrowError::FeeTooHigh);
+    check_fee_recipient(&ctx.accounts.fee_recipient.key(), fee_basis_points)?;
 
     let clock = Clock::get()?;
-    require!(deadline > clock.unix_timestamp, EscrowError::DeadlineExpired);
+    require!(!is_expired(deadline, clock.unix_timestamp), EscrowError::DeadlineExpired);
+
+    if let Some(config) = &ctx.accounts.config {
+        config.check_not_paused()?;
+        config.check_fee_floor(fee_basis_points)?;
+        config.check_deadline_horizon(deadline, clock.unix_timestamp)?;
+        for m in milestones.iter() {
+            config.check_milestone_amount(m.amount)?;
+        }
+    }
 
     // Calculate total amount
     let total_amount: u64 = milestones.iter()
@@ -96,6 +121,12 @@ pub fn handler(
         };
     }
     escrow.milestones = ms_array;
+    escrow.dispute_reason = [0u8; 64];
+    escrow.last_dispute_ts = 0;
+    escrow.dispute_count = 0;
+    escrow.accepted_at = 0;
+    escrow.milestone_disputed_by = [Pubkey::default(); MAX_MILESTONES];
+    escrow.version = ACCOUNT_VERSION;
 
     Ok(())
 }
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::util::{is_expired, check_fee_recipient};
+use crate::instructions::create_milestone_escrow::MilestoneInput;
+
+#[derive(Accounts)]
+#[instruction(escrow_id: u64, deadline: i64, terms_hash: [u8; 32], fee_basis_points: u16, milestones: Vec<MilestoneInput>)]
+pub struct CreateMilestoneEscrowCompact<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = CompactMilestoneEscrowAccount::space(milestones.len()),
+        seeds = [b"compact_milestone_escrow", creator.key().as_ref(), &escrow_id.to_le_bytes()],
+        bump
+    )]
+    pub escrow_account: Account<'info, CompactMilestoneEscrowAccount>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// CHECK: Recipient stored but doesn't sign at creation
+    #[account(
+        constraint = recipient.key() != creator.key() @ EscrowError::SelfEscrow
+    )]
+    pub recipient: UncheckedAccount<'info>,
+
+    /// CHECK: Fee recipient stored but doesn't sign
+    pub fee_recipient: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Protocol config (optional - pass to enforce configured limits)
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Option<Account<'info, Config>>,
+}
+
+pub fn handler(
+    ctx: Context<CreateMilestoneEscrowCompact>,
+    escrow_id: u64,
+    deadline: i64,
+    terms_hash: [u8; 32],
+    fee_basis_points: u16,
+    milestones: Vec<MilestoneInput>,
+) -> Result<()> {
+    require!(milestones.len() > 0 && milestones.len() <= MAX_MILESTONES, EscrowError::TooManyMilestones);
+    require!(fee_basis_points <= MAX_FEE_BASIS_POINTS, EscrowError::FeeTooHigh);
+    check_fee_recipient(&ctx.accounts.fee_recipient.key(), fee_basis_points)?;
+
+    let clock = Clock::get()?;
+    require!(!is_expired(deadline, clock.unix_timestamp), EscrowError::DeadlineExpired);
+
+    if let Some(config) = &ctx.accounts.config {
+        config.check_not_paused()?;
+        config.check_fee_floor(fee_basis_points)?;
+        config.check_deadline_horizon(deadline, clock.unix_timestamp)?;
+        for m in milestones.iter() {
+            config.check_milestone_amount(m.amount)?;
+        }
+    }
+
+    let total_amount: u64 = milestones.iter()
+        .map(|m| m.amount)
+        .try_fold(0u64, |acc, a| acc.checked_add(a))
+        .ok_or(EscrowError::Overflow)?;
+
+    require!(total_amount > 0, EscrowError::ZeroAmount);
+
+    // Transfer SOL from creator to escrow PDA
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.creator.to_account_info(),
+                to: ctx.accounts.escrow_account.to_account_info(),
+            },
+        ),
+        total_amount,
+    )?;
+
+    let escrow = &mut ctx.accounts.escrow_account;
+    escrow.creator = ctx.accounts.creator.key();
+    escrow.recipient = ctx.accounts.recipient.key();
+    escrow.total_amount = total_amount;
+    escrow.released_amount = 0;
+    escrow.status = EscrowStatus::Created;
+    escrow.deadline = deadline;
+    escrow.terms_hash = terms_hash;
+    escrow.fee_basis_points = fee_basis_points;
+    escrow.fee_recipient = ctx.accounts.fee_recipient.key();
+    escrow.created_at = clock.unix_timestamp;
+    escrow.escrow_id = escrow_id;
+    escrow.bump = ctx.bumps.escrow_account;
+    escrow.accepted_at = 0;
+    escrow.version = ACCOUNT_VERSION;
+    escrow.milestones = milestones.iter()
+        .map(|m| Milestone {
+            amount: m.amount,
+            status: MilestoneStatus::Pending,
+            description_hash: m.description_hash,
+        })
+        .collect();
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::util::check_fee_recipient;
+
+#[derive(Accounts)]
+#[instruction(escrow_id: u64)]
+pub struct CreateRecurringEscrow<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = RecurringEscrowAccount::SPACE,
+        seeds = [b"recurring_escrow", creator.key().as_ref(), &escrow_id.to_le_bytes()],
+        bump
+    )]
+    pub escrow_account: Account<'info, RecurringEscrowAccount>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// CHECK: Recipient is stored but doesn't sign at creation
+    #[account(
+        constraint = recipient.key() != creator.key() @ EscrowError::SelfEscrow
+    )]
+    pub recipient: UncheckedAccount<'info>,
+
+    /// CHECK: Fee recipient is stored but doesn't sign
+    pub fee_recipient: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Protocol config (optional - pass to enforce configured limits)
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Option<Account<'info, Config>>,
+}
+
+/// Opens a subscription: the first period can be released one
+/// `period_seconds` after creation
+pub fn handler(
+    ctx: Context<CreateRecurringEscrow>,
+    escrow_id: u64,
+    amount_per_period: u64,
+    period_seconds: i64,
+    deposit: u64,
+    fee_basis_points: u16,
+) -> Result<()> {
+    require!(amount_per_period > 0 && deposit > 0, EscrowError::ZeroAmount);
+    require!(period_seconds > 0, EscrowError::InvalidPeriod);
+    require!(fee_basis_points <= MAX_FEE_BASIS_POINTS, EscrowError::FeeTooHigh);
+    check_fee_recipient(&ctx.accounts.fee_recipient.key(), fee_basis_points)?;
+
+    if let Some(config) = &ctx.accounts.config {
+        config.check_not_paused()?;
+        config.check_fee_floor(fee_basis_points)?;
+    }
+
+    let clock = Clock::get()?;
+    let next_release_ts = clock.unix_timestamp.checked_add(period_seconds).ok_or(EscrowError::Overflow)?;
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.creator.to_account_info(),
+                to: ctx.accounts.escrow_account.to_account_info(),
+            },
+        ),
+        deposit,
+    )?;
+
+    let escrow = &mut ctx.accounts.escrow_account;
+    escrow.creator = ctx.accounts.creator.key();
+    escrow.recipient = ctx.accounts.recipient.key();
+    escrow.amount_per_period = amount_per_period;
+    escrow.period_seconds = period_seconds;
+    escrow.next_release_ts = next_release_ts;
+    escrow.balance = deposit;
+    escrow.periods_released = 0;
+    escrow.fee_basis_points = fee_basis_points;
+    escrow.fee_recipient = ctx.accounts.fee_recipient.key();
+    escrow.created_at = clock.unix_timestamp;
+    escrow.escrow_id = escrow_id;
+    escrow.bump = ctx.bumps.escrow_account;
+    escrow.version = ACCOUNT_VERSION;
+
+    Ok(())
+}
@@ -2,6 +2,8 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Mint, Transfer};
 use crate::state::*;
 use crate::errors::EscrowError;
+use crate::util::{is_expired, check_fee_recipient};
+use crate::events::{ReputationUpdated, ReputationChangeReason};
 
 #[derive(Accounts)]
 #[instruction(escrow_id: u64)]
@@ -29,9 +31,16 @@ pub struct CreateTokenEscrow<'info> {
     pub creator: Signer<'info>,
 
     /// CHECK: Recipient stored but doesn't sign at creation
+    #[account(
+        constraint = recipient.key() != creator.key() @ EscrowError::SelfEscrow
+    )]
     pub recipient: UncheckedAccount<'info>,
 
     /// CHECK: Arbiter stored but doesn't sign at creation
+    #[account(
+        constraint = arbiter.key() != creator.key() @ EscrowError::SelfEscrow,
+        constraint = arbiter.key() != recipient.key() @ EscrowError::SelfEscrow,
+    )]
     pub arbiter: UncheckedAccount<'info>,
 
     /// CHECK: Fee recipient stored but doesn't sign
@@ -49,6 +58,43 @@ pub struct CreateTokenEscrow<'info> {
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
+
+    /// Protocol config (optional - pass to enforce configured limits)
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Option<Account<'info, Config>>,
+
+    /// Mint allowlist (optional - pass to restrict which mints can back escrows)
+    #[account(
+        seeds = [b"mint_allowlist"],
+        bump = mint_allowlist.bump,
+    )]
+    pub mint_allowlist: Option<Account<'info, MintAllowlist>>,
+
+    /// Per-mint fee table (optional - required when fee_basis_points is USE_MINT_FEE_BPS)
+    #[account(
+        seeds = [b"mint_fee_config"],
+        bump = mint_fee_config.bump,
+    )]
+    pub mint_fee_config: Option<Account<'info, MintFeeConfig>>,
+
+    /// Creator's reputation account (optional - pass if tracking reputation)
+    #[account(
+        mut,
+        seeds = [b"reputation", creator.key().as_ref()],
+        bump = creator_reputation.bump,
+    )]
+    pub creator_reputation: Option<Account<'info, ReputationAccount>>,
+
+    /// Recipient's reputation account (optional - pass if tracking reputation)
+    #[account(
+        mut,
+        seeds = [b"reputation", recipient.key().as_ref()],
+        bump = recipient_reputation.bump,
+    )]
+    pub recipient_reputation: Option<Account<'info, ReputationAccount>>,
 }
 
 pub fn handler(
@@ -61,15 +107,46 @@ pub fn handler(
     auto_release_at: i64,
 ) -> Result<()> {
     require!(amount > 0, EscrowError::ZeroAmount);
-    require!(fee_basis_points <= 1000, EscrowError::FeeTooHigh);
+
+    let fee_basis_points = if fee_basis_points == USE_MINT_FEE_BPS {
+        ctx.accounts.mint_fee_config
+            .as_ref()
+            .and_then(|fees| fees.fee_for(&ctx.accounts.mint.key()))
+            .ok_or(EscrowError::MintFeeNotConfigured)?
+    } else {
+        fee_basis_points
+    };
+    require!(fee_basis_points <= MAX_FEE_BASIS_POINTS, EscrowError::FeeTooHigh);
+    check_fee_recipient(&ctx.accounts.fee_recipient.key(), fee_basis_points)?;
+
+    if let Some(allowlist) = &ctx.accounts.mint_allowlist {
+        require!(
+            allowlist.mints.contains(&ctx.accounts.mint.key()),
+            EscrowError::MintNotAllowed
+        );
+    }
 
     let clock = Clock::get()?;
-    require!(deadline > clock.unix_timestamp, EscrowError::DeadlineExpired);
+    require!(!is_expired(deadline, clock.unix_timestamp), EscrowError::DeadlineExpired);
 
     if auto_release_at != 0 {
         require!(auto_release_at > deadline, EscrowError::InvalidAutoRelease);
     }
 
+    // Enforce the configured horizons if a config is provided
+    if let Some(config) = &ctx.accounts.config {
+        config.check_not_paused()?;
+        config.check_fee_floor(fee_basis_points)?;
+        config.check_deadline_horizon(deadline, clock.unix_timestamp)?;
+
+        if auto_release_at != 0 && config.max_auto_release_horizon > 0 {
+            require!(
+                auto_release_at <= clock.unix_timestamp.saturating_add(config.max_auto_release_horizon),
+                EscrowError::AutoReleaseTooFar
+            );
+        }
+    }
+
     // Transfer tokens from creator to vault
     token::transfer(
         CpiContext::new(
@@ -83,6 +160,24 @@ pub fn handler(
         amount,
     )?;
 
+    // Update reputation accounts if provided AND amount >= 10_000_000 base
+    // units (anti-gaming; the same raw threshold as SOL escrows)
+    const MIN_REPUTATION_AMOUNT: u64 = 10_000_000;
+
+    if amount >= MIN_REPUTATION_AMOUNT {
+        if let Some(creator_rep) = &mut ctx.accounts.creator_reputation {
+            creator_rep.escrows_created = creator_rep.escrows_created.saturating_add(1);
+            creator_rep.last_activity = clock.unix_timestamp;
+            emit!(ReputationUpdated::new(creator_rep, ReputationChangeReason::EscrowCreated));
+        }
+
+        if let Some(recipient_rep) = &mut ctx.accounts.recipient_reputation {
+            recipient_rep.escrows_received = recipient_rep.escrows_received.saturating_add(1);
+            recipient_rep.last_activity = clock.unix_timestamp;
+            emit!(ReputationUpdated::new(recipient_rep, ReputationChangeReason::EscrowReceived));
+        }
+    }
+
     let escrow = &mut ctx.accounts.escrow_account;
     escrow.creator = ctx.accounts.creator.key();
     escrow.recipient = ctx.accounts.recipient.key();
@@ -99,6 +194,10 @@ pub fn handler(
     escrow.bump = ctx.bumps.escrow_account;
     escrow.dispute_reason = [0u8; 64];
     escrow.auto_release_at = auto_release_at;
+    escrow.accepted_at = 0;
+    escrow.frozen_reported_at = 0;
+    escrow.version = ACCOUNT_VERSION;
+    escrow.staged_amount = 0;
 
     Ok(())
 }
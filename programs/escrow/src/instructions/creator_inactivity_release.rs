@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::util::{pay_out_release, record_escrow_closed, ReleaseTargets};
+
+/// Lets the recipient pay themselves when the creator has gone silent on
+/// delivered work. The recipient must have called request_release, and
+/// `Config::creator_inactivity_window` must have passed since the deadline
+/// without the creator releasing, refunding or disputing. A creator who
+/// ignores delivered work that long forfeits the escrow.
+#[derive(Accounts)]
+pub struct CreatorInactivityRelease<'info> {
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
+        bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.recipient == recipient.key() @ EscrowError::UnauthorizedRecipient,
+        constraint = escrow_account.status == EscrowStatus::Active @ EscrowError::InvalidStatus,
+    )]
+    pub escrow_account: Account<'info, EscrowAccount>,
+
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    /// CHECK: validated against escrow_account.creator (receives rent on close)
+    #[account(
+        mut,
+        constraint = escrow_account.creator == creator.key() @ EscrowError::UnauthorizedCreator
+    )]
+    pub creator: UncheckedAccount<'info>,
+
+    /// CHECK: validated against escrow_account.fee_recipient
+    #[account(
+        mut,
+        constraint = escrow_account.fee_recipient == fee_recipient.key()
+    )]
+    pub fee_recipient: UncheckedAccount<'info>,
+
+    /// CHECK: validated against escrow_account.referrer (required if referrer_fee_bps > 0)
+    #[account(
+        mut,
+        constraint = escrow_account.referrer == referrer.key() @ EscrowError::InvalidReferrer
+    )]
+    pub referrer: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: creator's escrow counter PDA (freed on close if it has been initialized)
+    #[account(
+        mut,
+        seeds = [b"counter", escrow_account.creator.as_ref()],
+        bump,
+    )]
+    pub escrow_counter: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CreatorInactivityRelease>) -> Result<()> {
+    ctx.accounts.config.check_not_paused()?;
+
+    let escrow = &ctx.accounts.escrow_account;
+    require!(escrow.release_requested, EscrowError::ReleaseNotRequested);
+
+    let clock = Clock::get()?;
+    let unlocks_at = ctx.accounts.config
+        .inactivity_release_at(escrow.deadline)
+        .ok_or(EscrowError::InactivityWindowOpen)?;
+    require!(clock.unix_timestamp >= unlocks_at, EscrowError::InactivityWindowOpen);
+
+    let fee_basis_points = escrow.fee_basis_points;
+
+    ctx.accounts.escrow_account.status.transition_to(EscrowStatus::Completing)?;
+
+    pay_out_release(
+        &ctx.accounts.escrow_account,
+        fee_basis_points,
+        clock.unix_timestamp,
+        ReleaseTargets {
+            fee_recipient: &ctx.accounts.fee_recipient,
+            referrer: ctx.accounts.referrer.as_deref(),
+            destination: &ctx.accounts.recipient,
+            cranker: None,
+        },
+    )?;
+
+    // Update status and close, transferring remaining rent to creator
+    let escrow = &mut ctx.accounts.escrow_account;
+    escrow.status.transition_to(EscrowStatus::Completed)?;
+    escrow.close(ctx.accounts.creator.to_account_info())?;
+
+    record_escrow_closed(&ctx.accounts.escrow_counter)?;
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+
+#[derive(Accounts)]
+pub struct DeregisterArbiter<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"arbiter", authority.key().as_ref()],
+        bump = arbiter_account.bump,
+        has_one = authority,
+    )]
+    pub arbiter_account: Account<'info, ArbiterAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<DeregisterArbiter>) -> Result<()> {
+    let arbiter = &ctx.accounts.arbiter_account;
+    let clock = Clock::get()?;
+
+    // Stake stays locked until the arbiter has been inactive for the cooldown.
+    // Close returns stake + rent to the authority.
+    require!(
+        clock.unix_timestamp >= arbiter.last_active_at.saturating_add(ARBITER_DEREGISTER_COOLDOWN),
+        EscrowError::ArbiterCooldownActive
+    );
+
+    Ok(())
+}
@@ -1,12 +1,14 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::EscrowError;
+use crate::util::{is_expired, append_audit_entry};
+use crate::events::{DisputeOpened, ReputationUpdated, ReputationChangeReason};
 
 #[derive(Accounts)]
 pub struct Dispute<'info> {
     #[account(
         mut,
-        constraint = escrow_account.status == EscrowStatus::Active @ EscrowError::InvalidStatus,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
     )]
     pub escrow_account: Account<'info, EscrowAccount>,
 
@@ -19,6 +21,22 @@ pub struct Dispute<'info> {
         bump = disputer_reputation.bump,
     )]
     pub disputer_reputation: Option<Account<'info, ReputationAccount>>,
+
+    /// Protocol-wide stats (optional - pass to count this escrow in the aggregates)
+    #[account(
+        mut,
+        seeds = [b"global_stats"],
+        bump = global_stats.bump,
+    )]
+    pub global_stats: Option<Account<'info, GlobalStats>>,
+
+    /// Escrow's audit log (optional - pass to record this step on-chain)
+    #[account(
+        mut,
+        seeds = [b"audit_log", escrow_account.key().as_ref()],
+        bump = audit_log.bump,
+    )]
+    pub audit_log: Option<Account<'info, AuditLog>>,
 }
 
 pub fn handler(ctx: Context<Dispute>, reason: [u8; 64]) -> Result<()> {
@@ -31,16 +49,61 @@ pub fn handler(ctx: Context<Dispute>, reason: [u8; 64]) -> Result<()> {
         EscrowError::UnauthorizedDisputer
     );
 
+    let clock = Clock::get()?;
+
+    if escrow.dispute_deadline != 0 {
+        require!(
+            !is_expired(escrow.dispute_deadline, clock.unix_timestamp),
+            EscrowError::DisputeWindowClosed
+        );
+    }
+
+    match escrow.status {
+        EscrowStatus::Active => {}
+        // A recipient who acknowledged the terms but never formally accepted
+        // can still escalate; the creator can simply cancel instead
+        EscrowStatus::Created => {
+            require!(disputer_key == escrow.recipient, EscrowError::UnauthorizedRecipient);
+            require!(escrow.terms_acked_at != 0, EscrowError::TermsNotAcknowledged);
+        }
+        // Auto-released escrows held for a post-release window can still be
+        // disputed by the creator until the window elapses
+        EscrowStatus::Completed if escrow.released_at != 0 => {
+            require!(disputer_key == escrow.creator, EscrowError::UnauthorizedCreator);
+            require!(
+                clock.unix_timestamp < escrow.post_release_window_ends_at(),
+                EscrowError::PostReleaseWindowClosed
+            );
+        }
+        _ => return Err(EscrowError::InvalidStatus.into()),
+    }
+
     // Update reputation if provided
     if let Some(disputer_rep) = &mut ctx.accounts.disputer_reputation {
-        let clock = Clock::get()?;
         disputer_rep.disputes_initiated = disputer_rep.disputes_initiated.saturating_add(1);
         disputer_rep.last_activity = clock.unix_timestamp;
+        emit!(ReputationUpdated::new(disputer_rep, ReputationChangeReason::DisputeInitiated));
+    }
+
+    if let Some(stats) = &mut ctx.accounts.global_stats {
+        stats.total_disputes = stats.total_disputes.saturating_add(1);
     }
 
     let escrow = &mut ctx.accounts.escrow_account;
-    escrow.status = EscrowStatus::Disputed;
+    escrow.status.transition_to(EscrowStatus::Disputed)?;
     escrow.dispute_reason = reason;
+    escrow.dispute_count = escrow.dispute_count.saturating_add(1);
+
+    emit!(DisputeOpened {
+        escrow: escrow.key(),
+        disputer: disputer_key,
+        dispute_count: escrow.dispute_count,
+        timestamp: clock.unix_timestamp,
+    });
+
+    if let Some(log) = &mut ctx.accounts.audit_log {
+        append_audit_entry(log, AuditAction::Disputed, disputer_key, clock.unix_timestamp)?;
+    }
 
     Ok(())
 }
@@ -1,11 +1,13 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::EscrowError;
+use crate::events::DisputeOpened;
 
 #[derive(Accounts)]
 pub struct DisputeMilestone<'info> {
     #[account(
         mut,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
         constraint = escrow_account.status == EscrowStatus::Active @ EscrowError::InvalidStatus,
     )]
     pub escrow_account: Account<'info, MilestoneEscrowAccount>,
@@ -13,7 +15,7 @@ pub struct DisputeMilestone<'info> {
     pub disputer: Signer<'info>,
 }
 
-pub fn handler(ctx: Context<DisputeMilestone>, milestone_index: u8) -> Result<()> {
+pub fn handler(ctx: Context<DisputeMilestone>, milestone_index: u8, reason: [u8; 64]) -> Result<()> {
     let escrow = &ctx.accounts.escrow_account;
     let disputer_key = ctx.accounts.disputer.key();
 
@@ -28,13 +30,33 @@ pub fn handler(ctx: Context<DisputeMilestone>, milestone_index: u8) -> Result<()
     );
 
     require!(
-        escrow.milestones[milestone_index as usize].status == MilestoneStatus::Pending,
+        escrow.milestones[milestone_index as usize].status.is_open(),
         EscrowError::MilestoneNotPending
     );
 
+    // Block dispute/resolve harassment loops on the same escrow
+    let clock = Clock::get()?;
+    if escrow.last_dispute_ts != 0 {
+        require!(
+            clock.unix_timestamp >= escrow.last_dispute_ts.saturating_add(MILESTONE_DISPUTE_COOLDOWN),
+            EscrowError::DisputeCooldown
+        );
+    }
+
     let escrow = &mut ctx.accounts.escrow_account;
+    // Only the milestone is frozen; the escrow stays Active so unrelated
+    // milestones can still be released
     escrow.milestones[milestone_index as usize].status = MilestoneStatus::Disputed;
-    escrow.status = EscrowStatus::Disputed;
+    escrow.milestone_disputed_by[milestone_index as usize] = disputer_key;
+    escrow.dispute_reason = reason;
+    escrow.dispute_count = escrow.dispute_count.saturating_add(1);
+
+    emit!(DisputeOpened {
+        escrow: escrow.key(),
+        disputer: disputer_key,
+        dispute_count: escrow.dispute_count,
+        timestamp: clock.unix_timestamp,
+    });
 
     Ok(())
 }
@@ -6,6 +6,7 @@ use crate::errors::EscrowError;
 pub struct DisputeToken<'info> {
     #[account(
         mut,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
         constraint = escrow_account.status == EscrowStatus::Active @ EscrowError::InvalidStatus,
     )]
     pub escrow_account: Account<'info, TokenEscrowAccount>,
@@ -23,7 +24,7 @@ pub fn handler(ctx: Context<DisputeToken>, reason: [u8; 64]) -> Result<()> {
     );
 
     let escrow = &mut ctx.accounts.escrow_account;
-    escrow.status = EscrowStatus::Disputed;
+    escrow.status.transition_to(EscrowStatus::Disputed)?;
     escrow.dispute_reason = reason;
 
     Ok(())
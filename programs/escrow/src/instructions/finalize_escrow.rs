@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::util::{pay_out_release, record_escrow_closed, record_release_reputation, ReleaseTargets};
+
+#[derive(Accounts)]
+pub struct FinalizeEscrow<'info> {
+    #[account(
+        mut,
+        close = creator,
+        seeds = [b"escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
+        bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.status == EscrowStatus::Completed @ EscrowError::InvalidStatus,
+    )]
+    pub escrow_account: Account<'info, EscrowAccount>,
+
+    /// Anyone can finalize once the post-release window has elapsed
+    pub caller: Signer<'info>,
+
+    /// CHECK: validated against escrow_account.creator
+    #[account(
+        mut,
+        constraint = escrow_account.creator == creator.key() @ EscrowError::UnauthorizedCreator
+    )]
+    pub creator: UncheckedAccount<'info>,
+
+    /// CHECK: validated against escrow_account.recipient
+    #[account(
+        mut,
+        constraint = escrow_account.recipient == recipient.key() @ EscrowError::UnauthorizedRecipient
+    )]
+    pub recipient: UncheckedAccount<'info>,
+
+    /// CHECK: validated against escrow_account.fee_recipient
+    #[account(
+        mut,
+        constraint = escrow_account.fee_recipient == fee_recipient.key()
+    )]
+    pub fee_recipient: UncheckedAccount<'info>,
+
+    /// Creator's reputation account (optional - pass if tracking reputation)
+    #[account(
+        mut,
+        seeds = [b"reputation", escrow_account.creator.as_ref()],
+        bump = creator_reputation.bump,
+    )]
+    pub creator_reputation: Option<Account<'info, ReputationAccount>>,
+
+    /// Recipient's reputation account (optional - pass if tracking reputation)
+    #[account(
+        mut,
+        seeds = [b"reputation", escrow_account.recipient.as_ref()],
+        bump = recipient_reputation.bump,
+    )]
+    pub recipient_reputation: Option<Account<'info, ReputationAccount>>,
+
+    /// CHECK: validated against escrow_account.referrer (required if referrer_fee_bps > 0)
+    #[account(
+        mut,
+        constraint = escrow_account.referrer == referrer.key() @ EscrowError::InvalidReferrer
+    )]
+    pub referrer: Option<UncheckedAccount<'info>>,
+
+    /// Protocol config (optional - pass to honour the pause switch)
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Option<Account<'info, Config>>,
+
+    /// CHECK: creator's escrow counter PDA (freed on close if it has been initialized)
+    #[account(
+        mut,
+        seeds = [b"counter", escrow_account.creator.as_ref()],
+        bump,
+    )]
+    pub escrow_counter: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<FinalizeEscrow>) -> Result<()> {
+    if let Some(config) = &ctx.accounts.config {
+        config.check_not_paused()?;
+    }
+
+    let escrow = &ctx.accounts.escrow_account;
+    let amount = escrow.amount;
+
+    // Only auto-released escrows held for a post-release window can be finalized
+    require!(escrow.released_at != 0, EscrowError::InvalidStatus);
+
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp >= escrow.post_release_window_ends_at(),
+        EscrowError::PostReleaseWindowOpen
+    );
+
+    let fee_basis_points = escrow.fee_basis_points;
+    let released_at = escrow.released_at;
+
+    ctx.accounts.escrow_account.status.transition_to(EscrowStatus::Completing)?;
+
+    // The early-release bonus is earned as of the auto-release, not the finalize
+    pay_out_release(
+        &ctx.accounts.escrow_account,
+        fee_basis_points,
+        released_at,
+        ReleaseTargets {
+            fee_recipient: &ctx.accounts.fee_recipient,
+            referrer: ctx.accounts.referrer.as_deref(),
+            destination: &ctx.accounts.recipient,
+            cranker: None,
+        },
+    )?;
+
+    record_release_reputation(
+        ctx.accounts.creator_reputation.as_mut(),
+        ctx.accounts.recipient_reputation.as_mut(),
+        amount,
+        clock.unix_timestamp,
+    );
+
+    // Back to Completed (before close transfers remaining rent to creator)
+    ctx.accounts.escrow_account.status.transition_to(EscrowStatus::Completed)?;
+
+    record_escrow_closed(&ctx.accounts.escrow_counter)?;
+
+    Ok(())
+}
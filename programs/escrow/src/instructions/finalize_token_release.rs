@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer, CloseAccount};
+use crate::state::*;
+use crate::errors::EscrowError;
+
+/// Second half of a two-phase token release (see `release_token_staged`).
+/// Permissionless: the payout can only go to a token account owned by the
+/// recipient, and rent only back to the creator, so anyone may retry it.
+#[derive(Accounts)]
+pub struct FinalizeTokenRelease<'info> {
+    #[account(
+        mut,
+        close = creator,
+        seeds = [b"token_escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
+        bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.status == EscrowStatus::Completing @ EscrowError::InvalidStatus,
+    )]
+    pub escrow_account: Account<'info, TokenEscrowAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"token_vault", escrow_account.key().as_ref()],
+        bump,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// CHECK: validated against escrow_account.creator (receives rent on close)
+    #[account(
+        mut,
+        constraint = escrow_account.creator == creator.key() @ EscrowError::UnauthorizedCreator
+    )]
+    pub creator: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = recipient_token_account.owner == escrow_account.recipient,
+        constraint = recipient_token_account.mint == escrow_account.mint @ EscrowError::MintMismatch,
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// Protocol config (optional - pass to honour the pause switch)
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Option<Account<'info, Config>>,
+}
+
+pub fn handler(ctx: Context<FinalizeTokenRelease>) -> Result<()> {
+    if let Some(config) = &ctx.accounts.config {
+        config.check_not_paused()?;
+    }
+
+    let escrow = &ctx.accounts.escrow_account;
+
+    // Anything sent to the vault after staging also goes to the recipient
+    let vault_balance = ctx.accounts.vault.amount;
+    require!(vault_balance >= escrow.staged_amount, EscrowError::VaultBalanceMismatch);
+
+    let escrow_id_bytes = escrow.escrow_id.to_le_bytes();
+    let seeds = &[
+        b"token_escrow".as_ref(),
+        escrow.creator.as_ref(),
+        escrow_id_bytes.as_ref(),
+        &[escrow.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.recipient_token_account.to_account_info(),
+                authority: ctx.accounts.escrow_account.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        vault_balance,
+    )?;
+
+    // Close vault account, return rent to creator
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.vault.to_account_info(),
+            destination: ctx.accounts.creator.to_account_info(),
+            authority: ctx.accounts.escrow_account.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    let escrow = &mut ctx.accounts.escrow_account;
+    escrow.staged_amount = 0;
+    escrow.status.transition_to(EscrowStatus::Completed)?;
+
+    Ok(())
+}
@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::util::{record_escrow_closed, refund_target};
+use crate::events::ForceCompleted;
+use crate::instructions::resolve_dispute::DisputeWinner;
+
+/// Last-resort settlement of a stuck escrow by the config admin, once
+/// `Config::force_complete_delay` has passed since the deadline. The full
+/// amount goes to `winner` with no protocol or referrer fee; a creator win is
+/// refunded like any other (see `EscrowAccount::refund_recipient`). The arbiter
+/// never ruled, so any arbitration fee deposit is marked refundable on its bond
+/// receipt for the payer to take back with claim_bond.
+#[derive(Accounts)]
+pub struct ForceComplete<'info> {
+    #[account(
+        mut,
+        close = creator,
+        seeds = [b"escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
+        bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = matches!(
+            escrow_account.status,
+            EscrowStatus::Created | EscrowStatus::Active | EscrowStatus::Disputed
+        ) @ EscrowError::InvalidStatus,
+    )]
+    pub escrow_account: Account<'info, EscrowAccount>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ EscrowError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+
+    /// CHECK: validated against escrow_account.creator
+    #[account(
+        mut,
+        constraint = escrow_account.creator == creator.key() @ EscrowError::UnauthorizedCreator
+    )]
+    pub creator: UncheckedAccount<'info>,
+
+    /// CHECK: validated against escrow_account.recipient
+    #[account(
+        mut,
+        constraint = escrow_account.recipient == recipient.key() @ EscrowError::UnauthorizedRecipient
+    )]
+    pub recipient: UncheckedAccount<'info>,
+
+    /// CHECK: validated against escrow_account.sponsor (required if the escrow was sponsored)
+    #[account(
+        mut,
+        constraint = escrow_account.sponsor == sponsor.key() @ EscrowError::InvalidSponsor
+    )]
+    pub sponsor: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: validated against escrow_account.refund_destination (required if it differs from the creator)
+    #[account(
+        mut,
+        constraint = escrow_account.refund_destination == refund_destination.key() @ EscrowError::InvalidRefundDestination
+    )]
+    pub refund_destination: Option<UncheckedAccount<'info>>,
+
+    /// Escrow's bond receipt (optional - required if an arbitration fee was deposited)
+    #[account(
+        mut,
+        seeds = [b"bond", escrow_account.key().as_ref()],
+        bump = bond_receipt.bump,
+    )]
+    pub bond_receipt: Option<Account<'info, BondReceipt>>,
+
+    /// CHECK: creator's escrow counter PDA (freed on close if it has been initialized)
+    #[account(
+        mut,
+        seeds = [b"counter", escrow_account.creator.as_ref()],
+        bump,
+    )]
+    pub escrow_counter: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<ForceComplete>, winner: DisputeWinner) -> Result<()> {
+    let escrow = &ctx.accounts.escrow_account;
+    let clock = Clock::get()?;
+
+    let unlocks_at = ctx.accounts.config
+        .force_complete_at(escrow.deadline)
+        .ok_or(EscrowError::ForceCompleteLocked)?;
+    require!(clock.unix_timestamp >= unlocks_at, EscrowError::ForceCompleteLocked);
+
+    let amount = escrow.amount;
+    let next_status = match (escrow.status, winner) {
+        (EscrowStatus::Disputed, _) => EscrowStatus::Resolved,
+        (_, DisputeWinner::Recipient) => EscrowStatus::Completed,
+        (EscrowStatus::Created, DisputeWinner::Creator) => EscrowStatus::Cancelled,
+        (_, DisputeWinner::Creator) => EscrowStatus::Refunded,
+    };
+
+    // Payouts pass through the same transient statuses as release and resolve
+    match next_status {
+        EscrowStatus::Resolved => ctx.accounts.escrow_account.status.transition_to(EscrowStatus::Resolving)?,
+        EscrowStatus::Completed => ctx.accounts.escrow_account.status.transition_to(EscrowStatus::Completing)?,
+        _ => {}
+    }
+
+    let escrow = &ctx.accounts.escrow_account;
+    let escrow_info = escrow.to_account_info();
+
+    let payee: &AccountInfo = match winner {
+        DisputeWinner::Recipient => &ctx.accounts.recipient,
+        DisputeWinner::Creator => refund_target(
+            escrow,
+            &ctx.accounts.creator,
+            ctx.accounts.sponsor.as_deref(),
+            ctx.accounts.refund_destination.as_deref(),
+        )?,
+    };
+    **escrow_info.try_borrow_mut_lamports()? -= amount;
+    **payee.try_borrow_mut_lamports()? += amount;
+
+    // No ruling was made, so the deposit belongs to whoever put it up
+    if escrow.arbitration_fee > 0 {
+        let receipt = ctx.accounts.bond_receipt.as_mut().ok_or(EscrowError::InvalidBondReceipt)?;
+        receipt.outcome = BondOutcome::Refundable;
+    }
+
+    let escrow = &mut ctx.accounts.escrow_account;
+    escrow.status.transition_to(next_status)?;
+
+    emit!(ForceCompleted {
+        escrow: escrow.key(),
+        admin: ctx.accounts.admin.key(),
+        winner,
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    record_escrow_closed(&ctx.accounts.escrow_counter)?;
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+
+/// Returned via return data by `get_claimable`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ClaimableAmounts {
+    /// Sum of milestones still pending release
+    pub claimable: u64,
+    /// Total released so far
+    pub released: u64,
+}
+
+#[derive(Accounts)]
+pub struct GetClaimable<'info> {
+    #[account(
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+    )]
+    pub escrow_account: Account<'info, MilestoneEscrowAccount>,
+}
+
+pub fn handler(ctx: Context<GetClaimable>) -> Result<ClaimableAmounts> {
+    let escrow = &ctx.accounts.escrow_account;
+
+    let mut claimable: u64 = 0;
+    for milestone in escrow.milestones[..escrow.milestone_count as usize].iter() {
+        if milestone.status.is_open() {
+            claimable = claimable.checked_add(milestone.amount).ok_or(EscrowError::Overflow)?;
+        }
+    }
+
+    Ok(ClaimableAmounts {
+        claimable,
+        released: escrow.released_amount,
+    })
+}
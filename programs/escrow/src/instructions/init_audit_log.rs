@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::util::append_audit_entry;
+
+#[derive(Accounts)]
+pub struct InitAuditLog<'info> {
+    #[account(
+        seeds = [b"escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
+        bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.creator == creator.key() @ EscrowError::UnauthorizedCreator,
+    )]
+    pub escrow_account: Account<'info, EscrowAccount>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = AuditLog::space_for(0),
+        seeds = [b"audit_log", escrow_account.key().as_ref()],
+        bump
+    )]
+    pub audit_log: Account<'info, AuditLog>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitAuditLog>) -> Result<()> {
+    // Deposit rent for the full capacity now so later appends can realloc
+    // without every instruction having to carry a payer
+    let rent = Rent::get()?;
+    let top_up = rent
+        .minimum_balance(AuditLog::MAX_SPACE)
+        .saturating_sub(rent.minimum_balance(AuditLog::space_for(0)));
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.creator.to_account_info(),
+                to: ctx.accounts.audit_log.to_account_info(),
+            },
+        ),
+        top_up,
+    )?;
+
+    let escrow = &ctx.accounts.escrow_account;
+    let log = &mut ctx.accounts.audit_log;
+    log.escrow = escrow.key();
+    log.entries = Vec::new();
+    log.bump = ctx.bumps.audit_log;
+
+    // The log may be opened after creation; backdate its first entry
+    append_audit_entry(log, AuditAction::Created, escrow.creator, escrow.created_at)?;
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::program::Escrow;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ConfigParams {
+    pub max_auto_release_horizon: i64,
+    pub min_arbiter_stake: u64,
+    pub fee_discount_min_tasks: u32,
+    pub min_fee_bps: u16,
+    pub max_deadline_horizon: i64,
+    pub crank_reward_lamports: u64,
+    pub min_milestone_amount: u64,
+    pub trusted_min_tasks: u32,
+    pub force_complete_delay: i64,
+    pub creator_inactivity_window: i64,
+    pub max_active_per_creator: u32,
+    pub min_arbiter_cases: u32,
+    pub bond_to_arbiter_bps: u16,
+}
+
+impl Config {
+    /// Validates and stores the tunable config values
+    pub fn apply(&mut self, params: &ConfigParams) -> Result<()> {
+        require!(params.max_auto_release_horizon >= 0, EscrowError::InvalidConfig);
+        require!(params.min_fee_bps <= MAX_FEE_BASIS_POINTS, EscrowError::InvalidConfig);
+        require!(params.max_deadline_horizon >= 0, EscrowError::InvalidConfig);
+        require!(params.creator_inactivity_window >= 0, EscrowError::InvalidConfig);
+        require!(params.bond_to_arbiter_bps <= 10_000, EscrowError::InvalidConfig);
+
+        self.max_auto_release_horizon = params.max_auto_release_horizon;
+        self.min_arbiter_stake = params.min_arbiter_stake;
+        self.fee_discount_min_tasks = params.fee_discount_min_tasks;
+        self.min_fee_bps = params.min_fee_bps;
+        self.max_deadline_horizon = params.max_deadline_horizon;
+        self.crank_reward_lamports = params.crank_reward_lamports;
+        self.min_milestone_amount = params.min_milestone_amount;
+        self.trusted_min_tasks = params.trusted_min_tasks;
+        self.force_complete_delay = params.force_complete_delay;
+        self.creator_inactivity_window = params.creator_inactivity_window;
+        self.max_active_per_creator = params.max_active_per_creator;
+        self.min_arbiter_cases = params.min_arbiter_cases;
+        self.bond_to_arbiter_bps = params.bond_to_arbiter_bps;
+
+        Ok(())
+    }
+}
+
+/// Creates the global protocol config. Only the program's upgrade authority
+/// may call it, so the deployer can't be front-run for the admin seat; the
+/// signer becomes the admin.
+#[derive(Accounts)]
+pub struct InitConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = Config::SPACE,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, Escrow>,
+
+    /// Upgradeable loader state naming the program's upgrade authority
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ EscrowError::NotUpgradeAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitConfig>, params: ConfigParams) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.admin = ctx.accounts.admin.key();
+    config.bump = ctx.bumps.config;
+    config.paused = false;
+    config.apply(&params)
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitEscrowCounter<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = EscrowCounter::SPACE,
+        seeds = [b"counter", creator.key().as_ref()],
+        bump
+    )]
+    pub escrow_counter: Account<'info, EscrowCounter>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitEscrowCounter>) -> Result<()> {
+    let counter = &mut ctx.accounts.escrow_counter;
+    counter.creator = ctx.accounts.creator.key();
+    counter.next_id = 0;
+    counter.bump = ctx.bumps.escrow_counter;
+    counter.active_escrows = 0;
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+
+#[derive(Accounts)]
+pub struct InitFeeVault<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = FeeVault::SPACE,
+        seeds = [b"fee_vault"],
+        bump
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ EscrowError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitFeeVault>) -> Result<()> {
+    ctx.accounts.fee_vault.bump = ctx.bumps.fee_vault;
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+
+#[derive(Accounts)]
+pub struct InitGlobalStats<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = GlobalStats::SPACE,
+        seeds = [b"global_stats"],
+        bump
+    )]
+    pub global_stats: Account<'info, GlobalStats>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ EscrowError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitGlobalStats>) -> Result<()> {
+    let stats = &mut ctx.accounts.global_stats;
+    stats.total_escrows_created = 0;
+    stats.total_volume_lamports = 0;
+    stats.total_disputes = 0;
+    stats.total_fees_collected = 0;
+    stats.bump = ctx.bumps.global_stats;
+
+    Ok(())
+}
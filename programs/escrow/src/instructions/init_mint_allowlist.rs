@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+
+/// Creates the global mint allowlist. Once it exists, clients pass it to
+/// create_token_escrow to restrict escrows to approved mints.
+#[derive(Accounts)]
+pub struct InitMintAllowlist<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = MintAllowlist::SPACE,
+        seeds = [b"mint_allowlist"],
+        bump
+    )]
+    pub mint_allowlist: Account<'info, MintAllowlist>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ EscrowError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitMintAllowlist>) -> Result<()> {
+    let allowlist = &mut ctx.accounts.mint_allowlist;
+    allowlist.mints = Vec::new();
+    allowlist.bump = ctx.bumps.mint_allowlist;
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+
+/// Creates the global per-mint fee table. Clients pass it to
+/// create_token_escrow along with USE_MINT_FEE_BPS to pick up the mint's fee.
+#[derive(Accounts)]
+pub struct InitMintFeeConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = MintFeeConfig::SPACE,
+        seeds = [b"mint_fee_config"],
+        bump
+    )]
+    pub mint_fee_config: Account<'info, MintFeeConfig>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ EscrowError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitMintFeeConfig>) -> Result<()> {
+    let fee_config = &mut ctx.accounts.mint_fee_config;
+    fee_config.fees = Vec::new();
+    fee_config.bump = ctx.bumps.mint_fee_config;
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
+use crate::errors::EscrowError;
 
 #[derive(Accounts)]
 pub struct InitReputation<'info> {
@@ -15,6 +16,14 @@ pub struct InitReputation<'info> {
     /// CHECK: The agent whose reputation is being initialized
     pub agent: UncheckedAccount<'info>,
 
+    /// CHECK: must be empty - set when the agent's reputation was closed
+    #[account(
+        seeds = [b"reputation_tombstone", agent.key().as_ref()],
+        bump,
+        constraint = tombstone.data_is_empty() @ EscrowError::ReputationClosed,
+    )]
+    pub tombstone: UncheckedAccount<'info>,
+
     #[account(mut)]
     pub payer: Signer<'info>,
 
@@ -34,6 +43,10 @@ pub fn handler(ctx: Context<InitReputation>) -> Result<()> {
     reputation.total_volume_lamports = 0;
     reputation.last_activity = Clock::get()?.unix_timestamp;
     reputation.bump = ctx.bumps.reputation_account;
+    reputation.tips_received = 0;
+    reputation.rating_sum = 0;
+    reputation.rating_count = 0;
+    reputation.deadlines_missed = 0;
 
     Ok(())
 }
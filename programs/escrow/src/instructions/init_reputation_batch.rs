@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::errors::EscrowError;
+
+/// Remaining accounts come in pairs per agent, in order: the writable
+/// reputation PDA, then the agent's reputation tombstone PDA
+#[derive(Accounts)]
+pub struct InitReputationBatch<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, InitReputationBatch<'info>>,
+    agents: Vec<Pubkey>,
+) -> Result<()> {
+    require!(
+        !agents.is_empty() && agents.len() <= MAX_BATCH_SIZE,
+        EscrowError::InvalidBatchSize
+    );
+    require!(
+        ctx.remaining_accounts.len() == agents.len() * 2,
+        EscrowError::BatchAccountMismatch
+    );
+
+    let clock = Clock::get()?;
+    let rent = Rent::get()?.minimum_balance(ReputationAccount::SPACE);
+
+    for (agent, pair) in agents.iter().zip(ctx.remaining_accounts.chunks(2)) {
+        let (reputation_info, tombstone_info) = (&pair[0], &pair[1]);
+
+        // Each pair must be the reputation and tombstone PDAs for its agent
+        let (expected, bump) = Pubkey::find_program_address(
+            &[b"reputation", agent.as_ref()],
+            ctx.program_id,
+        );
+        require!(reputation_info.key() == expected, EscrowError::BatchAccountMismatch);
+        let (tombstone, _) = Pubkey::find_program_address(
+            &[b"reputation_tombstone", agent.as_ref()],
+            ctx.program_id,
+        );
+        require!(tombstone_info.key() == tombstone, EscrowError::BatchAccountMismatch);
+        require!(tombstone_info.data_is_empty(), EscrowError::ReputationClosed);
+
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::CreateAccount {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: reputation_info.clone(),
+                },
+                &[&[b"reputation", agent.as_ref(), &[bump]]],
+            ),
+            rent,
+            ReputationAccount::SPACE as u64,
+            ctx.program_id,
+        )?;
+
+        let reputation = ReputationAccount {
+            agent: *agent,
+            escrows_created: 0,
+            escrows_completed: 0,
+            escrows_received: 0,
+            tasks_completed: 0,
+            disputes_initiated: 0,
+            disputes_won: 0,
+            disputes_lost: 0,
+            total_volume_lamports: 0,
+            last_activity: clock.unix_timestamp,
+            bump,
+            tips_received: 0,
+            rating_sum: 0,
+            rating_count: 0,
+            deadlines_missed: 0,
+        };
+        let mut data = reputation_info.try_borrow_mut_data()?;
+        reputation.try_serialize(&mut &mut data[..])?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::events::{ReputationUpdated, ReputationChangeReason};
+
+/// Folds an agent's old reputation into the one for their new wallet when
+/// they rotate keys. Both wallets sign, so nobody can claim or dump someone
+/// else's history. The old account is closed and its rent returned to the
+/// old wallet, less the rent for a tombstone that stops the old wallet from
+/// starting a fresh record.
+#[derive(Accounts)]
+pub struct MergeReputation<'info> {
+    #[account(
+        mut,
+        close = old_agent,
+        seeds = [b"reputation", old_agent.key().as_ref()],
+        bump = old_reputation.bump,
+        constraint = old_reputation.agent == old_agent.key(),
+        constraint = old_agent.key() != new_agent.key() @ EscrowError::ReputationSelfMerge,
+    )]
+    pub old_reputation: Account<'info, ReputationAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"reputation", new_agent.key().as_ref()],
+        bump = new_reputation.bump,
+        constraint = new_reputation.agent == new_agent.key(),
+    )]
+    pub new_reputation: Account<'info, ReputationAccount>,
+
+    #[account(mut)]
+    pub old_agent: Signer<'info>,
+
+    pub new_agent: Signer<'info>,
+
+    #[account(
+        init,
+        payer = old_agent,
+        space = ReputationTombstone::SPACE,
+        seeds = [b"reputation_tombstone", old_agent.key().as_ref()],
+        bump
+    )]
+    pub tombstone: Account<'info, ReputationTombstone>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MergeReputation>) -> Result<()> {
+    let old = &ctx.accounts.old_reputation;
+    let new = &mut ctx.accounts.new_reputation;
+
+    new.escrows_created = new.escrows_created.saturating_add(old.escrows_created);
+    new.escrows_completed = new.escrows_completed.saturating_add(old.escrows_completed);
+    new.escrows_received = new.escrows_received.saturating_add(old.escrows_received);
+    new.tasks_completed = new.tasks_completed.saturating_add(old.tasks_completed);
+    new.disputes_initiated = new.disputes_initiated.saturating_add(old.disputes_initiated);
+    new.disputes_won = new.disputes_won.saturating_add(old.disputes_won);
+    new.disputes_lost = new.disputes_lost.saturating_add(old.disputes_lost);
+    new.total_volume_lamports = new.total_volume_lamports.saturating_add(old.total_volume_lamports);
+    new.tips_received = new.tips_received.saturating_add(old.tips_received);
+    new.rating_sum = new.rating_sum.saturating_add(old.rating_sum);
+    new.rating_count = new.rating_count.saturating_add(old.rating_count);
+    new.deadlines_missed = new.deadlines_missed.saturating_add(old.deadlines_missed);
+    new.last_activity = Clock::get()?.unix_timestamp;
+
+    let tombstone = &mut ctx.accounts.tombstone;
+    tombstone.agent = ctx.accounts.old_agent.key();
+    tombstone.closed_at = new.last_activity;
+    tombstone.bump = ctx.bumps.tombstone;
+
+    emit!(ReputationUpdated::new(new, ReputationChangeReason::Merged));
+
+    Ok(())
+}
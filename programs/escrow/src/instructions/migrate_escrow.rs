@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::util::migrate_escrow_layout;
+
+/// Grows an escrow created under an older, shorter layout to the current
+/// `EscrowAccount::SPACE` (see `util::migrate_escrow_layout`).
+#[derive(Accounts)]
+pub struct MigrateEscrow<'info> {
+    /// CHECK: old layouts can't be deserialized as EscrowAccount; owner,
+    /// discriminator and creator are validated in the handler
+    #[account(mut, owner = crate::ID)]
+    pub escrow_account: UncheckedAccount<'info>,
+
+    /// Creator pays the extra rent
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateEscrow>) -> Result<()> {
+    let escrow_info = ctx.accounts.escrow_account.to_account_info();
+    migrate_escrow_layout(
+        &escrow_info,
+        EscrowAccount::DISCRIMINATOR,
+        EscrowAccount::SPACE,
+        &ctx.accounts.creator,
+        &ctx.accounts.system_program,
+    )?;
+
+    let mut escrow = Account::<EscrowAccount>::try_from(&escrow_info)?;
+    // A zeroed refund destination would strand refunds; default it to the creator
+    if escrow.refund_destination == Pubkey::default() {
+        escrow.refund_destination = escrow.creator;
+    }
+    escrow.exit(&crate::ID)?;
+
+    Ok(())
+}
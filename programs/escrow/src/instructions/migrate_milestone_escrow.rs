@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::util::migrate_escrow_layout;
+
+/// Grows a milestone escrow created under an older, shorter layout to the
+/// current `MilestoneEscrowAccount::SPACE` (see `util::migrate_escrow_layout`).
+#[derive(Accounts)]
+pub struct MigrateMilestoneEscrow<'info> {
+    /// CHECK: old layouts can't be deserialized as MilestoneEscrowAccount; owner,
+    /// discriminator and creator are validated in the handler
+    #[account(mut, owner = crate::ID)]
+    pub escrow_account: UncheckedAccount<'info>,
+
+    /// Creator pays the extra rent
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateMilestoneEscrow>) -> Result<()> {
+    migrate_escrow_layout(
+        &ctx.accounts.escrow_account,
+        MilestoneEscrowAccount::DISCRIMINATOR,
+        MilestoneEscrowAccount::SPACE,
+        &ctx.accounts.creator,
+        &ctx.accounts.system_program,
+    )
+}
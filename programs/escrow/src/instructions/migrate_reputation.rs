@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::util::grow_account;
+
+/// Grows a reputation account created under an older, shorter layout to the
+/// current `ReputationAccount::SPACE`. Fields are only ever appended, so the
+/// existing counters stay valid and the new tail is zero-initialized.
+/// Anyone may migrate an agent's reputation, as with init_reputation.
+#[derive(Accounts)]
+pub struct MigrateReputation<'info> {
+    /// CHECK: old layouts can't be deserialized as ReputationAccount; the
+    /// discriminator is validated in the handler
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [b"reputation", agent.key().as_ref()],
+        bump,
+    )]
+    pub reputation_account: UncheckedAccount<'info>,
+
+    /// CHECK: The agent whose reputation is being migrated
+    pub agent: UncheckedAccount<'info>,
+
+    /// Pays the extra rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateReputation>) -> Result<()> {
+    let reputation_info = ctx.accounts.reputation_account.to_account_info();
+    {
+        let data = reputation_info.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && data[..8] == ReputationAccount::DISCRIMINATOR,
+            anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+        );
+    }
+
+    // Already on the current layout
+    if reputation_info.data_len() >= ReputationAccount::SPACE {
+        return Ok(());
+    }
+
+    grow_account(
+        &reputation_info,
+        ReputationAccount::SPACE,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::util::migrate_escrow_layout;
+
+/// Grows a token escrow created under an older, shorter layout to the
+/// current `TokenEscrowAccount::SPACE` (see `util::migrate_escrow_layout`).
+#[derive(Accounts)]
+pub struct MigrateTokenEscrow<'info> {
+    /// CHECK: old layouts can't be deserialized as TokenEscrowAccount; owner,
+    /// discriminator and creator are validated in the handler
+    #[account(mut, owner = crate::ID)]
+    pub escrow_account: UncheckedAccount<'info>,
+
+    /// Creator pays the extra rent
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateTokenEscrow>) -> Result<()> {
+    migrate_escrow_layout(
+        &ctx.accounts.escrow_account,
+        TokenEscrowAccount::DISCRIMINATOR,
+        TokenEscrowAccount::SPACE,
+        &ctx.accounts.creator,
+        &ctx.accounts.system_program,
+    )
+}
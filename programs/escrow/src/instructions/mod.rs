@@ -1,45 +1,181 @@
 #![allow(ambiguous_glob_reexports)]
 
 pub mod create_escrow;
+pub mod create_escrow_batch;
+pub mod init_escrow_counter;
 pub mod accept_task;
 pub mod release_payment;
+pub mod rate_recipient;
+pub mod release_and_rate;
+pub mod release_with_tip;
+pub mod trusted_fast_release;
 pub mod request_refund;
+pub mod request_partial_refund;
+pub mod amend_terms;
+pub mod reassign_task;
+pub mod cancel_auto_release;
+pub mod request_release;
+pub mod submit_work;
+pub mod set_delegate;
+pub mod acknowledge_terms;
+pub mod migrate_escrow;
+pub mod migrate_token_escrow;
+pub mod migrate_milestone_escrow;
+pub mod migrate_reputation;
+pub mod split_escrow;
 pub mod dispute;
 pub mod resolve_dispute;
+pub mod assign_arbiter;
+pub mod assign_random_arbiter;
+pub mod request_arbitration;
+pub mod claim_bond;
 pub mod auto_release;
+pub mod claim_auto_release;
+pub mod creator_inactivity_release;
+pub mod finalize_escrow;
 pub mod create_token_escrow;
 pub mod accept_token_task;
 pub mod release_token_payment;
+pub mod release_token_staged;
+pub mod finalize_token_release;
 pub mod refund_token_escrow;
+pub mod recover_frozen_token_escrow;
 pub mod dispute_token;
 pub mod resolve_token_dispute;
+pub mod resolve_token_dispute_split;
+pub mod resolve_token_dispute_advanced;
 pub mod auto_release_token;
 pub mod create_milestone_escrow;
 pub mod accept_milestone_task;
+pub mod accept_milestones;
 pub mod release_milestone;
 pub mod dispute_milestone;
 pub mod resolve_milestone_dispute;
 pub mod refund_milestone_escrow;
+pub mod create_milestone_escrow_compact;
+pub mod accept_compact_milestone_task;
+pub mod release_compact_milestone;
+pub mod refund_compact_milestone_escrow;
+pub mod get_claimable;
+pub mod create_hybrid_escrow;
+pub mod accept_hybrid_task;
+pub mod release_hybrid;
+pub mod refund_hybrid;
+pub mod create_recurring_escrow;
+pub mod release_period;
+pub mod top_up_recurring;
+pub mod cancel_recurring;
 pub mod init_reputation;
+pub mod init_reputation_batch;
+pub mod reputation_commitment;
+pub mod reputation_score;
+pub mod init_config;
+pub mod update_config;
+pub mod set_paused;
+pub mod redirect_fees;
+pub mod sweep_dust;
+pub mod init_fee_vault;
+pub mod withdraw_fees;
+pub mod init_global_stats;
+pub mod force_complete;
+pub mod init_mint_allowlist;
+pub mod add_mint;
+pub mod remove_mint;
+pub mod init_mint_fee_config;
+pub mod set_mint_fee;
+pub mod register_arbiter;
+pub mod deregister_arbiter;
+pub mod close_reputation;
+pub mod merge_reputation;
+pub mod init_audit_log;
+pub mod resolve_disputes_batch;
 
 pub use create_escrow::*;
+pub use create_escrow_batch::*;
+pub use init_escrow_counter::*;
 pub use accept_task::*;
 pub use release_payment::*;
+pub use rate_recipient::*;
+pub use release_and_rate::*;
+pub use release_with_tip::*;
+pub use trusted_fast_release::*;
 pub use request_refund::*;
+pub use request_partial_refund::*;
+pub use amend_terms::*;
+pub use reassign_task::*;
+pub use cancel_auto_release::*;
+pub use request_release::*;
+pub use submit_work::*;
+pub use set_delegate::*;
+pub use acknowledge_terms::*;
+pub use migrate_escrow::*;
+pub use migrate_token_escrow::*;
+pub use migrate_milestone_escrow::*;
+pub use migrate_reputation::*;
+pub use split_escrow::*;
 pub use dispute::*;
 pub use resolve_dispute::*;
+pub use assign_arbiter::*;
+pub use assign_random_arbiter::*;
+pub use request_arbitration::*;
+pub use claim_bond::*;
 pub use auto_release::*;
+pub use claim_auto_release::*;
+pub use creator_inactivity_release::*;
+pub use finalize_escrow::*;
 pub use create_token_escrow::*;
 pub use accept_token_task::*;
 pub use release_token_payment::*;
+pub use release_token_staged::*;
+pub use finalize_token_release::*;
 pub use refund_token_escrow::*;
+pub use recover_frozen_token_escrow::*;
 pub use dispute_token::*;
 pub use resolve_token_dispute::*;
+pub use resolve_token_dispute_split::*;
+pub use resolve_token_dispute_advanced::*;
 pub use auto_release_token::*;
 pub use create_milestone_escrow::*;
 pub use accept_milestone_task::*;
+pub use accept_milestones::*;
 pub use release_milestone::*;
 pub use dispute_milestone::*;
 pub use resolve_milestone_dispute::*;
 pub use refund_milestone_escrow::*;
+pub use create_milestone_escrow_compact::*;
+pub use accept_compact_milestone_task::*;
+pub use release_compact_milestone::*;
+pub use refund_compact_milestone_escrow::*;
+pub use get_claimable::*;
+pub use create_hybrid_escrow::*;
+pub use accept_hybrid_task::*;
+pub use release_hybrid::*;
+pub use refund_hybrid::*;
+pub use create_recurring_escrow::*;
+pub use release_period::*;
+pub use top_up_recurring::*;
+pub use cancel_recurring::*;
 pub use init_reputation::*;
+pub use init_reputation_batch::*;
+pub use reputation_commitment::*;
+pub use reputation_score::*;
+pub use init_config::*;
+pub use update_config::*;
+pub use set_paused::*;
+pub use redirect_fees::*;
+pub use sweep_dust::*;
+pub use init_fee_vault::*;
+pub use withdraw_fees::*;
+pub use init_global_stats::*;
+pub use force_complete::*;
+pub use init_mint_allowlist::*;
+pub use add_mint::*;
+pub use remove_mint::*;
+pub use init_mint_fee_config::*;
+pub use set_mint_fee::*;
+pub use register_arbiter::*;
+pub use deregister_arbiter::*;
+pub use close_reputation::*;
+pub use merge_reputation::*;
+pub use init_audit_log::*;
+pub use resolve_disputes_batch::*;
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::events::{ReputationUpdated, ReputationChangeReason};
+
+#[derive(Accounts)]
+pub struct RateRecipient<'info> {
+    /// Closing the receipt is what stops the same escrow being rated twice
+    #[account(
+        mut,
+        close = creator,
+        seeds = [b"rating_receipt", rating_receipt.escrow.as_ref()],
+        bump = rating_receipt.bump,
+        has_one = creator @ EscrowError::UnauthorizedCreator,
+    )]
+    pub rating_receipt: Account<'info, RatingReceipt>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"reputation", rating_receipt.recipient.as_ref()],
+        bump = recipient_reputation.bump,
+    )]
+    pub recipient_reputation: Account<'info, ReputationAccount>,
+}
+
+pub fn handler(ctx: Context<RateRecipient>, stars: u8) -> Result<()> {
+    require!((1..=5).contains(&stars), EscrowError::InvalidRating);
+
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp <= ctx.accounts.rating_receipt.released_at.saturating_add(RATING_WINDOW_SECONDS),
+        EscrowError::RatingWindowClosed
+    );
+
+    let recipient_rep = &mut ctx.accounts.recipient_reputation;
+    recipient_rep.rating_sum = recipient_rep.rating_sum.saturating_add(stars as u64);
+    recipient_rep.rating_count = recipient_rep.rating_count.saturating_add(1);
+    recipient_rep.last_activity = clock.unix_timestamp;
+    emit!(ReputationUpdated::new(recipient_rep, ReputationChangeReason::RatingReceived));
+
+    Ok(())
+}
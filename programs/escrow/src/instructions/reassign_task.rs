@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+
+#[derive(Accounts)]
+#[instruction(new_recipient: Pubkey)]
+pub struct ReassignTask<'info> {
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
+        bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.creator == creator.key() @ EscrowError::UnauthorizedCreator,
+        constraint = escrow_account.recipient == recipient.key() @ EscrowError::UnauthorizedRecipient,
+        constraint = escrow_account.status == EscrowStatus::Active @ EscrowError::InvalidStatus,
+        constraint = new_recipient != escrow_account.creator @ EscrowError::SelfEscrow,
+        constraint = new_recipient != escrow_account.arbiter @ EscrowError::SelfEscrow,
+    )]
+    pub escrow_account: Account<'info, EscrowAccount>,
+
+    /// Both parties must sign to hand the task to another worker
+    pub creator: Signer<'info>,
+
+    pub recipient: Signer<'info>,
+}
+
+/// Hands an active task to `new_recipient`. Reputation is untouched here:
+/// only whoever holds the task at settlement is credited for it.
+pub fn handler(ctx: Context<ReassignTask>, new_recipient: Pubkey) -> Result<()> {
+    let escrow = &mut ctx.accounts.escrow_account;
+    escrow.recipient = new_recipient;
+    // The new worker has not signalled completion yet
+    escrow.release_requested = false;
+    // The old recipient's hot key must not carry over
+    escrow.delegate = Pubkey::default();
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer, CloseAccount};
+use crate::state::*;
+use crate::errors::EscrowError;
+
+#[derive(Accounts)]
+pub struct RecoverFrozenTokenEscrow<'info> {
+    #[account(
+        mut,
+        seeds = [b"token_escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
+        bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.creator == creator.key() @ EscrowError::UnauthorizedCreator,
+        constraint = escrow_account.status == EscrowStatus::Active @ EscrowError::InvalidStatus,
+    )]
+    pub escrow_account: Account<'info, TokenEscrowAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"token_vault", escrow_account.key().as_ref()],
+        bump,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = creator_token_account.owner == escrow_account.creator,
+        constraint = creator_token_account.mint == escrow_account.mint,
+    )]
+    pub creator_token_account: Account<'info, TokenAccount>,
+
+    /// The account release_token_payment would pay into
+    #[account(
+        constraint = recipient_token_account.owner == escrow_account.recipient,
+        constraint = recipient_token_account.mint == escrow_account.mint,
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Two-step recovery for an active token escrow whose recipient account was
+/// frozen by the mint's freeze authority. The first call records the freeze;
+/// once it has persisted for FROZEN_RECOVERY_SECONDS a second call refunds
+/// the vault to the creator.
+pub fn handler(ctx: Context<RecoverFrozenTokenEscrow>) -> Result<()> {
+    require!(
+        ctx.accounts.recipient_token_account.is_frozen(),
+        EscrowError::RecipientAccountNotFrozen
+    );
+
+    let clock = Clock::get()?;
+    let escrow = &ctx.accounts.escrow_account;
+
+    if escrow.frozen_reported_at == 0 {
+        ctx.accounts.escrow_account.frozen_reported_at = clock.unix_timestamp;
+        return Ok(());
+    }
+
+    require!(
+        clock.unix_timestamp >= escrow.frozen_reported_at.saturating_add(FROZEN_RECOVERY_SECONDS),
+        EscrowError::FrozenRecoveryNotReady
+    );
+
+    let escrow_id_bytes = escrow.escrow_id.to_le_bytes();
+    let seeds = &[
+        b"token_escrow".as_ref(),
+        escrow.creator.as_ref(),
+        escrow_id_bytes.as_ref(),
+        &[escrow.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    // Transfer tokens back to creator
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.creator_token_account.to_account_info(),
+                authority: ctx.accounts.escrow_account.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        escrow.amount,
+    )?;
+
+    // Close vault
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.vault.to_account_info(),
+            destination: ctx.accounts.creator.to_account_info(),
+            authority: ctx.accounts.escrow_account.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    // Closed here rather than via `close =` so the reporting step keeps the account
+    let escrow = &mut ctx.accounts.escrow_account;
+    escrow.status.transition_to(EscrowStatus::Refunded)?;
+    escrow.close(ctx.accounts.creator.to_account_info())?;
+
+    Ok(())
+}
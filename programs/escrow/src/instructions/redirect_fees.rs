@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::util::check_fee_recipient;
+
+/// Safety valve for treasury rotation: lets the config admin point an
+/// in-flight escrow at a new fee recipient before it is released.
+#[derive(Accounts)]
+pub struct RedirectFees<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ EscrowError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
+        bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+    )]
+    pub escrow_account: Account<'info, EscrowAccount>,
+}
+
+pub fn handler(ctx: Context<RedirectFees>, new_fee_recipient: Pubkey) -> Result<()> {
+    let escrow = &mut ctx.accounts.escrow_account;
+
+    // Only escrows that still hold funds can be redirected
+    match escrow.status {
+        EscrowStatus::Created | EscrowStatus::Active | EscrowStatus::Disputed => {}
+        _ => return Err(EscrowError::InvalidStatus.into()),
+    }
+
+    check_fee_recipient(&new_fee_recipient, escrow.fee_basis_points)?;
+    escrow.fee_recipient = new_fee_recipient;
+
+    Ok(())
+}
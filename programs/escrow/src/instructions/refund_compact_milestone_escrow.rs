@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::util::is_expired;
+
+#[derive(Accounts)]
+pub struct RefundCompactMilestoneEscrow<'info> {
+    #[account(
+        mut,
+        close = creator,
+        seeds = [b"compact_milestone_escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
+        bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.creator == creator.key() @ EscrowError::UnauthorizedCreator,
+    )]
+    pub escrow_account: Account<'info, CompactMilestoneEscrowAccount>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RefundCompactMilestoneEscrow>) -> Result<()> {
+    let escrow = &ctx.accounts.escrow_account;
+    let clock = Clock::get()?;
+
+    match escrow.status {
+        EscrowStatus::Created => {}
+        EscrowStatus::Active => {
+            require!(
+                is_expired(escrow.deadline, clock.unix_timestamp),
+                EscrowError::DeadlineNotReached
+            );
+        }
+        _ => return Err(EscrowError::InvalidStatus.into()),
+    }
+
+    // Unreleased amount comes back with the rent when the account closes
+    let escrow = &mut ctx.accounts.escrow_account;
+    let next_status = if escrow.status == EscrowStatus::Created {
+        EscrowStatus::Cancelled
+    } else {
+        EscrowStatus::Refunded
+    };
+    escrow.status.transition_to(next_status)?;
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::EscrowError;
+use crate::util::is_expired;
 
 #[derive(Accounts)]
 pub struct RefundMilestoneEscrow<'info> {
@@ -9,6 +10,7 @@ pub struct RefundMilestoneEscrow<'info> {
         close = creator,
         seeds = [b"milestone_escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
         bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
         constraint = escrow_account.creator == creator.key() @ EscrowError::UnauthorizedCreator,
     )]
     pub escrow_account: Account<'info, MilestoneEscrowAccount>,
@@ -26,13 +28,21 @@ pub fn handler(ctx: Context<RefundMilestoneEscrow>) -> Result<()> {
         EscrowStatus::Created => {}
         EscrowStatus::Active => {
             require!(
-                clock.unix_timestamp >= escrow.deadline,
+                is_expired(escrow.deadline, clock.unix_timestamp),
                 EscrowError::DeadlineNotReached
             );
         }
         _ => return Err(EscrowError::InvalidStatus.into()),
     }
 
+    // Disputed milestones must be resolved by the arbiter before refunding
+    require!(
+        !escrow.milestones[..escrow.milestone_count as usize]
+            .iter()
+            .any(|m| m.status == MilestoneStatus::Disputed),
+        EscrowError::MilestoneDisputed
+    );
+
     // Refund only unreleased amount
     let unreleased = escrow.total_amount.checked_sub(escrow.released_amount).ok_or(EscrowError::Overflow)?;
 
@@ -43,11 +53,12 @@ pub fn handler(ctx: Context<RefundMilestoneEscrow>) -> Result<()> {
     }
 
     let escrow = &mut ctx.accounts.escrow_account;
-    escrow.status = if escrow.status == EscrowStatus::Created {
+    let next_status = if escrow.status == EscrowStatus::Created {
         EscrowStatus::Cancelled
     } else {
         EscrowStatus::Refunded
     };
+    escrow.status.transition_to(next_status)?;
 
     Ok(())
 }
@@ -2,6 +2,8 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer, CloseAccount};
 use crate::state::*;
 use crate::errors::EscrowError;
+use crate::util::is_expired;
+use crate::events::{TokenEscrowRefunded, ReputationUpdated, ReputationChangeReason};
 
 #[derive(Accounts)]
 pub struct RefundTokenEscrow<'info> {
@@ -10,6 +12,7 @@ pub struct RefundTokenEscrow<'info> {
         close = creator,
         seeds = [b"token_escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
         bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
         constraint = escrow_account.creator == creator.key() @ EscrowError::UnauthorizedCreator,
     )]
     pub escrow_account: Account<'info, TokenEscrowAccount>,
@@ -27,11 +30,19 @@ pub struct RefundTokenEscrow<'info> {
     #[account(
         mut,
         constraint = creator_token_account.owner == escrow_account.creator,
-        constraint = creator_token_account.mint == escrow_account.mint,
+        constraint = creator_token_account.mint == escrow_account.mint @ EscrowError::MintMismatch,
     )]
     pub creator_token_account: Account<'info, TokenAccount>,
 
     pub token_program: Program<'info, Token>,
+
+    /// Recipient's reputation account (optional - pass to record a missed deadline)
+    #[account(
+        mut,
+        seeds = [b"reputation", escrow_account.recipient.as_ref()],
+        bump = recipient_reputation.bump,
+    )]
+    pub recipient_reputation: Option<Account<'info, ReputationAccount>>,
 }
 
 pub fn handler(ctx: Context<RefundTokenEscrow>) -> Result<()> {
@@ -42,7 +53,7 @@ pub fn handler(ctx: Context<RefundTokenEscrow>) -> Result<()> {
         EscrowStatus::Created => {}
         EscrowStatus::Active => {
             require!(
-                clock.unix_timestamp >= escrow.deadline,
+                is_expired(escrow.deadline, clock.unix_timestamp),
                 EscrowError::DeadlineNotReached
             );
         }
@@ -84,11 +95,32 @@ pub fn handler(ctx: Context<RefundTokenEscrow>) -> Result<()> {
     ))?;
 
     let escrow = &mut ctx.accounts.escrow_account;
-    escrow.status = if escrow.status == EscrowStatus::Created {
+    let next_status = if escrow.status == EscrowStatus::Created {
         EscrowStatus::Cancelled
     } else {
         EscrowStatus::Refunded
     };
+    escrow.status.transition_to(next_status)?;
+
+    // Cancelling before acceptance is no one's fault; only an accepted
+    // escrow that ran out the clock counts against the recipient
+    if next_status == EscrowStatus::Refunded {
+        if let Some(recipient_rep) = &mut ctx.accounts.recipient_reputation {
+            recipient_rep.deadlines_missed = recipient_rep.deadlines_missed.saturating_add(1);
+            recipient_rep.last_activity = clock.unix_timestamp;
+            emit!(ReputationUpdated::new(recipient_rep, ReputationChangeReason::DeadlineMissed));
+        }
+    }
+
+    let escrow = &ctx.accounts.escrow_account;
+    emit!(TokenEscrowRefunded {
+        escrow: escrow.key(),
+        creator: escrow.creator,
+        mint: escrow.mint,
+        amount: escrow.amount,
+        status: next_status,
+        timestamp: clock.unix_timestamp,
+    });
 
     Ok(())
 }
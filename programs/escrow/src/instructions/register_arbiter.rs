@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::errors::EscrowError;
+
+#[derive(Accounts)]
+pub struct RegisterArbiter<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = ArbiterAccount::SPACE,
+        seeds = [b"arbiter", authority.key().as_ref()],
+        bump
+    )]
+    pub arbiter_account: Account<'info, ArbiterAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RegisterArbiter>, stake: u64) -> Result<()> {
+    require!(stake > 0, EscrowError::ZeroAmount);
+
+    // Lock the stake in the arbiter PDA
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.authority.to_account_info(),
+                to: ctx.accounts.arbiter_account.to_account_info(),
+            },
+        ),
+        stake,
+    )?;
+
+    let clock = Clock::get()?;
+    let arbiter = &mut ctx.accounts.arbiter_account;
+    arbiter.authority = ctx.accounts.authority.key();
+    arbiter.stake = stake;
+    arbiter.cases_resolved = 0;
+    arbiter.registered_at = clock.unix_timestamp;
+    arbiter.last_active_at = clock.unix_timestamp;
+    arbiter.bump = ctx.bumps.arbiter_account;
+
+    Ok(())
+}
@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::events::{ReputationUpdated, ReputationChangeReason};
+use crate::util::{pay_out_release, record_escrow_closed, record_release_reputation, ReleaseTargets};
+
+/// release_payment followed by rate_recipient in one instruction, for the
+/// common happy path. No RatingReceipt is created since the rating is
+/// recorded immediately.
+#[derive(Accounts)]
+pub struct ReleaseAndRate<'info> {
+    #[account(
+        mut,
+        close = creator,
+        seeds = [b"escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
+        bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.creator == creator.key() @ EscrowError::UnauthorizedCreator,
+        constraint = escrow_account.status != EscrowStatus::Completed @ EscrowError::AlreadyReleased,
+        constraint = escrow_account.status == EscrowStatus::Active @ EscrowError::InvalidStatus,
+    )]
+    pub escrow_account: Account<'info, EscrowAccount>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// CHECK: validated against escrow_account.recipient
+    #[account(
+        mut,
+        constraint = escrow_account.recipient == recipient.key() @ EscrowError::UnauthorizedRecipient
+    )]
+    pub recipient: UncheckedAccount<'info>,
+
+    /// CHECK: validated against escrow_account.fee_recipient
+    #[account(
+        mut,
+        constraint = escrow_account.fee_recipient == fee_recipient.key()
+    )]
+    pub fee_recipient: UncheckedAccount<'info>,
+
+    /// Creator's reputation account (optional - pass if tracking reputation)
+    #[account(
+        mut,
+        seeds = [b"reputation", escrow_account.creator.as_ref()],
+        bump = creator_reputation.bump,
+    )]
+    pub creator_reputation: Option<Account<'info, ReputationAccount>>,
+
+    /// Recipient's reputation account, which receives the rating
+    #[account(
+        mut,
+        seeds = [b"reputation", escrow_account.recipient.as_ref()],
+        bump = recipient_reputation.bump,
+    )]
+    pub recipient_reputation: Account<'info, ReputationAccount>,
+
+    /// CHECK: validated against escrow_account.referrer (required if referrer_fee_bps > 0)
+    #[account(
+        mut,
+        constraint = escrow_account.referrer == referrer.key() @ EscrowError::InvalidReferrer
+    )]
+    pub referrer: Option<UncheckedAccount<'info>>,
+
+    /// Protocol config (optional - pass to honour the pause switch and apply the reputation fee discount)
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Option<Account<'info, Config>>,
+
+    /// Second approver (optional - required if the escrow has a cosigner)
+    pub cosigner: Option<Signer<'info>>,
+
+    /// CHECK: creator's escrow counter PDA (freed on close if it has been initialized)
+    #[account(
+        mut,
+        seeds = [b"counter", escrow_account.creator.as_ref()],
+        bump,
+    )]
+    pub escrow_counter: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<ReleaseAndRate>, stars: u8) -> Result<()> {
+    require!((1..=5).contains(&stars), EscrowError::InvalidRating);
+
+    if let Some(config) = &ctx.accounts.config {
+        config.check_not_paused()?;
+    }
+
+    let escrow = &ctx.accounts.escrow_account;
+    let amount = escrow.amount;
+
+    escrow.check_cosigner(ctx.accounts.cosigner.as_ref().map(|c| c.key()))?;
+
+    // High-reputation recipients pay a reduced protocol fee
+    let fee_basis_points = match &ctx.accounts.config {
+        Some(config) => config.discounted_fee_bps(
+            escrow.fee_basis_points,
+            ctx.accounts.recipient_reputation.tasks_completed,
+        ),
+        None => escrow.fee_basis_points,
+    };
+
+    let clock = Clock::get()?;
+    ctx.accounts.escrow_account.status.transition_to(EscrowStatus::Completing)?;
+
+    pay_out_release(
+        &ctx.accounts.escrow_account,
+        fee_basis_points,
+        clock.unix_timestamp,
+        ReleaseTargets {
+            fee_recipient: &ctx.accounts.fee_recipient,
+            referrer: ctx.accounts.referrer.as_deref(),
+            destination: &ctx.accounts.recipient,
+            cranker: None,
+        },
+    )?;
+
+    // Same anti-gaming floor as release_payment; the rating itself always counts
+    record_release_reputation(
+        ctx.accounts.creator_reputation.as_mut(),
+        Some(&mut ctx.accounts.recipient_reputation),
+        amount,
+        clock.unix_timestamp,
+    );
+
+    let recipient_rep = &mut ctx.accounts.recipient_reputation;
+    recipient_rep.rating_sum = recipient_rep.rating_sum.saturating_add(stars as u64);
+    recipient_rep.rating_count = recipient_rep.rating_count.saturating_add(1);
+    recipient_rep.last_activity = clock.unix_timestamp;
+    emit!(ReputationUpdated::new(recipient_rep, ReputationChangeReason::RatingReceived));
+
+    let escrow = &mut ctx.accounts.escrow_account;
+    escrow.status.transition_to(EscrowStatus::Completed)?;
+
+    record_escrow_closed(&ctx.accounts.escrow_counter)?;
+
+    Ok(())
+}
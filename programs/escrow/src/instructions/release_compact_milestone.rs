@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::util::calc_fee;
+
+#[derive(Accounts)]
+pub struct ReleaseCompactMilestone<'info> {
+    #[account(
+        mut,
+        seeds = [b"compact_milestone_escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
+        bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.creator == creator.key() @ EscrowError::UnauthorizedCreator,
+        constraint = escrow_account.status == EscrowStatus::Active @ EscrowError::InvalidStatus,
+    )]
+    pub escrow_account: Account<'info, CompactMilestoneEscrowAccount>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// CHECK: validated against escrow_account.recipient
+    #[account(
+        mut,
+        constraint = escrow_account.recipient == recipient.key() @ EscrowError::UnauthorizedRecipient
+    )]
+    pub recipient: UncheckedAccount<'info>,
+
+    /// CHECK: validated against escrow_account.fee_recipient
+    #[account(
+        mut,
+        constraint = escrow_account.fee_recipient == fee_recipient.key()
+    )]
+    pub fee_recipient: UncheckedAccount<'info>,
+
+    /// Protocol config (optional - pass to honour the pause switch)
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Option<Account<'info, Config>>,
+}
+
+pub fn handler(ctx: Context<ReleaseCompactMilestone>, milestone_index: u8) -> Result<()> {
+    if let Some(config) = &ctx.accounts.config {
+        config.check_not_paused()?;
+    }
+
+    let escrow = &ctx.accounts.escrow_account;
+    let milestone = escrow.milestones
+        .get(milestone_index as usize)
+        .ok_or(EscrowError::InvalidMilestoneIndex)?;
+    require!(milestone.status.is_open(), EscrowError::MilestoneAlreadyReleased);
+
+    let amount = milestone.amount;
+    let (fee, recipient_amount) = calc_fee(amount, escrow.fee_basis_points)?;
+
+    // Mark the milestone released before moving lamports so it can't be paid twice
+    let escrow = &mut ctx.accounts.escrow_account;
+    escrow.milestones[milestone_index as usize].status = MilestoneStatus::Released;
+    escrow.released_amount = escrow.released_amount.checked_add(amount).ok_or(EscrowError::Overflow)?;
+    require!(escrow.released_amount <= escrow.total_amount, EscrowError::Overflow);
+
+    let escrow_info = ctx.accounts.escrow_account.to_account_info();
+
+    if fee > 0 {
+        **escrow_info.try_borrow_mut_lamports()? -= fee;
+        **ctx.accounts.fee_recipient.try_borrow_mut_lamports()? += fee;
+    }
+
+    **escrow_info.try_borrow_mut_lamports()? -= recipient_amount;
+    **ctx.accounts.recipient.try_borrow_mut_lamports()? += recipient_amount;
+
+    let escrow = &mut ctx.accounts.escrow_account;
+    if escrow.milestones.iter().all(|m| m.status.is_settled()) {
+        escrow.status.transition_to(EscrowStatus::Completed)?;
+    }
+
+    Ok(())
+}
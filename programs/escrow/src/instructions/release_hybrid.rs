@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer, CloseAccount};
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::util::calc_fee;
+
+#[derive(Accounts)]
+pub struct ReleaseHybrid<'info> {
+    #[account(
+        mut,
+        close = creator,
+        seeds = [b"hybrid_escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
+        bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.creator == creator.key() @ EscrowError::UnauthorizedCreator,
+        constraint = escrow_account.status != EscrowStatus::Completed @ EscrowError::AlreadyReleased,
+        constraint = escrow_account.status == EscrowStatus::Active @ EscrowError::InvalidStatus,
+    )]
+    pub escrow_account: Account<'info, HybridEscrowAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"hybrid_vault", escrow_account.key().as_ref()],
+        bump,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// CHECK: validated against escrow_account.recipient
+    #[account(
+        mut,
+        constraint = escrow_account.recipient == recipient.key() @ EscrowError::UnauthorizedRecipient
+    )]
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = recipient_token_account.owner == escrow_account.recipient,
+        constraint = recipient_token_account.mint == escrow_account.mint,
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: validated against escrow_account.fee_recipient
+    #[account(
+        mut,
+        constraint = escrow_account.fee_recipient == fee_recipient.key()
+    )]
+    pub fee_recipient: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = fee_token_account.owner == escrow_account.fee_recipient,
+        constraint = fee_token_account.mint == escrow_account.mint,
+    )]
+    pub fee_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// Protocol config (optional - pass to honour the pause switch)
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Option<Account<'info, Config>>,
+}
+
+pub fn handler(ctx: Context<ReleaseHybrid>) -> Result<()> {
+    if let Some(config) = &ctx.accounts.config {
+        config.check_not_paused()?;
+    }
+
+    ctx.accounts.escrow_account.status.transition_to(EscrowStatus::Completing)?;
+
+    let escrow = &ctx.accounts.escrow_account;
+
+    // Same fee rate on both legs
+    let (lamport_fee, recipient_lamports) = calc_fee(escrow.lamport_amount, escrow.fee_basis_points)?;
+    let (token_fee, recipient_tokens) = calc_fee(escrow.token_amount, escrow.fee_basis_points)?;
+
+    // SOL leg: program-owned PDA can debit directly
+    let escrow_info = ctx.accounts.escrow_account.to_account_info();
+
+    if lamport_fee > 0 {
+        **escrow_info.try_borrow_mut_lamports()? -= lamport_fee;
+        **ctx.accounts.fee_recipient.try_borrow_mut_lamports()? += lamport_fee;
+    }
+
+    **escrow_info.try_borrow_mut_lamports()? -= recipient_lamports;
+    **ctx.accounts.recipient.try_borrow_mut_lamports()? += recipient_lamports;
+
+    // Token leg: vault transfers signed by the escrow PDA
+    let escrow_id_bytes = escrow.escrow_id.to_le_bytes();
+    let seeds = &[
+        b"hybrid_escrow".as_ref(),
+        escrow.creator.as_ref(),
+        escrow_id_bytes.as_ref(),
+        &[escrow.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    if token_fee > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.fee_token_account.to_account_info(),
+                    authority: ctx.accounts.escrow_account.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            token_fee,
+        )?;
+    }
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.recipient_token_account.to_account_info(),
+                authority: ctx.accounts.escrow_account.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        recipient_tokens,
+    )?;
+
+    // Close vault account, return rent to creator
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.vault.to_account_info(),
+            destination: ctx.accounts.creator.to_account_info(),
+            authority: ctx.accounts.escrow_account.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    let escrow = &mut ctx.accounts.escrow_account;
+    escrow.status.transition_to(EscrowStatus::Completed)?;
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::EscrowError;
+use crate::util::calc_fee;
 
 #[derive(Accounts)]
 pub struct ReleaseMilestone<'info> {
@@ -8,6 +9,7 @@ pub struct ReleaseMilestone<'info> {
         mut,
         seeds = [b"milestone_escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
         bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
         constraint = escrow_account.creator == creator.key() @ EscrowError::UnauthorizedCreator,
         constraint = escrow_account.status == EscrowStatus::Active @ EscrowError::InvalidStatus,
     )]
@@ -29,9 +31,20 @@ pub struct ReleaseMilestone<'info> {
         constraint = escrow_account.fee_recipient == fee_recipient.key()
     )]
     pub fee_recipient: UncheckedAccount<'info>,
+
+    /// Protocol config (optional - pass to honour the pause switch)
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Option<Account<'info, Config>>,
 }
 
 pub fn handler(ctx: Context<ReleaseMilestone>, milestone_index: u8) -> Result<()> {
+    if let Some(config) = &ctx.accounts.config {
+        config.check_not_paused()?;
+    }
+
     let escrow = &ctx.accounts.escrow_account;
 
     require!(
@@ -40,18 +53,21 @@ pub fn handler(ctx: Context<ReleaseMilestone>, milestone_index: u8) -> Result<()
     );
 
     let milestone = &escrow.milestones[milestone_index as usize];
-    require!(milestone.status == MilestoneStatus::Pending, EscrowError::MilestoneAlreadyReleased);
+    require!(milestone.status != MilestoneStatus::Disputed, EscrowError::MilestoneDisputed);
+    require!(milestone.status.is_open(), EscrowError::MilestoneAlreadyReleased);
 
     let amount = milestone.amount;
 
     // Calculate fee
-    let fee = (amount as u128)
-        .checked_mul(escrow.fee_basis_points as u128)
-        .ok_or(EscrowError::Overflow)?
-        .checked_div(10_000)
-        .ok_or(EscrowError::Overflow)? as u64;
+    let (fee, recipient_amount) = calc_fee(amount, escrow.fee_basis_points)?;
 
-    let recipient_amount = amount.checked_sub(fee).ok_or(EscrowError::Overflow)?;
+    // Mark the milestone released before moving lamports so it can't be paid twice
+    let escrow = &mut ctx.accounts.escrow_account;
+    escrow.milestones[milestone_index as usize].status = MilestoneStatus::Released;
+    escrow.released_amount = escrow.released_amount.checked_add(amount).ok_or(EscrowError::Overflow)?;
+    // Defensive: releases can never exceed what was funded
+    require!(escrow.released_amount <= escrow.total_amount, EscrowError::Overflow);
+    escrow.validate_milestones()?;
 
     // Transfer lamports
     let escrow_info = ctx.accounts.escrow_account.to_account_info();
@@ -64,18 +80,15 @@ pub fn handler(ctx: Context<ReleaseMilestone>, milestone_index: u8) -> Result<()
     **escrow_info.try_borrow_mut_lamports()? -= recipient_amount;
     **ctx.accounts.recipient.try_borrow_mut_lamports()? += recipient_amount;
 
-    // Update milestone status
     let escrow = &mut ctx.accounts.escrow_account;
-    escrow.milestones[milestone_index as usize].status = MilestoneStatus::Released;
-    escrow.released_amount = escrow.released_amount.checked_add(amount).ok_or(EscrowError::Overflow)?;
 
     // If all milestones released, mark as completed
     let all_released = escrow.milestones[..escrow.milestone_count as usize]
         .iter()
-        .all(|m| m.status == MilestoneStatus::Released);
+        .all(|m| m.status.is_settled());
 
     if all_released {
-        escrow.status = EscrowStatus::Completed;
+        escrow.status.transition_to(EscrowStatus::Completed)?;
     }
 
     Ok(())
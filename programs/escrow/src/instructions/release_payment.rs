@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::EscrowError;
+use crate::util::{append_audit_entry, pay_out_release, record_escrow_closed, record_release_reputation, ReleasePayout, ReleaseTargets};
 
 #[derive(Accounts)]
 pub struct ReleasePayment<'info> {
@@ -9,7 +10,9 @@ pub struct ReleasePayment<'info> {
         close = creator,
         seeds = [b"escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
         bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
         constraint = escrow_account.creator == creator.key() @ EscrowError::UnauthorizedCreator,
+        constraint = escrow_account.status != EscrowStatus::Completed @ EscrowError::AlreadyReleased,
         constraint = escrow_account.status == EscrowStatus::Active @ EscrowError::InvalidStatus,
     )]
     pub escrow_account: Account<'info, EscrowAccount>,
@@ -47,54 +50,136 @@ pub struct ReleasePayment<'info> {
         bump = recipient_reputation.bump,
     )]
     pub recipient_reputation: Option<Account<'info, ReputationAccount>>,
-}
 
-pub fn handler(ctx: Context<ReleasePayment>) -> Result<()> {
-    let escrow = &ctx.accounts.escrow_account;
-    let amount = escrow.amount;
+    /// CHECK: validated against escrow_account.referrer (required if referrer_fee_bps > 0)
+    #[account(
+        mut,
+        constraint = escrow_account.referrer == referrer.key() @ EscrowError::InvalidReferrer
+    )]
+    pub referrer: Option<UncheckedAccount<'info>>,
+
+    /// Protocol config (optional - pass to honour the pause switch and apply the reputation fee discount)
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Option<Account<'info, Config>>,
 
-    // Calculate fee
-    let fee = (amount as u128)
-        .checked_mul(escrow.fee_basis_points as u128)
-        .ok_or(EscrowError::Overflow)?
-        .checked_div(10_000)
-        .ok_or(EscrowError::Overflow)? as u64;
+    /// Second approver (optional - required if the escrow has a cosigner)
+    pub cosigner: Option<Signer<'info>>,
 
-    let recipient_amount = amount.checked_sub(fee).ok_or(EscrowError::Overflow)?;
+    /// CHECK: Alternate payout wallet (optional - recipient must co-sign to authorize it)
+    #[account(mut)]
+    pub payout: Option<UncheckedAccount<'info>>,
+
+    /// Rating receipt (optional - pass to let the creator rate the recipient afterwards)
+    #[account(
+        init,
+        payer = creator,
+        space = RatingReceipt::SPACE,
+        seeds = [b"rating_receipt", escrow_account.key().as_ref()],
+        bump,
+    )]
+    pub rating_receipt: Option<Account<'info, RatingReceipt>>,
+
+    pub system_program: Program<'info, System>,
 
-    // Transfer lamports from PDA (program-owned account can debit directly)
-    let escrow_info = ctx.accounts.escrow_account.to_account_info();
+    /// Protocol-wide stats (optional - pass to count this escrow in the aggregates)
+    #[account(
+        mut,
+        seeds = [b"global_stats"],
+        bump = global_stats.bump,
+    )]
+    pub global_stats: Option<Account<'info, GlobalStats>>,
 
-    if fee > 0 {
-        **escrow_info.try_borrow_mut_lamports()? -= fee;
-        **ctx.accounts.fee_recipient.try_borrow_mut_lamports()? += fee;
+    /// CHECK: creator's escrow counter PDA (freed on close if it has been initialized)
+    #[account(
+        mut,
+        seeds = [b"counter", escrow_account.creator.as_ref()],
+        bump,
+    )]
+    pub escrow_counter: UncheckedAccount<'info>,
+
+    /// Escrow's audit log (optional - pass to record this step on-chain)
+    #[account(
+        mut,
+        seeds = [b"audit_log", escrow_account.key().as_ref()],
+        bump = audit_log.bump,
+    )]
+    pub audit_log: Option<Account<'info, AuditLog>>,
+}
+
+pub fn handler(ctx: Context<ReleasePayment>) -> Result<()> {
+    if let Some(config) = &ctx.accounts.config {
+        config.check_not_paused()?;
     }
 
-    **escrow_info.try_borrow_mut_lamports()? -= recipient_amount;
-    **ctx.accounts.recipient.try_borrow_mut_lamports()? += recipient_amount;
+    let escrow = &ctx.accounts.escrow_account;
+    let amount = escrow.amount;
 
-    // Update reputation accounts if provided AND amount >= 0.01 SOL (anti-gaming)
-    // Minimum 10_000_000 lamports = 0.01 SOL
-    const MIN_REPUTATION_AMOUNT: u64 = 10_000_000;
-    let clock = Clock::get()?;
+    escrow.check_cosigner(ctx.accounts.cosigner.as_ref().map(|c| c.key()))?;
 
-    if amount >= MIN_REPUTATION_AMOUNT {
-        if let Some(creator_rep) = &mut ctx.accounts.creator_reputation {
-            creator_rep.escrows_completed = creator_rep.escrows_completed.saturating_add(1);
-            creator_rep.total_volume_lamports = creator_rep.total_volume_lamports.saturating_add(amount);
-            creator_rep.last_activity = clock.unix_timestamp;
+    // High-reputation recipients pay a reduced protocol fee
+    let fee_basis_points = match (&ctx.accounts.config, &ctx.accounts.recipient_reputation) {
+        (Some(config), Some(recipient_rep)) => {
+            config.discounted_fee_bps(escrow.fee_basis_points, recipient_rep.tasks_completed)
         }
+        _ => escrow.fee_basis_points,
+    };
+
+    ctx.accounts.escrow_account.status.transition_to(EscrowStatus::Completing)?;
 
-        if let Some(recipient_rep) = &mut ctx.accounts.recipient_reputation {
-            recipient_rep.tasks_completed = recipient_rep.tasks_completed.saturating_add(1);
-            recipient_rep.total_volume_lamports = recipient_rep.total_volume_lamports.saturating_add(amount);
-            recipient_rep.last_activity = clock.unix_timestamp;
+    // Pay the recipient, or their authorized payout wallet
+    let destination = match &ctx.accounts.payout {
+        Some(payout) => {
+            require!(ctx.accounts.recipient.is_signer, EscrowError::PayoutNotAuthorized);
+            payout.to_account_info()
         }
+        None => ctx.accounts.recipient.to_account_info(),
+    };
+
+    let clock = Clock::get()?;
+    let ReleasePayout { fee, .. } = pay_out_release(
+        &ctx.accounts.escrow_account,
+        fee_basis_points,
+        clock.unix_timestamp,
+        ReleaseTargets {
+            fee_recipient: &ctx.accounts.fee_recipient,
+            referrer: ctx.accounts.referrer.as_deref(),
+            destination: &destination,
+            cranker: None,
+        },
+    )?;
+
+    record_release_reputation(
+        ctx.accounts.creator_reputation.as_mut(),
+        ctx.accounts.recipient_reputation.as_mut(),
+        amount,
+        clock.unix_timestamp,
+    );
+
+    if let Some(stats) = &mut ctx.accounts.global_stats {
+        stats.total_volume_lamports = stats.total_volume_lamports.saturating_add(amount);
+        stats.total_fees_collected = stats.total_fees_collected.saturating_add(fee);
+    }
+
+    if let Some(receipt) = &mut ctx.accounts.rating_receipt {
+        receipt.escrow = ctx.accounts.escrow_account.key();
+        receipt.creator = ctx.accounts.creator.key();
+        receipt.recipient = ctx.accounts.recipient.key();
+        receipt.released_at = clock.unix_timestamp;
+        receipt.bump = ctx.bumps.rating_receipt.unwrap();
     }
 
     // Update status (before close transfers remaining rent to creator)
     let escrow = &mut ctx.accounts.escrow_account;
-    escrow.status = EscrowStatus::Completed;
+    escrow.status.transition_to(EscrowStatus::Completed)?;
+
+    record_escrow_closed(&ctx.accounts.escrow_counter)?;
+
+    if let Some(log) = &mut ctx.accounts.audit_log {
+        append_audit_entry(log, AuditAction::Released, ctx.accounts.creator.key(), clock.unix_timestamp)?;
+    }
 
     Ok(())
 }
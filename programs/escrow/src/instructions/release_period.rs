@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::util::calc_fee;
+
+#[derive(Accounts)]
+pub struct ReleasePeriod<'info> {
+    #[account(
+        mut,
+        seeds = [b"recurring_escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
+        bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.recipient == recipient.key() @ EscrowError::UnauthorizedRecipient,
+    )]
+    pub escrow_account: Account<'info, RecurringEscrowAccount>,
+
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    /// CHECK: validated against escrow_account.fee_recipient
+    #[account(
+        mut,
+        constraint = escrow_account.fee_recipient == fee_recipient.key()
+    )]
+    pub fee_recipient: UncheckedAccount<'info>,
+
+    /// Protocol config (optional - pass to honour the pause switch)
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Option<Account<'info, Config>>,
+}
+
+/// Releases one period's payment. A recipient who falls behind can crank
+/// once per missed period.
+pub fn handler(ctx: Context<ReleasePeriod>) -> Result<()> {
+    if let Some(config) = &ctx.accounts.config {
+        config.check_not_paused()?;
+    }
+
+    let clock = Clock::get()?;
+    let escrow = &ctx.accounts.escrow_account;
+    require!(clock.unix_timestamp >= escrow.next_release_ts, EscrowError::PeriodNotReached);
+    require!(escrow.balance >= escrow.amount_per_period, EscrowError::RecurringBalanceExhausted);
+
+    let (fee, recipient_amount) = calc_fee(escrow.amount_per_period, escrow.fee_basis_points)?;
+
+    let escrow_info = ctx.accounts.escrow_account.to_account_info();
+
+    if fee > 0 {
+        **escrow_info.try_borrow_mut_lamports()? -= fee;
+        **ctx.accounts.fee_recipient.try_borrow_mut_lamports()? += fee;
+    }
+
+    **escrow_info.try_borrow_mut_lamports()? -= recipient_amount;
+    **ctx.accounts.recipient.to_account_info().try_borrow_mut_lamports()? += recipient_amount;
+
+    let escrow = &mut ctx.accounts.escrow_account;
+    escrow.balance -= escrow.amount_per_period;
+    escrow.periods_released = escrow.periods_released.checked_add(1).ok_or(EscrowError::Overflow)?;
+    escrow.next_release_ts = escrow.next_release_ts
+        .checked_add(escrow.period_seconds)
+        .ok_or(EscrowError::Overflow)?;
+
+    Ok(())
+}
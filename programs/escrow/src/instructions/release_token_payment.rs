@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer, CloseAccount};
 use crate::state::*;
 use crate::errors::EscrowError;
+use crate::util::calc_fee;
 
 #[derive(Accounts)]
 pub struct ReleaseTokenPayment<'info> {
@@ -10,7 +11,9 @@ pub struct ReleaseTokenPayment<'info> {
         close = creator,
         seeds = [b"token_escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
         bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
         constraint = escrow_account.creator == creator.key() @ EscrowError::UnauthorizedCreator,
+        constraint = escrow_account.status != EscrowStatus::Completed @ EscrowError::AlreadyReleased,
         constraint = escrow_account.status == EscrowStatus::Active @ EscrowError::InvalidStatus,
     )]
     pub escrow_account: Account<'info, TokenEscrowAccount>,
@@ -35,31 +38,50 @@ pub struct ReleaseTokenPayment<'info> {
     #[account(
         mut,
         constraint = recipient_token_account.owner == escrow_account.recipient,
-        constraint = recipient_token_account.mint == escrow_account.mint,
+        constraint = recipient_token_account.mint == escrow_account.mint @ EscrowError::MintMismatch,
     )]
     pub recipient_token_account: Account<'info, TokenAccount>,
 
+    /// May be the creator's own token account when the creator is also the
+    /// fee recipient. Every transfer debits the vault and the creator's
+    /// system account only receives rent, so nothing is borrowed twice.
     #[account(
         mut,
         constraint = fee_token_account.owner == escrow_account.fee_recipient,
-        constraint = fee_token_account.mint == escrow_account.mint,
+        constraint = fee_token_account.mint == escrow_account.mint @ EscrowError::MintMismatch,
     )]
     pub fee_token_account: Account<'info, TokenAccount>,
 
     pub token_program: Program<'info, Token>,
+
+    /// Protocol config (optional - pass to honour the pause switch)
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Option<Account<'info, Config>>,
 }
 
 pub fn handler(ctx: Context<ReleaseTokenPayment>) -> Result<()> {
+    if let Some(config) = &ctx.accounts.config {
+        config.check_not_paused()?;
+    }
+
+    ctx.accounts.escrow_account.status.transition_to(EscrowStatus::Completing)?;
+
     let escrow = &ctx.accounts.escrow_account;
 
     // Calculate fee
-    let fee = (escrow.amount as u128)
-        .checked_mul(escrow.fee_basis_points as u128)
-        .ok_or(EscrowError::Overflow)?
-        .checked_div(10_000)
-        .ok_or(EscrowError::Overflow)? as u64;
+    let (fee, recipient_amount) = calc_fee(escrow.amount, escrow.fee_basis_points)?;
 
-    let recipient_amount = escrow.amount.checked_sub(fee).ok_or(EscrowError::Overflow)?;
+    // The vault must still cover the escrowed amount; anything extra that was
+    // sent in directly goes to the recipient so the vault can close empty
+    let vault_balance = ctx.accounts.vault.amount;
+    require!(
+        vault_balance >= fee.checked_add(recipient_amount).ok_or(EscrowError::Overflow)?,
+        EscrowError::VaultBalanceMismatch
+    );
+    let recipient_amount = vault_balance - fee;
 
     // PDA signer seeds
     let escrow_id_bytes = escrow.escrow_id.to_le_bytes();
@@ -114,7 +136,7 @@ pub fn handler(ctx: Context<ReleaseTokenPayment>) -> Result<()> {
 
     // Update status
     let escrow = &mut ctx.accounts.escrow_account;
-    escrow.status = EscrowStatus::Completed;
+    escrow.status.transition_to(EscrowStatus::Completed)?;
 
     Ok(())
 }
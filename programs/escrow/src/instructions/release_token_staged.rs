@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::util::calc_fee;
+
+/// First half of a two-phase token release. Pays the protocol fee, records
+/// the recipient's share in `staged_amount` and parks the escrow in
+/// Completing; `finalize_token_release` then pays the recipient.
+///
+/// Recovery: once staged, the release can no longer be refunded or
+/// disputed. If the recipient leg fails (closed or frozen token account),
+/// that transaction aborts on its own and leaves the staged state intact, so
+/// finalize can simply be retried once the recipient has a usable account
+/// again. No step is ever paid twice: the fee is only taken here, and the
+/// recipient's share only leaves the vault when finalize closes it.
+#[derive(Accounts)]
+pub struct ReleaseTokenStaged<'info> {
+    #[account(
+        mut,
+        seeds = [b"token_escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
+        bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.creator == creator.key() @ EscrowError::UnauthorizedCreator,
+        constraint = escrow_account.status == EscrowStatus::Active @ EscrowError::InvalidStatus,
+    )]
+    pub escrow_account: Account<'info, TokenEscrowAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"token_vault", escrow_account.key().as_ref()],
+        bump,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = fee_token_account.owner == escrow_account.fee_recipient,
+        constraint = fee_token_account.mint == escrow_account.mint @ EscrowError::MintMismatch,
+    )]
+    pub fee_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// Protocol config (optional - pass to honour the pause switch)
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Option<Account<'info, Config>>,
+}
+
+pub fn handler(ctx: Context<ReleaseTokenStaged>) -> Result<()> {
+    if let Some(config) = &ctx.accounts.config {
+        config.check_not_paused()?;
+    }
+
+    let escrow = &ctx.accounts.escrow_account;
+
+    // Same split as release_token_payment
+    let (fee, recipient_amount) = calc_fee(escrow.amount, escrow.fee_basis_points)?;
+    let vault_balance = ctx.accounts.vault.amount;
+    require!(
+        vault_balance >= fee.checked_add(recipient_amount).ok_or(EscrowError::Overflow)?,
+        EscrowError::VaultBalanceMismatch
+    );
+    let recipient_amount = vault_balance - fee;
+
+    let escrow_id_bytes = escrow.escrow_id.to_le_bytes();
+    let seeds = &[
+        b"token_escrow".as_ref(),
+        escrow.creator.as_ref(),
+        escrow_id_bytes.as_ref(),
+        &[escrow.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    if fee > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.fee_token_account.to_account_info(),
+                    authority: ctx.accounts.escrow_account.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            fee,
+        )?;
+    }
+
+    // Checkpoint: fee paid, recipient's share waiting in the vault
+    let escrow = &mut ctx.accounts.escrow_account;
+    escrow.staged_amount = recipient_amount;
+    escrow.status.transition_to(EscrowStatus::Completing)?;
+
+    Ok(())
+}
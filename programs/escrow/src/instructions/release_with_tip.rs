@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::events::{ReputationUpdated, ReputationChangeReason};
+use crate::util::{pay_out_release, record_escrow_closed, record_release_reputation, ReleaseTargets};
+
+#[derive(Accounts)]
+pub struct ReleaseWithTip<'info> {
+    #[account(
+        mut,
+        close = creator,
+        seeds = [b"escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
+        bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.creator == creator.key() @ EscrowError::UnauthorizedCreator,
+        constraint = escrow_account.status != EscrowStatus::Completed @ EscrowError::AlreadyReleased,
+        constraint = escrow_account.status == EscrowStatus::Active @ EscrowError::InvalidStatus,
+    )]
+    pub escrow_account: Account<'info, EscrowAccount>,
+
+    /// CHECK: validated by constraint
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// CHECK: validated against escrow_account.recipient
+    #[account(
+        mut,
+        constraint = escrow_account.recipient == recipient.key() @ EscrowError::UnauthorizedRecipient
+    )]
+    pub recipient: UncheckedAccount<'info>,
+
+    /// CHECK: validated against escrow_account.fee_recipient
+    #[account(
+        mut,
+        constraint = escrow_account.fee_recipient == fee_recipient.key()
+    )]
+    pub fee_recipient: UncheckedAccount<'info>,
+
+    /// Creator's reputation account (optional - pass if tracking reputation)
+    #[account(
+        mut,
+        seeds = [b"reputation", escrow_account.creator.as_ref()],
+        bump = creator_reputation.bump,
+    )]
+    pub creator_reputation: Option<Account<'info, ReputationAccount>>,
+
+    /// Recipient's reputation account (optional - pass if tracking reputation)
+    #[account(
+        mut,
+        seeds = [b"reputation", escrow_account.recipient.as_ref()],
+        bump = recipient_reputation.bump,
+    )]
+    pub recipient_reputation: Option<Account<'info, ReputationAccount>>,
+
+    /// CHECK: validated against escrow_account.referrer (required if referrer_fee_bps > 0)
+    #[account(
+        mut,
+        constraint = escrow_account.referrer == referrer.key() @ EscrowError::InvalidReferrer
+    )]
+    pub referrer: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Second approver (optional - required if the escrow has a cosigner)
+    pub cosigner: Option<Signer<'info>>,
+
+    /// Protocol config (optional - pass to honour the pause switch)
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Option<Account<'info, Config>>,
+
+    /// CHECK: creator's escrow counter PDA (freed on close if it has been initialized)
+    #[account(
+        mut,
+        seeds = [b"counter", escrow_account.creator.as_ref()],
+        bump,
+    )]
+    pub escrow_counter: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<ReleaseWithTip>, tip: u64) -> Result<()> {
+    if let Some(config) = &ctx.accounts.config {
+        config.check_not_paused()?;
+    }
+
+    require!(tip > 0, EscrowError::ZeroAmount);
+    ctx.accounts.escrow_account.check_cosigner(ctx.accounts.cosigner.as_ref().map(|c| c.key()))?;
+
+    // Tip comes from the creator's own wallet and bypasses the protocol fee
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.creator.to_account_info(),
+                to: ctx.accounts.recipient.to_account_info(),
+            },
+        ),
+        tip,
+    )?;
+
+    let amount = ctx.accounts.escrow_account.amount;
+    let fee_basis_points = ctx.accounts.escrow_account.fee_basis_points;
+
+    let clock = Clock::get()?;
+    ctx.accounts.escrow_account.status.transition_to(EscrowStatus::Completing)?;
+
+    pay_out_release(
+        &ctx.accounts.escrow_account,
+        fee_basis_points,
+        clock.unix_timestamp,
+        ReleaseTargets {
+            fee_recipient: &ctx.accounts.fee_recipient,
+            referrer: ctx.accounts.referrer.as_deref(),
+            destination: &ctx.accounts.recipient,
+            cranker: None,
+        },
+    )?;
+
+    record_release_reputation(
+        ctx.accounts.creator_reputation.as_mut(),
+        ctx.accounts.recipient_reputation.as_mut(),
+        amount,
+        clock.unix_timestamp,
+    );
+
+    if let Some(recipient_rep) = &mut ctx.accounts.recipient_reputation {
+        recipient_rep.tips_received = recipient_rep.tips_received.saturating_add(tip);
+        recipient_rep.last_activity = clock.unix_timestamp;
+        emit!(ReputationUpdated::new(recipient_rep, ReputationChangeReason::TipReceived));
+    }
+
+    // Update status (before close transfers remaining rent to creator)
+    let escrow = &mut ctx.accounts.escrow_account;
+    escrow.status.transition_to(EscrowStatus::Completed)?;
+
+    record_escrow_closed(&ctx.accounts.escrow_counter)?;
+
+    Ok(())
+}
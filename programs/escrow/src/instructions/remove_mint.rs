@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+use crate::errors::EscrowError;
+use crate::instructions::add_mint::UpdateMintAllowlist;
+
+pub fn handler(ctx: Context<UpdateMintAllowlist>, mint: Pubkey) -> Result<()> {
+    let allowlist = &mut ctx.accounts.mint_allowlist;
+    let index = allowlist.mints
+        .iter()
+        .position(|m| *m == mint)
+        .ok_or(EscrowError::MintNotAllowed)?;
+
+    allowlist.mints.swap_remove(index);
+
+    Ok(())
+}
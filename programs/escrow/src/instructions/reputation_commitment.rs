@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::state::*;
+
+/// Read-only instruction that commits to a reputation snapshot for relaying
+/// to another chain. Returns, via return data, the SHA256 of:
+///
+/// ```text
+/// agent                   32 bytes
+/// escrows_created         u32 little-endian
+/// escrows_completed       u32 little-endian
+/// escrows_received        u32 little-endian
+/// tasks_completed         u32 little-endian
+/// disputes_initiated      u32 little-endian
+/// disputes_won            u32 little-endian
+/// disputes_lost           u32 little-endian
+/// total_volume_lamports   u64 little-endian
+/// tips_received           u64 little-endian
+/// ```
+///
+/// `last_activity` and `bump` are bookkeeping and are left out.
+#[derive(Accounts)]
+pub struct ReputationCommitment<'info> {
+    #[account(
+        seeds = [b"reputation", reputation_account.agent.as_ref()],
+        bump = reputation_account.bump,
+    )]
+    pub reputation_account: Account<'info, ReputationAccount>,
+}
+
+pub fn handler(ctx: Context<ReputationCommitment>) -> Result<[u8; 32]> {
+    let rep = &ctx.accounts.reputation_account;
+
+    let hash = hashv(&[
+        rep.agent.as_ref(),
+        &rep.escrows_created.to_le_bytes(),
+        &rep.escrows_completed.to_le_bytes(),
+        &rep.escrows_received.to_le_bytes(),
+        &rep.tasks_completed.to_le_bytes(),
+        &rep.disputes_initiated.to_le_bytes(),
+        &rep.disputes_won.to_le_bytes(),
+        &rep.disputes_lost.to_le_bytes(),
+        &rep.total_volume_lamports.to_le_bytes(),
+        &rep.tips_received.to_le_bytes(),
+    ]);
+
+    Ok(hash.to_bytes())
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Read-only instruction returning `ReputationAccount::score` via return
+/// data, so leaderboards rank agents with the on-chain formula
+#[derive(Accounts)]
+pub struct ReputationScore<'info> {
+    #[account(
+        seeds = [b"reputation", reputation_account.agent.as_ref()],
+        bump = reputation_account.bump,
+    )]
+    pub reputation_account: Account<'info, ReputationAccount>,
+}
+
+pub fn handler(ctx: Context<ReputationScore>) -> Result<u64> {
+    Ok(ctx.accounts.reputation_account.score())
+}
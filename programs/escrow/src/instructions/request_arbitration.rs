@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::errors::EscrowError;
+
+#[derive(Accounts)]
+pub struct RequestArbitration<'info> {
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
+        bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.status == EscrowStatus::Disputed @ EscrowError::InvalidStatus,
+        constraint = escrow_account.arbitration_fee == 0 @ EscrowError::ArbitrationAlreadyRequested,
+    )]
+    pub escrow_account: Account<'info, EscrowAccount>,
+
+    /// Holds the deposit until the dispute is settled
+    #[account(
+        init,
+        payer = payer,
+        space = BondReceipt::SPACE,
+        seeds = [b"bond", escrow_account.key().as_ref()],
+        bump,
+    )]
+    pub bond_receipt: Account<'info, BondReceipt>,
+
+    /// Either party can put up the arbitration fee
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RequestArbitration>, arbitration_fee: u64) -> Result<()> {
+    require!(arbitration_fee > 0, EscrowError::ZeroAmount);
+
+    let escrow = &ctx.accounts.escrow_account;
+    let payer_key = ctx.accounts.payer.key();
+    require!(
+        payer_key == escrow.creator || payer_key == escrow.recipient,
+        EscrowError::UnauthorizedDisputer
+    );
+
+    // Held in the bond receipt on top of its rent until the dispute is settled
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.payer.to_account_info(),
+                to: ctx.accounts.bond_receipt.to_account_info(),
+            },
+        ),
+        arbitration_fee,
+    )?;
+
+    let receipt = &mut ctx.accounts.bond_receipt;
+    receipt.escrow = ctx.accounts.escrow_account.key();
+    receipt.payer = payer_key;
+    receipt.amount = arbitration_fee;
+    receipt.outcome = BondOutcome::Pending;
+    receipt.bump = ctx.bumps.bond_receipt;
+
+    let escrow = &mut ctx.accounts.escrow_account;
+    escrow.arbitration_fee = arbitration_fee;
+    escrow.arbitration_fee_payer = payer_key;
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::util::{is_expired, record_escrow_closed, refund_target};
+
+#[derive(Accounts)]
+pub struct RequestPartialRefund<'info> {
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
+        bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.creator == creator.key() @ EscrowError::UnauthorizedCreator,
+        constraint = escrow_account.status == EscrowStatus::Active @ EscrowError::InvalidStatus,
+    )]
+    pub escrow_account: Account<'info, EscrowAccount>,
+
+    /// CHECK: validated by constraint
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// CHECK: validated against escrow_account.sponsor (required if the escrow was sponsored)
+    #[account(
+        mut,
+        constraint = escrow_account.sponsor == sponsor.key() @ EscrowError::InvalidSponsor
+    )]
+    pub sponsor: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: validated against escrow_account.refund_destination (required if it differs from the creator)
+    #[account(
+        mut,
+        constraint = escrow_account.refund_destination == refund_destination.key() @ EscrowError::InvalidRefundDestination
+    )]
+    pub refund_destination: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: creator's escrow counter PDA (freed on close if it has been initialized)
+    #[account(
+        mut,
+        seeds = [b"counter", escrow_account.creator.as_ref()],
+        bump,
+    )]
+    pub escrow_counter: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<RequestPartialRefund>, refund_amount: u64) -> Result<()> {
+    let escrow = &ctx.accounts.escrow_account;
+    let clock = Clock::get()?;
+
+    require!(refund_amount > 0, EscrowError::ZeroAmount);
+    require!(refund_amount <= escrow.amount, EscrowError::RefundExceedsAmount);
+    require!(
+        is_expired(escrow.refund_available_at(), clock.unix_timestamp),
+        EscrowError::DeadlineNotReached
+    );
+
+    // Transfer the refunded portion back to whoever funded it, or to the
+    // creator's refund destination
+    let escrow_info = ctx.accounts.escrow_account.to_account_info();
+    let refund_to = refund_target(
+        escrow,
+        &ctx.accounts.creator,
+        ctx.accounts.sponsor.as_deref(),
+        ctx.accounts.refund_destination.as_deref(),
+    )?;
+
+    **escrow_info.try_borrow_mut_lamports()? -= refund_amount;
+    **refund_to.try_borrow_mut_lamports()? += refund_amount;
+
+    let escrow = &mut ctx.accounts.escrow_account;
+    escrow.amount = escrow.amount.checked_sub(refund_amount).ok_or(EscrowError::Overflow)?;
+
+    // Remaining balance stays claimable by the recipient via release.
+    // If nothing is left, close the account and return rent to creator.
+    if escrow.amount == 0 {
+        escrow.status.transition_to(EscrowStatus::Refunded)?;
+        escrow.close(ctx.accounts.creator.to_account_info())?;
+
+        record_escrow_closed(&ctx.accounts.escrow_counter)?;
+    }
+
+    Ok(())
+}
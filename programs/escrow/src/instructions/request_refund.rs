@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::EscrowError;
+use crate::util::{is_expired, append_audit_entry, record_escrow_closed, refund_target};
 
 #[derive(Accounts)]
 pub struct RequestRefund<'info> {
@@ -9,6 +10,7 @@ pub struct RequestRefund<'info> {
         close = creator,
         seeds = [b"escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
         bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
         constraint = escrow_account.creator == creator.key() @ EscrowError::UnauthorizedCreator,
     )]
     pub escrow_account: Account<'info, EscrowAccount>,
@@ -16,6 +18,36 @@ pub struct RequestRefund<'info> {
     /// CHECK: validated by constraint
     #[account(mut)]
     pub creator: Signer<'info>,
+
+    /// CHECK: validated against escrow_account.sponsor (required if the escrow was sponsored)
+    #[account(
+        mut,
+        constraint = escrow_account.sponsor == sponsor.key() @ EscrowError::InvalidSponsor
+    )]
+    pub sponsor: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: validated against escrow_account.refund_destination (required if it differs from the creator)
+    #[account(
+        mut,
+        constraint = escrow_account.refund_destination == refund_destination.key() @ EscrowError::InvalidRefundDestination
+    )]
+    pub refund_destination: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: creator's escrow counter PDA (freed on close if it has been initialized)
+    #[account(
+        mut,
+        seeds = [b"counter", escrow_account.creator.as_ref()],
+        bump,
+    )]
+    pub escrow_counter: UncheckedAccount<'info>,
+
+    /// Escrow's audit log (optional - pass to record this step on-chain)
+    #[account(
+        mut,
+        seeds = [b"audit_log", escrow_account.key().as_ref()],
+        bump = audit_log.bump,
+    )]
+    pub audit_log: Option<Account<'info, AuditLog>>,
 }
 
 pub fn handler(ctx: Context<RequestRefund>) -> Result<()> {
@@ -24,34 +56,48 @@ pub fn handler(ctx: Context<RequestRefund>) -> Result<()> {
 
     // Allow refund if:
     // 1. Status is Created (not yet accepted) -- can cancel anytime
-    // 2. Status is Active but deadline has passed
+    // 2. Status is Active and deadline + refund grace period has passed
     match escrow.status {
         EscrowStatus::Created => {
             // Cancel -- no deadline check needed
         }
         EscrowStatus::Active => {
             require!(
-                clock.unix_timestamp >= escrow.deadline,
+                is_expired(escrow.refund_available_at(), clock.unix_timestamp),
                 EscrowError::DeadlineNotReached
             );
         }
         _ => return Err(EscrowError::InvalidStatus.into()),
     }
 
-    // Transfer escrowed amount back to creator
+    // Transfer escrowed amount back to whoever funded it; a creator-funded
+    // escrow may route it to another wallet while rent still closes to the creator
     let escrow_info = ctx.accounts.escrow_account.to_account_info();
     let amount = escrow.amount;
+    let refund_to = refund_target(
+        escrow,
+        &ctx.accounts.creator,
+        ctx.accounts.sponsor.as_deref(),
+        ctx.accounts.refund_destination.as_deref(),
+    )?;
 
     **escrow_info.try_borrow_mut_lamports()? -= amount;
-    **ctx.accounts.creator.try_borrow_mut_lamports()? += amount;
+    **refund_to.try_borrow_mut_lamports()? += amount;
 
     // Update status (close will transfer remaining rent to creator)
     let escrow = &mut ctx.accounts.escrow_account;
-    escrow.status = if escrow.status == EscrowStatus::Created {
+    let next_status = if escrow.status == EscrowStatus::Created {
         EscrowStatus::Cancelled
     } else {
         EscrowStatus::Refunded
     };
+    escrow.status.transition_to(next_status)?;
+
+    record_escrow_closed(&ctx.accounts.escrow_counter)?;
+
+    if let Some(log) = &mut ctx.accounts.audit_log {
+        append_audit_entry(log, AuditAction::Refunded, ctx.accounts.creator.key(), clock.unix_timestamp)?;
+    }
 
     Ok(())
 }
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::util::append_audit_entry;
+use crate::events::ReleaseRequested;
+
+#[derive(Accounts)]
+pub struct RequestRelease<'info> {
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
+        bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.recipient == recipient.key() @ EscrowError::UnauthorizedRecipient,
+        constraint = escrow_account.status == EscrowStatus::Active @ EscrowError::InvalidStatus,
+    )]
+    pub escrow_account: Account<'info, EscrowAccount>,
+
+    /// CHECK: validated against escrow_account.recipient; must sign unless the delegate does
+    pub recipient: UncheckedAccount<'info>,
+
+    /// Recipient's delegate key (optional - signs in place of the recipient)
+    #[account(
+        constraint = escrow_account.delegate == delegate.key() @ EscrowError::InvalidDelegate
+    )]
+    pub delegate: Option<Signer<'info>>,
+
+    /// Escrow's audit log (optional - pass to record this step on-chain)
+    #[account(
+        mut,
+        seeds = [b"audit_log", escrow_account.key().as_ref()],
+        bump = audit_log.bump,
+    )]
+    pub audit_log: Option<Account<'info, AuditLog>>,
+}
+
+pub fn handler(ctx: Context<RequestRelease>) -> Result<()> {
+    ctx.accounts.escrow_account.check_recipient_or_delegate(
+        ctx.accounts.recipient.is_signer,
+        ctx.accounts.delegate.as_ref().map(|d| d.key()),
+    )?;
+
+    let clock = Clock::get()?;
+
+    let escrow = &mut ctx.accounts.escrow_account;
+    escrow.release_requested = true;
+
+    emit!(ReleaseRequested {
+        escrow: escrow.key(),
+        recipient: escrow.recipient,
+        timestamp: clock.unix_timestamp,
+    });
+
+    if let Some(log) = &mut ctx.accounts.audit_log {
+        let actor = ctx.accounts.delegate.as_ref().map_or(ctx.accounts.recipient.key(), |d| d.key());
+        append_audit_entry(log, AuditAction::ReleaseRequested, actor, clock.unix_timestamp)?;
+    }
+
+    Ok(())
+}
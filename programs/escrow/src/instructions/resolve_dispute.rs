@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::EscrowError;
+use crate::util::{append_audit_entry, refund_target, settle_dispute, DisputeTargets};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
 pub enum DisputeWinner {
@@ -12,14 +13,18 @@ pub enum DisputeWinner {
 pub struct ResolveDispute<'info> {
     #[account(
         mut,
-        close = creator,
         seeds = [b"escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
         bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
         constraint = escrow_account.status == EscrowStatus::Disputed @ EscrowError::InvalidStatus,
         constraint = escrow_account.arbiter == arbiter.key() @ EscrowError::UnauthorizedArbiter,
+        constraint = arbiter.key() != escrow_account.creator @ EscrowError::ConflictedArbiter,
+        constraint = arbiter.key() != escrow_account.recipient @ EscrowError::ConflictedArbiter,
     )]
     pub escrow_account: Account<'info, EscrowAccount>,
 
+    /// Receives the arbitration fee, if one was deposited
+    #[account(mut)]
     pub arbiter: Signer<'info>,
 
     /// CHECK: validated against escrow_account.creator
@@ -43,6 +48,20 @@ pub struct ResolveDispute<'info> {
     )]
     pub fee_recipient: UncheckedAccount<'info>,
 
+    /// CHECK: validated against escrow_account.sponsor (required if the escrow was sponsored)
+    #[account(
+        mut,
+        constraint = escrow_account.sponsor == sponsor.key() @ EscrowError::InvalidSponsor
+    )]
+    pub sponsor: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: validated against escrow_account.refund_destination (required if it differs from the creator)
+    #[account(
+        mut,
+        constraint = escrow_account.refund_destination == refund_destination.key() @ EscrowError::InvalidRefundDestination
+    )]
+    pub refund_destination: Option<UncheckedAccount<'info>>,
+
     /// Creator's reputation account (optional - pass if tracking reputation)
     #[account(
         mut,
@@ -58,66 +77,88 @@ pub struct ResolveDispute<'info> {
         bump = recipient_reputation.bump,
     )]
     pub recipient_reputation: Option<Account<'info, ReputationAccount>>,
-}
 
-pub fn handler(ctx: Context<ResolveDispute>, winner: DisputeWinner) -> Result<()> {
-    let escrow = &ctx.accounts.escrow_account;
-    let escrow_info = ctx.accounts.escrow_account.to_account_info();
-    let amount = escrow.amount;
-
-    match winner {
-        DisputeWinner::Recipient => {
-            // Fee + remainder to recipient
-            let fee = (amount as u128)
-                .checked_mul(escrow.fee_basis_points as u128)
-                .ok_or(EscrowError::Overflow)?
-                .checked_div(10_000)
-                .ok_or(EscrowError::Overflow)? as u64;
-            let recipient_amount = amount.checked_sub(fee).ok_or(EscrowError::Overflow)?;
-
-            if fee > 0 {
-                **escrow_info.try_borrow_mut_lamports()? -= fee;
-                **ctx.accounts.fee_recipient.try_borrow_mut_lamports()? += fee;
-            }
-            **escrow_info.try_borrow_mut_lamports()? -= recipient_amount;
-            **ctx.accounts.recipient.try_borrow_mut_lamports()? += recipient_amount;
-        }
-        DisputeWinner::Creator => {
-            // Full refund to creator, no fee
-            **escrow_info.try_borrow_mut_lamports()? -= amount;
-            **ctx.accounts.creator.try_borrow_mut_lamports()? += amount;
-        }
-    }
+    /// Arbiter's registry account (optional - pass to track resolved cases)
+    #[account(
+        mut,
+        seeds = [b"arbiter", arbiter.key().as_ref()],
+        bump = arbiter_account.bump,
+    )]
+    pub arbiter_account: Option<Account<'info, ArbiterAccount>>,
+
+    /// CHECK: creator's escrow counter PDA (freed on close if it has been initialized)
+    #[account(
+        mut,
+        seeds = [b"counter", escrow_account.creator.as_ref()],
+        bump,
+    )]
+    pub escrow_counter: UncheckedAccount<'info>,
+
+    /// Protocol config (splits a losing party's arbitration deposit)
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Escrow's bond receipt (optional - required if an arbitration fee was deposited)
+    #[account(
+        mut,
+        seeds = [b"bond", escrow_account.key().as_ref()],
+        bump = bond_receipt.bump,
+    )]
+    pub bond_receipt: Option<Account<'info, BondReceipt>>,
 
-    // Update reputation accounts if provided
+    /// Escrow's audit log (optional - pass to record this step on-chain)
+    #[account(
+        mut,
+        seeds = [b"audit_log", escrow_account.key().as_ref()],
+        bump = audit_log.bump,
+    )]
+    pub audit_log: Option<Account<'info, AuditLog>>,
+}
+
+pub fn handler(ctx: Context<ResolveDispute>, winner: DisputeWinner, resolution_hash: [u8; 32]) -> Result<()> {
     let clock = Clock::get()?;
 
-    match winner {
-        DisputeWinner::Recipient => {
-            if let Some(recipient_rep) = &mut ctx.accounts.recipient_reputation {
-                recipient_rep.disputes_won = recipient_rep.disputes_won.saturating_add(1);
-                recipient_rep.last_activity = clock.unix_timestamp;
-            }
-            if let Some(creator_rep) = &mut ctx.accounts.creator_reputation {
-                creator_rep.disputes_lost = creator_rep.disputes_lost.saturating_add(1);
-                creator_rep.last_activity = clock.unix_timestamp;
-            }
-        }
-        DisputeWinner::Creator => {
-            if let Some(creator_rep) = &mut ctx.accounts.creator_reputation {
-                creator_rep.disputes_won = creator_rep.disputes_won.saturating_add(1);
-                creator_rep.last_activity = clock.unix_timestamp;
-            }
-            if let Some(recipient_rep) = &mut ctx.accounts.recipient_reputation {
-                recipient_rep.disputes_lost = recipient_rep.disputes_lost.saturating_add(1);
-                recipient_rep.last_activity = clock.unix_timestamp;
-            }
-        }
+    // A creator win is refunded to whoever funded the escrow
+    let refund_to = match winner {
+        DisputeWinner::Creator => refund_target(
+            &ctx.accounts.escrow_account,
+            &ctx.accounts.creator,
+            ctx.accounts.sponsor.as_deref(),
+            ctx.accounts.refund_destination.as_deref(),
+        )?,
+        DisputeWinner::Recipient => &ctx.accounts.creator,
+    };
+
+    settle_dispute(
+        &mut ctx.accounts.escrow_account,
+        winner,
+        resolution_hash,
+        clock.unix_timestamp,
+        DisputeTargets {
+            arbiter: &ctx.accounts.arbiter,
+            creator: &ctx.accounts.creator,
+            recipient: &ctx.accounts.recipient,
+            fee_recipient: &ctx.accounts.fee_recipient,
+            refund_to,
+            bond_receipt: ctx.accounts.bond_receipt.as_ref(),
+        },
+        &ctx.accounts.config,
+        ctx.accounts.creator_reputation.as_mut(),
+        ctx.accounts.recipient_reputation.as_mut(),
+        &ctx.accounts.escrow_counter,
+    )?;
+
+    if let Some(arbiter_account) = &mut ctx.accounts.arbiter_account {
+        arbiter_account.cases_resolved = arbiter_account.cases_resolved.saturating_add(1);
+        arbiter_account.last_active_at = clock.unix_timestamp;
     }
 
-    // Update status (close will transfer remaining rent to creator)
-    let escrow = &mut ctx.accounts.escrow_account;
-    escrow.status = EscrowStatus::Resolved;
+    if let Some(log) = &mut ctx.accounts.audit_log {
+        append_audit_entry(log, AuditAction::Resolved, ctx.accounts.arbiter.key(), clock.unix_timestamp)?;
+    }
 
     Ok(())
 }
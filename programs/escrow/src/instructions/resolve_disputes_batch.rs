@@ -0,0 +1,153 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::util::{settle_dispute, DisputeTargets};
+use crate::instructions::resolve_dispute::DisputeWinner;
+
+/// Remaining accounts per resolved escrow: escrow, creator, recipient, fee_recipient,
+/// escrow_counter, refund_to, creator_reputation, recipient_reputation, bond_receipt
+const ACCOUNTS_PER_DISPUTE: usize = 9;
+
+/// Outcome of one escrow in a batch resolution
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BatchResolution {
+    pub winner: DisputeWinner,
+    /// Hash of the off-chain ruling, as passed to resolve_dispute
+    pub resolution_hash: [u8; 32],
+}
+
+/// Each entry in `resolutions` is matched, in order, with a writable
+/// `[escrow, creator, recipient, fee_recipient, escrow_counter, refund_to,
+/// creator_reputation, recipient_reputation, bond_receipt]` group in remaining
+/// accounts, where `escrow_counter`, the reputations and `bond_receipt` are
+/// the PDAs (skipped if not yet initialized) and `refund_to` the escrow's
+/// `refund_recipient()`.
+/// Each escrow is settled exactly as resolve_dispute would; audit logs are
+/// not touched, so use resolve_dispute for escrows that keep one.
+#[derive(Accounts)]
+pub struct ResolveDisputesBatch<'info> {
+    /// Receives the arbitration fees, if any were deposited
+    #[account(mut)]
+    pub arbiter: Signer<'info>,
+
+    /// Arbiter's registry account (optional - pass to track resolved cases)
+    #[account(
+        mut,
+        seeds = [b"arbiter", arbiter.key().as_ref()],
+        bump = arbiter_account.bump,
+    )]
+    pub arbiter_account: Option<Account<'info, ArbiterAccount>>,
+
+    /// Protocol config (splits a losing party's arbitration deposit)
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Loads `agent`'s reputation PDA from remaining accounts, or None if it has
+/// not been initialized
+fn load_reputation<'info>(
+    info: &AccountInfo<'info>,
+    agent: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<Option<Account<'info, ReputationAccount>>> {
+    let (expected, _) = Pubkey::find_program_address(&[b"reputation", agent.as_ref()], program_id);
+    require!(info.key() == expected, EscrowError::BatchAccountMismatch);
+
+    if info.owner != program_id || info.data_is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(Account::try_from(info)?))
+}
+
+/// Loads the escrow's bond receipt PDA from remaining accounts, or None if no
+/// arbitration fee was deposited
+fn load_bond_receipt<'info>(
+    info: &AccountInfo<'info>,
+    escrow: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<Option<Account<'info, BondReceipt>>> {
+    let (expected, _) = Pubkey::find_program_address(&[b"bond", escrow.as_ref()], program_id);
+    require!(info.key() == expected, EscrowError::BatchAccountMismatch);
+
+    if info.owner != program_id || info.data_is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(Account::try_from(info)?))
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ResolveDisputesBatch<'info>>,
+    resolutions: Vec<BatchResolution>,
+) -> Result<()> {
+    require!(
+        !resolutions.is_empty() && resolutions.len() <= MAX_BATCH_SIZE,
+        EscrowError::InvalidBatchSize
+    );
+    require!(
+        ctx.remaining_accounts.len() == resolutions.len() * ACCOUNTS_PER_DISPUTE,
+        EscrowError::BatchAccountMismatch
+    );
+
+    let clock = Clock::get()?;
+    let arbiter_key = ctx.accounts.arbiter.key();
+
+    for (resolution, group) in resolutions.iter().zip(ctx.remaining_accounts.chunks(ACCOUNTS_PER_DISPUTE)) {
+        let (escrow_info, creator, recipient, fee_recipient, counter, refund_to) =
+            (&group[0], &group[1], &group[2], &group[3], &group[4], &group[5]);
+
+        // Owner and discriminator are checked here; an escrow listed twice
+        // fails on its second entry because the first one closed it
+        let mut escrow = Account::<EscrowAccount>::try_from(escrow_info)?;
+        require!(escrow.version == ACCOUNT_VERSION, EscrowError::UnsupportedVersion);
+        require!(escrow.status == EscrowStatus::Disputed, EscrowError::InvalidStatus);
+        require!(escrow.arbiter == arbiter_key, EscrowError::UnauthorizedArbiter);
+        require!(
+            arbiter_key != escrow.creator && arbiter_key != escrow.recipient,
+            EscrowError::ConflictedArbiter
+        );
+        require!(escrow.creator == creator.key(), EscrowError::UnauthorizedCreator);
+        require!(escrow.recipient == recipient.key(), EscrowError::UnauthorizedRecipient);
+        require!(escrow.fee_recipient == fee_recipient.key(), EscrowError::BatchAccountMismatch);
+        let (counter_key, _) = Pubkey::find_program_address(&[b"counter", escrow.creator.as_ref()], ctx.program_id);
+        require!(counter.key() == counter_key, EscrowError::BatchAccountMismatch);
+        require!(refund_to.key() == escrow.refund_recipient(), EscrowError::InvalidRefundDestination);
+
+        let mut creator_rep = load_reputation(&group[6], &escrow.creator, ctx.program_id)?;
+        let mut recipient_rep = load_reputation(&group[7], &escrow.recipient, ctx.program_id)?;
+        let bond_receipt = load_bond_receipt(&group[8], &escrow_info.key(), ctx.program_id)?;
+
+        settle_dispute(
+            &mut escrow,
+            resolution.winner,
+            resolution.resolution_hash,
+            clock.unix_timestamp,
+            DisputeTargets {
+                arbiter: &ctx.accounts.arbiter,
+                creator,
+                recipient,
+                fee_recipient,
+                refund_to,
+                bond_receipt: bond_receipt.as_ref(),
+            },
+            &ctx.accounts.config,
+            creator_rep.as_mut(),
+            recipient_rep.as_mut(),
+            counter,
+        )?;
+
+        // Remaining accounts aren't persisted by Anchor
+        for rep in creator_rep.iter().chain(recipient_rep.iter()) {
+            rep.exit(ctx.program_id)?;
+        }
+    }
+
+    if let Some(arbiter_account) = &mut ctx.accounts.arbiter_account {
+        arbiter_account.cases_resolved = arbiter_account.cases_resolved.saturating_add(resolutions.len() as u32);
+        arbiter_account.last_active_at = clock.unix_timestamp;
+    }
+
+    Ok(())
+}
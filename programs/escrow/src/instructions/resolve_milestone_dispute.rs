@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::EscrowError;
+use crate::util::calc_fee;
+use crate::events::{ReputationUpdated, ReputationChangeReason};
 use crate::instructions::resolve_dispute::DisputeWinner;
 
 #[derive(Accounts)]
@@ -9,7 +11,8 @@ pub struct ResolveMilestoneDispute<'info> {
         mut,
         seeds = [b"milestone_escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
         bump = escrow_account.bump,
-        constraint = escrow_account.status == EscrowStatus::Disputed @ EscrowError::InvalidStatus,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.status == EscrowStatus::Active @ EscrowError::InvalidStatus,
         constraint = escrow_account.arbiter == arbiter.key() @ EscrowError::UnauthorizedArbiter,
     )]
     pub escrow_account: Account<'info, MilestoneEscrowAccount>,
@@ -36,6 +39,22 @@ pub struct ResolveMilestoneDispute<'info> {
         constraint = escrow_account.fee_recipient == fee_recipient.key()
     )]
     pub fee_recipient: UncheckedAccount<'info>,
+
+    /// Creator's reputation account (optional - pass if tracking reputation)
+    #[account(
+        mut,
+        seeds = [b"reputation", escrow_account.creator.as_ref()],
+        bump = creator_reputation.bump,
+    )]
+    pub creator_reputation: Option<Account<'info, ReputationAccount>>,
+
+    /// Recipient's reputation account (optional - pass if tracking reputation)
+    #[account(
+        mut,
+        seeds = [b"reputation", escrow_account.recipient.as_ref()],
+        bump = recipient_reputation.bump,
+    )]
+    pub recipient_reputation: Option<Account<'info, ReputationAccount>>,
 }
 
 pub fn handler(ctx: Context<ResolveMilestoneDispute>, milestone_index: u8, winner: DisputeWinner) -> Result<()> {
@@ -52,16 +71,21 @@ pub fn handler(ctx: Context<ResolveMilestoneDispute>, milestone_index: u8, winne
     );
 
     let amount = escrow.milestones[milestone_index as usize].amount;
+    let fee_basis_points = escrow.fee_basis_points;
+
+    // Settle the milestone before moving lamports so it can't be paid twice
+    let escrow = &mut ctx.accounts.escrow_account;
+    escrow.milestones[milestone_index as usize].status = MilestoneStatus::Released;
+    escrow.released_amount = escrow.released_amount.checked_add(amount).ok_or(EscrowError::Overflow)?;
+    // Defensive: releases can never exceed what was funded
+    require!(escrow.released_amount <= escrow.total_amount, EscrowError::Overflow);
+    escrow.validate_milestones()?;
+
     let escrow_info = ctx.accounts.escrow_account.to_account_info();
 
     match winner {
         DisputeWinner::Recipient => {
-            let fee = (amount as u128)
-                .checked_mul(escrow.fee_basis_points as u128)
-                .ok_or(EscrowError::Overflow)?
-                .checked_div(10_000)
-                .ok_or(EscrowError::Overflow)? as u64;
-            let recipient_amount = amount.checked_sub(fee).ok_or(EscrowError::Overflow)?;
+            let (fee, recipient_amount) = calc_fee(amount, fee_basis_points)?;
 
             if fee > 0 {
                 **escrow_info.try_borrow_mut_lamports()? -= fee;
@@ -76,20 +100,46 @@ pub fn handler(ctx: Context<ResolveMilestoneDispute>, milestone_index: u8, winne
         }
     }
 
-    let escrow = &mut ctx.accounts.escrow_account;
-    escrow.milestones[milestone_index as usize].status = MilestoneStatus::Released;
-    escrow.released_amount = escrow.released_amount.checked_add(amount).ok_or(EscrowError::Overflow)?;
+    // Update reputation accounts if provided, same as a SOL escrow dispute
+    let clock = Clock::get()?;
+
+    match winner {
+        DisputeWinner::Recipient => {
+            if let Some(recipient_rep) = &mut ctx.accounts.recipient_reputation {
+                recipient_rep.disputes_won = recipient_rep.disputes_won.saturating_add(1);
+                recipient_rep.last_activity = clock.unix_timestamp;
+                emit!(ReputationUpdated::new(recipient_rep, ReputationChangeReason::DisputeWon));
+            }
+            if let Some(creator_rep) = &mut ctx.accounts.creator_reputation {
+                creator_rep.disputes_lost = creator_rep.disputes_lost.saturating_add(1);
+                creator_rep.last_activity = clock.unix_timestamp;
+                emit!(ReputationUpdated::new(creator_rep, ReputationChangeReason::DisputeLost));
+            }
+        }
+        DisputeWinner::Creator => {
+            if let Some(creator_rep) = &mut ctx.accounts.creator_reputation {
+                creator_rep.disputes_won = creator_rep.disputes_won.saturating_add(1);
+                creator_rep.last_activity = clock.unix_timestamp;
+                emit!(ReputationUpdated::new(creator_rep, ReputationChangeReason::DisputeWon));
+            }
+            if let Some(recipient_rep) = &mut ctx.accounts.recipient_reputation {
+                recipient_rep.disputes_lost = recipient_rep.disputes_lost.saturating_add(1);
+                recipient_rep.last_activity = clock.unix_timestamp;
+                emit!(ReputationUpdated::new(recipient_rep, ReputationChangeReason::DisputeLost));
+            }
+        }
+    }
 
-    // Back to Active status after resolving the milestone dispute
-    escrow.status = EscrowStatus::Active;
+    let escrow = &mut ctx.accounts.escrow_account;
+    escrow.last_dispute_ts = clock.unix_timestamp;
 
     // Check if all milestones are done
     let all_released = escrow.milestones[..escrow.milestone_count as usize]
         .iter()
-        .all(|m| m.status == MilestoneStatus::Released);
+        .all(|m| m.status.is_settled());
 
     if all_released {
-        escrow.status = EscrowStatus::Completed;
+        escrow.status.transition_to(EscrowStatus::Completed)?;
     }
 
     Ok(())
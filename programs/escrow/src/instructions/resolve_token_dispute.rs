@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer, CloseAccount};
 use crate::state::*;
 use crate::errors::EscrowError;
+use crate::util::calc_fee;
 use crate::instructions::resolve_dispute::DisputeWinner;
 
 #[derive(Accounts)]
@@ -11,6 +12,7 @@ pub struct ResolveTokenDispute<'info> {
         close = creator,
         seeds = [b"token_escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
         bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
         constraint = escrow_account.status == EscrowStatus::Disputed @ EscrowError::InvalidStatus,
         constraint = escrow_account.arbiter == arbiter.key() @ EscrowError::UnauthorizedArbiter,
     )]
@@ -64,6 +66,8 @@ pub struct ResolveTokenDispute<'info> {
 }
 
 pub fn handler(ctx: Context<ResolveTokenDispute>, winner: DisputeWinner) -> Result<()> {
+    ctx.accounts.escrow_account.status.transition_to(EscrowStatus::Resolving)?;
+
     let escrow = &ctx.accounts.escrow_account;
     let amount = escrow.amount;
 
@@ -78,12 +82,7 @@ pub fn handler(ctx: Context<ResolveTokenDispute>, winner: DisputeWinner) -> Resu
 
     match winner {
         DisputeWinner::Recipient => {
-            let fee = (amount as u128)
-                .checked_mul(escrow.fee_basis_points as u128)
-                .ok_or(EscrowError::Overflow)?
-                .checked_div(10_000)
-                .ok_or(EscrowError::Overflow)? as u64;
-            let recipient_amount = amount.checked_sub(fee).ok_or(EscrowError::Overflow)?;
+            let (fee, recipient_amount) = calc_fee(amount, escrow.fee_basis_points)?;
 
             if fee > 0 {
                 token::transfer(
@@ -141,7 +140,7 @@ pub fn handler(ctx: Context<ResolveTokenDispute>, winner: DisputeWinner) -> Resu
     ))?;
 
     let escrow = &mut ctx.accounts.escrow_account;
-    escrow.status = EscrowStatus::Resolved;
+    escrow.status.transition_to(EscrowStatus::Resolved)?;
 
     Ok(())
 }
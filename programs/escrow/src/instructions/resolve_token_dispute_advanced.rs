@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer, CloseAccount};
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::util::calc_fee;
+use crate::instructions::resolve_token_dispute::ResolveTokenDispute;
+
+/// Pays `recipient_amount` to the recipient and refunds `refund_amount` to the
+/// creator; together they must cover the whole escrow. The refunded portion is
+/// never charged a fee, and `charge_fee = false` waives the fee on the
+/// recipient's portion too.
+pub fn handler(
+    ctx: Context<ResolveTokenDispute>,
+    recipient_amount: u64,
+    refund_amount: u64,
+    charge_fee: bool,
+) -> Result<()> {
+    ctx.accounts.escrow_account.status.transition_to(EscrowStatus::Resolving)?;
+
+    let escrow = &ctx.accounts.escrow_account;
+    require!(
+        recipient_amount.checked_add(refund_amount) == Some(escrow.amount),
+        EscrowError::InvalidSplit
+    );
+
+    let (fee, recipient_net) = if charge_fee {
+        calc_fee(recipient_amount, escrow.fee_basis_points)?
+    } else {
+        (0, recipient_amount)
+    };
+
+    let escrow_id_bytes = escrow.escrow_id.to_le_bytes();
+    let seeds = &[
+        b"token_escrow".as_ref(),
+        escrow.creator.as_ref(),
+        escrow_id_bytes.as_ref(),
+        &[escrow.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let transfers = [
+        (ctx.accounts.fee_token_account.to_account_info(), fee),
+        (ctx.accounts.recipient_token_account.to_account_info(), recipient_net),
+        (ctx.accounts.creator_token_account.to_account_info(), refund_amount),
+    ];
+    for (to, amount) in transfers {
+        if amount > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.vault.to_account_info(),
+                        to,
+                        authority: ctx.accounts.escrow_account.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            )?;
+        }
+    }
+
+    // Close vault
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.vault.to_account_info(),
+            destination: ctx.accounts.creator.to_account_info(),
+            authority: ctx.accounts.escrow_account.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    let escrow = &mut ctx.accounts.escrow_account;
+    escrow.status.transition_to(EscrowStatus::Resolved)?;
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer, CloseAccount};
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::util::calc_fee;
+use crate::instructions::resolve_token_dispute::ResolveTokenDispute;
+
+/// Splits the post-fee tokens: `recipient_bps` to the recipient, the rest back to the creator
+pub fn handler(ctx: Context<ResolveTokenDispute>, recipient_bps: u16) -> Result<()> {
+    require!(recipient_bps <= 10_000, EscrowError::InvalidSplit);
+
+    ctx.accounts.escrow_account.status.transition_to(EscrowStatus::Resolving)?;
+
+    let escrow = &ctx.accounts.escrow_account;
+    let (fee, net) = calc_fee(escrow.amount, escrow.fee_basis_points)?;
+    let (recipient_amount, creator_amount) = calc_fee(net, recipient_bps)?;
+
+    let escrow_id_bytes = escrow.escrow_id.to_le_bytes();
+    let seeds = &[
+        b"token_escrow".as_ref(),
+        escrow.creator.as_ref(),
+        escrow_id_bytes.as_ref(),
+        &[escrow.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let transfers = [
+        (ctx.accounts.fee_token_account.to_account_info(), fee),
+        (ctx.accounts.recipient_token_account.to_account_info(), recipient_amount),
+        (ctx.accounts.creator_token_account.to_account_info(), creator_amount),
+    ];
+    for (to, amount) in transfers {
+        if amount > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.vault.to_account_info(),
+                        to,
+                        authority: ctx.accounts.escrow_account.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            )?;
+        }
+    }
+
+    // Close vault
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.vault.to_account_info(),
+            destination: ctx.accounts.creator.to_account_info(),
+            authority: ctx.accounts.escrow_account.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    let escrow = &mut ctx.accounts.escrow_account;
+    escrow.status.transition_to(EscrowStatus::Resolved)?;
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct SetDelegate<'info> {
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
+        bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.recipient == recipient.key() @ EscrowError::UnauthorizedRecipient,
+        constraint = matches!(
+            escrow_account.status,
+            EscrowStatus::Created | EscrowStatus::Active
+        ) @ EscrowError::InvalidStatus,
+        constraint = delegate != escrow_account.creator @ EscrowError::SelfEscrow,
+    )]
+    pub escrow_account: Account<'info, EscrowAccount>,
+
+    pub recipient: Signer<'info>,
+}
+
+/// Lets `delegate` call request_release and claim_auto_release for the
+/// recipient. Pass `Pubkey::default()` to revoke.
+pub fn handler(ctx: Context<SetDelegate>, delegate: Pubkey) -> Result<()> {
+    let escrow = &mut ctx.accounts.escrow_account;
+    escrow.delegate = delegate;
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+
+#[derive(Accounts)]
+pub struct SetMintFee<'info> {
+    #[account(
+        mut,
+        seeds = [b"mint_fee_config"],
+        bump = mint_fee_config.bump,
+    )]
+    pub mint_fee_config: Account<'info, MintFeeConfig>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ EscrowError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+}
+
+/// Adds or updates the default fee for `mint`
+pub fn handler(ctx: Context<SetMintFee>, mint: Pubkey, fee_basis_points: u16) -> Result<()> {
+    require!(fee_basis_points <= MAX_FEE_BASIS_POINTS, EscrowError::FeeTooHigh);
+
+    let fee_config = &mut ctx.accounts.mint_fee_config;
+    if let Some(entry) = fee_config.fees.iter_mut().find(|f| f.mint == mint) {
+        entry.fee_basis_points = fee_basis_points;
+        return Ok(());
+    }
+
+    require!(fee_config.fees.len() < MAX_MINT_FEES, EscrowError::MintFeeConfigFull);
+    fee_config.fees.push(MintFee { mint, fee_basis_points });
+
+    Ok(())
+}
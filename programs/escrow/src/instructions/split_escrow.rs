@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::events::EscrowCreated;
+use crate::util::calc_fee;
+
+#[derive(Accounts)]
+#[instruction(new_escrow_id: u64)]
+pub struct SplitEscrow<'info> {
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
+        bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.creator == creator.key() @ EscrowError::UnauthorizedCreator,
+        constraint = escrow_account.status == EscrowStatus::Created @ EscrowError::InvalidStatus,
+    )]
+    pub escrow_account: Account<'info, EscrowAccount>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = EscrowAccount::SPACE,
+        seeds = [b"escrow", creator.key().as_ref(), &new_escrow_id.to_le_bytes()],
+        bump
+    )]
+    pub new_escrow_account: Account<'info, EscrowAccount>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Protocol config (optional - pass to honour the pause switch and the active escrow cap)
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Option<Account<'info, Config>>,
+
+    /// Creator's escrow counter (optional - pass to draw a guaranteed-unique new_escrow_id;
+    /// required if config caps active escrows per creator)
+    #[account(
+        mut,
+        seeds = [b"counter", creator.key().as_ref()],
+        bump = escrow_counter.bump,
+    )]
+    pub escrow_counter: Option<Account<'info, EscrowCounter>>,
+}
+
+/// Carves `split_amount` out of an unaccepted escrow into a new escrow with
+/// the same parties and terms
+pub fn handler(ctx: Context<SplitEscrow>, new_escrow_id: u64, split_amount: u64) -> Result<()> {
+    if let Some(config) = &ctx.accounts.config {
+        config.check_not_paused()?;
+    }
+
+    require!(split_amount > 0, EscrowError::ZeroAmount);
+    require!(
+        split_amount < ctx.accounts.escrow_account.amount,
+        EscrowError::SplitAmountTooLarge
+    );
+
+    // The new escrow takes a slot under the creator's cap like any other
+    let max_active = ctx.accounts.config.as_ref().map_or(0, |c| c.max_active_per_creator);
+    match &mut ctx.accounts.escrow_counter {
+        Some(counter) => {
+            require!(new_escrow_id == counter.next_escrow_id(), EscrowError::EscrowIdMismatch);
+            counter.next_id = counter.next_id.checked_add(1).ok_or(EscrowError::Overflow)?;
+            counter.record_opened(max_active)?;
+        }
+        None => require!(max_active == 0, EscrowError::TooManyActiveEscrows),
+    }
+
+    // A creator-paid fee moves with its share of the amount
+    let moved = if ctx.accounts.escrow_account.fee_paid_by_creator {
+        let (fee, _) = calc_fee(split_amount, ctx.accounts.escrow_account.fee_basis_points)?;
+        split_amount.checked_add(fee).ok_or(EscrowError::Overflow)?
+    } else {
+        split_amount
+    };
+
+    // Both escrows are program-owned, so lamports move directly; the creator
+    // paid the new account's rent at init
+    **ctx.accounts.escrow_account.to_account_info().try_borrow_mut_lamports()? -= moved;
+    **ctx.accounts.new_escrow_account.to_account_info().try_borrow_mut_lamports()? += moved;
+
+    let clock = Clock::get()?;
+    let original = &mut ctx.accounts.escrow_account;
+    original.amount = original.amount.checked_sub(split_amount).ok_or(EscrowError::Overflow)?;
+
+    let mut split = (**original).clone();
+    split.amount = split_amount;
+    split.created_at = clock.unix_timestamp;
+    split.escrow_id = new_escrow_id;
+    split.bump = ctx.bumps.new_escrow_account;
+    // The early-release bonus was funded for the original escrow only
+    split.early_bonus_lamports = 0;
+    ctx.accounts.new_escrow_account.set_inner(split);
+
+    let split = &ctx.accounts.new_escrow_account;
+    emit!(EscrowCreated {
+        escrow: split.key(),
+        creator: split.creator,
+        recipient: split.recipient,
+        amount: split_amount,
+        deadline: split.deadline,
+        category: split.category,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
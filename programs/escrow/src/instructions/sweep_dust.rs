@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+
+/// Housekeeping for lamports stranded in program-owned accounts that no
+/// longer hold a live account (empty or zeroed data). The config admin can
+/// sweep them to a treasury wallet of their choosing.
+#[derive(Accounts)]
+pub struct SweepDust<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ EscrowError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+
+    /// CHECK: must be owned by this program with no live account data (checked in handler)
+    #[account(mut, owner = crate::ID)]
+    pub orphan: UncheckedAccount<'info>,
+
+    /// CHECK: treasury wallet receiving the swept lamports
+    #[account(mut)]
+    pub treasury: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<SweepDust>) -> Result<()> {
+    let orphan_info = ctx.accounts.orphan.to_account_info();
+
+    // Any non-zero byte means a live account (every one starts with a discriminator)
+    require!(
+        orphan_info.try_borrow_data()?.iter().all(|b| *b == 0),
+        EscrowError::AccountNotOrphaned
+    );
+
+    let dust = orphan_info.lamports();
+    require!(dust > 0, EscrowError::ZeroAmount);
+
+    **orphan_info.try_borrow_mut_lamports()? -= dust;
+    **ctx.accounts.treasury.try_borrow_mut_lamports()? += dust;
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::errors::EscrowError;
+
+#[derive(Accounts)]
+pub struct TopUpRecurring<'info> {
+    #[account(
+        mut,
+        seeds = [b"recurring_escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
+        bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.creator == creator.key() @ EscrowError::UnauthorizedCreator,
+    )]
+    pub escrow_account: Account<'info, RecurringEscrowAccount>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Adds `amount` to the prepaid balance
+pub fn handler(ctx: Context<TopUpRecurring>, amount: u64) -> Result<()> {
+    require!(amount > 0, EscrowError::ZeroAmount);
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.creator.to_account_info(),
+                to: ctx.accounts.escrow_account.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let escrow = &mut ctx.accounts.escrow_account;
+    escrow.balance = escrow.balance.checked_add(amount).ok_or(EscrowError::Overflow)?;
+
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::util::{pay_out_release, record_escrow_closed, record_release_reputation, ReleaseTargets};
+
+/// Settles a Created escrow in one step for a pair that meets
+/// `Config::is_trusted_pair`, skipping acceptance and the dispute window.
+/// Both parties sign: the recipient's signature is their opt-in.
+#[derive(Accounts)]
+pub struct TrustedFastRelease<'info> {
+    #[account(
+        mut,
+        close = creator,
+        seeds = [b"escrow", escrow_account.creator.as_ref(), &escrow_account.escrow_id.to_le_bytes()],
+        bump = escrow_account.bump,
+        constraint = escrow_account.version == ACCOUNT_VERSION @ EscrowError::UnsupportedVersion,
+        constraint = escrow_account.creator == creator.key() @ EscrowError::UnauthorizedCreator,
+        constraint = escrow_account.recipient == recipient.key() @ EscrowError::UnauthorizedRecipient,
+        constraint = escrow_account.status == EscrowStatus::Created @ EscrowError::InvalidStatus,
+    )]
+    pub escrow_account: Account<'info, EscrowAccount>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    /// CHECK: validated against escrow_account.fee_recipient
+    #[account(
+        mut,
+        constraint = escrow_account.fee_recipient == fee_recipient.key()
+    )]
+    pub fee_recipient: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"reputation", escrow_account.creator.as_ref()],
+        bump = creator_reputation.bump,
+    )]
+    pub creator_reputation: Account<'info, ReputationAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"reputation", escrow_account.recipient.as_ref()],
+        bump = recipient_reputation.bump,
+    )]
+    pub recipient_reputation: Account<'info, ReputationAccount>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: validated against escrow_account.referrer (required if referrer_fee_bps > 0)
+    #[account(
+        mut,
+        constraint = escrow_account.referrer == referrer.key() @ EscrowError::InvalidReferrer
+    )]
+    pub referrer: Option<UncheckedAccount<'info>>,
+
+    /// Second approver (optional - required if the escrow has a cosigner)
+    pub cosigner: Option<Signer<'info>>,
+
+    /// CHECK: creator's escrow counter PDA (freed on close if it has been initialized)
+    #[account(
+        mut,
+        seeds = [b"counter", escrow_account.creator.as_ref()],
+        bump,
+    )]
+    pub escrow_counter: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<TrustedFastRelease>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    config.check_not_paused()?;
+    require!(
+        config.is_trusted_pair(&ctx.accounts.creator_reputation, &ctx.accounts.recipient_reputation),
+        EscrowError::NotTrustedPair
+    );
+
+    let escrow = &ctx.accounts.escrow_account;
+    let amount = escrow.amount;
+    escrow.check_cosigner(ctx.accounts.cosigner.as_ref().map(|c| c.key()))?;
+
+    let fee_basis_points = escrow.fee_basis_points;
+
+    let clock = Clock::get()?;
+    ctx.accounts.escrow_account.status.transition_to(EscrowStatus::Completing)?;
+
+    pay_out_release(
+        &ctx.accounts.escrow_account,
+        fee_basis_points,
+        clock.unix_timestamp,
+        ReleaseTargets {
+            fee_recipient: &ctx.accounts.fee_recipient,
+            referrer: ctx.accounts.referrer.as_deref(),
+            destination: &ctx.accounts.recipient,
+            cranker: None,
+        },
+    )?;
+
+    record_release_reputation(
+        Some(&mut ctx.accounts.creator_reputation),
+        Some(&mut ctx.accounts.recipient_reputation),
+        amount,
+        clock.unix_timestamp,
+    );
+
+    let escrow = &mut ctx.accounts.escrow_account;
+    escrow.status.transition_to(EscrowStatus::Completed)?;
+
+    record_escrow_closed(&ctx.accounts.escrow_counter)?;
+
+    Ok(())
+}
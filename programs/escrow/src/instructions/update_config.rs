@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+use crate::instructions::init_config::ConfigParams;
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ EscrowError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UpdateConfig>, params: ConfigParams) -> Result<()> {
+    ctx.accounts.config.apply(&params)
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::EscrowError;
+
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    #[account(
+        mut,
+        seeds = [b"fee_vault"],
+        bump = fee_vault.bump,
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ EscrowError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+
+    /// CHECK: wallet receiving the accumulated fees
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+}
+
+/// Sweeps everything above the vault's rent-exempt minimum to `destination`
+pub fn handler(ctx: Context<WithdrawFees>) -> Result<()> {
+    let vault_info = ctx.accounts.fee_vault.to_account_info();
+    let rent_floor = Rent::get()?.minimum_balance(FeeVault::SPACE);
+
+    let fees = vault_info.lamports().saturating_sub(rent_floor);
+    require!(fees > 0, EscrowError::ZeroAmount);
+
+    **vault_info.try_borrow_mut_lamports()? -= fees;
+    **ctx.accounts.destination.try_borrow_mut_lamports()? += fees;
+
+    Ok(())
+}
@@ -2,6 +2,8 @@ use anchor_lang::prelude::*;
 
 pub mod state;
 pub mod errors;
+pub mod events;
+pub mod util;
 pub mod instructions;
 
 use instructions::*;
@@ -20,32 +22,164 @@ pub mod escrow {
         terms_hash: [u8; 32],
         fee_basis_points: u16,
         auto_release_at: i64,
+        refund_grace_seconds: i64,
+        referrer_fee_bps: u16,
+        post_release_window_seconds: i64,
+        not_before: i64,
+        category: u16,
+        metadata_uri: String,
+        auto_release_after_deadline: i64,
+        accept_deadline: i64,
+        fee_paid_by_creator: bool,
+        early_bonus_lamports: u64,
+        dispute_deadline: i64,
     ) -> Result<()> {
-        instructions::create_escrow::handler(ctx, escrow_id, amount, deadline, terms_hash, fee_basis_points, auto_release_at)
+        instructions::create_escrow::handler(ctx, escrow_id, amount, deadline, terms_hash, fee_basis_points, auto_release_at, refund_grace_seconds, referrer_fee_bps, post_release_window_seconds, not_before, category, metadata_uri, auto_release_after_deadline, accept_deadline, fee_paid_by_creator, early_bonus_lamports, dispute_deadline)
+    }
+
+    pub fn init_escrow_counter(ctx: Context<InitEscrowCounter>) -> Result<()> {
+        instructions::init_escrow_counter::handler(ctx)
+    }
+
+    pub fn init_audit_log(ctx: Context<InitAuditLog>) -> Result<()> {
+        instructions::init_audit_log::handler(ctx)
+    }
+
+    pub fn create_escrow_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateEscrowBatch<'info>>,
+        escrows: Vec<BatchEscrowParams>,
+        fee_basis_points: u16,
+    ) -> Result<()> {
+        instructions::create_escrow_batch::handler(ctx, escrows, fee_basis_points)
     }
 
     pub fn auto_release(ctx: Context<AutoRelease>) -> Result<()> {
         instructions::auto_release::handler(ctx)
     }
 
-    pub fn accept_task(ctx: Context<AcceptTask>) -> Result<()> {
-        instructions::accept_task::handler(ctx)
+    pub fn claim_auto_release(ctx: Context<ClaimAutoRelease>) -> Result<()> {
+        instructions::claim_auto_release::handler(ctx)
+    }
+
+    pub fn creator_inactivity_release(ctx: Context<CreatorInactivityRelease>) -> Result<()> {
+        instructions::creator_inactivity_release::handler(ctx)
+    }
+
+    pub fn finalize_escrow(ctx: Context<FinalizeEscrow>) -> Result<()> {
+        instructions::finalize_escrow::handler(ctx)
+    }
+
+    pub fn accept_task(ctx: Context<AcceptTask>, terms_ack: [u8; 32]) -> Result<()> {
+        instructions::accept_task::handler(ctx, terms_ack)
     }
 
     pub fn release_payment(ctx: Context<ReleasePayment>) -> Result<()> {
         instructions::release_payment::handler(ctx)
     }
 
+    pub fn rate_recipient(ctx: Context<RateRecipient>, stars: u8) -> Result<()> {
+        instructions::rate_recipient::handler(ctx, stars)
+    }
+
+    pub fn release_and_rate(ctx: Context<ReleaseAndRate>, stars: u8) -> Result<()> {
+        instructions::release_and_rate::handler(ctx, stars)
+    }
+
+    pub fn release_with_tip(ctx: Context<ReleaseWithTip>, tip: u64) -> Result<()> {
+        instructions::release_with_tip::handler(ctx, tip)
+    }
+
+    pub fn trusted_fast_release(ctx: Context<TrustedFastRelease>) -> Result<()> {
+        instructions::trusted_fast_release::handler(ctx)
+    }
+
     pub fn request_refund(ctx: Context<RequestRefund>) -> Result<()> {
         instructions::request_refund::handler(ctx)
     }
 
+    pub fn request_partial_refund(ctx: Context<RequestPartialRefund>, refund_amount: u64) -> Result<()> {
+        instructions::request_partial_refund::handler(ctx, refund_amount)
+    }
+
+    pub fn amend_terms(ctx: Context<AmendTerms>, new_terms_hash: [u8; 32]) -> Result<()> {
+        instructions::amend_terms::handler(ctx, new_terms_hash)
+    }
+
+    pub fn reassign_task(ctx: Context<ReassignTask>, new_recipient: Pubkey) -> Result<()> {
+        instructions::reassign_task::handler(ctx, new_recipient)
+    }
+
+    pub fn cancel_auto_release(ctx: Context<CancelAutoRelease>) -> Result<()> {
+        instructions::cancel_auto_release::handler(ctx)
+    }
+
+    pub fn request_release(ctx: Context<RequestRelease>) -> Result<()> {
+        instructions::request_release::handler(ctx)
+    }
+
+    pub fn submit_work(ctx: Context<SubmitWork>, work_hash: [u8; 32]) -> Result<()> {
+        instructions::submit_work::handler(ctx, work_hash)
+    }
+
+    pub fn set_delegate(ctx: Context<SetDelegate>, delegate: Pubkey) -> Result<()> {
+        instructions::set_delegate::handler(ctx, delegate)
+    }
+
+    pub fn acknowledge_terms(ctx: Context<AcknowledgeTerms>, terms_ack: [u8; 32]) -> Result<()> {
+        instructions::acknowledge_terms::handler(ctx, terms_ack)
+    }
+
+    pub fn migrate_escrow(ctx: Context<MigrateEscrow>) -> Result<()> {
+        instructions::migrate_escrow::handler(ctx)
+    }
+
+    pub fn migrate_token_escrow(ctx: Context<MigrateTokenEscrow>) -> Result<()> {
+        instructions::migrate_token_escrow::handler(ctx)
+    }
+
+    pub fn migrate_milestone_escrow(ctx: Context<MigrateMilestoneEscrow>) -> Result<()> {
+        instructions::migrate_milestone_escrow::handler(ctx)
+    }
+
+    pub fn migrate_reputation(ctx: Context<MigrateReputation>) -> Result<()> {
+        instructions::migrate_reputation::handler(ctx)
+    }
+
+    pub fn split_escrow(ctx: Context<SplitEscrow>, new_escrow_id: u64, split_amount: u64) -> Result<()> {
+        instructions::split_escrow::handler(ctx, new_escrow_id, split_amount)
+    }
+
     pub fn dispute(ctx: Context<Dispute>, reason: [u8; 64]) -> Result<()> {
         instructions::dispute::handler(ctx, reason)
     }
 
-    pub fn resolve_dispute(ctx: Context<ResolveDispute>, winner: DisputeWinner) -> Result<()> {
-        instructions::resolve_dispute::handler(ctx, winner)
+    pub fn resolve_dispute(ctx: Context<ResolveDispute>, winner: DisputeWinner, resolution_hash: [u8; 32]) -> Result<()> {
+        instructions::resolve_dispute::handler(ctx, winner, resolution_hash)
+    }
+
+    pub fn resolve_disputes_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, ResolveDisputesBatch<'info>>,
+        resolutions: Vec<BatchResolution>,
+    ) -> Result<()> {
+        instructions::resolve_disputes_batch::handler(ctx, resolutions)
+    }
+
+    pub fn assign_arbiter(ctx: Context<AssignArbiter>, arbiter: Pubkey) -> Result<()> {
+        instructions::assign_arbiter::handler(ctx, arbiter)
+    }
+
+    pub fn assign_random_arbiter<'info>(
+        ctx: Context<'_, '_, '_, 'info, AssignRandomArbiter<'info>>,
+    ) -> Result<()> {
+        instructions::assign_random_arbiter::handler(ctx)
+    }
+
+    pub fn request_arbitration(ctx: Context<RequestArbitration>, arbitration_fee: u64) -> Result<()> {
+        instructions::request_arbitration::handler(ctx, arbitration_fee)
+    }
+
+    pub fn claim_bond(ctx: Context<ClaimBond>) -> Result<()> {
+        instructions::claim_bond::handler(ctx)
     }
 
     // --- Token Escrow Instructions ---
@@ -70,10 +204,22 @@ pub mod escrow {
         instructions::release_token_payment::handler(ctx)
     }
 
+    pub fn release_token_staged(ctx: Context<ReleaseTokenStaged>) -> Result<()> {
+        instructions::release_token_staged::handler(ctx)
+    }
+
+    pub fn finalize_token_release(ctx: Context<FinalizeTokenRelease>) -> Result<()> {
+        instructions::finalize_token_release::handler(ctx)
+    }
+
     pub fn refund_token_escrow(ctx: Context<RefundTokenEscrow>) -> Result<()> {
         instructions::refund_token_escrow::handler(ctx)
     }
 
+    pub fn recover_frozen_token_escrow(ctx: Context<RecoverFrozenTokenEscrow>) -> Result<()> {
+        instructions::recover_frozen_token_escrow::handler(ctx)
+    }
+
     pub fn dispute_token(ctx: Context<DisputeToken>, reason: [u8; 64]) -> Result<()> {
         instructions::dispute_token::handler(ctx, reason)
     }
@@ -82,6 +228,19 @@ pub mod escrow {
         instructions::resolve_token_dispute::handler(ctx, winner)
     }
 
+    pub fn resolve_token_dispute_split(ctx: Context<ResolveTokenDispute>, recipient_bps: u16) -> Result<()> {
+        instructions::resolve_token_dispute_split::handler(ctx, recipient_bps)
+    }
+
+    pub fn resolve_token_dispute_advanced(
+        ctx: Context<ResolveTokenDispute>,
+        recipient_amount: u64,
+        refund_amount: u64,
+        charge_fee: bool,
+    ) -> Result<()> {
+        instructions::resolve_token_dispute_advanced::handler(ctx, recipient_amount, refund_amount, charge_fee)
+    }
+
     pub fn auto_release_token(ctx: Context<AutoReleaseToken>) -> Result<()> {
         instructions::auto_release_token::handler(ctx)
     }
@@ -103,12 +262,16 @@ pub mod escrow {
         instructions::accept_milestone_task::handler(ctx)
     }
 
+    pub fn accept_milestones(ctx: Context<AcceptMilestones>, indices: Vec<u8>) -> Result<()> {
+        instructions::accept_milestones::handler(ctx, indices)
+    }
+
     pub fn release_milestone(ctx: Context<ReleaseMilestone>, milestone_index: u8) -> Result<()> {
         instructions::release_milestone::handler(ctx, milestone_index)
     }
 
-    pub fn dispute_milestone(ctx: Context<DisputeMilestone>, milestone_index: u8) -> Result<()> {
-        instructions::dispute_milestone::handler(ctx, milestone_index)
+    pub fn dispute_milestone(ctx: Context<DisputeMilestone>, milestone_index: u8, reason: [u8; 64]) -> Result<()> {
+        instructions::dispute_milestone::handler(ctx, milestone_index, reason)
     }
 
     pub fn resolve_milestone_dispute(ctx: Context<ResolveMilestoneDispute>, milestone_index: u8, winner: DisputeWinner) -> Result<()> {
@@ -119,9 +282,177 @@ pub mod escrow {
         instructions::refund_milestone_escrow::handler(ctx)
     }
 
+    pub fn create_milestone_escrow_compact(
+        ctx: Context<CreateMilestoneEscrowCompact>,
+        escrow_id: u64,
+        deadline: i64,
+        terms_hash: [u8; 32],
+        fee_basis_points: u16,
+        milestones: Vec<MilestoneInput>,
+    ) -> Result<()> {
+        instructions::create_milestone_escrow_compact::handler(ctx, escrow_id, deadline, terms_hash, fee_basis_points, milestones)
+    }
+
+    pub fn accept_compact_milestone_task(ctx: Context<AcceptCompactMilestoneTask>) -> Result<()> {
+        instructions::accept_compact_milestone_task::handler(ctx)
+    }
+
+    pub fn release_compact_milestone(ctx: Context<ReleaseCompactMilestone>, milestone_index: u8) -> Result<()> {
+        instructions::release_compact_milestone::handler(ctx, milestone_index)
+    }
+
+    pub fn refund_compact_milestone_escrow(ctx: Context<RefundCompactMilestoneEscrow>) -> Result<()> {
+        instructions::refund_compact_milestone_escrow::handler(ctx)
+    }
+
+    pub fn get_claimable(ctx: Context<GetClaimable>) -> Result<ClaimableAmounts> {
+        instructions::get_claimable::handler(ctx)
+    }
+
+    // --- Hybrid Escrow Instructions ---
+
+    pub fn create_hybrid_escrow(
+        ctx: Context<CreateHybridEscrow>,
+        escrow_id: u64,
+        lamport_amount: u64,
+        token_amount: u64,
+        deadline: i64,
+        terms_hash: [u8; 32],
+        fee_basis_points: u16,
+    ) -> Result<()> {
+        instructions::create_hybrid_escrow::handler(ctx, escrow_id, lamport_amount, token_amount, deadline, terms_hash, fee_basis_points)
+    }
+
+    pub fn accept_hybrid_task(ctx: Context<AcceptHybridTask>) -> Result<()> {
+        instructions::accept_hybrid_task::handler(ctx)
+    }
+
+    pub fn release_hybrid(ctx: Context<ReleaseHybrid>) -> Result<()> {
+        instructions::release_hybrid::handler(ctx)
+    }
+
+    pub fn refund_hybrid(ctx: Context<RefundHybrid>) -> Result<()> {
+        instructions::refund_hybrid::handler(ctx)
+    }
+
+    // --- Recurring Escrow Instructions ---
+
+    pub fn create_recurring_escrow(
+        ctx: Context<CreateRecurringEscrow>,
+        escrow_id: u64,
+        amount_per_period: u64,
+        period_seconds: i64,
+        deposit: u64,
+        fee_basis_points: u16,
+    ) -> Result<()> {
+        instructions::create_recurring_escrow::handler(ctx, escrow_id, amount_per_period, period_seconds, deposit, fee_basis_points)
+    }
+
+    pub fn release_period(ctx: Context<ReleasePeriod>) -> Result<()> {
+        instructions::release_period::handler(ctx)
+    }
+
+    pub fn top_up_recurring(ctx: Context<TopUpRecurring>, amount: u64) -> Result<()> {
+        instructions::top_up_recurring::handler(ctx, amount)
+    }
+
+    pub fn cancel_recurring(ctx: Context<CancelRecurring>) -> Result<()> {
+        instructions::cancel_recurring::handler(ctx)
+    }
+
     // --- Reputation ---
 
     pub fn init_reputation(ctx: Context<InitReputation>) -> Result<()> {
         instructions::init_reputation::handler(ctx)
     }
+
+    pub fn init_reputation_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, InitReputationBatch<'info>>,
+        agents: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::init_reputation_batch::handler(ctx, agents)
+    }
+
+    pub fn reputation_commitment(ctx: Context<ReputationCommitment>) -> Result<[u8; 32]> {
+        instructions::reputation_commitment::handler(ctx)
+    }
+
+    pub fn reputation_score(ctx: Context<ReputationScore>) -> Result<u64> {
+        instructions::reputation_score::handler(ctx)
+    }
+
+    pub fn close_reputation(ctx: Context<CloseReputation>) -> Result<()> {
+        instructions::close_reputation::handler(ctx)
+    }
+
+    pub fn merge_reputation(ctx: Context<MergeReputation>) -> Result<()> {
+        instructions::merge_reputation::handler(ctx)
+    }
+    // --- Config ---
+
+    pub fn init_config(ctx: Context<InitConfig>, params: ConfigParams) -> Result<()> {
+        instructions::init_config::handler(ctx, params)
+    }
+
+    pub fn update_config(ctx: Context<UpdateConfig>, params: ConfigParams) -> Result<()> {
+        instructions::update_config::handler(ctx, params)
+    }
+
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        instructions::set_paused::handler(ctx, paused)
+    }
+
+    pub fn redirect_fees(ctx: Context<RedirectFees>, new_fee_recipient: Pubkey) -> Result<()> {
+        instructions::redirect_fees::handler(ctx, new_fee_recipient)
+    }
+
+    pub fn sweep_dust(ctx: Context<SweepDust>) -> Result<()> {
+        instructions::sweep_dust::handler(ctx)
+    }
+
+    pub fn init_fee_vault(ctx: Context<InitFeeVault>) -> Result<()> {
+        instructions::init_fee_vault::handler(ctx)
+    }
+
+    pub fn withdraw_fees(ctx: Context<WithdrawFees>) -> Result<()> {
+        instructions::withdraw_fees::handler(ctx)
+    }
+
+    pub fn init_global_stats(ctx: Context<InitGlobalStats>) -> Result<()> {
+        instructions::init_global_stats::handler(ctx)
+    }
+
+    pub fn force_complete(ctx: Context<ForceComplete>, winner: DisputeWinner) -> Result<()> {
+        instructions::force_complete::handler(ctx, winner)
+    }
+
+    pub fn init_mint_allowlist(ctx: Context<InitMintAllowlist>) -> Result<()> {
+        instructions::init_mint_allowlist::handler(ctx)
+    }
+
+    pub fn add_mint(ctx: Context<UpdateMintAllowlist>, mint: Pubkey) -> Result<()> {
+        instructions::add_mint::handler(ctx, mint)
+    }
+
+    pub fn remove_mint(ctx: Context<UpdateMintAllowlist>, mint: Pubkey) -> Result<()> {
+        instructions::remove_mint::handler(ctx, mint)
+    }
+
+    pub fn init_mint_fee_config(ctx: Context<InitMintFeeConfig>) -> Result<()> {
+        instructions::init_mint_fee_config::handler(ctx)
+    }
+
+    pub fn set_mint_fee(ctx: Context<SetMintFee>, mint: Pubkey, fee_basis_points: u16) -> Result<()> {
+        instructions::set_mint_fee::handler(ctx, mint, fee_basis_points)
+    }
+
+    // --- Arbiter Registry ---
+
+    pub fn register_arbiter(ctx: Context<RegisterArbiter>, stake: u64) -> Result<()> {
+        instructions::register_arbiter::handler(ctx, stake)
+    }
+
+    pub fn deregister_arbiter(ctx: Context<DeregisterArbiter>) -> Result<()> {
+        instructions::deregister_arbiter::handler(ctx)
+    }
 }
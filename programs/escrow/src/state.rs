@@ -1,4 +1,38 @@
 use anchor_lang::prelude::*;
+use crate::errors::EscrowError;
+use crate::util::calc_fee;
+
+/// Maximum total fee (protocol + referrer) in basis points (1000 = 10%)
+pub const MAX_FEE_BASIS_POINTS: u16 = 1000;
+
+/// Seconds an arbiter must be inactive before deregistering (7 days)
+pub const ARBITER_DEREGISTER_COOLDOWN: i64 = 7 * 24 * 60 * 60;
+
+/// Maximum number of accounts created by a single create_escrow_batch or
+/// init_reputation_batch call, or escrows settled by resolve_disputes_batch
+pub const MAX_BATCH_SIZE: usize = 8;
+
+/// Seconds after a milestone dispute is resolved before another can be opened (1 hour)
+pub const MILESTONE_DISPUTE_COOLDOWN: i64 = 60 * 60;
+
+/// Maximum number of candidates assign_random_arbiter will draw from
+pub const MAX_ARBITER_POOL: usize = 16;
+
+/// Bytes reserved for an escrow's off-chain metadata URI
+pub const METADATA_URI_LEN: usize = 128;
+
+/// Layout version stored as the first field of every escrow account. Later
+/// fields are only ever appended (see util::migrate_escrow_layout); bump this
+/// when a change needs more than a zero-filled tail, so handlers reject
+/// accounts they can't read safely.
+pub const ACCOUNT_VERSION: u8 = 1;
+
+/// Seconds after release during which the creator may rate the recipient (7 days)
+pub const RATING_WINDOW_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+/// How long a recipient token account must stay frozen after being reported
+/// before the creator can reclaim an active token escrow (24 hours)
+pub const FROZEN_RECOVERY_SECONDS: i64 = 24 * 60 * 60;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
 pub enum EscrowStatus {
@@ -9,10 +43,62 @@ pub enum EscrowStatus {
     Refunded,
     Cancelled,
     Resolved,
+    /// Transient: payout to the recipient is being transferred
+    Completing,
+    /// Transient: a dispute ruling is being paid out
+    Resolving,
+}
+
+impl EscrowStatus {
+    /// Legal state machine transitions. Refunded, Cancelled and Resolved are terminal.
+    ///
+    /// Created -> Active | Cancelled | Disputed (recipient, after acknowledge_terms)
+    /// Created -> Completing -> Completed (trusted_fast_release, force_complete)
+    /// Active -> Completed (held auto-release, last milestone settled)
+    /// Active -> Disputed | Refunded
+    /// Active -> Completing -> Completed (every release path)
+    /// Completed -> Disputed (only while a post-release window is open)
+    /// Completed -> Completing -> Completed (finalize_escrow)
+    /// Disputed -> Resolving -> Resolved (resolve_dispute, force_complete)
+    ///
+    /// Completing and Resolving are entered before lamports move and left
+    /// once they have, on every path that pays out of an escrow. A failed
+    /// transaction is rolled back as a whole, so neither is stored on chain
+    /// except by release_token_staged, which leaves the escrow Completing
+    /// until finalize_token_release pays out. They stop a handler from paying
+    /// out from an escrow it has already started settling.
+    pub fn can_transition_to(&self, next: EscrowStatus) -> bool {
+        use EscrowStatus::*;
+        matches!(
+            (*self, next),
+            (Created, Active)
+                | (Created, Cancelled)
+                | (Created, Disputed)
+                | (Created, Completing)
+                | (Active, Completed)
+                | (Active, Disputed)
+                | (Active, Refunded)
+                | (Active, Completing)
+                | (Completing, Completed)
+                | (Completed, Completing)
+                | (Completed, Disputed)
+                | (Disputed, Resolving)
+                | (Resolving, Resolved)
+        )
+    }
+
+    /// Moves to `next`, rejecting illegal transitions with `InvalidStatus`
+    pub fn transition_to(&mut self, next: EscrowStatus) -> Result<()> {
+        require!(self.can_transition_to(next), EscrowError::InvalidStatus);
+        *self = next;
+        Ok(())
+    }
 }
 
 #[account]
 pub struct EscrowAccount {
+    /// Layout version stamped at creation (see ACCOUNT_VERSION)
+    pub version: u8,
     /// Creator (task poster) pubkey
     pub creator: Pubkey,
     /// Recipient (task executor) pubkey
@@ -41,10 +127,60 @@ pub struct EscrowAccount {
     pub dispute_reason: [u8; 64],
     /// Auto-release timestamp (0 = disabled, >0 = unix timestamp when anyone can release)
     pub auto_release_at: i64,
+    /// Seconds after deadline before an active escrow can be refunded (0 = immediately)
+    pub refund_grace_seconds: i64,
+    /// Referrer pubkey (default = no referrer)
+    pub referrer: Pubkey,
+    /// Referrer fee in basis points, paid out of the escrow on release
+    pub referrer_fee_bps: u16,
+    /// Seconds after auto-release during which the creator can still dispute (0 = pay out immediately)
+    pub post_release_window_seconds: i64,
+    /// Auto-release timestamp while payout is held for the post-release window (0 = not released)
+    pub released_at: i64,
+    /// Earliest timestamp at which the recipient can accept (0 = immediately)
+    pub not_before: i64,
+    /// Terms hash replaced by the last amend_terms (zeroed = never amended)
+    pub prev_terms_hash: [u8; 32],
+    /// Set once the recipient signals the work is done via request_release
+    pub release_requested: bool,
+    /// Marketplace-defined category tag for off-chain analytics (no on-chain meaning)
+    pub category: u16,
+    /// Second approver who must co-sign creator releases (default = not required)
+    pub cosigner: Pubkey,
+    /// Third party that funded the escrow; refunds go back to them (default = creator funded)
+    pub sponsor: Pubkey,
+    /// Arbitration fee deposited after a dispute, held by its BondReceipt and
+    /// paid to the arbiter on resolution
+    pub arbitration_fee: u64,
+    /// Party that deposited the arbitration fee (default = none deposited)
+    pub arbitration_fee_payer: Pubkey,
+    /// When the recipient acknowledged the terms before accepting (0 = never)
+    pub terms_acked_at: i64,
+    /// Off-chain metadata URI (Arweave/IPFS/...), zero-padded UTF-8
+    pub metadata_uri: [u8; METADATA_URI_LEN],
+    /// Times this escrow has entered Disputed
+    pub dispute_count: u8,
+    /// When the recipient accepted the task (0 = not yet accepted)
+    pub accepted_at: i64,
+    /// Hot key allowed to act for the recipient; payouts still go to the recipient (default = none)
+    pub delegate: Pubkey,
+    /// Latest time the recipient may accept; the work deadline still governs refunds (0 = deadline)
+    pub accept_deadline: i64,
+    /// Creator funded the protocol fee on top of `amount`, so the recipient is paid in full
+    pub fee_paid_by_creator: bool,
+    /// Hash of the delivered work recorded by the recipient via submit_work (zeroed = not submitted)
+    pub work_hash: [u8; 32],
+    /// Creator-prefunded bonus for releasing early; decays linearly to zero at the deadline
+    pub early_bonus_lamports: u64,
+    /// Last moment a new dispute may be opened; bounds the recipient's liability after delivery (0 = unbounded)
+    pub dispute_deadline: i64,
+    /// Wallet that receives request_refund payouts for creator-funded escrows (defaults to creator)
+    pub refund_destination: Pubkey,
 }
 
 impl EscrowAccount {
     pub const SPACE: usize = 8  // discriminator
+        + 1   // version
         + 32  // creator
         + 32  // recipient
         + 8   // amount
@@ -58,11 +194,160 @@ impl EscrowAccount {
         + 8   // escrow_id
         + 1   // bump
         + 64  // dispute_reason
-        + 8;  // auto_release_at
+        + 8   // auto_release_at
+        + 8   // refund_grace_seconds
+        + 32  // referrer
+        + 2   // referrer_fee_bps
+        + 8   // post_release_window_seconds
+        + 8   // released_at
+        + 8   // not_before
+        + 32  // prev_terms_hash
+        + 1   // release_requested
+        + 2   // category
+        + 32  // cosigner
+        + 32  // sponsor
+        + 8   // arbitration_fee
+        + 32  // arbitration_fee_payer
+        + 8   // terms_acked_at
+        + METADATA_URI_LEN // metadata_uri
+        + 1   // dispute_count
+        + 8   // accepted_at
+        + 32  // delegate
+        + 8   // accept_deadline
+        + 1   // fee_paid_by_creator
+        + 32  // work_hash
+        + 8   // early_bonus_lamports
+        + 8   // dispute_deadline
+        + 32; // refund_destination
+
+    /// Requires the recipient, or their delegate if one is set, to have signed
+    pub fn check_recipient_or_delegate(&self, recipient_signed: bool, delegate: Option<Pubkey>) -> Result<()> {
+        let delegate_signed = self.delegate != Pubkey::default() && delegate == Some(self.delegate);
+        require!(recipient_signed || delegate_signed, EscrowError::UnauthorizedRecipient);
+        Ok(())
+    }
+
+    /// Requires the configured cosigner (if any) to have signed a creator release
+    pub fn check_cosigner(&self, cosigner: Option<Pubkey>) -> Result<()> {
+        if self.cosigner != Pubkey::default() {
+            require!(cosigner == Some(self.cosigner), EscrowError::CosignerRequired);
+        }
+        Ok(())
+    }
+
+    /// Timestamp after which the task can no longer be accepted
+    pub fn accept_by(&self) -> i64 {
+        if self.accept_deadline != 0 {
+            self.accept_deadline
+        } else {
+            self.deadline
+        }
+    }
+
+    /// Earliest timestamp at which an active escrow can be refunded
+    pub fn refund_available_at(&self) -> i64 {
+        self.deadline.saturating_add(self.refund_grace_seconds)
+    }
+
+    /// Wallet owed a creator-side refund of the escrowed amount: the sponsor
+    /// that funded it, else the creator's refund destination
+    pub fn refund_recipient(&self) -> Pubkey {
+        if self.sponsor != Pubkey::default() {
+            self.sponsor
+        } else {
+            self.refund_destination
+        }
+    }
+
+    /// Splits the escrowed amount into (protocol fee, referrer fee, recipient amount)
+    pub fn payout_split(&self) -> Result<(u64, u64, u64)> {
+        self.payout_split_at(self.fee_basis_points)
+    }
+
+    /// Same as `payout_split`, with the protocol fee charged at `fee_basis_points`.
+    /// A creator-paid fee comes out of the lamports funded on top of `amount`.
+    pub fn payout_split_at(&self, fee_basis_points: u16) -> Result<(u64, u64, u64)> {
+        let (fee, _) = calc_fee(self.amount, fee_basis_points)?;
+        let (referrer_fee, _) = calc_fee(self.amount, self.referrer_fee_bps)?;
+
+        let recipient_fee = if self.fee_paid_by_creator { 0 } else { fee };
+        let recipient_amount = self.amount
+            .checked_sub(recipient_fee)
+            .and_then(|a| a.checked_sub(referrer_fee))
+            .ok_or(EscrowError::Overflow)?;
+
+        Ok((fee, referrer_fee, recipient_amount))
+    }
+
+    /// Part of the early-release bonus earned by releasing at `now`: all of
+    /// it at creation, shrinking linearly to nothing at the deadline
+    pub fn early_bonus_at(&self, now: i64) -> Result<u64> {
+        if self.early_bonus_lamports == 0 || now >= self.deadline {
+            return Ok(0);
+        }
+        let window = self.deadline.saturating_sub(self.created_at);
+        if window <= 0 {
+            return Ok(0);
+        }
+        let remaining = self.deadline.saturating_sub(now.max(self.created_at));
+        let bonus = (self.early_bonus_lamports as u128)
+            .checked_mul(remaining as u128)
+            .ok_or(EscrowError::Overflow)?
+            / window as u128;
+        Ok(bonus as u64)
+    }
+
+    /// Timestamp at which a held auto-release can be finalized
+    pub fn post_release_window_ends_at(&self) -> i64 {
+        self.released_at.saturating_add(self.post_release_window_seconds)
+    }
+}
+
+#[account]
+pub struct EscrowCounter {
+    /// Creator whose escrow ids this counter issues
+    pub creator: Pubkey,
+    /// Next escrow id to use
+    pub next_id: u64,
+    /// PDA bump
+    pub bump: u8,
+    /// SOL escrows opened with this counter that have not yet closed
+    pub active_escrows: u32,
+}
+
+impl EscrowCounter {
+    pub const SPACE: usize = 8  // discriminator
+        + 32  // creator
+        + 8   // next_id
+        + 1   // bump
+        + 4;  // active_escrows
+
+    /// Escrow id the next create_escrow must use when the counter is passed
+    pub fn next_escrow_id(&self) -> u64 {
+        self.next_id
+    }
+
+    /// Counts a newly opened escrow, rejecting it if the creator is at `max_active` (0 = unlimited)
+    pub fn record_opened(&mut self, max_active: u32) -> Result<()> {
+        require!(
+            max_active == 0 || self.active_escrows < max_active,
+            EscrowError::TooManyActiveEscrows
+        );
+        self.active_escrows = self.active_escrows.checked_add(1).ok_or(EscrowError::Overflow)?;
+        Ok(())
+    }
+
+    /// Frees the slot of a closed escrow. Saturates because escrows opened
+    /// without the counter may still be closed with it.
+    pub fn record_closed(&mut self) {
+        self.active_escrows = self.active_escrows.saturating_sub(1);
+    }
 }
 
 #[account]
 pub struct TokenEscrowAccount {
+    /// Layout version stamped at creation (see ACCOUNT_VERSION)
+    pub version: u8,
     /// Creator (task poster) pubkey
     pub creator: Pubkey,
     /// Recipient (task executor) pubkey
@@ -93,10 +378,17 @@ pub struct TokenEscrowAccount {
     pub dispute_reason: [u8; 64],
     /// Auto-release timestamp (0 = disabled)
     pub auto_release_at: i64,
+    /// When the recipient accepted the task (0 = not yet accepted)
+    pub accepted_at: i64,
+    /// When the creator first reported the recipient's token account frozen (0 = never)
+    pub frozen_reported_at: i64,
+    /// Recipient share awaiting finalize_token_release after a staged release (0 = not staged)
+    pub staged_amount: u64,
 }
 
 impl TokenEscrowAccount {
     pub const SPACE: usize = 8  // discriminator
+        + 1   // version
         + 32  // creator
         + 32  // recipient
         + 32  // mint
@@ -111,7 +403,119 @@ impl TokenEscrowAccount {
         + 8   // escrow_id
         + 1   // bump
         + 64  // dispute_reason
-        + 8;  // auto_release_at
+        + 8   // auto_release_at
+        + 8   // accepted_at
+        + 8   // frozen_reported_at
+        + 8;  // staged_amount
+}
+
+#[account]
+pub struct HybridEscrowAccount {
+    /// Layout version stamped at creation (see ACCOUNT_VERSION)
+    pub version: u8,
+    /// Creator (task poster) pubkey
+    pub creator: Pubkey,
+    /// Recipient (task executor) pubkey
+    pub recipient: Pubkey,
+    /// SPL token mint of the bonus leg
+    pub mint: Pubkey,
+    /// Base payment in lamports held in this account
+    pub lamport_amount: u64,
+    /// Token bonus in smallest units held in the vault
+    pub token_amount: u64,
+    /// Current status
+    pub status: EscrowStatus,
+    /// Deadline as Unix timestamp (seconds)
+    pub deadline: i64,
+    /// SHA256 hash of terms/agreement
+    pub terms_hash: [u8; 32],
+    /// Arbiter pubkey (for dispute resolution)
+    pub arbiter: Pubkey,
+    /// Fee in basis points, charged on both legs
+    pub fee_basis_points: u16,
+    /// Fee recipient (treasury) pubkey
+    pub fee_recipient: Pubkey,
+    /// Creation timestamp (Unix seconds)
+    pub created_at: i64,
+    /// Unique escrow ID
+    pub escrow_id: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl HybridEscrowAccount {
+    pub const SPACE: usize = 8  // discriminator
+        + 1   // version
+        + 32  // creator
+        + 32  // recipient
+        + 32  // mint
+        + 8   // lamport_amount
+        + 8   // token_amount
+        + 1   // status
+        + 8   // deadline
+        + 32  // terms_hash
+        + 32  // arbiter
+        + 2   // fee_basis_points
+        + 32  // fee_recipient
+        + 8   // created_at
+        + 8   // escrow_id
+        + 1;  // bump
+}
+
+#[account]
+pub struct RecurringEscrowAccount {
+    /// Layout version stamped at creation (see ACCOUNT_VERSION)
+    pub version: u8,
+    /// Creator (subscriber) pubkey
+    pub creator: Pubkey,
+    /// Recipient (service provider) pubkey
+    pub recipient: Pubkey,
+    /// Lamports released to the recipient each period
+    pub amount_per_period: u64,
+    /// Length of one period in seconds
+    pub period_seconds: i64,
+    /// Earliest time the next period can be released
+    pub next_release_ts: i64,
+    /// Prepaid lamports not yet released (excludes rent)
+    pub balance: u64,
+    /// Periods released so far
+    pub periods_released: u32,
+    /// Fee in basis points, charged on each released period
+    pub fee_basis_points: u16,
+    /// Fee recipient (treasury) pubkey
+    pub fee_recipient: Pubkey,
+    /// Creation timestamp (Unix seconds)
+    pub created_at: i64,
+    /// Unique escrow ID
+    pub escrow_id: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RecurringEscrowAccount {
+    pub const SPACE: usize = 8  // discriminator
+        + 1   // version
+        + 32  // creator
+        + 32  // recipient
+        + 8   // amount_per_period
+        + 8   // period_seconds
+        + 8   // next_release_ts
+        + 8   // balance
+        + 4   // periods_released
+        + 2   // fee_basis_points
+        + 32  // fee_recipient
+        + 8   // created_at
+        + 8   // escrow_id
+        + 1;  // bump
+
+    /// Periods that have come due by `now` and are covered by the balance
+    pub fn due_periods(&self, now: i64) -> u64 {
+        if now < self.next_release_ts {
+            return 0;
+        }
+        let elapsed = (now - self.next_release_ts) as u64 / self.period_seconds as u64 + 1;
+        elapsed.min(self.balance / self.amount_per_period)
+    }
 }
 
 pub const MAX_MILESTONES: usize = 10;
@@ -121,9 +525,25 @@ pub enum MilestoneStatus {
     Pending,
     Released,
     Disputed,
+    /// Committed to by the recipient via accept_milestones
+    Accepted,
+    /// Declined by the recipient via accept_milestones and returned to the creator
+    Refunded,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+impl MilestoneStatus {
+    /// Funded and awaiting release
+    pub fn is_open(&self) -> bool {
+        matches!(self, MilestoneStatus::Pending | MilestoneStatus::Accepted)
+    }
+
+    /// Paid out one way or the other
+    pub fn is_settled(&self) -> bool {
+        matches!(self, MilestoneStatus::Released | MilestoneStatus::Refunded)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
 pub struct Milestone {
     pub amount: u64,
     pub status: MilestoneStatus,
@@ -146,6 +566,8 @@ impl Default for Milestone {
 
 #[account]
 pub struct MilestoneEscrowAccount {
+    /// Layout version stamped at creation (see ACCOUNT_VERSION)
+    pub version: u8,
     pub creator: Pubkey,
     pub recipient: Pubkey,
     pub total_amount: u64,
@@ -161,10 +583,22 @@ pub struct MilestoneEscrowAccount {
     pub bump: u8,
     pub milestone_count: u8,
     pub milestones: [Milestone; MAX_MILESTONES],
+    /// Dispute reason (truncated to 64 bytes)
+    pub dispute_reason: [u8; 64],
+    /// Timestamp of the last milestone dispute resolution (0 = never)
+    pub last_dispute_ts: i64,
+    /// Milestone disputes opened on this escrow
+    pub dispute_count: u8,
+    /// When the recipient accepted the task (0 = not yet accepted)
+    pub accepted_at: i64,
+    /// Who opened the dispute on each milestone, by index (default = never disputed).
+    /// Kept out of `Milestone` so the milestones array keeps its original layout.
+    pub milestone_disputed_by: [Pubkey; MAX_MILESTONES],
 }
 
 impl MilestoneEscrowAccount {
     pub const SPACE: usize = 8  // discriminator
+        + 1   // version
         + 32  // creator
         + 32  // recipient
         + 8   // total_amount
@@ -179,9 +613,75 @@ impl MilestoneEscrowAccount {
         + 8   // escrow_id
         + 1   // bump
         + 1   // milestone_count
-        + (Milestone::SPACE * MAX_MILESTONES); // milestones
+        + (Milestone::SPACE * MAX_MILESTONES) // milestones
+        + 64  // dispute_reason
+        + 8   // last_dispute_ts
+        + 1   // dispute_count
+        + 8   // accepted_at
+        + (32 * MAX_MILESTONES); // milestone_disputed_by
+
+    /// Checks `milestone_count` agrees with the populated part of `milestones`:
+    /// every slot past the count must still be `Milestone::default()`
+    pub fn validate_milestones(&self) -> Result<()> {
+        let count = self.milestone_count as usize;
+        require!(count <= MAX_MILESTONES, EscrowError::MilestoneCountMismatch);
+        require!(
+            self.milestones[count..].iter().all(|m| *m == Milestone::default()),
+            EscrowError::MilestoneCountMismatch
+        );
+        Ok(())
+    }
+}
+
+/// Milestone escrow sized to its milestones instead of `MAX_MILESTONES`,
+/// for escrows with only a few. Supports accept, release and refund; use
+/// `MilestoneEscrowAccount` when milestone disputes are needed.
+#[account]
+pub struct CompactMilestoneEscrowAccount {
+    /// Layout version stamped at creation (see ACCOUNT_VERSION)
+    pub version: u8,
+    pub creator: Pubkey,
+    pub recipient: Pubkey,
+    pub total_amount: u64,
+    pub released_amount: u64,
+    pub status: EscrowStatus,
+    pub deadline: i64,
+    pub terms_hash: [u8; 32],
+    pub fee_basis_points: u16,
+    pub fee_recipient: Pubkey,
+    pub created_at: i64,
+    pub escrow_id: u64,
+    pub bump: u8,
+    /// When the recipient accepted the task (0 = not yet accepted)
+    pub accepted_at: i64,
+    /// Exactly the milestones the escrow was created with
+    pub milestones: Vec<Milestone>,
+}
+
+impl CompactMilestoneEscrowAccount {
+    /// Account size for `milestone_count` milestones
+    pub const fn space(milestone_count: usize) -> usize {
+        8   // discriminator
+        + 1   // version
+        + 32  // creator
+        + 32  // recipient
+        + 8   // total_amount
+        + 8   // released_amount
+        + 1   // status
+        + 8   // deadline
+        + 32  // terms_hash
+        + 2   // fee_basis_points
+        + 32  // fee_recipient
+        + 8   // created_at
+        + 8   // escrow_id
+        + 1   // bump
+        + 8   // accepted_at
+        + 4 + Milestone::SPACE * milestone_count // milestones
+    }
 }
 
+/// Fields are only ever appended; migrate_reputation grows accounts created
+/// under an older layout.
 #[account]
 pub struct ReputationAccount {
     /// Agent's public key
@@ -206,6 +706,14 @@ pub struct ReputationAccount {
     pub last_activity: i64,
     /// PDA bump
     pub bump: u8,
+    /// Total tips received as recipient, in lamports
+    pub tips_received: u64,
+    /// Sum of 1-5 star ratings received as recipient
+    pub rating_sum: u64,
+    /// Number of ratings received as recipient
+    pub rating_count: u32,
+    /// Escrows refunded to the creator after this agent, as recipient, let the deadline pass
+    pub deadlines_missed: u32,
 }
 
 impl ReputationAccount {
@@ -220,5 +728,553 @@ impl ReputationAccount {
         + 4   // disputes_lost
         + 8   // total_volume_lamports
         + 8   // last_activity
+        + 1   // bump
+        + 8   // tips_received
+        + 8   // rating_sum
+        + 4   // rating_count
+        + 4;  // deadlines_missed
+
+    /// Points per completed escrow, as creator or recipient
+    pub const SCORE_PER_COMPLETION: u64 = 100;
+    /// Lamports of volume worth one point (0.01 SOL, so 1 SOL ~ one completion)
+    pub const SCORE_VOLUME_UNIT: u64 = 10_000_000;
+    /// Points deducted per dispute lost
+    pub const SCORE_DISPUTE_PENALTY: u64 = 500;
+
+    /// Disputes this agent has seen through to a ruling, either way
+    pub fn dispute_cases(&self) -> u32 {
+        self.disputes_won.saturating_add(self.disputes_lost)
+    }
+
+    /// Single sortable leaderboard score:
+    ///
+    /// ```text
+    /// score = 100 * (escrows_completed + tasks_completed)
+    ///       + total_volume_lamports / 10_000_000
+    ///       - 500 * disputes_lost          (floored at 0)
+    /// ```
+    ///
+    /// Completions dominate so volume can't be bought with a few large
+    /// escrows, and one lost dispute costs five completions. Ratings and
+    /// tips are left out; clients can break ties with them.
+    pub fn score(&self) -> u64 {
+        let completions = (self.escrows_completed as u64).saturating_add(self.tasks_completed as u64);
+        let earned = completions
+            .saturating_mul(Self::SCORE_PER_COMPLETION)
+            .saturating_add(self.total_volume_lamports / Self::SCORE_VOLUME_UNIT);
+        let penalty = (self.disputes_lost as u64).saturating_mul(Self::SCORE_DISPUTE_PENALTY);
+
+        earned.saturating_sub(penalty)
+    }
+}
+
+/// Left behind when an agent's reputation is closed or merged away, so the
+/// same wallet can't initialize a fresh record and shed its history
+#[account]
+pub struct ReputationTombstone {
+    /// Agent whose reputation was closed
+    pub agent: Pubkey,
+    /// When the reputation account was closed
+    pub closed_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ReputationTombstone {
+    pub const SPACE: usize = 8  // discriminator
+        + 32  // agent
+        + 8   // closed_at
+        + 1;  // bump
+}
+
+/// Left behind by a release so the creator can rate the recipient once.
+/// Closed when the rating is submitted.
+#[account]
+pub struct RatingReceipt {
+    /// Released escrow this receipt was issued for
+    pub escrow: Pubkey,
+    /// Creator entitled to rate
+    pub creator: Pubkey,
+    /// Recipient being rated
+    pub recipient: Pubkey,
+    /// Release timestamp; the rating window runs from here
+    pub released_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl RatingReceipt {
+    pub const SPACE: usize = 8  // discriminator
+        + 32  // escrow
+        + 32  // creator
+        + 32  // recipient
+        + 8   // released_at
+        + 1;  // bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum BondOutcome {
+    /// Dispute still open; a ruling pays the deposit out and closes the receipt
+    Pending,
+    /// Dispute settled without a ruling; the payer can take the deposit back
+    /// with claim_bond
+    Refundable,
+}
+
+/// Holds an escrow's arbitration deposit apart from the escrow, so the
+/// deposit outlives the escrow closing. Created by request_arbitration.
+#[account]
+pub struct BondReceipt {
+    /// Disputed escrow the deposit was made for
+    pub escrow: Pubkey,
+    /// Party that put up the deposit
+    pub payer: Pubkey,
+    /// Deposit in lamports (held in this account on top of rent)
+    pub amount: u64,
+    /// What the settlement decided for the deposit
+    pub outcome: BondOutcome,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl BondReceipt {
+    pub const SPACE: usize = 8  // discriminator
+        + 32  // escrow
+        + 32  // payer
+        + 8   // amount
+        + 1   // outcome
+        + 1;  // bump
+}
+
+#[account]
+pub struct Config {
+    /// Admin allowed to update the config
+    pub admin: Pubkey,
+    /// Max seconds from creation that auto_release_at may be set (0 = unbounded)
+    pub max_auto_release_horizon: i64,
+    /// Minimum stake (lamports) a registered arbiter must hold to be chosen (0 = not required)
+    pub min_arbiter_stake: u64,
+    /// PDA bump
+    pub bump: u8,
+    /// Completed tasks a recipient needs for the reputation fee discount (0 = disabled)
+    pub fee_discount_min_tasks: u32,
+    /// Minimum protocol fee in basis points every new escrow must charge (0 = no floor)
+    pub min_fee_bps: u16,
+    /// Max seconds from creation that a deadline may be set (0 = unbounded)
+    pub max_deadline_horizon: i64,
+    /// Reward paid to whoever cranks an auto-release, carved out of the protocol
+    /// fee (token escrows pay the same figure in token base units; 0 = disabled)
+    pub crank_reward_lamports: u64,
+    /// Smallest amount (lamports) a single milestone may hold (0 = no minimum)
+    pub min_milestone_amount: u64,
+    /// Emergency switch: blocks creates and releases, refunds stay open
+    pub paused: bool,
+    /// Completed escrows each side of a pair needs for trusted_fast_release (0 = disabled)
+    pub trusted_min_tasks: u32,
+    /// Seconds past an escrow's deadline before the admin may force_complete it (0 = disabled)
+    pub force_complete_delay: i64,
+    /// Seconds past the deadline after which the recipient of a delivered but
+    /// unreleased escrow may pay themselves (0 = disabled; meant to be weeks)
+    pub creator_inactivity_window: i64,
+    /// Open escrows a creator may have at once; enforced through their escrow counter (0 = unlimited)
+    pub max_active_per_creator: u32,
+    /// Disputes (won + lost) a chosen arbiter's reputation must show at creation (0 = no minimum)
+    pub min_arbiter_cases: u32,
+    /// Share (bps) of a losing party's arbitration deposit the arbiter keeps; the rest goes to
+    /// the winner (0 = disabled, the arbiter keeps the whole deposit)
+    pub bond_to_arbiter_bps: u16,
+}
+
+impl Config {
+    pub const SPACE: usize = 8  // discriminator
+        + 32  // admin
+        + 8   // max_auto_release_horizon
+        + 8   // min_arbiter_stake
+        + 1   // bump
+        + 4   // fee_discount_min_tasks
+        + 2   // min_fee_bps
+        + 8   // max_deadline_horizon
+        + 8   // crank_reward_lamports
+        + 8   // min_milestone_amount
+        + 1   // paused
+        + 4   // trusted_min_tasks
+        + 8   // force_complete_delay
+        + 8   // creator_inactivity_window
+        + 4   // max_active_per_creator
+        + 4   // min_arbiter_cases
+        + 2;  // bond_to_arbiter_bps
+
+    /// Rejects creates and releases while the admin has paused the program
+    pub fn check_not_paused(&self) -> Result<()> {
+        require!(!self.paused, EscrowError::ProgramPaused);
+        Ok(())
+    }
+
+    /// Rejects escrows whose protocol fee is below the configured floor
+    pub fn check_fee_floor(&self, fee_basis_points: u16) -> Result<()> {
+        require!(fee_basis_points >= self.min_fee_bps, EscrowError::FeeTooLow);
+        Ok(())
+    }
+
+    /// Rejects dust milestones below the configured minimum
+    pub fn check_milestone_amount(&self, amount: u64) -> Result<()> {
+        require!(amount >= self.min_milestone_amount, EscrowError::AmountBelowMinimum);
+        Ok(())
+    }
+
+    /// Arbiter's cut of an arbitration deposit put up by the losing party
+    pub fn losing_deposit_to_arbiter(&self, deposit: u64) -> Result<u64> {
+        if self.bond_to_arbiter_bps == 0 {
+            return Ok(deposit);
+        }
+        Ok(calc_fee(deposit, self.bond_to_arbiter_bps)?.0)
+    }
+
+    /// Portion of `fee` paid to an auto-release cranker (never more than the fee)
+    pub fn crank_reward(&self, fee: u64) -> u64 {
+        self.crank_reward_lamports.min(fee)
+    }
+
+    /// Rejects deadlines further out than the configured horizon from `now`
+    pub fn check_deadline_horizon(&self, deadline: i64, now: i64) -> Result<()> {
+        if self.max_deadline_horizon > 0 {
+            require!(
+                deadline <= now.saturating_add(self.max_deadline_horizon),
+                EscrowError::DeadlineTooFar
+            );
+        }
+        Ok(())
+    }
+
+    /// Earliest time the admin may force_complete an escrow with this deadline
+    pub fn force_complete_at(&self, deadline: i64) -> Option<i64> {
+        if self.force_complete_delay <= 0 {
+            return None;
+        }
+        deadline.checked_add(self.force_complete_delay)
+    }
+
+    /// Earliest time the recipient may take an escrow with this deadline
+    /// via creator_inactivity_release
+    pub fn inactivity_release_at(&self, deadline: i64) -> Option<i64> {
+        if self.creator_inactivity_window <= 0 {
+            return None;
+        }
+        deadline.checked_add(self.creator_inactivity_window)
+    }
+
+    /// Trust criteria for trusted_fast_release. Both parties qualify when:
+    /// - `trusted_min_tasks` is set (non-zero)
+    /// - the creator has completed at least that many escrows as creator
+    /// - the recipient has completed at least that many tasks as recipient
+    /// - neither has ever lost a dispute
+    pub fn is_trusted_pair(&self, creator: &ReputationAccount, recipient: &ReputationAccount) -> bool {
+        self.trusted_min_tasks > 0
+            && creator.escrows_completed >= self.trusted_min_tasks
+            && recipient.tasks_completed >= self.trusted_min_tasks
+            && creator.disputes_lost == 0
+            && recipient.disputes_lost == 0
+    }
+
+    /// Protocol fee tiers applied by release_payment:
+    /// - fewer than `fee_discount_min_tasks` completed tasks: full fee
+    /// - `fee_discount_min_tasks` or more: fee halved
+    pub fn discounted_fee_bps(&self, fee_basis_points: u16, tasks_completed: u32) -> u16 {
+        if self.fee_discount_min_tasks > 0 && tasks_completed >= self.fee_discount_min_tasks {
+            fee_basis_points / 2
+        } else {
+            fee_basis_points
+        }
+    }
+}
+
+/// Protocol-wide aggregates for SOL escrows. Handlers only update it when a
+/// client passes the PDA, so the totals cover opted-in traffic only.
+#[account]
+pub struct GlobalStats {
+    /// Escrows created
+    pub total_escrows_created: u64,
+    /// Lamports paid out through release_payment (fees included)
+    pub total_volume_lamports: u64,
+    /// Disputes opened
+    pub total_disputes: u64,
+    /// Protocol fees (lamports) charged on release
+    pub total_fees_collected: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl GlobalStats {
+    pub const SPACE: usize = 8  // discriminator
+        + 8   // total_escrows_created
+        + 8   // total_volume_lamports
+        + 8   // total_disputes
+        + 8   // total_fees_collected
+        + 1;  // bump
+}
+
+/// Protocol-wide SOL fee vault. Escrows opt in by naming this PDA as their
+/// fee_recipient, so every release credits it directly; the config admin
+/// sweeps the balance with withdraw_fees.
+#[account]
+pub struct FeeVault {
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl FeeVault {
+    pub const SPACE: usize = 8  // discriminator
         + 1;  // bump
 }
+
+/// Maximum number of mints a MintAllowlist can hold
+pub const MAX_ALLOWED_MINTS: usize = 16;
+
+#[account]
+pub struct MintAllowlist {
+    /// Mints approved to back token escrows
+    pub mints: Vec<Pubkey>,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl MintAllowlist {
+    pub const SPACE: usize = 8  // discriminator
+        + 4 + (32 * MAX_ALLOWED_MINTS) // mints
+        + 1;  // bump
+}
+
+/// Maximum number of per-mint fee overrides a MintFeeConfig can hold
+pub const MAX_MINT_FEES: usize = 16;
+
+/// Sentinel `fee_basis_points` telling create_token_escrow to use the mint's configured fee
+pub const USE_MINT_FEE_BPS: u16 = u16::MAX;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct MintFee {
+    pub mint: Pubkey,
+    pub fee_basis_points: u16,
+}
+
+impl MintFee {
+    pub const SPACE: usize = 32 + 2; // 34 bytes
+}
+
+#[account]
+pub struct MintFeeConfig {
+    /// Default protocol fee per mint, applied when a creator passes USE_MINT_FEE_BPS
+    pub fees: Vec<MintFee>,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl MintFeeConfig {
+    pub const SPACE: usize = 8  // discriminator
+        + 4 + (MintFee::SPACE * MAX_MINT_FEES) // fees
+        + 1;  // bump
+
+    pub fn fee_for(&self, mint: &Pubkey) -> Option<u16> {
+        self.fees.iter().find(|f| f.mint == *mint).map(|f| f.fee_basis_points)
+    }
+}
+
+#[account]
+pub struct ArbiterAccount {
+    /// Arbiter's public key
+    pub authority: Pubkey,
+    /// Staked bond in lamports (held in this account on top of rent)
+    pub stake: u64,
+    /// Number of disputes resolved
+    pub cases_resolved: u32,
+    /// Registration timestamp
+    pub registered_at: i64,
+    /// Last registration or resolution timestamp (starts the deregister cooldown)
+    pub last_active_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ArbiterAccount {
+    pub const SPACE: usize = 8  // discriminator
+        + 32  // authority
+        + 8   // stake
+        + 4   // cases_resolved
+        + 8   // registered_at
+        + 8   // last_active_at
+        + 1;  // bump
+}
+
+/// Maximum number of entries an AuditLog can hold
+pub const MAX_AUDIT_ENTRIES: usize = 32;
+
+/// Lifecycle step recorded in an AuditLog entry
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum AuditAction {
+    Created = 0,
+    Accepted = 1,
+    WorkSubmitted = 2,
+    ReleaseRequested = 3,
+    Released = 4,
+    Disputed = 5,
+    Resolved = 6,
+    Refunded = 7,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct AuditEntry {
+    pub timestamp: i64,
+    /// AuditAction discriminant
+    pub action: u8,
+    pub actor: Pubkey,
+}
+
+impl AuditEntry {
+    pub const SPACE: usize = 8 + 1 + 32; // 41 bytes
+}
+
+/// Append-only on-chain trail for a single SOL escrow. Starts empty and is
+/// grown one entry at a time with realloc; rent for the full capacity is
+/// deposited up front so appends never need a payer. Outlives the escrow.
+#[account]
+pub struct AuditLog {
+    /// Escrow this log belongs to
+    pub escrow: Pubkey,
+    /// Recorded entries, oldest first
+    pub entries: Vec<AuditEntry>,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl AuditLog {
+    pub const BASE_SPACE: usize = 8  // discriminator
+        + 32  // escrow
+        + 4   // entries length prefix
+        + 1;  // bump
+
+    pub const MAX_SPACE: usize = Self::BASE_SPACE + AuditEntry::SPACE * MAX_AUDIT_ENTRIES;
+
+    pub fn space_for(entries: usize) -> usize {
+        Self::BASE_SPACE + AuditEntry::SPACE * entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Milestone escrow with the first `populated` milestones funded and
+    /// `milestone_count` set independently
+    fn milestone_escrow(populated: usize, milestone_count: u8) -> MilestoneEscrowAccount {
+        let mut milestones = [Milestone::default(); MAX_MILESTONES];
+        for (i, milestone) in milestones.iter_mut().take(populated).enumerate() {
+            milestone.amount = 100_000_000;
+            milestone.description_hash = [i as u8 + 1; 32];
+        }
+
+        MilestoneEscrowAccount {
+            version: ACCOUNT_VERSION,
+            creator: Pubkey::new_unique(),
+            recipient: Pubkey::new_unique(),
+            total_amount: 100_000_000 * populated as u64,
+            released_amount: 0,
+            status: EscrowStatus::Created,
+            deadline: 0,
+            terms_hash: [0; 32],
+            arbiter: Pubkey::new_unique(),
+            fee_basis_points: 0,
+            fee_recipient: Pubkey::new_unique(),
+            created_at: 0,
+            escrow_id: 0,
+            bump: 255,
+            milestone_count,
+            milestones,
+            dispute_reason: [0; 64],
+            last_dispute_ts: 0,
+            dispute_count: 0,
+            accepted_at: 0,
+            milestone_disputed_by: [Pubkey::default(); MAX_MILESTONES],
+        }
+    }
+
+    #[test]
+    fn validate_milestones_accepts_matching_count() {
+        assert!(milestone_escrow(3, 3).validate_milestones().is_ok());
+        assert!(milestone_escrow(MAX_MILESTONES, MAX_MILESTONES as u8).validate_milestones().is_ok());
+    }
+
+    #[test]
+    fn validate_milestones_rejects_count_below_populated_entries() {
+        let err = milestone_escrow(3, 2).validate_milestones().unwrap_err();
+        assert_eq!(err, EscrowError::MilestoneCountMismatch.into());
+    }
+
+    #[test]
+    fn validate_milestones_rejects_count_above_max() {
+        let err = milestone_escrow(MAX_MILESTONES, MAX_MILESTONES as u8 + 1).validate_milestones().unwrap_err();
+        assert_eq!(err, EscrowError::MilestoneCountMismatch.into());
+    }
+
+    fn reputation() -> ReputationAccount {
+        ReputationAccount {
+            agent: Pubkey::new_unique(),
+            escrows_created: 0,
+            escrows_completed: 0,
+            escrows_received: 0,
+            tasks_completed: 0,
+            disputes_initiated: 0,
+            disputes_won: 0,
+            disputes_lost: 0,
+            total_volume_lamports: 0,
+            last_activity: 0,
+            bump: 255,
+            tips_received: 0,
+            rating_sum: 0,
+            rating_count: 0,
+            deadlines_missed: 0,
+        }
+    }
+
+    #[test]
+    fn score_is_zero_for_a_fresh_agent() {
+        assert_eq!(reputation().score(), 0);
+    }
+
+    #[test]
+    fn score_counts_volume_in_hundredths_of_a_sol() {
+        let mut rep = reputation();
+        rep.escrows_completed = 1;
+        rep.total_volume_lamports = 50 * 1_000_000_000;
+
+        // One completion plus 50 SOL, worth another 50 completions
+        assert_eq!(rep.score(), 100 + 5_000);
+    }
+
+    #[test]
+    fn score_floors_lost_disputes_at_zero() {
+        let mut rep = reputation();
+        rep.tasks_completed = 2;
+        rep.disputes_lost = 1;
+
+        assert_eq!(rep.score(), 0);
+    }
+
+    #[test]
+    fn score_does_not_overflow_at_maximum_counters() {
+        let mut rep = reputation();
+        rep.escrows_completed = u32::MAX;
+        rep.tasks_completed = u32::MAX;
+        rep.total_volume_lamports = u64::MAX;
+
+        let completions = 2 * u32::MAX as u64;
+        assert_eq!(rep.score(), completions * 100 + u64::MAX / 10_000_000);
+
+        rep.disputes_lost = u32::MAX;
+        assert_eq!(rep.score(), completions * 100 + u64::MAX / 10_000_000 - u32::MAX as u64 * 500);
+
+        rep.escrows_completed = 0;
+        rep.tasks_completed = 0;
+        rep.total_volume_lamports = 0;
+        assert_eq!(rep.score(), 0);
+    }
+}
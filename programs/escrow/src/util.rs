@@ -0,0 +1,440 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::system_program;
+use crate::errors::EscrowError;
+use crate::events::{DisputeResolved, ReputationUpdated, ReputationChangeReason};
+use crate::instructions::resolve_dispute::DisputeWinner;
+use crate::state::{
+    AuditAction, AuditLog, AuditEntry, BondReceipt, Config, EscrowAccount, EscrowCounter,
+    EscrowStatus, ReputationAccount, ACCOUNT_VERSION, METADATA_URI_LEN, MAX_AUDIT_ENTRIES,
+};
+
+/// Releases below this amount (0.01 SOL) don't count towards reputation (anti-gaming)
+const MIN_REPUTATION_AMOUNT: u64 = 10_000_000;
+
+/// Splits `amount` into `(fee, net)` for a fee in basis points (rounds the fee down)
+pub fn calc_fee(amount: u64, bps: u16) -> Result<(u64, u64)> {
+    // Zero-fee escrows are common; skip the u128 math entirely
+    if bps == 0 {
+        return Ok((0, amount));
+    }
+
+    let fee = (amount as u128)
+        .checked_mul(bps as u128)
+        .ok_or(EscrowError::Overflow)?
+        .checked_div(10_000)
+        .ok_or(EscrowError::Overflow)? as u64;
+
+    let net = amount.checked_sub(fee).ok_or(EscrowError::Overflow)?;
+
+    Ok((fee, net))
+}
+
+/// A fee-charging escrow must name a real fee recipient; fees sent to the
+/// default pubkey would be lost to the system program. Zero-fee escrows never
+/// pay out, so any recipient is accepted.
+pub fn check_fee_recipient(fee_recipient: &Pubkey, fee_basis_points: u16) -> Result<()> {
+    require!(
+        fee_basis_points == 0 || *fee_recipient != Pubkey::default(),
+        EscrowError::InvalidFeeRecipient
+    );
+    Ok(())
+}
+
+/// Whether `deadline` has passed at `now`. The deadline second itself counts
+/// as expired: from `now == deadline` an escrow can no longer be created or
+/// accepted, and an active escrow becomes refundable.
+pub fn is_expired(deadline: i64, now: i64) -> bool {
+    now >= deadline
+}
+
+/// Rejects a batch of milestone indices that names the same milestone twice
+pub fn check_unique_indices(indices: &[u8]) -> Result<()> {
+    let mut seen = [false; 256];
+    for &index in indices {
+        require!(!seen[index as usize], EscrowError::DuplicateMilestoneIndex);
+        seen[index as usize] = true;
+    }
+    Ok(())
+}
+
+/// Packs a metadata URI into its fixed-size account field. Surrounding
+/// whitespace is trimmed and the rest zero-padded; URIs that don't fit are
+/// rejected rather than truncated into a broken link.
+pub fn pack_metadata_uri(uri: &str) -> Result<[u8; METADATA_URI_LEN]> {
+    let bytes = uri.trim().as_bytes();
+    require!(bytes.len() <= METADATA_URI_LEN, EscrowError::MetadataUriTooLong);
+
+    let mut packed = [0u8; METADATA_URI_LEN];
+    packed[..bytes.len()].copy_from_slice(bytes);
+    Ok(packed)
+}
+
+/// Index of the arbiter picked from a pool of `pool_len` candidates for an
+/// escrow. The draw depends only on the escrow address and id, so anyone can
+/// reproduce it off-chain:
+///
+/// ```text
+/// seed  = sha256("arbiter_selection" || escrow || escrow_id as u64 LE)
+/// index = u64::from_le_bytes(seed[0..8]) % pool_len
+/// ```
+pub fn arbiter_pool_index(escrow: &Pubkey, escrow_id: u64, pool_len: usize) -> usize {
+    let seed = hashv(&[b"arbiter_selection", escrow.as_ref(), &escrow_id.to_le_bytes()]).to_bytes();
+    let mut draw = [0u8; 8];
+    draw.copy_from_slice(&seed[..8]);
+    (u64::from_le_bytes(draw) % pool_len as u64) as usize
+}
+
+/// Append one entry to an escrow's audit log, growing the account by exactly
+/// one entry. Rent for MAX_AUDIT_ENTRIES was deposited at init, so the grown
+/// account stays rent-exempt without a payer.
+pub fn append_audit_entry(
+    log: &mut Account<AuditLog>,
+    action: AuditAction,
+    actor: Pubkey,
+    timestamp: i64,
+) -> Result<()> {
+    let len = log.entries.len();
+    require!(len < MAX_AUDIT_ENTRIES, EscrowError::AuditLogFull);
+
+    log.to_account_info().realloc(AuditLog::space_for(len + 1), false)?;
+    log.entries.push(AuditEntry {
+        timestamp,
+        action: action as u8,
+        actor,
+    });
+    Ok(())
+}
+
+/// Accounts a released SOL escrow pays out to
+pub struct ReleaseTargets<'a, 'info> {
+    /// Receives the protocol fee
+    pub fee_recipient: &'a AccountInfo<'info>,
+    /// Receives the referrer fee (required if referrer_fee_bps > 0)
+    pub referrer: Option<&'a AccountInfo<'info>>,
+    /// Receives the recipient's share and the early-release bonus
+    pub destination: &'a AccountInfo<'info>,
+    /// Cranker paid `Config::crank_reward` out of the protocol fee
+    pub cranker: Option<(&'a AccountInfo<'info>, &'a Config)>,
+}
+
+/// Lamports moved out of an escrow by `pay_out_release`
+pub struct ReleasePayout {
+    pub fee: u64,
+    pub referrer_fee: u64,
+    pub recipient_amount: u64,
+    pub bonus: u64,
+}
+
+/// Pays out a released SOL escrow: protocol fee, referrer fee, the recipient's
+/// share and the part of the early-release bonus earned at `released_at`. The
+/// unearned rest of the bonus stays behind and returns to the creator on close.
+/// Every SOL release path settles through here so none can skip a payee.
+pub fn pay_out_release<'info>(
+    escrow: &Account<'info, EscrowAccount>,
+    fee_basis_points: u16,
+    released_at: i64,
+    targets: ReleaseTargets<'_, 'info>,
+) -> Result<ReleasePayout> {
+    let (fee, referrer_fee, recipient_amount) = escrow.payout_split_at(fee_basis_points)?;
+    let bonus = escrow.early_bonus_at(released_at)?;
+    let escrow_info = escrow.to_account_info();
+
+    let crank_reward = match targets.cranker {
+        Some((cranker, config)) => {
+            let reward = config.crank_reward(fee);
+            move_lamports(&escrow_info, cranker, reward)?;
+            reward
+        }
+        None => 0,
+    };
+    move_lamports(&escrow_info, targets.fee_recipient, fee - crank_reward)?;
+
+    if referrer_fee > 0 {
+        let referrer = targets.referrer.ok_or(EscrowError::InvalidReferrer)?;
+        move_lamports(&escrow_info, referrer, referrer_fee)?;
+    }
+
+    // Early-release bonus is fee-free
+    let payout = recipient_amount.checked_add(bonus).ok_or(EscrowError::Overflow)?;
+    move_lamports(&escrow_info, targets.destination, payout)?;
+
+    Ok(ReleasePayout { fee, referrer_fee, recipient_amount, bonus })
+}
+
+/// Credits a completed release to whichever reputation accounts were passed
+pub fn record_release_reputation(
+    creator_rep: Option<&mut Account<ReputationAccount>>,
+    recipient_rep: Option<&mut Account<ReputationAccount>>,
+    amount: u64,
+    now: i64,
+) {
+    if amount < MIN_REPUTATION_AMOUNT {
+        return;
+    }
+
+    if let Some(creator_rep) = creator_rep {
+        creator_rep.escrows_completed = creator_rep.escrows_completed.saturating_add(1);
+        creator_rep.total_volume_lamports = creator_rep.total_volume_lamports.saturating_add(amount);
+        creator_rep.last_activity = now;
+        emit!(ReputationUpdated::new(creator_rep, ReputationChangeReason::EscrowCompleted));
+    }
+
+    if let Some(recipient_rep) = recipient_rep {
+        recipient_rep.tasks_completed = recipient_rep.tasks_completed.saturating_add(1);
+        recipient_rep.total_volume_lamports = recipient_rep.total_volume_lamports.saturating_add(amount);
+        recipient_rep.last_activity = now;
+        emit!(ReputationUpdated::new(recipient_rep, ReputationChangeReason::TaskCompleted));
+    }
+}
+
+/// Settles an escrow whose auto-release time has passed, for auto_release and
+/// claim_auto_release alike. With a post-release window the funds are held
+/// (Completed, with `released_at` stamped) until finalize_escrow; otherwise the
+/// escrow is paid out, reputation credited and the account closed to `creator`.
+pub fn settle_auto_release<'info>(
+    escrow: &mut Account<'info, EscrowAccount>,
+    now: i64,
+    targets: ReleaseTargets<'_, 'info>,
+    creator: &AccountInfo<'info>,
+    creator_rep: Option<&mut Account<ReputationAccount>>,
+    recipient_rep: Option<&mut Account<ReputationAccount>>,
+    counter: &AccountInfo<'info>,
+) -> Result<()> {
+    require!(escrow.auto_release_at != 0, EscrowError::AutoReleaseNotEnabled);
+    require!(now >= escrow.auto_release_at, EscrowError::AutoReleaseNotReady);
+
+    if escrow.post_release_window_seconds > 0 {
+        escrow.status.transition_to(EscrowStatus::Completed)?;
+        escrow.released_at = now;
+        return Ok(());
+    }
+
+    escrow.status.transition_to(EscrowStatus::Completing)?;
+
+    let fee_basis_points = escrow.fee_basis_points;
+    pay_out_release(escrow, fee_basis_points, now, targets)?;
+    record_release_reputation(creator_rep, recipient_rep, escrow.amount, now);
+
+    escrow.status.transition_to(EscrowStatus::Completed)?;
+    escrow.close(creator.clone())?;
+    record_escrow_closed(counter)
+}
+
+/// Accounts paid when a SOL escrow dispute is settled
+pub struct DisputeTargets<'a, 'info> {
+    /// Receives the arbitration fee, if one was deposited
+    pub arbiter: &'a AccountInfo<'info>,
+    /// Receives the rent on close
+    pub creator: &'a AccountInfo<'info>,
+    pub recipient: &'a AccountInfo<'info>,
+    pub fee_recipient: &'a AccountInfo<'info>,
+    /// Receives the amount if the creator wins (see `refund_target`)
+    pub refund_to: &'a AccountInfo<'info>,
+    /// Holds the arbitration deposit (required if one was made)
+    pub bond_receipt: Option<&'a Account<'info, BondReceipt>>,
+}
+
+/// Settles a disputed escrow for resolve_dispute and resolve_disputes_batch
+/// alike: pays the winner, splits any arbitration deposit, records the outcome
+/// on both parties' reputation, emits DisputeResolved and closes the escrow to
+/// the creator, freeing its slot on `counter`.
+#[allow(clippy::too_many_arguments)]
+pub fn settle_dispute<'info>(
+    escrow: &mut Account<'info, EscrowAccount>,
+    winner: DisputeWinner,
+    resolution_hash: [u8; 32],
+    now: i64,
+    targets: DisputeTargets<'_, 'info>,
+    config: &Config,
+    creator_rep: Option<&mut Account<ReputationAccount>>,
+    recipient_rep: Option<&mut Account<ReputationAccount>>,
+    counter: &AccountInfo<'info>,
+) -> Result<()> {
+    escrow.status.transition_to(EscrowStatus::Resolving)?;
+    let escrow_info = escrow.to_account_info();
+    let amount = escrow.amount;
+
+    match winner {
+        DisputeWinner::Recipient => {
+            // Fee + remainder to recipient
+            let (fee, recipient_amount) = calc_fee(amount, escrow.fee_basis_points)?;
+            // A creator-paid fee was funded on top of the amount
+            let recipient_amount = if escrow.fee_paid_by_creator { amount } else { recipient_amount };
+
+            move_lamports(&escrow_info, targets.fee_recipient, fee)?;
+            move_lamports(&escrow_info, targets.recipient, recipient_amount)?;
+        }
+        // Full refund, no fee
+        DisputeWinner::Creator => move_lamports(&escrow_info, targets.refund_to, amount)?,
+    }
+
+    // Arbiter is paid regardless of who wins, but if the party that put up
+    // the deposit lost, the configured remainder compensates the winner. The
+    // receipt's rent goes back to whoever put up the deposit.
+    let arbitration_fee = escrow.arbitration_fee;
+    if arbitration_fee > 0 {
+        let receipt = targets.bond_receipt.ok_or(EscrowError::InvalidBondReceipt)?;
+        require!(receipt.escrow == escrow.key(), EscrowError::InvalidBondReceipt);
+        let receipt_info = receipt.to_account_info();
+
+        let (loser, winner_info) = match winner {
+            DisputeWinner::Recipient => (escrow.creator, targets.recipient),
+            DisputeWinner::Creator => (escrow.recipient, targets.creator),
+        };
+        let to_arbiter = if escrow.arbitration_fee_payer == loser {
+            config.losing_deposit_to_arbiter(arbitration_fee)?
+        } else {
+            arbitration_fee
+        };
+
+        move_lamports(&receipt_info, targets.arbiter, to_arbiter)?;
+        move_lamports(&receipt_info, winner_info, arbitration_fee - to_arbiter)?;
+
+        let payer_info = if receipt.payer == escrow.creator { targets.creator } else { targets.recipient };
+        receipt.close(payer_info.clone())?;
+    }
+
+    let (winner_rep, loser_rep) = match winner {
+        DisputeWinner::Recipient => (recipient_rep, creator_rep),
+        DisputeWinner::Creator => (creator_rep, recipient_rep),
+    };
+    if let Some(rep) = winner_rep {
+        rep.disputes_won = rep.disputes_won.saturating_add(1);
+        rep.last_activity = now;
+        emit!(ReputationUpdated::new(rep, ReputationChangeReason::DisputeWon));
+    }
+    if let Some(rep) = loser_rep {
+        rep.disputes_lost = rep.disputes_lost.saturating_add(1);
+        rep.last_activity = now;
+        emit!(ReputationUpdated::new(rep, ReputationChangeReason::DisputeLost));
+    }
+
+    emit!(DisputeResolved {
+        escrow: escrow.key(),
+        arbiter: targets.arbiter.key(),
+        winner,
+        resolution_hash,
+        timestamp: now,
+    });
+
+    // Remaining rent goes back to the creator
+    escrow.status.transition_to(EscrowStatus::Resolved)?;
+    escrow.close(targets.creator.clone())?;
+    record_escrow_closed(counter)
+}
+
+/// Frees the active slot of a closed escrow on its creator's counter PDA.
+/// Close paths always pass the (seed-checked) counter address so no caller
+/// can skip this; a creator who never initialized a counter has no slot to free.
+pub fn record_escrow_closed<'info>(counter_info: &AccountInfo<'info>) -> Result<()> {
+    if counter_info.owner != &crate::ID || counter_info.data_is_empty() {
+        return Ok(());
+    }
+
+    let mut counter = Account::<EscrowCounter>::try_from(counter_info)?;
+    counter.record_closed();
+    counter.exit(&crate::ID)
+}
+
+/// Picks the account for a creator-side refund (see `EscrowAccount::refund_recipient`)
+/// out of those passed, which the caller has already matched against the escrow.
+/// Every refund, cancellation and creator-won ruling pays through this.
+pub fn refund_target<'a, 'info>(
+    escrow: &EscrowAccount,
+    creator: &'a AccountInfo<'info>,
+    sponsor: Option<&'a AccountInfo<'info>>,
+    refund_destination: Option<&'a AccountInfo<'info>>,
+) -> Result<&'a AccountInfo<'info>> {
+    if escrow.sponsor != Pubkey::default() {
+        sponsor.ok_or(EscrowError::InvalidSponsor.into())
+    } else if escrow.refund_destination != escrow.creator {
+        refund_destination.ok_or(EscrowError::InvalidRefundDestination.into())
+    } else {
+        Ok(creator)
+    }
+}
+
+/// Reallocs `account` to `space` bytes, zero-filling the new tail, with
+/// `payer` topping it up to rent exemption
+pub fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    space: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let rent_needed = Rent::get()?.minimum_balance(space);
+    let top_up = rent_needed.saturating_sub(account.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+
+    account.realloc(space, true)?;
+    Ok(())
+}
+
+/// Brings an escrow of the account type with `discriminator` up to its
+/// current `space`, for the migrate_* instructions. Escrows from before the
+/// version header (creator straight after the discriminator) are shifted one
+/// byte to make room for it and stamped with ACCOUNT_VERSION; after the
+/// header, fields are only ever appended, so the existing prefix stays valid
+/// and the new tail is zero-initialized. Only the escrow's creator may
+/// migrate it; they pay the extra rent.
+pub fn migrate_escrow_layout<'info>(
+    escrow_info: &AccountInfo<'info>,
+    discriminator: [u8; 8],
+    space: usize,
+    creator: &Signer<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let creator_key = creator.key();
+    let legacy = {
+        let data = escrow_info.try_borrow_data()?;
+        require!(
+            data.len() >= 8 + 1 + 32 && data[..8] == discriminator,
+            anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+        );
+
+        if data[8..40] == creator_key.to_bytes() {
+            true
+        } else {
+            require!(data[9..41] == creator_key.to_bytes(), EscrowError::UnauthorizedCreator);
+            false
+        }
+    };
+
+    let old_len = escrow_info.data_len();
+
+    // Already on the current layout
+    if !legacy && old_len >= space {
+        return Ok(());
+    }
+
+    grow_account(escrow_info, space, creator, system_program)?;
+
+    if legacy {
+        // Shift the old fields past the header slot and stamp it
+        let mut data = escrow_info.try_borrow_mut_data()?;
+        data.copy_within(8..old_len, 9);
+        data[8] = ACCOUNT_VERSION;
+    }
+    Ok(())
+}
+
+/// Moves lamports out of a program-owned account, which can be debited directly
+fn move_lamports(from: &AccountInfo, to: &AccountInfo, lamports: u64) -> Result<()> {
+    if lamports > 0 {
+        **from.try_borrow_mut_lamports()? -= lamports;
+        **to.try_borrow_mut_lamports()? += lamports;
+    }
+    Ok(())
+}
@@ -36,6 +36,14 @@ function deriveEscrowPda(creator: PublicKey, escrowId: anchor.BN): [PublicKey, n
   );
 }
 
+function deriveConfigPda(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("config")], PROGRAM_ID);
+}
+
+function deriveCounterPda(creator: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("counter"), creator.toBuffer()], PROGRAM_ID);
+}
+
 function loadKeypair(filePath: string): Keypair {
   const raw = JSON.parse(fs.readFileSync(filePath, "utf-8"));
   return Keypair.fromSecretKey(Uint8Array.from(raw));
@@ -50,10 +58,13 @@ async function main() {
   const connection = new Connection(DEVNET_URL, "confirmed");
   const creator = loadKeypair(path.resolve(process.env.HOME!, ".config/solana/id.json"));
   const recipient = Keypair.generate();
+  // The program rejects an arbiter who is also the creator or recipient
+  const arbiter = Keypair.generate();
   const treasury = Keypair.generate();
 
   console.log(`Creator:   ${creator.publicKey.toBase58()}`);
   console.log(`Recipient: ${recipient.publicKey.toBase58()}`);
+  console.log(`Arbiter:   ${arbiter.publicKey.toBase58()}`);
   console.log(`Treasury:  ${treasury.publicKey.toBase58()}`);
 
   const creatorBalance = await connection.getBalance(creator.publicKey);
@@ -80,6 +91,10 @@ async function main() {
   const provider = new anchor.AnchorProvider(connection, wallet as any, { commitment: "confirmed" });
   const program = new anchor.Program(idl as any, PROGRAM_ID, provider);
 
+  // Config is optional; pass it if it has been initialized on devnet
+  const [configPda] = deriveConfigPda();
+  const config = (await connection.getAccountInfo(configPda)) ? configPda : null;
+
   // 1. CREATE ESCROW
   console.log("═══ Step 1: CREATE ESCROW ═══");
   const escrowId = new anchor.BN(Date.now());
@@ -98,14 +113,33 @@ async function main() {
   console.log(`  Escrow PDA: ${escrowPda.toBase58()}`);
 
   const createSig = await program.methods
-    .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints)
+    .createEscrow(
+      escrowId,
+      amount,
+      deadline,
+      termsHash,
+      feeBasisPoints,
+      new anchor.BN(0), // auto_release_at: none
+      new anchor.BN(0), // refund_grace_seconds
+      0, // referrer_fee_bps: no referrer
+      new anchor.BN(0), // post_release_window_seconds
+      new anchor.BN(0), // not_before: accept immediately
+      0, // category
+      "", // metadata_uri
+      new anchor.BN(0), // auto_release_after_deadline
+      new anchor.BN(0), // accept_deadline: the deadline
+      false, // fee_paid_by_creator
+      new anchor.BN(0), // early_bonus_lamports
+      new anchor.BN(0), // dispute_deadline: unbounded
+    )
     .accounts({
       escrowAccount: escrowPda,
       creator: creator.publicKey,
       recipient: recipient.publicKey,
-      arbiter: creator.publicKey,
+      arbiter: arbiter.publicKey,
       feeRecipient: treasury.publicKey,
       systemProgram: SystemProgram.programId,
+      config,
     })
     .signers([creator])
     .rpc();
@@ -130,7 +164,7 @@ async function main() {
   const recipientProgram = new anchor.Program(idl as any, PROGRAM_ID, recipientProvider);
 
   const acceptSig = await recipientProgram.methods
-    .acceptTask()
+    .acceptTask(termsHash)
     .accounts({
       escrowAccount: escrowPda,
       recipient: recipient.publicKey,
@@ -156,6 +190,8 @@ async function main() {
       creator: creator.publicKey,
       recipient: recipient.publicKey,
       feeRecipient: treasury.publicKey,
+      config,
+      escrowCounter: deriveCounterPda(creator.publicKey)[0],
     })
     .signers([creator])
     .rpc();
@@ -0,0 +1,598 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { createHash } from "crypto";
+
+import idl from "../target/idl/escrow.json";
+
+const PROGRAM_ID = new PublicKey("EFnubV4grWUCFRPkRTTNVxEdetxYb8VJtAAqQQmxmw8X");
+const BPF_LOADER_UPGRADEABLE_ID = new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111");
+
+function deriveConfigPda(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("config")], PROGRAM_ID);
+}
+
+// Upgradeable loader account naming the program's upgrade authority, which
+// init_config requires to sign
+function deriveProgramDataPda(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([PROGRAM_ID.toBuffer()], BPF_LOADER_UPGRADEABLE_ID);
+}
+
+function deriveCounterPda(creator: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("counter"), creator.toBuffer()], PROGRAM_ID);
+}
+
+function deriveArbiterPda(authority: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("arbiter"), authority.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+function deriveEscrowPda(
+  creator: PublicKey,
+  escrowId: anchor.BN,
+): [PublicKey, number] {
+  const idBuffer = Buffer.alloc(8);
+  idBuffer.writeBigUInt64LE(BigInt(escrowId.toString()));
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("escrow"), creator.toBuffer(), idBuffer],
+    PROGRAM_ID
+  );
+}
+
+function deriveReputationPda(agent: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("reputation"), agent.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+function deriveTombstonePda(agent: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("reputation_tombstone"), agent.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+async function airdrop(
+  connection: anchor.web3.Connection,
+  pubkey: PublicKey,
+  amount: number
+) {
+  const sig = await connection.requestAirdrop(pubkey, amount * LAMPORTS_PER_SOL);
+  await connection.confirmTransaction(sig, "confirmed");
+}
+
+function randomEscrowId(): anchor.BN {
+  const bytes = new Uint8Array(8);
+  for (let i = 0; i < 8; i++) bytes[i] = Math.floor(Math.random() * 256);
+  return new anchor.BN(Buffer.from(bytes), "le");
+}
+
+// Config values with every limit disabled
+function defaultConfigParams() {
+  return {
+    maxAutoReleaseHorizon: new anchor.BN(0),
+    minArbiterStake: new anchor.BN(0),
+    feeDiscountMinTasks: 0,
+    minFeeBps: 0,
+    maxDeadlineHorizon: new anchor.BN(0),
+    crankRewardLamports: new anchor.BN(0),
+    minMilestoneAmount: new anchor.BN(0),
+    trustedMinTasks: 0,
+    forceCompleteDelay: new anchor.BN(0),
+    creatorInactivityWindow: new anchor.BN(0),
+    maxActivePerCreator: 0,
+    minArbiterCases: 0,
+    bondToArbiterBps: 0,
+  };
+}
+
+describe("arbiter", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = new Program(idl as any, PROGRAM_ID, provider);
+  const connection = provider.connection;
+
+  const admin = provider.wallet.publicKey;
+  const [configPda] = deriveConfigPda();
+
+  const creator = Keypair.generate();
+  const recipient = Keypair.generate();
+  const arbiter = Keypair.generate();
+  const unregisteredArbiter = Keypair.generate();
+  const treasury = Keypair.generate();
+
+  const stake = 2 * LAMPORTS_PER_SOL;
+  const [arbiterPda] = deriveArbiterPda(arbiter.publicKey);
+
+  async function setConfig(overrides: Record<string, any>) {
+    await program.methods
+      .updateConfig({ ...defaultConfigParams(), ...overrides })
+      .accounts({ config: configPda, admin })
+      .rpc();
+  }
+
+  before(async () => {
+    await airdrop(connection, creator.publicKey, 10);
+    await airdrop(connection, recipient.publicKey, 1);
+    await airdrop(connection, arbiter.publicKey, 5);
+
+    const existing = await connection.getAccountInfo(configPda);
+    if (!existing) {
+      await program.methods
+        .initConfig(defaultConfigParams())
+        .accounts({
+          config: configPda,
+          admin,
+          program: PROGRAM_ID,
+          programData: deriveProgramDataPda()[0],
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    }
+  });
+
+  after(async () => {
+    await setConfig({});
+  });
+
+  it("registers an arbiter with a staked bond", async () => {
+    await program.methods
+      .registerArbiter(new anchor.BN(stake))
+      .accounts({
+        arbiterAccount: arbiterPda,
+        authority: arbiter.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([arbiter])
+      .rpc();
+
+    const account = await program.account.arbiterAccount.fetch(arbiterPda) as any;
+    expect(account.authority.toBase58()).to.equal(arbiter.publicKey.toBase58());
+    expect(account.stake.toNumber()).to.equal(stake);
+    expect(account.casesResolved).to.equal(0);
+
+    // Stake held on top of rent
+    expect(await connection.getBalance(arbiterPda)).to.be.greaterThan(stake);
+  });
+
+  it("cannot deregister before the cooldown", async () => {
+    try {
+      await program.methods
+        .deregisterArbiter()
+        .accounts({ arbiterAccount: arbiterPda, authority: arbiter.publicKey })
+        .signers([arbiter])
+        .rpc();
+      expect.fail("Should have thrown");
+    } catch (err: any) {
+      expect(err.error?.errorCode?.code || err.message).to.include("ArbiterCooldownActive");
+    }
+  });
+
+  describe("min_arbiter_stake gate", () => {
+    before(async () => {
+      await setConfig({ minArbiterStake: new anchor.BN(1 * LAMPORTS_PER_SOL) });
+    });
+
+    async function createEscrow(arbiterKey: PublicKey, arbiterAccount: PublicKey | null) {
+      const escrowId = randomEscrowId();
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), 0, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiterKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+          arbiterAccount,
+        })
+        .signers([creator])
+        .rpc();
+
+      return escrowPda;
+    }
+
+    it("accepts a registered arbiter with enough stake", async () => {
+      const escrowPda = await createEscrow(arbiter.publicKey, arbiterPda);
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.arbiter.toBase58()).to.equal(arbiter.publicKey.toBase58());
+    });
+
+    it("rejects an unregistered arbiter", async () => {
+      try {
+        await createEscrow(unregisteredArbiter.publicKey, null);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InsufficientArbiterStake");
+      }
+    });
+
+    it("rejects an arbiter staked below the minimum", async () => {
+      await setConfig({ minArbiterStake: new anchor.BN(3 * LAMPORTS_PER_SOL) });
+
+      try {
+        await createEscrow(arbiter.publicKey, arbiterPda);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InsufficientArbiterStake");
+      }
+    });
+  });
+
+  it("increments cases_resolved when the arbiter resolves a dispute", async () => {
+    await setConfig({});
+
+    const escrowId = randomEscrowId();
+    const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+    const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+    await program.methods
+      .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), 0, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        escrowAccount: escrowPda,
+        creator: creator.publicKey,
+        recipient: recipient.publicKey,
+        arbiter: arbiter.publicKey,
+        feeRecipient: treasury.publicKey,
+        systemProgram: SystemProgram.programId,
+        config: configPda,
+      })
+      .signers([creator])
+      .rpc();
+
+    await program.methods
+      .acceptTask(Array(32).fill(0))
+      .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+      .signers([recipient])
+      .rpc();
+
+    await program.methods
+      .dispute(Array(64).fill(0))
+      .accounts({ escrowAccount: escrowPda, disputer: creator.publicKey })
+      .signers([creator])
+      .rpc();
+
+    await program.methods
+      .resolveDispute({ creator: {} }, Array(32).fill(0))
+      .accounts({
+        escrowAccount: escrowPda,
+        arbiter: arbiter.publicKey,
+        creator: creator.publicKey,
+        recipient: recipient.publicKey,
+        feeRecipient: treasury.publicKey,
+        arbiterAccount: arbiterPda,
+        config: configPda,
+        escrowCounter: deriveCounterPda(creator.publicKey)[0],
+      })
+      .signers([arbiter])
+      .rpc();
+
+    const account = await program.account.arbiterAccount.fetch(arbiterPda) as any;
+    expect(account.casesResolved).to.equal(1);
+  });
+
+  describe("min_arbiter_cases gate", () => {
+    const veteran = Keypair.generate();
+    const novice = Keypair.generate();
+    const [veteranRep] = deriveReputationPda(veteran.publicKey);
+    const [noviceRep] = deriveReputationPda(novice.publicKey);
+
+    async function createEscrow(arbiterKey: PublicKey, arbiterReputation: PublicKey | null) {
+      const escrowId = randomEscrowId();
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), 0, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiterKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+          arbiterReputation,
+        })
+        .signers([creator])
+        .rpc();
+
+      return escrowPda;
+    }
+
+    before(async () => {
+      await airdrop(connection, veteran.publicKey, 2);
+      await airdrop(connection, novice.publicKey, 1);
+
+      for (const [agent, rep] of [[veteran, veteranRep], [novice, noviceRep]] as [Keypair, PublicKey][]) {
+        await program.methods
+          .initReputation()
+          .accounts({
+            reputationAccount: rep,
+            agent: agent.publicKey,
+            tombstone: deriveTombstonePda(agent.publicKey)[0],
+            payer: agent.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([agent])
+          .rpc();
+      }
+
+      // The veteran goes through one dispute of their own as a creator
+      const escrowId = randomEscrowId();
+      const [escrowPda] = deriveEscrowPda(veteran.publicKey, escrowId);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), 0, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: veteran.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([veteran])
+        .rpc();
+
+      await program.methods
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      await program.methods
+        .dispute(Array(64).fill(0))
+        .accounts({ escrowAccount: escrowPda, disputer: veteran.publicKey })
+        .signers([veteran])
+        .rpc();
+
+      await program.methods
+        .resolveDispute({ creator: {} }, Array(32).fill(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          arbiter: arbiter.publicKey,
+          creator: veteran.publicKey,
+          recipient: recipient.publicKey,
+          feeRecipient: treasury.publicKey,
+          creatorReputation: veteranRep,
+          config: configPda,
+          escrowCounter: deriveCounterPda(veteran.publicKey)[0],
+        })
+        .signers([arbiter])
+        .rpc();
+
+      await setConfig({ minArbiterCases: 1 });
+    });
+
+    after(async () => {
+      await setConfig({});
+    });
+
+    it("accepts an arbiter with enough dispute history", async () => {
+      const rep = await program.account.reputationAccount.fetch(veteranRep) as any;
+      expect(rep.disputesWon + rep.disputesLost).to.equal(1);
+
+      const escrowPda = await createEscrow(veteran.publicKey, veteranRep);
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.arbiter.toBase58()).to.equal(veteran.publicKey.toBase58());
+    });
+
+    it("rejects an arbiter without any disputes", async () => {
+      try {
+        await createEscrow(novice.publicKey, noviceRep);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InsufficientReputation");
+      }
+    });
+
+    it("rejects an arbiter whose reputation account is not passed", async () => {
+      try {
+        await createEscrow(veteran.publicKey, null);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InsufficientReputation");
+      }
+    });
+
+    // Leaving the arbiter unset at creation must not skip the gate
+    async function disputeUnassigned() {
+      const escrowPda = await createEscrow(PublicKey.default, null);
+
+      await program.methods
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      await program.methods
+        .dispute(Array(64).fill(0))
+        .accounts({ escrowAccount: escrowPda, disputer: creator.publicKey })
+        .signers([creator])
+        .rpc();
+
+      return escrowPda;
+    }
+
+    async function assignArbiter(escrowPda: PublicKey, arbiterKey: PublicKey, arbiterReputation: PublicKey | null) {
+      await program.methods
+        .assignArbiter(arbiterKey)
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          config: configPda,
+          arbiterReputation,
+        })
+        .signers([creator, recipient])
+        .rpc();
+    }
+
+    it("assign_arbiter accepts an arbiter with enough dispute history", async () => {
+      const escrowPda = await disputeUnassigned();
+      await assignArbiter(escrowPda, veteran.publicKey, veteranRep);
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.arbiter.toBase58()).to.equal(veteran.publicKey.toBase58());
+    });
+
+    it("assign_arbiter rejects an arbiter without any disputes", async () => {
+      const escrowPda = await disputeUnassigned();
+      try {
+        await assignArbiter(escrowPda, novice.publicKey, noviceRep);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InsufficientReputation");
+      }
+    });
+
+    it("assign_arbiter rejects an arbiter whose reputation account is not passed", async () => {
+      const escrowPda = await disputeUnassigned();
+      try {
+        await assignArbiter(escrowPda, veteran.publicKey, null);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InsufficientReputation");
+      }
+    });
+  });
+
+  describe("assign_random_arbiter", () => {
+    const pool = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+    // Canonical order: ascending by authority bytes
+    const sortedPool = [...pool].sort((a, b) => Buffer.compare(a.publicKey.toBuffer(), b.publicKey.toBuffer()));
+    const poolAccounts = (keys: Keypair[]) =>
+      keys.map((k) => ({ pubkey: deriveArbiterPda(k.publicKey)[0], isWritable: false, isSigner: false }));
+
+    // Mirrors arbiter_pool_index in the program
+    function expectedArbiter(escrowPda: PublicKey, escrowId: anchor.BN): PublicKey {
+      const seed = createHash("sha256")
+        .update(Buffer.concat([
+          Buffer.from("arbiter_selection"),
+          escrowPda.toBuffer(),
+          escrowId.toArrayLike(Buffer, "le", 8),
+        ]))
+        .digest();
+      const index = Number(seed.readBigUInt64LE(0) % BigInt(sortedPool.length));
+      return sortedPool[index].publicKey;
+    }
+
+    async function createDisputedEscrow() {
+      const escrowId = randomEscrowId();
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), 0, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: PublicKey.default,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      await program.methods
+        .dispute(Array(64).fill(0))
+        .accounts({ escrowAccount: escrowPda, disputer: creator.publicKey })
+        .signers([creator])
+        .rpc();
+
+      return { escrowPda, escrowId };
+    }
+
+    before(async () => {
+      await setConfig({});
+      for (const member of pool) {
+        await airdrop(connection, member.publicKey, 1);
+        await program.methods
+          .registerArbiter(new anchor.BN(0.1 * LAMPORTS_PER_SOL))
+          .accounts({
+            arbiterAccount: deriveArbiterPda(member.publicKey)[0],
+            authority: member.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([member])
+          .rpc();
+      }
+    });
+
+    it("selects the arbiter reproducibly from the escrow seed", async () => {
+      for (let i = 0; i < 2; i++) {
+        const { escrowPda, escrowId } = await createDisputedEscrow();
+
+        await program.methods
+          .assignRandomArbiter()
+          .accounts({ escrowAccount: escrowPda, creator: creator.publicKey, recipient: recipient.publicKey, config: configPda })
+          .remainingAccounts(poolAccounts(sortedPool))
+          .signers([creator, recipient])
+          .rpc();
+
+        const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+        expect(escrow.arbiter.toBase58()).to.equal(expectedArbiter(escrowPda, escrowId).toBase58());
+      }
+    });
+
+    it("rejects a pool that is not in canonical order", async () => {
+      const { escrowPda } = await createDisputedEscrow();
+
+      try {
+        await program.methods
+          .assignRandomArbiter()
+          .accounts({ escrowAccount: escrowPda, creator: creator.publicKey, recipient: recipient.publicKey, config: configPda })
+          .remainingAccounts(poolAccounts([...sortedPool].reverse()))
+          .signers([creator, recipient])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InvalidArbiterPool");
+      }
+    });
+
+    it("applies the case-count minimum to the drawn arbiter", async () => {
+      const { escrowPda } = await createDisputedEscrow();
+      await setConfig({ minArbiterCases: 1 });
+
+      try {
+        await program.methods
+          .assignRandomArbiter()
+          .accounts({ escrowAccount: escrowPda, creator: creator.publicKey, recipient: recipient.publicKey, config: configPda })
+          .remainingAccounts(poolAccounts(sortedPool))
+          .signers([creator, recipient])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InsufficientReputation");
+      } finally {
+        await setConfig({});
+      }
+    });
+  });
+});
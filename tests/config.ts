@@ -0,0 +1,2042 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  createMint,
+  createAccount,
+  mintTo,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import { expect } from "chai";
+
+import idl from "../target/idl/escrow.json";
+
+const PROGRAM_ID = new PublicKey("EFnubV4grWUCFRPkRTTNVxEdetxYb8VJtAAqQQmxmw8X");
+const BPF_LOADER_UPGRADEABLE_ID = new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111");
+
+function deriveConfigPda(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("config")], PROGRAM_ID);
+}
+
+// Upgradeable loader account naming the program's upgrade authority, which
+// init_config requires to sign
+function deriveProgramDataPda(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([PROGRAM_ID.toBuffer()], BPF_LOADER_UPGRADEABLE_ID);
+}
+
+function deriveCounterPda(creator: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("counter"), creator.toBuffer()], PROGRAM_ID);
+}
+
+function deriveReputationPda(agent: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("reputation"), agent.toBuffer()], PROGRAM_ID);
+}
+
+function deriveTombstonePda(agent: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("reputation_tombstone"), agent.toBuffer()], PROGRAM_ID);
+}
+
+function deriveMintAllowlistPda(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("mint_allowlist")], PROGRAM_ID);
+}
+
+function deriveGlobalStatsPda(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("global_stats")], PROGRAM_ID);
+}
+
+function deriveMintFeeConfigPda(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("mint_fee_config")], PROGRAM_ID);
+}
+
+function deriveBondReceiptPda(escrowPda: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("bond"), escrowPda.toBuffer()], PROGRAM_ID);
+}
+
+function deriveTokenEscrowPda(creator: PublicKey, escrowId: anchor.BN): [PublicKey, number] {
+  const idBuffer = Buffer.alloc(8);
+  idBuffer.writeBigUInt64LE(BigInt(escrowId.toString()));
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("token_escrow"), creator.toBuffer(), idBuffer],
+    PROGRAM_ID
+  );
+}
+
+function deriveMilestoneEscrowPda(creator: PublicKey, escrowId: anchor.BN): [PublicKey, number] {
+  const idBuffer = Buffer.alloc(8);
+  idBuffer.writeBigUInt64LE(BigInt(escrowId.toString()));
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("milestone_escrow"), creator.toBuffer(), idBuffer],
+    PROGRAM_ID
+  );
+}
+
+function deriveEscrowPda(
+  creator: PublicKey,
+  escrowId: anchor.BN,
+): [PublicKey, number] {
+  const idBuffer = Buffer.alloc(8);
+  idBuffer.writeBigUInt64LE(BigInt(escrowId.toString()));
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("escrow"), creator.toBuffer(), idBuffer],
+    PROGRAM_ID
+  );
+}
+
+async function airdrop(
+  connection: anchor.web3.Connection,
+  pubkey: PublicKey,
+  amount: number
+) {
+  const sig = await connection.requestAirdrop(pubkey, amount * LAMPORTS_PER_SOL);
+  await connection.confirmTransaction(sig, "confirmed");
+}
+
+function randomEscrowId(): anchor.BN {
+  const bytes = new Uint8Array(8);
+  for (let i = 0; i < 8; i++) bytes[i] = Math.floor(Math.random() * 256);
+  return new anchor.BN(Buffer.from(bytes), "le");
+}
+
+async function getEvents(
+  connection: anchor.web3.Connection,
+  signature: string
+): Promise<anchor.Event[]> {
+  const tx = await connection.getTransaction(signature, {
+    commitment: "confirmed",
+    maxSupportedTransactionVersion: 0,
+  });
+  const parser = new anchor.EventParser(PROGRAM_ID, new anchor.BorshCoder(idl as any));
+  return Array.from(parser.parseLogs(tx?.meta?.logMessages ?? []));
+}
+
+// Config values with every limit disabled
+function defaultConfigParams() {
+  return {
+    maxAutoReleaseHorizon: new anchor.BN(0),
+    minArbiterStake: new anchor.BN(0),
+    feeDiscountMinTasks: 0,
+    minFeeBps: 0,
+    maxDeadlineHorizon: new anchor.BN(0),
+    crankRewardLamports: new anchor.BN(0),
+    minMilestoneAmount: new anchor.BN(0),
+    trustedMinTasks: 0,
+    forceCompleteDelay: new anchor.BN(0),
+    creatorInactivityWindow: new anchor.BN(0),
+    maxActivePerCreator: 0,
+    minArbiterCases: 0,
+    bondToArbiterBps: 0,
+  };
+}
+
+describe("config", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = new Program(idl as any, PROGRAM_ID, provider);
+  const connection = provider.connection;
+
+  // The provider wallet is the config admin (shared across test files)
+  const admin = provider.wallet.publicKey;
+  const [configPda] = deriveConfigPda();
+
+  const creator = Keypair.generate();
+  const recipient = Keypair.generate();
+  const arbiter = Keypair.generate();
+  const treasury = Keypair.generate();
+
+  async function setConfig(overrides: Record<string, any>) {
+    await program.methods
+      .updateConfig({ ...defaultConfigParams(), ...overrides })
+      .accounts({ config: configPda, admin })
+      .rpc();
+  }
+
+  before(async () => {
+    await airdrop(connection, creator.publicKey, 10);
+
+    const existing = await connection.getAccountInfo(configPda);
+    if (!existing) {
+      await program.methods
+        .initConfig(defaultConfigParams())
+        .accounts({
+          config: configPda,
+          admin,
+          program: PROGRAM_ID,
+          programData: deriveProgramDataPda()[0],
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    }
+  });
+
+  after(async () => {
+    await setConfig({});
+  });
+
+  it("initializes config with the provider wallet as admin", async () => {
+    const config = await program.account.config.fetch(configPda) as any;
+    expect(config.admin.toBase58()).to.equal(admin.toBase58());
+  });
+
+  it("config admin is the program's upgrade authority", async () => {
+    // ProgramData layout: u32 tag, u64 slot, Option<Pubkey> upgrade authority
+    const programData = await connection.getAccountInfo(deriveProgramDataPda()[0]);
+    expect(programData?.data[12]).to.equal(1);
+    expect(new PublicKey(programData!.data.subarray(13, 45)).toBase58()).to.equal(admin.toBase58());
+  });
+
+  it("non-admin cannot update config", async () => {
+    const imposter = Keypair.generate();
+    await airdrop(connection, imposter.publicKey, 1);
+
+    try {
+      await program.methods
+        .updateConfig(defaultConfigParams())
+        .accounts({ config: configPda, admin: imposter.publicKey })
+        .signers([imposter])
+        .rpc();
+      expect.fail("Should have thrown");
+    } catch (err: any) {
+      expect(err.error?.errorCode?.code || err.message).to.include("UnauthorizedAdmin");
+    }
+  });
+
+  describe("max_auto_release_horizon", () => {
+    const horizon = 3600;
+
+    before(async () => {
+      await setConfig({ maxAutoReleaseHorizon: new anchor.BN(horizon) });
+    });
+
+    async function createWithAutoRelease(autoReleaseAt: anchor.BN) {
+      const escrowId = randomEscrowId();
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 60);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), 0, autoReleaseAt, new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      return escrowPda;
+    }
+
+    it("accepts auto_release_at just inside the horizon", async () => {
+      const autoReleaseAt = new anchor.BN(Math.floor(Date.now() / 1000) + horizon - 60);
+      const escrowPda = await createWithAutoRelease(autoReleaseAt);
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.autoReleaseAt.toNumber()).to.equal(autoReleaseAt.toNumber());
+    });
+
+    it("rejects auto_release_at just beyond the horizon", async () => {
+      const autoReleaseAt = new anchor.BN(Math.floor(Date.now() / 1000) + horizon + 60);
+
+      try {
+        await createWithAutoRelease(autoReleaseAt);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("AutoReleaseTooFar");
+      }
+    });
+
+    it("is unbounded when no config is passed", async () => {
+      const escrowId = randomEscrowId();
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 60);
+      const autoReleaseAt = new anchor.BN(Math.floor(Date.now() / 1000) + 10 * 365 * 86400);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), 0, autoReleaseAt, new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: null,
+        })
+        .signers([creator])
+        .rpc();
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.autoReleaseAt.toNumber()).to.equal(autoReleaseAt.toNumber());
+    });
+
+    it("cannot be skipped by passing some other account as config", async () => {
+      const escrowId = randomEscrowId();
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 60);
+      const autoReleaseAt = new anchor.BN(Math.floor(Date.now() / 1000) + 10 * 365 * 86400);
+
+      try {
+        await program.methods
+          .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), 0, autoReleaseAt, new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+          .accounts({
+            escrowAccount: escrowPda,
+            creator: creator.publicKey,
+            recipient: recipient.publicKey,
+            arbiter: arbiter.publicKey,
+            feeRecipient: treasury.publicKey,
+            systemProgram: SystemProgram.programId,
+            config: Keypair.generate().publicKey,
+          })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("AccountNotInitialized");
+      }
+    });
+  });
+
+  describe("max_deadline_horizon", () => {
+    const horizon = 3600;
+
+    before(async () => {
+      await setConfig({ maxDeadlineHorizon: new anchor.BN(horizon) });
+    });
+
+    after(async () => {
+      await setConfig({});
+    });
+
+    async function createWithDeadline(deadline: anchor.BN, config: PublicKey | null = configPda) {
+      const escrowId = randomEscrowId();
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), 0, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config,
+        })
+        .signers([creator])
+        .rpc();
+
+      return escrowPda;
+    }
+
+    it("accepts a deadline just inside the horizon", async () => {
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + horizon - 60);
+      const escrowPda = await createWithDeadline(deadline);
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.deadline.toNumber()).to.equal(deadline.toNumber());
+    });
+
+    it("rejects a deadline just beyond the horizon", async () => {
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + horizon + 60);
+
+      try {
+        await createWithDeadline(deadline);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("DeadlineTooFar");
+      }
+    });
+
+    it("is unbounded when no config is passed", async () => {
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 100 * 365 * 86400);
+      const escrowPda = await createWithDeadline(deadline, null);
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.deadline.toNumber()).to.equal(deadline.toNumber());
+    });
+
+    it("cannot be skipped by passing some other account as config", async () => {
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 100 * 365 * 86400);
+
+      try {
+        await createWithDeadline(deadline, Keypair.generate().publicKey);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("AccountNotInitialized");
+      }
+    });
+  });
+
+  describe("min_fee_bps", () => {
+    const floor = 100;
+
+    before(async () => {
+      await setConfig({ minFeeBps: floor });
+    });
+
+    after(async () => {
+      await setConfig({});
+    });
+
+    async function createWithFee(feeBps: number) {
+      const escrowId = randomEscrowId();
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), feeBps, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      return escrowPda;
+    }
+
+    it("rejects an escrow below the fee floor", async () => {
+      try {
+        await createWithFee(floor - 1);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("FeeTooLow");
+      }
+    });
+
+    it("accepts an escrow at or above the fee floor", async () => {
+      const escrowPda = await createWithFee(floor);
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.feeBasisPoints).to.equal(floor);
+    });
+  });
+
+  describe("pause", () => {
+    async function setPaused(paused: boolean) {
+      await program.methods
+        .setPaused(paused)
+        .accounts({ config: configPda, admin })
+        .rpc();
+    }
+
+    async function createEscrow(): Promise<PublicKey> {
+      const escrowId = randomEscrowId();
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), 100, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      return escrowPda;
+    }
+
+    after(async () => {
+      await setPaused(false);
+    });
+
+    it("non-admin cannot pause", async () => {
+      const imposter = Keypair.generate();
+      await airdrop(connection, imposter.publicKey, 1);
+
+      try {
+        await program.methods
+          .setPaused(true)
+          .accounts({ config: configPda, admin: imposter.publicKey })
+          .signers([imposter])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("UnauthorizedAdmin");
+      }
+    });
+
+    it("blocks creates but still allows refunds while paused", async () => {
+      const openEscrow = await createEscrow();
+      await setPaused(true);
+
+      try {
+        await createEscrow();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("ProgramPaused");
+      }
+
+      await program.methods
+        .requestRefund()
+        .accounts({ escrowAccount: openEscrow, creator: creator.publicKey, escrowCounter: deriveCounterPda(creator.publicKey)[0] })
+        .signers([creator])
+        .rpc();
+      expect(await connection.getAccountInfo(openEscrow)).to.be.null;
+    });
+
+    it("blocks releases and splits while paused", async () => {
+      const escrowPda = await createEscrow();
+      await program.methods
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+      const splitSource = await createEscrow();
+      await setPaused(true);
+
+      try {
+        await program.methods
+          .releasePayment()
+          .accounts({
+            escrowAccount: escrowPda,
+            creator: creator.publicKey,
+            recipient: recipient.publicKey,
+            feeRecipient: treasury.publicKey,
+            config: configPda,
+            escrowCounter: deriveCounterPda(creator.publicKey)[0],
+          })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("ProgramPaused");
+      }
+
+      const newEscrowId = randomEscrowId();
+      try {
+        await program.methods
+          .splitEscrow(newEscrowId, new anchor.BN(0.01 * LAMPORTS_PER_SOL))
+          .accounts({
+            escrowAccount: splitSource,
+            newEscrowAccount: deriveEscrowPda(creator.publicKey, newEscrowId)[0],
+            creator: creator.publicKey,
+            systemProgram: SystemProgram.programId,
+            config: configPda,
+          })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("ProgramPaused");
+      }
+    });
+
+    it("creates again once unpaused", async () => {
+      await setPaused(false);
+      const escrowPda = await createEscrow();
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.status).to.have.property("created");
+    });
+  });
+
+  describe("min_milestone_amount", () => {
+    const minimum = 10_000;
+
+    before(async () => {
+      await setConfig({ minMilestoneAmount: new anchor.BN(minimum) });
+    });
+
+    after(async () => {
+      await setConfig({});
+    });
+
+    async function createWithMilestones(amounts: number[]) {
+      const escrowId = randomEscrowId();
+      const [escrowPda] = deriveMilestoneEscrowPda(creator.publicKey, escrowId);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const milestones = amounts.map((amount) => ({
+        amount: new anchor.BN(amount),
+        descriptionHash: Array(32).fill(0),
+      }));
+
+      await program.methods
+        .createMilestoneEscrow(escrowId, deadline, Array(32).fill(0), 100, milestones)
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      return escrowPda;
+    }
+
+    it("rejects a milestone below the minimum", async () => {
+      try {
+        await createWithMilestones([0.1 * LAMPORTS_PER_SOL, 1]);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("AmountBelowMinimum");
+      }
+    });
+
+    it("accepts milestones at the minimum", async () => {
+      const escrowPda = await createWithMilestones([0.1 * LAMPORTS_PER_SOL, minimum]);
+
+      const escrow = await program.account.milestoneEscrowAccount.fetch(escrowPda) as any;
+      expect(escrow.milestoneCount).to.equal(2);
+    });
+  });
+
+  describe("redirect_fees", () => {
+    async function createActiveEscrow(): Promise<PublicKey> {
+      const escrowId = randomEscrowId();
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), 150, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await airdrop(connection, recipient.publicKey, 1);
+      await program.methods
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      return escrowPda;
+    }
+
+    it("admin redirects fees and release pays the new fee wallet", async () => {
+      const escrowPda = await createActiveEscrow();
+      const newTreasury = Keypair.generate();
+
+      await program.methods
+        .redirectFees(newTreasury.publicKey)
+        .accounts({ config: configPda, admin, escrowAccount: escrowPda })
+        .rpc();
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.feeRecipient.toBase58()).to.equal(newTreasury.publicKey.toBase58());
+
+      await program.methods
+        .releasePayment()
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          feeRecipient: newTreasury.publicKey,
+          config: configPda,
+          escrowCounter: deriveCounterPda(creator.publicKey)[0],
+        })
+        .signers([creator])
+        .rpc();
+
+      const expectedFee = Math.floor(1 * LAMPORTS_PER_SOL * 150 / 10000);
+      expect(await connection.getBalance(newTreasury.publicKey)).to.equal(expectedFee);
+    });
+
+    it("non-admin cannot redirect fees", async () => {
+      const escrowPda = await createActiveEscrow();
+
+      try {
+        await program.methods
+          .redirectFees(creator.publicKey)
+          .accounts({ config: configPda, admin: creator.publicKey, escrowAccount: escrowPda })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("UnauthorizedAdmin");
+      }
+    });
+
+    it("rejects redirecting a fee-charging escrow to the default pubkey", async () => {
+      const escrowPda = await createActiveEscrow();
+
+      try {
+        await program.methods
+          .redirectFees(PublicKey.default)
+          .accounts({ config: configPda, admin, escrowAccount: escrowPda })
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InvalidFeeRecipient");
+      }
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.feeRecipient.toBase58()).to.equal(treasury.publicKey.toBase58());
+    });
+  });
+
+  describe("fee vault", () => {
+    const [feeVaultPda] = PublicKey.findProgramAddressSync([Buffer.from("fee_vault")], PROGRAM_ID);
+    const escrowAmount = 0.5 * LAMPORTS_PER_SOL;
+    const feeBps = 100;
+
+    async function releaseIntoVault() {
+      const escrowId = randomEscrowId();
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(escrowAmount), deadline, Array(32).fill(0), feeBps, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: feeVaultPda,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      await program.methods
+        .releasePayment()
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          feeRecipient: feeVaultPda,
+          config: configPda,
+          escrowCounter: deriveCounterPda(creator.publicKey)[0],
+        })
+        .signers([creator])
+        .rpc();
+    }
+
+    before(async () => {
+      await airdrop(connection, recipient.publicKey, 1);
+
+      const existing = await connection.getAccountInfo(feeVaultPda);
+      if (!existing) {
+        await program.methods
+          .initFeeVault()
+          .accounts({ feeVault: feeVaultPda, config: configPda, admin, systemProgram: SystemProgram.programId })
+          .rpc();
+      }
+    });
+
+    it("accumulates fees from two releases and withdraws the total", async () => {
+      const vaultBefore = await connection.getBalance(feeVaultPda);
+
+      await releaseIntoVault();
+      await releaseIntoVault();
+
+      const vaultAfter = await connection.getBalance(feeVaultPda);
+      const fee = Math.floor(escrowAmount * feeBps / 10000);
+      expect(vaultAfter - vaultBefore).to.equal(2 * fee);
+
+      const destination = Keypair.generate();
+      await program.methods
+        .withdrawFees()
+        .accounts({ feeVault: feeVaultPda, config: configPda, admin, destination: destination.publicKey })
+        .rpc();
+
+      const rentFloor = await connection.getMinimumBalanceForRentExemption(9);
+      expect(await connection.getBalance(destination.publicKey)).to.equal(vaultAfter - rentFloor);
+      expect(await connection.getBalance(feeVaultPda)).to.equal(rentFloor);
+    });
+
+    it("non-admin cannot withdraw fees", async () => {
+      try {
+        await program.methods
+          .withdrawFees()
+          .accounts({ feeVault: feeVaultPda, config: configPda, admin: creator.publicKey, destination: creator.publicKey })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("UnauthorizedAdmin");
+      }
+    });
+  });
+
+  describe("sweep_dust", () => {
+    before(async () => {
+      // Keep the treasury rent-exempt so small sweeps can land
+      await airdrop(connection, treasury.publicKey, 1);
+    });
+
+    // Program-owned account with no data, standing in for closed-escrow residue
+    async function seedOrphan(lamports: number): Promise<PublicKey> {
+      const orphan = Keypair.generate();
+      const tx = new Transaction().add(
+        SystemProgram.createAccount({
+          fromPubkey: creator.publicKey,
+          newAccountPubkey: orphan.publicKey,
+          lamports,
+          space: 0,
+          programId: PROGRAM_ID,
+        })
+      );
+      await sendAndConfirmTransaction(connection, tx, [creator, orphan]);
+      return orphan.publicKey;
+    }
+
+    it("admin sweeps orphaned lamports to the treasury", async () => {
+      const dust = 5000;
+      const orphan = await seedOrphan(dust);
+      const treasuryBefore = await connection.getBalance(treasury.publicKey);
+
+      await program.methods
+        .sweepDust()
+        .accounts({ config: configPda, admin, orphan, treasury: treasury.publicKey })
+        .rpc();
+
+      expect(await connection.getBalance(orphan)).to.equal(0);
+      expect((await connection.getBalance(treasury.publicKey)) - treasuryBefore).to.equal(dust);
+    });
+
+    it("non-admin cannot sweep", async () => {
+      const orphan = await seedOrphan(5000);
+
+      try {
+        await program.methods
+          .sweepDust()
+          .accounts({ config: configPda, admin: creator.publicKey, orphan, treasury: creator.publicKey })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("UnauthorizedAdmin");
+      }
+    });
+
+    it("refuses to sweep a live account", async () => {
+      try {
+        await program.methods
+          .sweepDust()
+          .accounts({ config: configPda, admin, orphan: configPda, treasury: treasury.publicKey })
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("AccountNotOrphaned");
+      }
+    });
+  });
+
+  describe("reputation fee discount", () => {
+    const escrowAmount = 1 * LAMPORTS_PER_SOL;
+    const fullFeeBps = 200;
+    const lowRep = Keypair.generate();
+    const highRep = Keypair.generate();
+
+    async function initReputation(agent: Keypair) {
+      await program.methods
+        .initReputation()
+        .accounts({
+          reputationAccount: deriveReputationPda(agent.publicKey)[0],
+          agent: agent.publicKey,
+          tombstone: deriveTombstonePda(agent.publicKey)[0],
+          payer: agent.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent])
+        .rpc();
+    }
+
+    // Creates, accepts and releases an escrow to `worker`, returning the fee charged
+    async function releaseTo(worker: Keypair, withConfig = true): Promise<number> {
+      const escrowId = randomEscrowId();
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+      const [workerRep] = deriveReputationPda(worker.publicKey);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(escrowAmount), deadline, Array(32).fill(0), fullFeeBps, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: worker.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: escrowPda, recipient: worker.publicKey })
+        .signers([worker])
+        .rpc();
+
+      const treasuryBefore = await connection.getBalance(treasury.publicKey);
+
+      await program.methods
+        .releasePayment()
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: worker.publicKey,
+          feeRecipient: treasury.publicKey,
+          recipientReputation: workerRep,
+          config: withConfig ? configPda : null,
+          escrowCounter: deriveCounterPda(creator.publicKey)[0],
+        })
+        .signers([creator])
+        .rpc();
+
+      return (await connection.getBalance(treasury.publicKey)) - treasuryBefore;
+    }
+
+    before(async () => {
+      await airdrop(connection, treasury.publicKey, 1);
+      await airdrop(connection, lowRep.publicKey, 1);
+      await airdrop(connection, highRep.publicKey, 1);
+      await initReputation(lowRep);
+      await initReputation(highRep);
+
+      // Build one completed task for the high-rep worker before enabling discounts
+      await releaseTo(highRep);
+
+      await setConfig({ feeDiscountMinTasks: 1 });
+    });
+
+    it("charges the full fee to a low-rep recipient", async () => {
+      const fee = await releaseTo(lowRep);
+      expect(fee).to.equal(Math.floor(escrowAmount * fullFeeBps / 10000));
+    });
+
+    it("halves the fee for a high-rep recipient on the same amount", async () => {
+      const fee = await releaseTo(highRep);
+      expect(fee).to.equal(Math.floor(escrowAmount * (fullFeeBps / 2) / 10000));
+    });
+
+    it("charges the full fee when the release leaves out the config", async () => {
+      const fee = await releaseTo(highRep, false);
+      expect(fee).to.equal(Math.floor(escrowAmount * fullFeeBps / 10000));
+    });
+  });
+
+  describe("crank reward", () => {
+    const escrowAmount = 1 * LAMPORTS_PER_SOL;
+    const feeBps = 100;
+    const fee = Math.floor(escrowAmount * feeBps / 10000);
+
+    // Creates and accepts an escrow that becomes auto-releasable a few seconds out
+    async function createAutoReleasable(): Promise<PublicKey> {
+      const escrowId = randomEscrowId();
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+      const now = Math.floor(Date.now() / 1000);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(escrowAmount), new anchor.BN(now + 2), Array(32).fill(0), feeBps, new anchor.BN(now + 3), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      await new Promise((resolve) => setTimeout(resolve, 5000));
+      return escrowPda;
+    }
+
+    // Cranks auto-release from a fresh caller, returning [caller gain, treasury gain]
+    async function crank(escrowPda: PublicKey): Promise<[number, number]> {
+      const caller = Keypair.generate();
+      await airdrop(connection, caller.publicKey, 1);
+
+      const callerBefore = await connection.getBalance(caller.publicKey);
+      const treasuryBefore = await connection.getBalance(treasury.publicKey);
+
+      // Provider wallet pays the tx fee so the caller's balance reflects only the reward
+      await program.methods
+        .autoRelease()
+        .accounts({
+          escrowAccount: escrowPda,
+          caller: caller.publicKey,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          feeRecipient: treasury.publicKey,
+          config: configPda,
+          escrowCounter: deriveCounterPda(creator.publicKey)[0],
+        })
+        .signers([caller])
+        .rpc();
+
+      return [
+        (await connection.getBalance(caller.publicKey)) - callerBefore,
+        (await connection.getBalance(treasury.publicKey)) - treasuryBefore,
+      ];
+    }
+
+    before(async () => {
+      await airdrop(connection, recipient.publicKey, 1);
+      await airdrop(connection, treasury.publicKey, 1);
+    });
+
+    afterEach(async () => {
+      await setConfig({});
+    });
+
+    it("pays the caller the reward and the fee recipient the remainder", async () => {
+      const reward = 5_000;
+      await setConfig({ crankRewardLamports: new anchor.BN(reward) });
+
+      const [callerGain, treasuryGain] = await crank(await createAutoReleasable());
+      expect(callerGain).to.equal(reward);
+      expect(treasuryGain).to.equal(fee - reward);
+    });
+
+    it("caps the reward at the fee", async () => {
+      await setConfig({ crankRewardLamports: new anchor.BN(fee * 2) });
+
+      const [callerGain, treasuryGain] = await crank(await createAutoReleasable());
+      expect(callerGain).to.equal(fee);
+      expect(treasuryGain).to.equal(0);
+    });
+  });
+
+  describe("mint allowlist", () => {
+    const [allowlistPda] = deriveMintAllowlistPda();
+    let allowedMint: PublicKey;
+    let otherMint: PublicKey;
+    let allowedAta: PublicKey;
+    let otherAta: PublicKey;
+
+    before(async () => {
+      allowedMint = await createMint(connection, creator, creator.publicKey, null, 6);
+      otherMint = await createMint(connection, creator, creator.publicKey, null, 6);
+      allowedAta = await createAccount(connection, creator, allowedMint, creator.publicKey);
+      otherAta = await createAccount(connection, creator, otherMint, creator.publicKey);
+      await mintTo(connection, creator, allowedMint, allowedAta, creator, 10_000_000);
+      await mintTo(connection, creator, otherMint, otherAta, creator, 10_000_000);
+
+      const existing = await connection.getAccountInfo(allowlistPda);
+      if (!existing) {
+        await program.methods
+          .initMintAllowlist()
+          .accounts({ mintAllowlist: allowlistPda, config: configPda, admin, systemProgram: SystemProgram.programId })
+          .rpc();
+      }
+
+      await program.methods
+        .addMint(allowedMint)
+        .accounts({ mintAllowlist: allowlistPda, config: configPda, admin })
+        .rpc();
+    });
+
+    after(async () => {
+      await program.methods
+        .removeMint(allowedMint)
+        .accounts({ mintAllowlist: allowlistPda, config: configPda, admin })
+        .rpc();
+    });
+
+    async function createTokenEscrow(mint: PublicKey, creatorAta: PublicKey) {
+      const escrowId = randomEscrowId();
+      const [escrowPda] = deriveTokenEscrowPda(creator.publicKey, escrowId);
+      const [vaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("token_vault"), escrowPda.toBuffer()],
+        PROGRAM_ID
+      );
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+      await program.methods
+        .createTokenEscrow(escrowId, new anchor.BN(1_000_000), deadline, Array(32).fill(0), 150, new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          vault: vaultPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          mint,
+          creatorTokenAccount: creatorAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          mintAllowlist: allowlistPda,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      return escrowPda;
+    }
+
+    it("accepts a token escrow backed by an allowed mint", async () => {
+      const escrowPda = await createTokenEscrow(allowedMint, allowedAta);
+
+      const escrow = await program.account.tokenEscrowAccount.fetch(escrowPda) as any;
+      expect(escrow.mint.toBase58()).to.equal(allowedMint.toBase58());
+    });
+
+    it("rejects a token escrow backed by a mint not on the allowlist", async () => {
+      try {
+        await createTokenEscrow(otherMint, otherAta);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("MintNotAllowed");
+      }
+    });
+
+    it("non-admin cannot add a mint", async () => {
+      try {
+        await program.methods
+          .addMint(otherMint)
+          .accounts({ mintAllowlist: allowlistPda, config: configPda, admin: creator.publicKey })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("UnauthorizedAdmin");
+      }
+    });
+  });
+
+  describe("per-mint fee overrides", () => {
+    const [mintFeeConfigPda] = deriveMintFeeConfigPda();
+    // Sentinel fee_basis_points asking for the mint's configured fee
+    const useMintFee = 0xffff;
+    const stableFeeBps = 25;
+    let stableMint: PublicKey;
+    let unlistedMint: PublicKey;
+    let stableAta: PublicKey;
+    let unlistedAta: PublicKey;
+
+    before(async () => {
+      stableMint = await createMint(connection, creator, creator.publicKey, null, 6);
+      unlistedMint = await createMint(connection, creator, creator.publicKey, null, 6);
+      stableAta = await createAccount(connection, creator, stableMint, creator.publicKey);
+      unlistedAta = await createAccount(connection, creator, unlistedMint, creator.publicKey);
+      await mintTo(connection, creator, stableMint, stableAta, creator, 10_000_000);
+      await mintTo(connection, creator, unlistedMint, unlistedAta, creator, 10_000_000);
+
+      const existing = await connection.getAccountInfo(mintFeeConfigPda);
+      if (!existing) {
+        await program.methods
+          .initMintFeeConfig()
+          .accounts({ mintFeeConfig: mintFeeConfigPda, config: configPda, admin, systemProgram: SystemProgram.programId })
+          .rpc();
+      }
+
+      await program.methods
+        .setMintFee(stableMint, stableFeeBps)
+        .accounts({ mintFeeConfig: mintFeeConfigPda, config: configPda, admin })
+        .rpc();
+    });
+
+    async function createTokenEscrow(mint: PublicKey, creatorAta: PublicKey, feeBps: number) {
+      const escrowId = randomEscrowId();
+      const [escrowPda] = deriveTokenEscrowPda(creator.publicKey, escrowId);
+      const [vaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("token_vault"), escrowPda.toBuffer()],
+        PROGRAM_ID
+      );
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+      await program.methods
+        .createTokenEscrow(escrowId, new anchor.BN(1_000_000), deadline, Array(32).fill(0), feeBps, new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          vault: vaultPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          mint,
+          creatorTokenAccount: creatorAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          mintFeeConfig: mintFeeConfigPda,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      return escrowPda;
+    }
+
+    it("applies the mint's configured fee when the sentinel is passed", async () => {
+      const escrowPda = await createTokenEscrow(stableMint, stableAta, useMintFee);
+
+      const escrow = await program.account.tokenEscrowAccount.fetch(escrowPda) as any;
+      expect(escrow.feeBasisPoints).to.equal(stableFeeBps);
+    });
+
+    it("keeps an explicit fee as passed", async () => {
+      const escrowPda = await createTokenEscrow(stableMint, stableAta, 150);
+
+      const escrow = await program.account.tokenEscrowAccount.fetch(escrowPda) as any;
+      expect(escrow.feeBasisPoints).to.equal(150);
+    });
+
+    it("rejects the sentinel for a mint with no configured fee", async () => {
+      try {
+        await createTokenEscrow(unlistedMint, unlistedAta, useMintFee);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("MintFeeNotConfigured");
+      }
+    });
+
+    it("non-admin cannot set a mint fee", async () => {
+      try {
+        await program.methods
+          .setMintFee(unlistedMint, 10)
+          .accounts({ mintFeeConfig: mintFeeConfigPda, config: configPda, admin: creator.publicKey })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("UnauthorizedAdmin");
+      }
+    });
+  });
+
+  describe("trusted fast release", () => {
+    const escrowAmount = 1 * LAMPORTS_PER_SOL;
+    const feeBps = 100;
+    const client = Keypair.generate();
+    const trustedWorker = Keypair.generate();
+    const newWorker = Keypair.generate();
+
+    async function initReputation(agent: Keypair) {
+      await program.methods
+        .initReputation()
+        .accounts({
+          reputationAccount: deriveReputationPda(agent.publicKey)[0],
+          agent: agent.publicKey,
+          tombstone: deriveTombstonePda(agent.publicKey)[0],
+          payer: agent.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent])
+        .rpc();
+    }
+
+    async function createFor(worker: Keypair): Promise<PublicKey> {
+      const escrowId = randomEscrowId();
+      const [escrowPda] = deriveEscrowPda(client.publicKey, escrowId);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(escrowAmount), deadline, Array(32).fill(0), feeBps, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: client.publicKey,
+          recipient: worker.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([client])
+        .rpc();
+
+      return escrowPda;
+    }
+
+    async function fastRelease(escrowPda: PublicKey, worker: Keypair) {
+      await program.methods
+        .trustedFastRelease()
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: client.publicKey,
+          recipient: worker.publicKey,
+          feeRecipient: treasury.publicKey,
+          creatorReputation: deriveReputationPda(client.publicKey)[0],
+          recipientReputation: deriveReputationPda(worker.publicKey)[0],
+          config: configPda,
+          referrer: null,
+          cosigner: null,
+          escrowCounter: deriveCounterPda(client.publicKey)[0],
+        })
+        .signers([client, worker])
+        .rpc();
+    }
+
+    before(async () => {
+      await airdrop(connection, client.publicKey, 5);
+      await airdrop(connection, trustedWorker.publicKey, 1);
+      await airdrop(connection, newWorker.publicKey, 1);
+      await airdrop(connection, treasury.publicKey, 1);
+      await initReputation(client);
+      await initReputation(trustedWorker);
+      await initReputation(newWorker);
+
+      // One regular, fully reputation-tracked escrow between client and trustedWorker
+      const escrowPda = await createFor(trustedWorker);
+      await program.methods
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: escrowPda, recipient: trustedWorker.publicKey })
+        .signers([trustedWorker])
+        .rpc();
+      await program.methods
+        .releasePayment()
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: client.publicKey,
+          recipient: trustedWorker.publicKey,
+          feeRecipient: treasury.publicKey,
+          creatorReputation: deriveReputationPda(client.publicKey)[0],
+          recipientReputation: deriveReputationPda(trustedWorker.publicKey)[0],
+          config: configPda,
+          escrowCounter: deriveCounterPda(client.publicKey)[0],
+        })
+        .signers([client])
+        .rpc();
+
+      await setConfig({ trustedMinTasks: 1 });
+    });
+
+    it("releases a Created escrow immediately for a qualifying pair", async () => {
+      const escrowPda = await createFor(trustedWorker);
+      const workerBefore = await connection.getBalance(trustedWorker.publicKey);
+
+      await fastRelease(escrowPda, trustedWorker);
+
+      const fee = Math.floor(escrowAmount * feeBps / 10000);
+      // Worker signs but the provider wallet pays the transaction fee
+      expect((await connection.getBalance(trustedWorker.publicKey)) - workerBefore).to.equal(escrowAmount - fee);
+      expect(await connection.getAccountInfo(escrowPda)).to.be.null;
+
+      const rep = await program.account.reputationAccount.fetch(deriveReputationPda(trustedWorker.publicKey)[0]) as any;
+      expect(rep.tasksCompleted).to.equal(2);
+    });
+
+    it("rejects a pair where the recipient has no completed tasks", async () => {
+      const escrowPda = await createFor(newWorker);
+
+      try {
+        await fastRelease(escrowPda, newWorker);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("NotTrustedPair");
+      }
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.status).to.have.property("created");
+    });
+  });
+
+  describe("force_complete", () => {
+    const escrowAmount = 1 * LAMPORTS_PER_SOL;
+    const delay = 2;
+
+    async function createAndAccept(deadlineIn: number): Promise<PublicKey> {
+      const escrowId = randomEscrowId();
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + deadlineIn);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(escrowAmount), deadline, Array(32).fill(0), 100, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      return escrowPda;
+    }
+
+    function forceComplete(escrowPda: PublicKey, winner: object, signer?: Keypair, withBond = false) {
+      return program.methods
+        .forceComplete(winner)
+        .accounts({
+          escrowAccount: escrowPda,
+          config: configPda,
+          admin: signer ? signer.publicKey : admin,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          escrowCounter: deriveCounterPda(creator.publicKey)[0],
+          bondReceipt: withBond ? deriveBondReceiptPda(escrowPda)[0] : null,
+        })
+        .signers(signer ? [signer] : [])
+        .rpc({ commitment: "confirmed" });
+    }
+
+    before(async () => {
+      await airdrop(connection, recipient.publicKey, 1);
+      await setConfig({ forceCompleteDelay: new anchor.BN(delay) });
+    });
+
+    it("admin settles a stuck escrow to the recipient once the timeout has passed", async () => {
+      const escrowPda = await createAndAccept(2);
+      await new Promise((resolve) => setTimeout(resolve, (2 + delay + 2) * 1000));
+
+      const recipientBefore = await connection.getBalance(recipient.publicKey);
+      const sig = await forceComplete(escrowPda, { recipient: {} });
+
+      // Full amount, no protocol fee
+      expect((await connection.getBalance(recipient.publicKey)) - recipientBefore).to.equal(escrowAmount);
+      expect(await connection.getAccountInfo(escrowPda)).to.be.null;
+
+      const events = await getEvents(connection, sig);
+      const event = events.find((e) => e.name === "ForceCompleted");
+      expect(event).to.exist;
+      expect(event!.data.admin.toBase58()).to.equal(admin.toBase58());
+      expect(event!.data.amount.toNumber()).to.equal(escrowAmount);
+    });
+
+    // Creates, accepts and disputes an escrow; the recipient puts up the arbitration deposit
+    async function disputeWithRecipientDeposit(deposit: number): Promise<PublicKey> {
+      const escrowPda = await createAndAccept(2);
+
+      await program.methods
+        .dispute(Array(64).fill(0))
+        .accounts({ escrowAccount: escrowPda, disputer: creator.publicKey })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .requestArbitration(new anchor.BN(deposit))
+        .accounts({
+          escrowAccount: escrowPda,
+          bondReceipt: deriveBondReceiptPda(escrowPda)[0],
+          payer: recipient.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([recipient])
+        .rpc();
+
+      return escrowPda;
+    }
+
+    function claimBond(escrowPda: PublicKey, payer: Keypair) {
+      return program.methods
+        .claimBond()
+        .accounts({ bondReceipt: deriveBondReceiptPda(escrowPda)[0], payer: payer.publicKey })
+        .signers([payer])
+        .rpc();
+    }
+
+    it("lets the recipient claim their arbitration deposit after a timeout with no ruling", async () => {
+      const arbitrationFee = 0.05 * LAMPORTS_PER_SOL;
+      const escrowPda = await disputeWithRecipientDeposit(arbitrationFee);
+      const [bondReceiptPda] = deriveBondReceiptPda(escrowPda);
+      const receiptRent = (await connection.getBalance(bondReceiptPda)) - arbitrationFee;
+
+      await new Promise((resolve) => setTimeout(resolve, (2 + delay + 2) * 1000));
+
+      // Creator wins the amount, but the recipient's deposit is not forfeited
+      await forceComplete(escrowPda, { creator: {} }, undefined, true);
+      expect(await connection.getAccountInfo(escrowPda)).to.be.null;
+
+      const receipt = await program.account.bondReceipt.fetch(bondReceiptPda) as any;
+      expect(receipt.outcome).to.have.property("refundable");
+      expect(receipt.payer.toBase58()).to.equal(recipient.publicKey.toBase58());
+      expect(receipt.amount.toNumber()).to.equal(arbitrationFee);
+
+      // Only the party that put up the deposit can take it back
+      try {
+        await claimBond(escrowPda, creator);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InvalidBondReceipt");
+      }
+
+      const recipientBefore = await connection.getBalance(recipient.publicKey);
+      await claimBond(escrowPda, recipient);
+
+      expect((await connection.getBalance(recipient.publicKey)) - recipientBefore).to.equal(arbitrationFee + receiptRent);
+      expect(await connection.getAccountInfo(bondReceiptPda)).to.be.null;
+    });
+
+    it("rejects claim_bond while the dispute is still open", async () => {
+      const escrowPda = await disputeWithRecipientDeposit(0.05 * LAMPORTS_PER_SOL);
+
+      try {
+        await claimBond(escrowPda, recipient);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("BondNotRefundable");
+      }
+    });
+
+    it("rejects force_complete on a deposited dispute without the bond receipt", async () => {
+      const escrowPda = await disputeWithRecipientDeposit(0.05 * LAMPORTS_PER_SOL);
+      await new Promise((resolve) => setTimeout(resolve, (2 + delay + 2) * 1000));
+
+      try {
+        await forceComplete(escrowPda, { creator: {} });
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InvalidBondReceipt");
+      }
+    });
+
+    it("rejects force_complete before the timeout", async () => {
+      const escrowPda = await createAndAccept(3600);
+
+      try {
+        await forceComplete(escrowPda, { creator: {} });
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("ForceCompleteLocked");
+      }
+    });
+
+    it("non-admin cannot force_complete", async () => {
+      const escrowPda = await createAndAccept(2);
+      await new Promise((resolve) => setTimeout(resolve, (2 + delay + 2) * 1000));
+
+      try {
+        await forceComplete(escrowPda, { recipient: {} }, recipient);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("UnauthorizedAdmin");
+      }
+    });
+  });
+
+  describe("creator_inactivity_release", () => {
+    const escrowAmount = 1 * LAMPORTS_PER_SOL;
+    const window = 2;
+
+    async function createAndAccept(deadlineIn: number, requestRelease: boolean): Promise<PublicKey> {
+      const escrowId = randomEscrowId();
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + deadlineIn);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(escrowAmount), deadline, Array(32).fill(0), 100, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      if (requestRelease) {
+        await program.methods
+          .requestRelease()
+          .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+          .signers([recipient])
+          .rpc();
+      }
+
+      return escrowPda;
+    }
+
+    function inactivityRelease(escrowPda: PublicKey) {
+      return program.methods
+        .creatorInactivityRelease()
+        .accounts({
+          escrowAccount: escrowPda,
+          recipient: recipient.publicKey,
+          creator: creator.publicKey,
+          feeRecipient: treasury.publicKey,
+          referrer: null,
+          config: configPda,
+          escrowCounter: deriveCounterPda(creator.publicKey)[0],
+        })
+        .signers([recipient])
+        .rpc();
+    }
+
+    before(async () => {
+      await airdrop(connection, recipient.publicKey, 1);
+      await setConfig({ creatorInactivityWindow: new anchor.BN(window) });
+    });
+
+    it("rejects the release while the inactivity window is still open", async () => {
+      const escrowPda = await createAndAccept(2, true);
+      await new Promise((resolve) => setTimeout(resolve, 3000));
+
+      // Past the deadline but not past deadline + window
+      try {
+        await inactivityRelease(escrowPda);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InactivityWindowOpen");
+      }
+    });
+
+    it("recipient releases to themselves once the window has elapsed", async () => {
+      const escrowPda = await createAndAccept(2, true);
+      await new Promise((resolve) => setTimeout(resolve, (2 + window + 2) * 1000));
+
+      const treasuryBefore = await connection.getBalance(treasury.publicKey);
+      await inactivityRelease(escrowPda);
+
+      const fee = Math.floor(escrowAmount * 100 / 10000);
+      expect((await connection.getBalance(treasury.publicKey)) - treasuryBefore).to.equal(fee);
+      expect(await connection.getAccountInfo(escrowPda)).to.be.null;
+    });
+
+    it("requires the recipient to have requested release", async () => {
+      const escrowPda = await createAndAccept(2, false);
+      await new Promise((resolve) => setTimeout(resolve, (2 + window + 2) * 1000));
+
+      try {
+        await inactivityRelease(escrowPda);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("ReleaseNotRequested");
+      }
+    });
+
+    it("stays disabled when the window is zero", async () => {
+      await setConfig({});
+      const escrowPda = await createAndAccept(2, true);
+      await new Promise((resolve) => setTimeout(resolve, 3000));
+
+      try {
+        await inactivityRelease(escrowPda);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InactivityWindowOpen");
+      }
+    });
+  });
+
+  describe("max active escrows per creator", () => {
+    const spammer = Keypair.generate();
+    const [counterPda] = deriveCounterPda(spammer.publicKey);
+    const cap = 2;
+
+    async function createCounted(withCounter = true): Promise<PublicKey> {
+      const counter = await program.account.escrowCounter.fetch(counterPda) as any;
+      const escrowId = withCounter ? counter.nextId as anchor.BN : randomEscrowId();
+      const [escrowPda] = deriveEscrowPda(spammer.publicKey, escrowId);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), 100, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: spammer.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+          escrowCounter: withCounter ? counterPda : null,
+        })
+        .signers([spammer])
+        .rpc();
+
+      return escrowPda;
+    }
+
+    before(async () => {
+      await airdrop(connection, spammer.publicKey, 5);
+
+      await program.methods
+        .initEscrowCounter()
+        .accounts({
+          escrowCounter: counterPda,
+          creator: spammer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([spammer])
+        .rpc();
+
+      await setConfig({ maxActivePerCreator: cap });
+    });
+
+    after(async () => {
+      await setConfig({});
+    });
+
+    it("allows creating up to the cap and rejects the next one", async () => {
+      const escrows: PublicKey[] = [];
+      for (let i = 0; i < cap; i++) {
+        escrows.push(await createCounted());
+      }
+
+      const counter = await program.account.escrowCounter.fetch(counterPda) as any;
+      expect(counter.activeEscrows).to.equal(cap);
+
+      try {
+        await createCounted();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("TooManyActiveEscrows");
+      }
+
+      // Closing one frees a slot
+      await program.methods
+        .requestRefund()
+        .accounts({
+          escrowAccount: escrows[0],
+          creator: spammer.publicKey,
+          escrowCounter: counterPda,
+        })
+        .signers([spammer])
+        .rpc();
+
+      expect((await program.account.escrowCounter.fetch(counterPda) as any).activeEscrows).to.equal(cap - 1);
+      await createCounted();
+      expect((await program.account.escrowCounter.fetch(counterPda) as any).activeEscrows).to.equal(cap);
+    });
+
+    it("requires the counter while a cap is configured", async () => {
+      try {
+        await createCounted(false);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("TooManyActiveEscrows");
+      }
+    });
+
+    it("counts a split escrow against the cap", async () => {
+      // The first test left the creator at the cap; split its latest escrow
+      const counter = await program.account.escrowCounter.fetch(counterPda) as any;
+      const [escrowPda] = deriveEscrowPda(spammer.publicKey, counter.nextId.subn(1));
+
+      try {
+        await program.methods
+          .splitEscrow(counter.nextId, new anchor.BN(0.01 * LAMPORTS_PER_SOL))
+          .accounts({
+            escrowAccount: escrowPda,
+            newEscrowAccount: deriveEscrowPda(spammer.publicKey, counter.nextId)[0],
+            creator: spammer.publicKey,
+            systemProgram: SystemProgram.programId,
+            config: configPda,
+            escrowCounter: counterPda,
+          })
+          .signers([spammer])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("TooManyActiveEscrows");
+      }
+    });
+
+    it("frees the slot when a batch resolution closes the escrow", async () => {
+      const counter = await program.account.escrowCounter.fetch(counterPda) as any;
+      const [escrowPda] = deriveEscrowPda(spammer.publicKey, counter.nextId.subn(1));
+      const activeBefore = counter.activeEscrows;
+
+      await program.methods
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+      await program.methods
+        .dispute(Array(64).fill(0))
+        .accounts({ escrowAccount: escrowPda, disputer: spammer.publicKey })
+        .signers([spammer])
+        .rpc();
+
+      await program.methods
+        .resolveDisputesBatch([{ winner: { creator: {} }, resolutionHash: Array(32).fill(0) }])
+        .accounts({ arbiter: arbiter.publicKey, config: configPda })
+        .remainingAccounts([escrowPda, spammer.publicKey, recipient.publicKey, treasury.publicKey, counterPda, spammer.publicKey, deriveReputationPda(spammer.publicKey)[0], deriveReputationPda(recipient.publicKey)[0], deriveBondReceiptPda(escrowPda)[0]].map((pubkey) => ({
+          pubkey,
+          isSigner: false,
+          isWritable: true,
+        })))
+        .signers([arbiter])
+        .rpc();
+
+      expect((await program.account.escrowCounter.fetch(counterPda) as any).activeEscrows).to.equal(activeBefore - 1);
+    });
+  });
+
+  describe("losing arbitration deposit split", () => {
+    const escrowAmount = 1 * LAMPORTS_PER_SOL;
+    const deposit = 0.1 * LAMPORTS_PER_SOL;
+    const bondToArbiterBps = 4000;
+
+    // Creates, accepts and disputes an escrow; the creator puts up the arbitration deposit
+    async function disputeWithCreatorDeposit(): Promise<PublicKey> {
+      const escrowId = randomEscrowId();
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(escrowAmount), deadline, Array(32).fill(0), 100, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      await program.methods
+        .dispute(Array(64).fill(0))
+        .accounts({ escrowAccount: escrowPda, disputer: creator.publicKey })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .requestArbitration(new anchor.BN(deposit))
+        .accounts({
+          escrowAccount: escrowPda,
+          bondReceipt: deriveBondReceiptPda(escrowPda)[0],
+          payer: creator.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      return escrowPda;
+    }
+
+    function resolve(escrowPda: PublicKey, winner: object) {
+      return program.methods
+        .resolveDispute(winner, Array(32).fill(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          arbiter: arbiter.publicKey,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          feeRecipient: treasury.publicKey,
+          config: configPda,
+          escrowCounter: deriveCounterPda(creator.publicKey)[0],
+          bondReceipt: deriveBondReceiptPda(escrowPda)[0],
+        })
+        .signers([arbiter])
+        .rpc();
+    }
+
+    before(async () => {
+      await airdrop(connection, arbiter.publicKey, 1);
+      await airdrop(connection, recipient.publicKey, 1);
+      await setConfig({ bondToArbiterBps });
+    });
+
+    after(async () => {
+      await setConfig({});
+    });
+
+    it("splits a losing depositor's deposit between the arbiter and the winner", async () => {
+      const escrowPda = await disputeWithCreatorDeposit();
+
+      const arbiterBefore = await connection.getBalance(arbiter.publicKey);
+      const recipientBefore = await connection.getBalance(recipient.publicKey);
+      const treasuryBefore = await connection.getBalance(treasury.publicKey);
+
+      // Creator paid the deposit and loses
+      await resolve(escrowPda, { recipient: {} });
+
+      const fee = Math.floor(escrowAmount * 100 / 10000);
+      const toArbiter = Math.floor(deposit * bondToArbiterBps / 10000);
+      expect((await connection.getBalance(arbiter.publicKey)) - arbiterBefore).to.equal(toArbiter);
+      expect((await connection.getBalance(recipient.publicKey)) - recipientBefore).to.equal(escrowAmount - fee + deposit - toArbiter);
+      expect((await connection.getBalance(treasury.publicKey)) - treasuryBefore).to.equal(fee);
+    });
+
+    it("pays the arbiter the whole deposit when the depositor wins", async () => {
+      const escrowPda = await disputeWithCreatorDeposit();
+      const arbiterBefore = await connection.getBalance(arbiter.publicKey);
+
+      await resolve(escrowPda, { creator: {} });
+
+      expect((await connection.getBalance(arbiter.publicKey)) - arbiterBefore).to.equal(deposit);
+    });
+
+    it("splits a losing depositor's deposit in a batch resolution", async () => {
+      const escrowPda = await disputeWithCreatorDeposit();
+      const arbiterBefore = await connection.getBalance(arbiter.publicKey);
+
+      await program.methods
+        .resolveDisputesBatch([{ winner: { recipient: {} }, resolutionHash: Array(32).fill(0) }])
+        .accounts({ arbiter: arbiter.publicKey, config: configPda })
+        .remainingAccounts([escrowPda, creator.publicKey, recipient.publicKey, treasury.publicKey, deriveCounterPda(creator.publicKey)[0], creator.publicKey, deriveReputationPda(creator.publicKey)[0], deriveReputationPda(recipient.publicKey)[0], deriveBondReceiptPda(escrowPda)[0]].map((pubkey) => ({
+          pubkey,
+          isSigner: false,
+          isWritable: true,
+        })))
+        .signers([arbiter])
+        .rpc();
+
+      const toArbiter = Math.floor(deposit * bondToArbiterBps / 10000);
+      expect((await connection.getBalance(arbiter.publicKey)) - arbiterBefore).to.equal(toArbiter);
+    });
+  });
+
+  describe("global stats", () => {
+    const [globalStatsPda] = deriveGlobalStatsPda();
+    const feeBps = 100;
+    const amounts = [0.2, 0.3, 0.5].map((sol) => sol * LAMPORTS_PER_SOL);
+
+    async function createTracked(amount: number): Promise<PublicKey> {
+      const escrowId = randomEscrowId();
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(amount), deadline, Array(32).fill(0), feeBps, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          globalStats: globalStatsPda,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      return escrowPda;
+    }
+
+    before(async () => {
+      await airdrop(connection, recipient.publicKey, 1);
+      await airdrop(connection, treasury.publicKey, 1);
+
+      const existing = await connection.getAccountInfo(globalStatsPda);
+      if (!existing) {
+        await program.methods
+          .initGlobalStats()
+          .accounts({ globalStats: globalStatsPda, config: configPda, admin, systemProgram: SystemProgram.programId })
+          .rpc();
+      }
+    });
+
+    it("aggregates creates, releases and disputes across escrows", async () => {
+      const before = await program.account.globalStats.fetch(globalStatsPda) as any;
+
+      const escrows: PublicKey[] = [];
+      for (const amount of amounts) {
+        escrows.push(await createTracked(amount));
+      }
+
+      // Release the first two, dispute the third
+      for (const escrowPda of escrows.slice(0, 2)) {
+        await program.methods
+          .releasePayment()
+          .accounts({
+            escrowAccount: escrowPda,
+            creator: creator.publicKey,
+            recipient: recipient.publicKey,
+            feeRecipient: treasury.publicKey,
+            globalStats: globalStatsPda,
+            config: configPda,
+            escrowCounter: deriveCounterPda(creator.publicKey)[0],
+          })
+          .signers([creator])
+          .rpc();
+      }
+
+      await program.methods
+        .dispute(Array(64).fill(0))
+        .accounts({ escrowAccount: escrows[2], disputer: creator.publicKey, globalStats: globalStatsPda })
+        .signers([creator])
+        .rpc();
+
+      const after = await program.account.globalStats.fetch(globalStatsPda) as any;
+      const released = amounts[0] + amounts[1];
+      const fees = amounts.slice(0, 2).reduce((sum, a) => sum + Math.floor(a * feeBps / 10000), 0);
+
+      expect(after.totalEscrowsCreated.sub(before.totalEscrowsCreated).toNumber()).to.equal(3);
+      expect(after.totalVolumeLamports.sub(before.totalVolumeLamports).toNumber()).to.equal(released);
+      expect(after.totalFeesCollected.sub(before.totalFeesCollected).toNumber()).to.equal(fees);
+      expect(after.totalDisputes.sub(before.totalDisputes).toNumber()).to.equal(1);
+    });
+
+    it("leaves the aggregates alone when the account is not passed", async () => {
+      const before = await program.account.globalStats.fetch(globalStatsPda) as any;
+
+      const escrowId = randomEscrowId();
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(amounts[0]), new anchor.BN(Math.floor(Date.now() / 1000) + 3600), Array(32).fill(0), feeBps, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      const after = await program.account.globalStats.fetch(globalStatsPda) as any;
+      expect(after.totalEscrowsCreated.toNumber()).to.equal(before.totalEscrowsCreated.toNumber());
+    });
+  });
+});
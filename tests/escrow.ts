@@ -11,9 +11,20 @@ import { createHash } from "crypto";
 
 // Load the IDL from the built program
 import idl from "../target/idl/escrow.json";
+import legacyCreatorSecret from "./fixtures/legacy-creator.json";
+import legacyRecipientSecret from "./fixtures/legacy-recipient.json";
 
 // Program ID from declare_id!
 const PROGRAM_ID = new PublicKey("EFnubV4grWUCFRPkRTTNVxEdetxYb8VJtAAqQQmxmw8X");
+const BPF_LOADER_UPGRADEABLE_ID = new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111");
+
+// Mirrors ACCOUNT_VERSION in state.rs
+const CURRENT_ACCOUNT_VERSION = 1;
+
+// Loaded by the test validator from tests/fixtures (see Anchor.toml): an escrow
+// written before the version header, and one stamped with an unknown version
+const LEGACY_ESCROW = new PublicKey("BvY3WbLmHUyeDG6q5WhVGfZY86MwpR8Gw3De6gcD2TX3");
+const FORGED_VERSION_ESCROW = new PublicKey("Btn9wyKui9BAfRyfvEuVbPU4JQ6KcShTrJzGpnsRXkA5");
 
 function deriveEscrowPda(
   creator: PublicKey,
@@ -28,6 +39,20 @@ function deriveEscrowPda(
   );
 }
 
+function deriveAuditLogPda(escrowPda: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("audit_log"), escrowPda.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+function deriveBondReceiptPda(escrowPda: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("bond"), escrowPda.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
 async function airdrop(
   connection: anchor.web3.Connection,
   pubkey: PublicKey,
@@ -45,12 +70,81 @@ function randomEscrowId(): anchor.BN {
   return new anchor.BN(Buffer.from(bytes), "le");
 }
 
+async function getEvents(
+  connection: anchor.web3.Connection,
+  signature: string
+): Promise<anchor.Event[]> {
+  const tx = await connection.getTransaction(signature, {
+    commitment: "confirmed",
+    maxSupportedTransactionVersion: 0,
+  });
+  const parser = new anchor.EventParser(PROGRAM_ID, new anchor.BorshCoder(idl as any));
+  return Array.from(parser.parseLogs(tx?.meta?.logMessages ?? []));
+}
+
+// Current validator clock, which can drift from Date.now() on localnet
+async function chainTime(connection: anchor.web3.Connection): Promise<number> {
+  const blockTime = await connection.getBlockTime(await connection.getSlot("confirmed"));
+  return blockTime ?? Math.floor(Date.now() / 1000);
+}
+
+function deriveConfigPda(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("config")], PROGRAM_ID);
+}
+
+// Upgradeable loader account naming the program's upgrade authority, which
+// init_config requires to sign
+function deriveProgramDataPda(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([PROGRAM_ID.toBuffer()], BPF_LOADER_UPGRADEABLE_ID);
+}
+
+function deriveCounterPda(creator: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("counter"), creator.toBuffer()], PROGRAM_ID);
+}
+
+function deriveReputationPda(agent: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("reputation"), agent.toBuffer()], PROGRAM_ID);
+}
+
+// These tests pass the config to create and release instructions. Initialize it
+// with every limit disabled unless another test file already has; the
+// provider wallet is the admin.
+async function ensureConfig(program: Program, configPda: PublicKey) {
+  if (await program.provider.connection.getAccountInfo(configPda)) return;
+
+  await program.methods
+    .initConfig({
+      maxAutoReleaseHorizon: new anchor.BN(0),
+      minArbiterStake: new anchor.BN(0),
+      feeDiscountMinTasks: 0,
+      minFeeBps: 0,
+      maxDeadlineHorizon: new anchor.BN(0),
+      crankRewardLamports: new anchor.BN(0),
+      minMilestoneAmount: new anchor.BN(0),
+      trustedMinTasks: 0,
+      forceCompleteDelay: new anchor.BN(0),
+      creatorInactivityWindow: new anchor.BN(0),
+      maxActivePerCreator: 0,
+      minArbiterCases: 0,
+      bondToArbiterBps: 0,
+    })
+    .accounts({
+      config: configPda,
+      admin: program.provider.publicKey,
+      program: PROGRAM_ID,
+      programData: deriveProgramDataPda()[0],
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+}
+
 describe("escrow", () => {
   const provider = anchor.AnchorProvider.env();
   anchor.setProvider(provider);
 
   const program = new Program(idl, PROGRAM_ID, provider);
   const connection = provider.connection;
+  const [configPda] = deriveConfigPda();
 
   // Actors
   const creator = Keypair.generate();
@@ -62,6 +156,8 @@ describe("escrow", () => {
   const feeBasisPoints = 150;
 
   before(async () => {
+    await ensureConfig(program, configPda);
+
     // Fund all actors
     await airdrop(connection, creator.publicKey, 10);
     await airdrop(connection, recipient.publicKey, 2);
@@ -79,7 +175,7 @@ describe("escrow", () => {
       const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
 
       await program.methods
-        .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0))
+        .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
         .accounts({
           escrowAccount: escrowPda,
           creator: creator.publicKey,
@@ -87,6 +183,7 @@ describe("escrow", () => {
           arbiter: arbiter.publicKey,
           feeRecipient: treasury.publicKey,
           systemProgram: SystemProgram.programId,
+          config: configPda,
         })
         .signers([creator])
         .rpc();
@@ -99,6 +196,7 @@ describe("escrow", () => {
       expect(escrow.feeRecipient.toBase58()).to.equal(treasury.publicKey.toBase58());
       expect(escrow.arbiter.toBase58()).to.equal(arbiter.publicKey.toBase58());
       expect(escrow.status).to.have.property("created");
+      expect(escrow.version).to.equal(CURRENT_ACCOUNT_VERSION);
 
       // Verify SOL was transferred to PDA
       const pdaBalance = await connection.getBalance(escrowPda);
@@ -106,6 +204,74 @@ describe("escrow", () => {
       expect(pdaBalance).to.be.greaterThan(0.5 * LAMPORTS_PER_SOL);
     });
 
+    it("stores the category tag and emits it in EscrowCreated", async () => {
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const category = 3;
+
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      const sig = await program.methods
+        .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), category, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc({ commitment: "confirmed" });
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.category).to.equal(category);
+
+      const created = (await getEvents(connection, sig)).find((e) => e.name === "EscrowCreated");
+      expect(created).to.not.be.undefined;
+      expect(created!.data.escrow.toBase58()).to.equal(escrowPda.toBase58());
+      expect(created!.data.category).to.equal(category);
+    });
+
+    it("round-trips a metadata URI and rejects one that doesn't fit", async () => {
+      const uri = "ar://bNbA3TEQVL60xlgCcqdz4ZPHFZ711cZ3hmkpGttDt_U";
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+      async function createWithUri(metadataUri: string): Promise<PublicKey> {
+        const escrowId = randomEscrowId();
+        const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+        await program.methods
+          .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, metadataUri, new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+          .accounts({
+            escrowAccount: escrowPda,
+            creator: creator.publicKey,
+            recipient: recipient.publicKey,
+            arbiter: arbiter.publicKey,
+            feeRecipient: treasury.publicKey,
+            systemProgram: SystemProgram.programId,
+            config: configPda,
+          })
+          .signers([creator])
+          .rpc();
+
+        return escrowPda;
+      }
+
+      const escrowPda = await createWithUri(uri);
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      const stored = Buffer.from(escrow.metadataUri as number[]).toString("utf8").replace(/\0+$/, "");
+      expect(stored).to.equal(uri);
+
+      try {
+        await createWithUri("ipfs://" + "a".repeat(128));
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("MetadataUriTooLong");
+      }
+    });
+
     it("fails with zero amount", async () => {
       const escrowId = randomEscrowId();
       const amount = new anchor.BN(0);
@@ -116,7 +282,7 @@ describe("escrow", () => {
 
       try {
         await program.methods
-          .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0))
+          .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
           .accounts({
             escrowAccount: escrowPda,
             creator: creator.publicKey,
@@ -124,6 +290,7 @@ describe("escrow", () => {
             arbiter: arbiter.publicKey,
             feeRecipient: treasury.publicKey,
             systemProgram: SystemProgram.programId,
+            config: configPda,
           })
           .signers([creator])
           .rpc();
@@ -143,7 +310,7 @@ describe("escrow", () => {
 
       try {
         await program.methods
-          .createEscrow(escrowId, amount, deadline, termsHash, 1001, new anchor.BN(0)) // >10%
+          .createEscrow(escrowId, amount, deadline, termsHash, 1001, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0)) // >10%
           .accounts({
             escrowAccount: escrowPda,
             creator: creator.publicKey,
@@ -151,6 +318,7 @@ describe("escrow", () => {
             arbiter: arbiter.publicKey,
             feeRecipient: treasury.publicKey,
             systemProgram: SystemProgram.programId,
+            config: configPda,
           })
           .signers([creator])
           .rpc();
@@ -159,22 +327,281 @@ describe("escrow", () => {
         expect(err.error?.errorCode?.code || err.message).to.include("FeeTooHigh");
       }
     });
+
+    const selfDealingCases: [string, () => { recipient: PublicKey; arbiter: PublicKey }][] = [
+      ["recipient == creator", () => ({ recipient: creator.publicKey, arbiter: arbiter.publicKey })],
+      ["arbiter == creator", () => ({ recipient: recipient.publicKey, arbiter: creator.publicKey })],
+      ["arbiter == recipient", () => ({ recipient: recipient.publicKey, arbiter: recipient.publicKey })],
+    ];
+
+    for (const [label, parties] of selfDealingCases) {
+      it(`fails when ${label}`, async () => {
+        const escrowId = randomEscrowId();
+        const amount = new anchor.BN(0.1 * LAMPORTS_PER_SOL);
+        const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+        const termsHash = Array(32).fill(0);
+
+        const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+        try {
+          await program.methods
+            .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+            .accounts({
+              escrowAccount: escrowPda,
+              creator: creator.publicKey,
+              ...parties(),
+              feeRecipient: treasury.publicKey,
+              systemProgram: SystemProgram.programId,
+              config: configPda,
+            })
+            .signers([creator])
+            .rpc();
+          expect.fail("Should have thrown");
+        } catch (err: any) {
+          expect(err.error?.errorCode?.code || err.message).to.include("SelfEscrow");
+        }
+      });
+    }
+
+    it("rejects the default pubkey as fee recipient when a fee is charged", async () => {
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      try {
+        await program.methods
+          .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+          .accounts({
+            escrowAccount: escrowPda,
+            creator: creator.publicKey,
+            recipient: recipient.publicKey,
+            arbiter: arbiter.publicKey,
+            feeRecipient: PublicKey.default,
+            systemProgram: SystemProgram.programId,
+            config: configPda,
+          })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InvalidFeeRecipient");
+      }
+    });
+
+    it("allows the default pubkey as fee recipient on a zero-fee escrow", async () => {
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), 0, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: PublicKey.default,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda);
+      expect(escrow.feeBasisPoints).to.equal(0);
+      expect(escrow.feeRecipient.toBase58()).to.equal(PublicKey.default.toBase58());
+    });
+  });
+
+  describe("create_escrow_batch", () => {
+    it("creates 3 escrows atomically", async () => {
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const amounts = [0.1, 0.2, 0.3].map((sol) => new anchor.BN(sol * LAMPORTS_PER_SOL));
+      const params = amounts.map((amount) => ({
+        escrowId: randomEscrowId(),
+        recipient: recipient.publicKey,
+        amount,
+        deadline,
+        termsHash: Array(32).fill(0),
+        autoReleaseAt: new anchor.BN(0),
+      }));
+      const pdas = params.map((p) => deriveEscrowPda(creator.publicKey, p.escrowId)[0]);
+
+      const sig = await program.methods
+        .createEscrowBatch(params, feeBasisPoints)
+        .accounts({
+          creator: creator.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .remainingAccounts(pdas.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false })))
+        .signers([creator])
+        .rpc({ commitment: "confirmed" });
+
+      for (let i = 0; i < pdas.length; i++) {
+        const escrow = await program.account.escrowAccount.fetch(pdas[i]);
+        expect(escrow.creator.toBase58()).to.equal(creator.publicKey.toBase58());
+        expect(escrow.recipient.toBase58()).to.equal(recipient.publicKey.toBase58());
+        expect(escrow.amount.toNumber()).to.equal(amounts[i].toNumber());
+        expect(escrow.escrowId.toNumber()).to.equal(params[i].escrowId.toNumber());
+        expect(escrow.status).to.have.property("created");
+      }
+
+      const created = (await getEvents(connection, sig)).filter((e) => e.name === "EscrowCreated");
+      expect(created.map((e) => e.data.escrow.toBase58())).to.deep.equal(pdas.map((pda) => pda.toBase58()));
+    });
+
+    it("rejects remaining accounts that do not match the params", async () => {
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const params = [randomEscrowId(), randomEscrowId()].map((escrowId) => ({
+        escrowId,
+        recipient: recipient.publicKey,
+        amount: new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+        deadline,
+        termsHash: Array(32).fill(0),
+        autoReleaseAt: new anchor.BN(0),
+      }));
+      // Swap the PDAs so neither matches its params entry
+      const pdas = params.map((p) => deriveEscrowPda(creator.publicKey, p.escrowId)[0]).reverse();
+
+      try {
+        await program.methods
+          .createEscrowBatch(params, feeBasisPoints)
+          .accounts({
+            creator: creator.publicKey,
+            arbiter: arbiter.publicKey,
+            feeRecipient: treasury.publicKey,
+            systemProgram: SystemProgram.programId,
+            config: configPda,
+          })
+          .remainingAccounts(pdas.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false })))
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("BatchAccountMismatch");
+      }
+    });
+  });
+
+  describe("escrow counter", () => {
+    it("issues distinct ids for consecutive escrows", async () => {
+      const [counterPda] = deriveCounterPda(creator.publicKey);
+
+      await program.methods
+        .initEscrowCounter()
+        .accounts({
+          escrowCounter: counterPda,
+          creator: creator.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      const ids: number[] = [];
+      for (let i = 0; i < 2; i++) {
+        const counter = await program.account.escrowCounter.fetch(counterPda) as any;
+        const escrowId = counter.nextId as anchor.BN;
+        const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+        const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+        await program.methods
+          .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+          .accounts({
+            escrowAccount: escrowPda,
+            creator: creator.publicKey,
+            recipient: recipient.publicKey,
+            arbiter: arbiter.publicKey,
+            feeRecipient: treasury.publicKey,
+            systemProgram: SystemProgram.programId,
+            escrowCounter: counterPda,
+            config: configPda,
+          })
+          .signers([creator])
+          .rpc();
+
+        const escrow = await program.account.escrowAccount.fetch(escrowPda);
+        ids.push(escrow.escrowId.toNumber());
+      }
+
+      expect(ids[0]).to.not.equal(ids[1]);
+      const counter = await program.account.escrowCounter.fetch(counterPda) as any;
+      expect(counter.nextId.toNumber()).to.equal(ids[1] + 1);
+    });
+
+    // Batch params for one escrow per id
+    function batchParams(ids: anchor.BN[]) {
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      return ids.map((escrowId) => ({
+        escrowId,
+        recipient: recipient.publicKey,
+        amount: new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+        deadline,
+        termsHash: Array(32).fill(0),
+        autoReleaseAt: new anchor.BN(0),
+      }));
+    }
+
+    function createBatch(params: ReturnType<typeof batchParams>) {
+      return program.methods
+        .createEscrowBatch(params, feeBasisPoints)
+        .accounts({
+          creator: creator.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+          escrowCounter: deriveCounterPda(creator.publicKey)[0],
+        })
+        .remainingAccounts(params.map((p) => ({
+          pubkey: deriveEscrowPda(creator.publicKey, p.escrowId)[0],
+          isWritable: true,
+          isSigner: false,
+        })))
+        .signers([creator])
+        .rpc();
+    }
+
+    it("advances the counter once per batch entry", async () => {
+      const [counterPda] = deriveCounterPda(creator.publicKey);
+      const nextId = (await program.account.escrowCounter.fetch(counterPda) as any).nextId as anchor.BN;
+
+      await createBatch(batchParams([nextId, nextId.addn(1)]));
+
+      const counter = await program.account.escrowCounter.fetch(counterPda) as any;
+      expect(counter.nextId.toNumber()).to.equal(nextId.toNumber() + 2);
+    });
+
+    it("rejects a batch entry that does not take the next counter id", async () => {
+      const [counterPda] = deriveCounterPda(creator.publicKey);
+      const nextId = (await program.account.escrowCounter.fetch(counterPda) as any).nextId as anchor.BN;
+
+      try {
+        // Second entry skips an id instead of drawing the next one
+        await createBatch(batchParams([nextId, nextId.addn(2)]));
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("EscrowIdMismatch");
+      }
+    });
   });
 
   describe("accept_task", () => {
     let escrowPda: PublicKey;
     let escrowId: anchor.BN;
+    const termsHash = Array.from(createHash("sha256").update("Accept task terms").digest());
 
     before(async () => {
       escrowId = randomEscrowId();
       const amount = new anchor.BN(1 * LAMPORTS_PER_SOL);
       const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
-      const termsHash = Array(32).fill(0);
 
       [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
 
       await program.methods
-        .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0))
+        .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
         .accounts({
           escrowAccount: escrowPda,
           creator: creator.publicKey,
@@ -182,23 +609,68 @@ describe("escrow", () => {
           arbiter: arbiter.publicKey,
           feeRecipient: treasury.publicKey,
           systemProgram: SystemProgram.programId,
+          config: configPda,
         })
         .signers([creator])
         .rpc();
     });
 
+    it("rejects an acknowledgment that doesn't match the terms hash", async () => {
+      try {
+        await program.methods
+          .acceptTask(Array(32).fill(9))
+          .accounts({
+            escrowAccount: escrowPda,
+            recipient: recipient.publicKey,
+          })
+          .signers([recipient])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("TermsHashMismatch");
+      }
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda);
+      expect(escrow.status).to.have.property("created");
+    });
+
     it("recipient can accept the task", async () => {
-      await program.methods
-        .acceptTask()
+      const before = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(before.acceptedAt.toNumber()).to.equal(0);
+
+      const sig = await program.methods
+        .acceptTask(termsHash)
         .accounts({
           escrowAccount: escrowPda,
           recipient: recipient.publicKey,
         })
         .signers([recipient])
-        .rpc();
+        .rpc({ commitment: "confirmed" });
 
-      const escrow = await program.account.escrowAccount.fetch(escrowPda);
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
       expect(escrow.status).to.have.property("active");
+      expect(escrow.acceptedAt.toNumber()).to.be.at.least(escrow.createdAt.toNumber());
+
+      const accepted = (await getEvents(connection, sig)).find((e) => e.name === "TaskAccepted");
+      expect(accepted).to.not.be.undefined;
+      expect(accepted!.data.recipient.toBase58()).to.equal(recipient.publicKey.toBase58());
+      expect(accepted!.data.acceptedAt.toNumber()).to.equal(escrow.acceptedAt.toNumber());
+    });
+
+    it("cannot accept an already active escrow", async () => {
+      try {
+        await program.methods
+          .acceptTask(termsHash)
+          .accounts({
+            escrowAccount: escrowPda,
+            recipient: recipient.publicKey,
+          })
+          .signers([recipient])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InvalidStatus");
+      }
     });
 
     it("non-recipient cannot accept", async () => {
@@ -211,7 +683,7 @@ describe("escrow", () => {
       const [newPda] = deriveEscrowPda(creator.publicKey, newId);
 
       await program.methods
-        .createEscrow(newId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0))
+        .createEscrow(newId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
         .accounts({
           escrowAccount: newPda,
           creator: creator.publicKey,
@@ -219,6 +691,7 @@ describe("escrow", () => {
           arbiter: arbiter.publicKey,
           feeRecipient: treasury.publicKey,
           systemProgram: SystemProgram.programId,
+          config: configPda,
         })
         .signers([creator])
         .rpc();
@@ -228,7 +701,7 @@ describe("escrow", () => {
 
       try {
         await program.methods
-          .acceptTask()
+          .acceptTask(termsHash)
           .accounts({
             escrowAccount: newPda,
             recipient: imposter.publicKey,
@@ -240,46 +713,198 @@ describe("escrow", () => {
         expect(err.error?.errorCode?.code || err.message).to.include("UnauthorizedRecipient");
       }
     });
-  });
 
-  describe("release_payment", () => {
-    it("creator releases payment with correct fee split", async () => {
-      const escrowId = randomEscrowId();
-      const escrowAmount = 2 * LAMPORTS_PER_SOL;
-      const amount = new anchor.BN(escrowAmount);
-      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+    it("cannot accept before not_before, can accept after", async () => {
+      const newId = randomEscrowId();
+      const amount = new anchor.BN(0.1 * LAMPORTS_PER_SOL);
+      const now = Math.floor(Date.now() / 1000);
+      const deadline = new anchor.BN(now + 3600);
+      const notBefore = new anchor.BN(now + 3);
       const termsHash = Array(32).fill(0);
 
-      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+      const [newPda] = deriveEscrowPda(creator.publicKey, newId);
 
-      // Create
       await program.methods
-        .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0))
+        .createEscrow(newId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), notBefore, 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
         .accounts({
-          escrowAccount: escrowPda,
+          escrowAccount: newPda,
           creator: creator.publicKey,
           recipient: recipient.publicKey,
           arbiter: arbiter.publicKey,
           feeRecipient: treasury.publicKey,
           systemProgram: SystemProgram.programId,
+          config: configPda,
         })
         .signers([creator])
         .rpc();
 
-      // Accept
-      await program.methods
-        .acceptTask()
-        .accounts({
-          escrowAccount: escrowPda,
-          recipient: recipient.publicKey,
-        })
-        .signers([recipient])
-        .rpc();
+      let escrow = await program.account.escrowAccount.fetch(newPda);
+      expect(escrow.notBefore.toNumber()).to.equal(notBefore.toNumber());
 
-      // Record balances before release
-      const recipientBefore = await connection.getBalance(recipient.publicKey);
-      const treasuryBefore = await connection.getBalance(treasury.publicKey);
-      const creatorBefore = await connection.getBalance(creator.publicKey);
+      try {
+        await program.methods
+          .acceptTask(termsHash)
+          .accounts({
+            escrowAccount: newPda,
+            recipient: recipient.publicKey,
+          })
+          .signers([recipient])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("NotYetStarted");
+      }
+
+      // Wait for the start time to pass
+      await new Promise((resolve) => setTimeout(resolve, 5000));
+
+      await program.methods
+        .acceptTask(termsHash)
+        .accounts({
+          escrowAccount: newPda,
+          recipient: recipient.publicKey,
+        })
+        .signers([recipient])
+        .rpc();
+
+      escrow = await program.account.escrowAccount.fetch(newPda);
+      expect(escrow.status).to.have.property("active");
+    });
+
+    it("rejects not_before at or after the deadline", async () => {
+      const newId = randomEscrowId();
+      const amount = new anchor.BN(0.1 * LAMPORTS_PER_SOL);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const termsHash = Array(32).fill(0);
+
+      const [newPda] = deriveEscrowPda(creator.publicKey, newId);
+
+      try {
+        await program.methods
+          .createEscrow(newId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), deadline, 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+          .accounts({
+            escrowAccount: newPda,
+            creator: creator.publicKey,
+            recipient: recipient.publicKey,
+            arbiter: arbiter.publicKey,
+            feeRecipient: treasury.publicKey,
+            systemProgram: SystemProgram.programId,
+            config: configPda,
+          })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InvalidStartTime");
+      }
+    });
+
+    async function createWithAcceptDeadline(acceptDeadline: anchor.BN, deadline: anchor.BN): Promise<PublicKey> {
+      const newId = randomEscrowId();
+      const [newPda] = deriveEscrowPda(creator.publicKey, newId);
+
+      await program.methods
+        .createEscrow(newId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), acceptDeadline, false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: newPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      return newPda;
+    }
+
+    it("accepts before the accept deadline", async () => {
+      const now = Math.floor(Date.now() / 1000);
+      const acceptDeadline = new anchor.BN(now + 600);
+      const newPda = await createWithAcceptDeadline(acceptDeadline, new anchor.BN(now + 3600));
+
+      await program.methods
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: newPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      const escrow = await program.account.escrowAccount.fetch(newPda) as any;
+      expect(escrow.acceptDeadline.toNumber()).to.equal(acceptDeadline.toNumber());
+      expect(escrow.status).to.have.property("active");
+    });
+
+    it("cannot accept after the accept deadline even though the work deadline is open", async () => {
+      const now = Math.floor(Date.now() / 1000);
+      const newPda = await createWithAcceptDeadline(new anchor.BN(now + 2), new anchor.BN(now + 3600));
+
+      await new Promise((resolve) => setTimeout(resolve, 4000));
+
+      try {
+        await program.methods
+          .acceptTask(Array(32).fill(0))
+          .accounts({ escrowAccount: newPda, recipient: recipient.publicKey })
+          .signers([recipient])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("AcceptDeadlinePassed");
+      }
+    });
+
+    it("rejects an accept deadline after the work deadline", async () => {
+      const now = Math.floor(Date.now() / 1000);
+
+      try {
+        await createWithAcceptDeadline(new anchor.BN(now + 7200), new anchor.BN(now + 3600));
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InvalidAcceptDeadline");
+      }
+    });
+  });
+
+  describe("release_payment", () => {
+    it("creator releases payment with correct fee split", async () => {
+      const escrowId = randomEscrowId();
+      const escrowAmount = 2 * LAMPORTS_PER_SOL;
+      const amount = new anchor.BN(escrowAmount);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const termsHash = Array(32).fill(0);
+
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      // Create
+      await program.methods
+        .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      // Accept
+      await program.methods
+        .acceptTask(termsHash)
+        .accounts({
+          escrowAccount: escrowPda,
+          recipient: recipient.publicKey,
+        })
+        .signers([recipient])
+        .rpc();
+
+      // Record balances before release
+      const recipientBefore = await connection.getBalance(recipient.publicKey);
+      const treasuryBefore = await connection.getBalance(treasury.publicKey);
+      const creatorBefore = await connection.getBalance(creator.publicKey);
 
       // Release
       await program.methods
@@ -289,6 +914,8 @@ describe("escrow", () => {
           creator: creator.publicKey,
           recipient: recipient.publicKey,
           feeRecipient: treasury.publicKey,
+          config: configPda,
+          escrowCounter: deriveCounterPda(creator.publicKey)[0],
         })
         .signers([creator])
         .rpc();
@@ -316,22 +943,24 @@ describe("escrow", () => {
       const accountInfo = await connection.getAccountInfo(escrowPda);
       expect(accountInfo).to.be.null;
     });
-  });
 
-  describe("request_refund", () => {
-    it("creator can cancel before acceptance", async () => {
+    it("appends the transient statuses without renumbering stored ones", () => {
+      const statusType = (idl as any).types.find((t: any) => t.name === "EscrowStatus");
+      expect(statusType.type.variants.map((v: any) => v.name)).to.deep.equal([
+        "Created", "Active", "Completed", "Disputed", "Refunded", "Cancelled", "Resolved",
+        "Completing", "Resolving",
+      ]);
+    });
+
+    it("a release that fails mid-transfer leaves the escrow active and funded", async () => {
+      const referrer = Keypair.generate();
       const escrowId = randomEscrowId();
-      const amount = new anchor.BN(0.5 * LAMPORTS_PER_SOL);
+      const escrowAmount = 0.5 * LAMPORTS_PER_SOL;
       const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
-      const termsHash = Array(32).fill(0);
-
       const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
 
-      const creatorBefore = await connection.getBalance(creator.publicKey);
-
-      // Create
       await program.methods
-        .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0))
+        .createEscrow(escrowId, new anchor.BN(escrowAmount), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 100, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
         .accounts({
           escrowAccount: escrowPda,
           creator: creator.publicKey,
@@ -339,40 +968,114 @@ describe("escrow", () => {
           arbiter: arbiter.publicKey,
           feeRecipient: treasury.publicKey,
           systemProgram: SystemProgram.programId,
+          referrer: referrer.publicKey,
+          config: configPda,
         })
         .signers([creator])
         .rpc();
 
-      // Refund (cancel) — escrow is still in Created status
       await program.methods
-        .requestRefund()
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      const balanceBefore = await connection.getBalance(escrowPda);
+      const treasuryBefore = await connection.getBalance(treasury.publicKey);
+
+      // Leaving out the referrer fails after the protocol fee has moved and
+      // the escrow has entered Completing
+      try {
+        await program.methods
+          .releasePayment()
+          .accounts({
+            escrowAccount: escrowPda,
+            creator: creator.publicKey,
+            recipient: recipient.publicKey,
+            feeRecipient: treasury.publicKey,
+            config: configPda,
+            escrowCounter: deriveCounterPda(creator.publicKey)[0],
+          })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InvalidReferrer");
+      }
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.status).to.have.property("active");
+      expect(await connection.getBalance(escrowPda)).to.equal(balanceBefore);
+      expect(await connection.getBalance(treasury.publicKey)).to.equal(treasuryBefore);
+    });
+
+    it("a tipped release that fails mid-transfer leaves the escrow active and funded", async () => {
+      const referrer = Keypair.generate();
+      const escrowId = randomEscrowId();
+      const escrowAmount = 0.5 * LAMPORTS_PER_SOL;
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(escrowAmount), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 100, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
         .accounts({
           escrowAccount: escrowPda,
           creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          referrer: referrer.publicKey,
+          config: configPda,
         })
         .signers([creator])
         .rpc();
 
-      // Escrow account closed
-      const accountInfo = await connection.getAccountInfo(escrowPda);
-      expect(accountInfo).to.be.null;
+      await program.methods
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
 
-      // Creator got funds back (minus tx fees)
-      const creatorAfter = await connection.getBalance(creator.publicKey);
-      expect(creatorAfter).to.be.greaterThan(creatorBefore - 0.01 * LAMPORTS_PER_SOL);
+      const balanceBefore = await connection.getBalance(escrowPda);
+      const treasuryBefore = await connection.getBalance(treasury.publicKey);
+
+      // Leaving out the referrer fails after the protocol fee has moved and
+      // the escrow has entered Completing
+      try {
+        await program.methods
+          .releaseWithTip(new anchor.BN(1))
+          .accounts({
+            escrowAccount: escrowPda,
+            creator: creator.publicKey,
+            recipient: recipient.publicKey,
+            feeRecipient: treasury.publicKey,
+            systemProgram: SystemProgram.programId,
+            config: configPda,
+            escrowCounter: deriveCounterPda(creator.publicKey)[0],
+          })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InvalidReferrer");
+      }
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.status).to.have.property("active");
+      expect(await connection.getBalance(escrowPda)).to.equal(balanceBefore);
+      expect(await connection.getBalance(treasury.publicKey)).to.equal(treasuryBefore);
     });
+  });
 
-    it("cannot refund active escrow before deadline", async () => {
+  describe("payout redirect", () => {
+    async function createActive(escrowAmount: number): Promise<PublicKey> {
       const escrowId = randomEscrowId();
-      const amount = new anchor.BN(0.1 * LAMPORTS_PER_SOL);
-      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600); // far future
-      const termsHash = Array(32).fill(0);
-
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
       const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
 
-      // Create
       await program.methods
-        .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0))
+        .createEscrow(escrowId, new anchor.BN(escrowAmount), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
         .accounts({
           escrowAccount: escrowPda,
           creator: creator.publicKey,
@@ -380,109 +1083,2601 @@ describe("escrow", () => {
           arbiter: arbiter.publicKey,
           feeRecipient: treasury.publicKey,
           systemProgram: SystemProgram.programId,
+          config: configPda,
         })
         .signers([creator])
         .rpc();
 
-      // Accept
       await program.methods
-        .acceptTask()
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      return escrowPda;
+    }
+
+    it("pays a third-party wallet when the recipient co-signs", async () => {
+      const escrowAmount = 1 * LAMPORTS_PER_SOL;
+      const escrowPda = await createActive(escrowAmount);
+      const payout = Keypair.generate();
+      const recipientBefore = await connection.getBalance(recipient.publicKey);
+
+      await program.methods
+        .releasePayment()
         .accounts({
           escrowAccount: escrowPda,
+          creator: creator.publicKey,
           recipient: recipient.publicKey,
+          feeRecipient: treasury.publicKey,
+          payout: payout.publicKey,
+          config: configPda,
+          escrowCounter: deriveCounterPda(creator.publicKey)[0],
         })
+        .signers([creator, recipient])
+        .rpc();
+
+      const expectedFee = Math.floor(escrowAmount * feeBasisPoints / 10000);
+      expect(await connection.getBalance(payout.publicKey)).to.equal(escrowAmount - expectedFee);
+      expect(await connection.getBalance(recipient.publicKey)).to.equal(recipientBefore);
+    });
+
+    it("creator cannot redirect the payout without the recipient", async () => {
+      const escrowPda = await createActive(0.1 * LAMPORTS_PER_SOL);
+      const payout = Keypair.generate();
+
+      try {
+        await program.methods
+          .releasePayment()
+          .accounts({
+            escrowAccount: escrowPda,
+            creator: creator.publicKey,
+            recipient: recipient.publicKey,
+            feeRecipient: treasury.publicKey,
+            payout: payout.publicKey,
+            config: configPda,
+            escrowCounter: deriveCounterPda(creator.publicKey)[0],
+          })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("PayoutNotAuthorized");
+      }
+    });
+  });
+
+  describe("release cosigner", () => {
+    const cosigner = Keypair.generate();
+    let escrowPda: PublicKey;
+
+    before(async () => {
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const termsHash = Array(32).fill(0);
+      [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(1 * LAMPORTS_PER_SOL), deadline, termsHash, feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          cosigner: cosigner.publicKey,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTask(termsHash)
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+    });
+
+    it("stores the cosigner", async () => {
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.cosigner.toBase58()).to.equal(cosigner.publicKey.toBase58());
+    });
+
+    it("creator alone cannot release", async () => {
+      try {
+        await program.methods
+          .releasePayment()
+          .accounts({
+            escrowAccount: escrowPda,
+            creator: creator.publicKey,
+            recipient: recipient.publicKey,
+            feeRecipient: treasury.publicKey,
+            config: configPda,
+            escrowCounter: deriveCounterPda(creator.publicKey)[0],
+          })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("CosignerRequired");
+      }
+    });
+
+    it("a different second signer is rejected", async () => {
+      const imposter = Keypair.generate();
+
+      try {
+        await program.methods
+          .releasePayment()
+          .accounts({
+            escrowAccount: escrowPda,
+            creator: creator.publicKey,
+            recipient: recipient.publicKey,
+            feeRecipient: treasury.publicKey,
+            cosigner: imposter.publicKey,
+            config: configPda,
+            escrowCounter: deriveCounterPda(creator.publicKey)[0],
+          })
+          .signers([creator, imposter])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("CosignerRequired");
+      }
+    });
+
+    it("releases when creator and cosigner both sign", async () => {
+      await program.methods
+        .releasePayment()
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          feeRecipient: treasury.publicKey,
+          cosigner: cosigner.publicKey,
+          config: configPda,
+          escrowCounter: deriveCounterPda(creator.publicKey)[0],
+        })
+        .signers([creator, cosigner])
+        .rpc();
+
+      expect(await connection.getAccountInfo(escrowPda)).to.be.null;
+    });
+  });
+
+  describe("fee math", () => {
+    // Creates, accepts and releases an escrow, returning the lamports paid out
+    async function releaseWithFee(escrowAmount: number, bps: number, feePaidByCreator = false) {
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(escrowAmount), deadline, Array(32).fill(0), bps, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), feePaidByCreator, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      const recipientBefore = await connection.getBalance(recipient.publicKey);
+      const treasuryBefore = await connection.getBalance(treasury.publicKey);
+
+      await program.methods
+        .releasePayment()
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          feeRecipient: treasury.publicKey,
+          config: configPda,
+          escrowCounter: deriveCounterPda(creator.publicKey)[0],
+        })
+        .signers([creator])
+        .rpc();
+
+      return {
+        fee: (await connection.getBalance(treasury.publicKey)) - treasuryBefore,
+        net: (await connection.getBalance(recipient.publicKey)) - recipientBefore,
+      };
+    }
+
+    it("rounds the fee down", async () => {
+      // 9_999 * 150 / 10_000 = 149.985
+      const { fee, net } = await releaseWithFee(9_999, 150);
+      expect(fee).to.equal(149);
+      expect(net).to.equal(9_999 - 149);
+    });
+
+    it("charges nothing at zero bps", async () => {
+      const { fee, net } = await releaseWithFee(1_000_000, 0);
+      expect(fee).to.equal(0);
+      expect(net).to.equal(1_000_000);
+    });
+
+    it("charges 10% at the max bps", async () => {
+      const escrowAmount = 1 * LAMPORTS_PER_SOL;
+      const { fee, net } = await releaseWithFee(escrowAmount, 1000);
+      expect(fee).to.equal(escrowAmount / 10);
+      expect(net).to.equal(escrowAmount - escrowAmount / 10);
+    });
+
+    it("pays the recipient the full amount when the creator covers the fee", async () => {
+      const escrowAmount = 1 * LAMPORTS_PER_SOL;
+      const expectedFee = Math.floor(escrowAmount * feeBasisPoints / 10000);
+
+      const recipientPaid = await releaseWithFee(escrowAmount, feeBasisPoints, false);
+      const creatorPaid = await releaseWithFee(escrowAmount, feeBasisPoints, true);
+
+      expect(recipientPaid.fee).to.equal(expectedFee);
+      expect(recipientPaid.net).to.equal(escrowAmount - expectedFee);
+
+      expect(creatorPaid.fee).to.equal(expectedFee);
+      expect(creatorPaid.net).to.equal(escrowAmount);
+    });
+
+    it("funds the escrow with amount + fee when the creator covers the fee", async () => {
+      const escrowAmount = 1 * LAMPORTS_PER_SOL;
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(escrowAmount), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), true, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      const info = await connection.getAccountInfo(escrowPda);
+      const rent = await connection.getMinimumBalanceForRentExemption(info!.data.length);
+      const expectedFee = Math.floor(escrowAmount * feeBasisPoints / 10000);
+      expect(info!.lamports).to.equal(rent + escrowAmount + expectedFee);
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.amount.toNumber()).to.equal(escrowAmount);
+      expect(escrow.feePaidByCreator).to.equal(true);
+    });
+  });
+
+  describe("early release bonus", () => {
+    const escrowAmount = 0.1 * LAMPORTS_PER_SOL;
+    const bonus = 0.05 * LAMPORTS_PER_SOL;
+    const window = 8;
+
+    // Creates and accepts an escrow with a prefunded bonus and a deadline `window` seconds out
+    async function createWithBonus(): Promise<PublicKey> {
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + window);
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(escrowAmount), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(bonus), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      return escrowPda;
+    }
+
+    // Releases and returns the bonus the recipient got on top of the usual payout
+    async function releaseBonus(escrowPda: PublicKey): Promise<number> {
+      const recipientBefore = await connection.getBalance(recipient.publicKey);
+
+      await program.methods
+        .releasePayment()
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          feeRecipient: treasury.publicKey,
+          config: configPda,
+          escrowCounter: deriveCounterPda(creator.publicKey)[0],
+        })
+        .signers([creator])
+        .rpc();
+
+      const fee = Math.floor(escrowAmount * feeBasisPoints / 10000);
+      return (await connection.getBalance(recipient.publicKey)) - recipientBefore - (escrowAmount - fee);
+    }
+
+    it("prefunds the bonus on top of the amount", async () => {
+      const escrowPda = await createWithBonus();
+      const rent = await connection.getMinimumBalanceForRentExemption(
+        (await connection.getAccountInfo(escrowPda))!.data.length
+      );
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.earlyBonusLamports.toNumber()).to.equal(bonus);
+      expect(await connection.getBalance(escrowPda)).to.equal(rent + escrowAmount + bonus);
+    });
+
+    it("pays most of the bonus for an early release", async () => {
+      const escrowPda = await createWithBonus();
+      const earned = await releaseBonus(escrowPda);
+
+      expect(earned).to.be.greaterThan(bonus / 2);
+      expect(earned).to.be.at.most(bonus);
+    });
+
+    it("pays a partial bonus midway to the deadline", async () => {
+      const escrowPda = await createWithBonus();
+      await new Promise((resolve) => setTimeout(resolve, (window / 2) * 1000));
+      const earned = await releaseBonus(escrowPda);
+
+      expect(earned).to.be.greaterThan(0);
+      expect(earned).to.be.lessThan(bonus);
+    });
+
+    it("pays no bonus after the deadline and returns it to the creator", async () => {
+      const escrowPda = await createWithBonus();
+      await new Promise((resolve) => setTimeout(resolve, (window + 2) * 1000));
+
+      const creatorBefore = await connection.getBalance(creator.publicKey);
+      const earned = await releaseBonus(escrowPda);
+
+      expect(earned).to.equal(0);
+      // Creator paid the tx fee, but got the bonus back with the rent
+      expect((await connection.getBalance(creator.publicKey)) - creatorBefore).to.be.greaterThan(bonus);
+    });
+
+    it("pays the bonus when releasing with a tip", async () => {
+      const escrowPda = await createWithBonus();
+      const tip = 1;
+      const recipientBefore = await connection.getBalance(recipient.publicKey);
+
+      await program.methods
+        .releaseWithTip(new anchor.BN(tip))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+          escrowCounter: deriveCounterPda(creator.publicKey)[0],
+        })
+        .signers([creator])
+        .rpc();
+
+      const fee = Math.floor(escrowAmount * feeBasisPoints / 10000);
+      const earned = (await connection.getBalance(recipient.publicKey)) - recipientBefore - (escrowAmount - fee) - tip;
+      expect(earned).to.be.greaterThan(bonus / 2);
+      expect(earned).to.be.at.most(bonus);
+    });
+  });
+
+  describe("referral fee", () => {
+    it("routes the referrer share on release", async () => {
+      const referrer = Keypair.generate();
+      const referrerFeeBps = 200; // 2%
+      const escrowId = randomEscrowId();
+      const escrowAmount = 1 * LAMPORTS_PER_SOL;
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const termsHash = Array(32).fill(0);
+
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(escrowAmount), deadline, termsHash, feeBasisPoints, new anchor.BN(0), new anchor.BN(0), referrerFeeBps, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          referrer: referrer.publicKey,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.referrer.toBase58()).to.equal(referrer.publicKey.toBase58());
+      expect(escrow.referrerFeeBps).to.equal(referrerFeeBps);
+
+      await program.methods
+        .acceptTask(termsHash)
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      const recipientBefore = await connection.getBalance(recipient.publicKey);
+      const treasuryBefore = await connection.getBalance(treasury.publicKey);
+
+      await program.methods
+        .releasePayment()
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          feeRecipient: treasury.publicKey,
+          referrer: referrer.publicKey,
+          config: configPda,
+          escrowCounter: deriveCounterPda(creator.publicKey)[0],
+        })
+        .signers([creator])
+        .rpc();
+
+      const expectedFee = Math.floor(escrowAmount * feeBasisPoints / 10000);
+      const expectedReferrerFee = Math.floor(escrowAmount * referrerFeeBps / 10000);
+
+      expect(await connection.getBalance(referrer.publicKey)).to.equal(expectedReferrerFee);
+      expect((await connection.getBalance(treasury.publicKey)) - treasuryBefore).to.equal(expectedFee);
+      expect((await connection.getBalance(recipient.publicKey)) - recipientBefore).to.equal(
+        escrowAmount - expectedFee - expectedReferrerFee
+      );
+    });
+
+    it("fails when protocol + referrer fee exceeds the max", async () => {
+      const referrer = Keypair.generate();
+      const escrowId = randomEscrowId();
+      const amount = new anchor.BN(0.1 * LAMPORTS_PER_SOL);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const termsHash = Array(32).fill(0);
+
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      try {
+        await program.methods
+          .createEscrow(escrowId, amount, deadline, termsHash, 900, new anchor.BN(0), new anchor.BN(0), 101, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0)) // 9% + 1.01%
+          .accounts({
+            escrowAccount: escrowPda,
+            creator: creator.publicKey,
+            recipient: recipient.publicKey,
+            arbiter: arbiter.publicKey,
+            feeRecipient: treasury.publicKey,
+            systemProgram: SystemProgram.programId,
+            referrer: referrer.publicKey,
+            config: configPda,
+          })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("FeeTooHigh");
+      }
+    });
+  });
+
+  describe("request_refund", () => {
+    it("creator can cancel before acceptance", async () => {
+      const escrowId = randomEscrowId();
+      const amount = new anchor.BN(0.5 * LAMPORTS_PER_SOL);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const termsHash = Array(32).fill(0);
+
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      const creatorBefore = await connection.getBalance(creator.publicKey);
+
+      // Create
+      await program.methods
+        .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      // Refund (cancel) — escrow is still in Created status
+      await program.methods
+        .requestRefund()
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          escrowCounter: deriveCounterPda(creator.publicKey)[0],
+        })
+        .signers([creator])
+        .rpc();
+
+      // Escrow account closed
+      const accountInfo = await connection.getAccountInfo(escrowPda);
+      expect(accountInfo).to.be.null;
+
+      // Creator got funds back (minus tx fees)
+      const creatorAfter = await connection.getBalance(creator.publicKey);
+      expect(creatorAfter).to.be.greaterThan(creatorBefore - 0.01 * LAMPORTS_PER_SOL);
+    });
+
+    it("cannot refund active escrow before deadline", async () => {
+      const escrowId = randomEscrowId();
+      const amount = new anchor.BN(0.1 * LAMPORTS_PER_SOL);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600); // far future
+      const termsHash = Array(32).fill(0);
+
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      // Create
+      await program.methods
+        .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      // Accept
+      await program.methods
+        .acceptTask(termsHash)
+        .accounts({
+          escrowAccount: escrowPda,
+          recipient: recipient.publicKey,
+        })
+        .signers([recipient])
+        .rpc();
+
+      // Try refund — should fail (deadline not reached)
+      try {
+        await program.methods
+          .requestRefund()
+          .accounts({
+            escrowAccount: escrowPda,
+            creator: creator.publicKey,
+            escrowCounter: deriveCounterPda(creator.publicKey)[0],
+          })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("DeadlineNotReached");
+      }
+    });
+
+    it("cannot refund after deadline while inside refund grace period", async () => {
+      const escrowId = randomEscrowId();
+      const amount = new anchor.BN(0.1 * LAMPORTS_PER_SOL);
+      const now = Math.floor(Date.now() / 1000);
+      const deadline = new anchor.BN(now + 2);
+      const refundGrace = new anchor.BN(3600);
+      const termsHash = Array(32).fill(0);
+
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0), refundGrace, 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.refundGraceSeconds.toNumber()).to.equal(3600);
+
+      await program.methods
+        .acceptTask(termsHash)
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      // Wait until the deadline has passed
+      await new Promise((resolve) => setTimeout(resolve, 4000));
+
+      // Deadline passed but still inside the grace window
+      try {
+        await program.methods
+          .requestRefund()
+          .accounts({ escrowAccount: escrowPda, creator: creator.publicKey, escrowCounter: deriveCounterPda(creator.publicKey)[0] })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("DeadlineNotReached");
+      }
+    });
+
+    it("can refund once deadline + refund grace period has passed", async () => {
+      const escrowId = randomEscrowId();
+      const amount = new anchor.BN(0.1 * LAMPORTS_PER_SOL);
+      const now = Math.floor(Date.now() / 1000);
+      const deadline = new anchor.BN(now + 2);
+      const refundGrace = new anchor.BN(2);
+      const termsHash = Array(32).fill(0);
+
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0), refundGrace, 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.refundGraceSeconds.toNumber()).to.equal(2);
+
+      await program.methods
+        .acceptTask(termsHash)
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      // Wait until the deadline has passed
+      await new Promise((resolve) => setTimeout(resolve, 6000));
+
+      // Deadline + grace window both elapsed
+      await program.methods
+        .requestRefund()
+        .accounts({ escrowAccount: escrowPda, creator: creator.publicKey, escrowCounter: deriveCounterPda(creator.publicKey)[0] })
+        .signers([creator])
+        .rpc();
+
+      const accountInfo = await connection.getAccountInfo(escrowPda);
+      expect(accountInfo).to.be.null;
+    });
+  });
+
+  describe("sponsored escrow", () => {
+    const sponsor = Keypair.generate();
+    const escrowAmount = 0.5 * LAMPORTS_PER_SOL;
+
+    before(async () => {
+      await airdrop(connection, sponsor.publicKey, 2);
+    });
+
+    async function createSponsored(): Promise<PublicKey> {
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(escrowAmount), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          sponsor: sponsor.publicKey,
+          config: configPda,
+        })
+        .signers([creator, sponsor])
+        .rpc();
+
+      return escrowPda;
+    }
+
+    it("sponsor funds the escrow while the creator stays the owner", async () => {
+      const sponsorBefore = await connection.getBalance(sponsor.publicKey);
+      const escrowPda = await createSponsored();
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.creator.toBase58()).to.equal(creator.publicKey.toBase58());
+      expect(escrow.sponsor.toBase58()).to.equal(sponsor.publicKey.toBase58());
+      expect(sponsorBefore - (await connection.getBalance(sponsor.publicKey))).to.equal(escrowAmount);
+    });
+
+    it("refund goes back to the sponsor", async () => {
+      const escrowPda = await createSponsored();
+      const sponsorBefore = await connection.getBalance(sponsor.publicKey);
+
+      await program.methods
+        .requestRefund()
+        .accounts({ escrowAccount: escrowPda, creator: creator.publicKey, sponsor: sponsor.publicKey, escrowCounter: deriveCounterPda(creator.publicKey)[0] })
+        .signers([creator])
+        .rpc();
+
+      expect((await connection.getBalance(sponsor.publicKey)) - sponsorBefore).to.equal(escrowAmount);
+      expect(await connection.getAccountInfo(escrowPda)).to.be.null;
+    });
+
+    it("refund without the sponsor account fails", async () => {
+      const escrowPda = await createSponsored();
+
+      try {
+        await program.methods
+          .requestRefund()
+          .accounts({ escrowAccount: escrowPda, creator: creator.publicKey, escrowCounter: deriveCounterPda(creator.publicKey)[0] })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InvalidSponsor");
+      }
+    });
+  });
+
+  describe("refund destination", () => {
+    const coldWallet = Keypair.generate();
+    const escrowAmount = 0.5 * LAMPORTS_PER_SOL;
+
+    async function createWithDestination(destination?: PublicKey): Promise<PublicKey> {
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(escrowAmount), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          refundDestination: destination ?? null,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      return escrowPda;
+    }
+
+    it("defaults to the creator", async () => {
+      const escrowPda = await createWithDestination();
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.refundDestination.toBase58()).to.equal(creator.publicKey.toBase58());
+    });
+
+    it("refund goes to the destination while rent returns to the creator", async () => {
+      const escrowPda = await createWithDestination(coldWallet.publicKey);
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.refundDestination.toBase58()).to.equal(coldWallet.publicKey.toBase58());
+
+      const rent = (await connection.getBalance(escrowPda)) - escrowAmount;
+      const creatorBefore = await connection.getBalance(creator.publicKey);
+
+      await program.methods
+        .requestRefund()
+        .accounts({ escrowAccount: escrowPda, creator: creator.publicKey, refundDestination: coldWallet.publicKey, escrowCounter: deriveCounterPda(creator.publicKey)[0] })
+        .signers([creator])
+        .rpc();
+
+      expect(await connection.getBalance(coldWallet.publicKey)).to.equal(escrowAmount);
+      expect((await connection.getBalance(creator.publicKey)) - creatorBefore).to.equal(rent);
+      expect(await connection.getAccountInfo(escrowPda)).to.be.null;
+    });
+
+    it("refund without the destination account fails", async () => {
+      const escrowPda = await createWithDestination(coldWallet.publicKey);
+
+      try {
+        await program.methods
+          .requestRefund()
+          .accounts({ escrowAccount: escrowPda, creator: creator.publicKey, escrowCounter: deriveCounterPda(creator.publicKey)[0] })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InvalidRefundDestination");
+      }
+    });
+
+    it("a dispute won by the creator pays the destination", async () => {
+      const escrowPda = await createWithDestination(coldWallet.publicKey);
+      await program.methods
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+      await program.methods
+        .dispute(Array(64).fill(0))
+        .accounts({ escrowAccount: escrowPda, disputer: creator.publicKey })
+        .signers([creator])
+        .rpc();
+
+      const destinationBefore = await connection.getBalance(coldWallet.publicKey);
+
+      await program.methods
+        .resolveDispute({ creator: {} }, Array(32).fill(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          arbiter: arbiter.publicKey,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          feeRecipient: treasury.publicKey,
+          refundDestination: coldWallet.publicKey,
+          escrowCounter: deriveCounterPda(creator.publicKey)[0],
+          config: configPda,
+        })
+        .signers([arbiter])
+        .rpc();
+
+      expect((await connection.getBalance(coldWallet.publicKey)) - destinationBefore).to.equal(escrowAmount);
+    });
+
+    it("rejects the default pubkey as refund destination", async () => {
+      try {
+        await createWithDestination(PublicKey.default);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InvalidRefundDestination");
+      }
+    });
+  });
+
+  describe("request_partial_refund", () => {
+    it("partially refunds past deadline, then releases the remainder", async () => {
+      const escrowId = randomEscrowId();
+      const escrowAmount = 1 * LAMPORTS_PER_SOL;
+      const refundAmount = 0.3 * LAMPORTS_PER_SOL;
+      const now = Math.floor(Date.now() / 1000);
+      const deadline = new anchor.BN(now + 2);
+      const termsHash = Array(32).fill(0);
+
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(escrowAmount), deadline, termsHash, feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTask(termsHash)
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      // Wait for deadline to pass
+      await new Promise((resolve) => setTimeout(resolve, 4000));
+
+      const pdaBefore = await connection.getBalance(escrowPda);
+
+      await program.methods
+        .requestPartialRefund(new anchor.BN(refundAmount))
+        .accounts({ escrowAccount: escrowPda, creator: creator.publicKey, escrowCounter: deriveCounterPda(creator.publicKey)[0] })
+        .signers([creator])
+        .rpc();
+
+      const pdaAfter = await connection.getBalance(escrowPda);
+      expect(pdaBefore - pdaAfter).to.equal(refundAmount);
+
+      // Account stays open with the remaining balance
+      let escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.amount.toNumber()).to.equal(escrowAmount - refundAmount);
+      expect(escrow.status).to.have.property("active");
+
+      const recipientBefore = await connection.getBalance(recipient.publicKey);
+
+      // Creator releases the remainder to the recipient
+      await program.methods
+        .releasePayment()
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          feeRecipient: treasury.publicKey,
+          config: configPda,
+          escrowCounter: deriveCounterPda(creator.publicKey)[0],
+        })
+        .signers([creator])
+        .rpc();
+
+      const recipientAfter = await connection.getBalance(recipient.publicKey);
+      const remaining = escrowAmount - refundAmount;
+      const expectedFee = Math.floor(remaining * feeBasisPoints / 10000);
+      expect(recipientAfter - recipientBefore).to.equal(remaining - expectedFee);
+
+      const accountInfo = await connection.getAccountInfo(escrowPda);
+      expect(accountInfo).to.be.null;
+    });
+
+    it("refunding the full amount closes the escrow", async () => {
+      const escrowId = randomEscrowId();
+      const escrowAmount = 0.5 * LAMPORTS_PER_SOL;
+      const now = Math.floor(Date.now() / 1000);
+      const deadline = new anchor.BN(now + 2);
+      const termsHash = Array(32).fill(0);
+
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(escrowAmount), deadline, termsHash, feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTask(termsHash)
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      await new Promise((resolve) => setTimeout(resolve, 4000));
+
+      // Over-refund is rejected
+      try {
+        await program.methods
+          .requestPartialRefund(new anchor.BN(escrowAmount + 1))
+          .accounts({ escrowAccount: escrowPda, creator: creator.publicKey, escrowCounter: deriveCounterPda(creator.publicKey)[0] })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("RefundExceedsAmount");
+      }
+
+      await program.methods
+        .requestPartialRefund(new anchor.BN(escrowAmount))
+        .accounts({ escrowAccount: escrowPda, creator: creator.publicKey, escrowCounter: deriveCounterPda(creator.publicKey)[0] })
+        .signers([creator])
+        .rpc();
+
+      const accountInfo = await connection.getAccountInfo(escrowPda);
+      expect(accountInfo).to.be.null;
+    });
+  });
+
+  describe("amend_terms", () => {
+    let escrowPda: PublicKey;
+    const originalTerms = Array(32).fill(1);
+    const amendedTerms = Array(32).fill(2);
+
+    before(async () => {
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, originalTerms, feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTask(originalTerms)
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+    });
+
+    it("fails when only the creator signs", async () => {
+      try {
+        await program.methods
+          .amendTerms(amendedTerms)
+          .accounts({
+            escrowAccount: escrowPda,
+            creator: creator.publicKey,
+            recipient: recipient.publicKey,
+          })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.message).to.include("Signature verification failed");
+      }
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.termsHash).to.deep.equal(originalTerms);
+    });
+
+    it("amends terms when both parties sign and keeps the previous hash", async () => {
+      await program.methods
+        .amendTerms(amendedTerms)
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+        })
+        .signers([creator, recipient])
+        .rpc();
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.termsHash).to.deep.equal(amendedTerms);
+      expect(escrow.prevTermsHash).to.deep.equal(originalTerms);
+    });
+  });
+
+  describe("reassign_task", () => {
+    const newWorker = Keypair.generate();
+
+    async function createEscrowFor(accept: boolean): Promise<PublicKey> {
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      if (accept) {
+        await program.methods
+          .acceptTask(Array(32).fill(0))
+          .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+          .signers([recipient])
+          .rpc();
+      }
+
+      return escrowPda;
+    }
+
+    it("fails when only the recipient signs", async () => {
+      const escrowPda = await createEscrowFor(true);
+
+      try {
+        await program.methods
+          .reassignTask(newWorker.publicKey)
+          .accounts({
+            escrowAccount: escrowPda,
+            creator: creator.publicKey,
+            recipient: recipient.publicKey,
+          })
+          .signers([recipient])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.message).to.include("Signature verification failed");
+      }
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.recipient.toBase58()).to.equal(recipient.publicKey.toBase58());
+    });
+
+    it("cannot reassign an escrow that is not active", async () => {
+      const escrowPda = await createEscrowFor(false);
+
+      try {
+        await program.methods
+          .reassignTask(newWorker.publicKey)
+          .accounts({
+            escrowAccount: escrowPda,
+            creator: creator.publicKey,
+            recipient: recipient.publicKey,
+          })
+          .signers([creator, recipient])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InvalidStatus");
+      }
+    });
+
+    it("hands the task to the new worker when both parties sign", async () => {
+      const escrowPda = await createEscrowFor(true);
+
+      await program.methods
+        .reassignTask(newWorker.publicKey)
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+        })
+        .signers([creator, recipient])
+        .rpc();
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.recipient.toBase58()).to.equal(newWorker.publicKey.toBase58());
+      expect(escrow.status).to.have.property("active");
+    });
+  });
+
+  describe("cancel_auto_release", () => {
+    async function createWithAutoRelease(accept: boolean): Promise<PublicKey> {
+      const escrowId = randomEscrowId();
+      const now = Math.floor(Date.now() / 1000);
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), new anchor.BN(now + 3600), Array(32).fill(0), feeBasisPoints, new anchor.BN(now + 7200), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      if (accept) {
+        await program.methods
+          .acceptTask(Array(32).fill(0))
+          .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+          .signers([recipient])
+          .rpc();
+      }
+
+      return escrowPda;
+    }
+
+    it("fails when only the creator signs", async () => {
+      const escrowPda = await createWithAutoRelease(true);
+
+      try {
+        await program.methods
+          .cancelAutoRelease()
+          .accounts({
+            escrowAccount: escrowPda,
+            creator: creator.publicKey,
+            recipient: recipient.publicKey,
+          })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.message).to.include("Signature verification failed");
+      }
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.autoReleaseAt.toNumber()).to.be.greaterThan(0);
+    });
+
+    it("cannot cancel auto-release on an escrow that is not active", async () => {
+      const escrowPda = await createWithAutoRelease(false);
+
+      try {
+        await program.methods
+          .cancelAutoRelease()
+          .accounts({
+            escrowAccount: escrowPda,
+            creator: creator.publicKey,
+            recipient: recipient.publicKey,
+          })
+          .signers([creator, recipient])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InvalidStatus");
+      }
+    });
+
+    it("clears auto_release_at when both parties sign", async () => {
+      const escrowPda = await createWithAutoRelease(true);
+
+      await program.methods
+        .cancelAutoRelease()
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+        })
+        .signers([creator, recipient])
+        .rpc();
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.autoReleaseAt.toNumber()).to.equal(0);
+      expect(escrow.status).to.have.property("active");
+    });
+  });
+
+  describe("request_release", () => {
+    let escrowPda: PublicKey;
+
+    before(async () => {
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+    });
+
+    it("sets the flag and emits ReleaseRequested without moving funds", async () => {
+      const balanceBefore = await connection.getBalance(escrowPda);
+
+      const sig = await program.methods
+        .requestRelease()
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc({ commitment: "confirmed" });
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.releaseRequested).to.equal(true);
+      expect(escrow.status).to.have.property("active");
+      expect(await connection.getBalance(escrowPda)).to.equal(balanceBefore);
+
+      const requested = (await getEvents(connection, sig)).find((e) => e.name === "ReleaseRequested");
+      expect(requested).to.not.be.undefined;
+      expect(requested!.data.escrow.toBase58()).to.equal(escrowPda.toBase58());
+      expect(requested!.data.recipient.toBase58()).to.equal(recipient.publicKey.toBase58());
+    });
+
+    it("only the recipient can request release", async () => {
+      try {
+        await program.methods
+          .requestRelease()
+          .accounts({ escrowAccount: escrowPda, recipient: creator.publicKey })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("UnauthorizedRecipient");
+      }
+    });
+  });
+
+  describe("submit_work", () => {
+    let escrowPda: PublicKey;
+
+    before(async () => {
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+    });
+
+    it("stores the work hash and emits WorkSubmitted", async () => {
+      const workHash = Array(32).fill(7);
+
+      const sig = await program.methods
+        .submitWork(workHash)
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc({ commitment: "confirmed" });
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(Array.from(escrow.workHash)).to.deep.equal(workHash);
+      expect(escrow.status).to.have.property("active");
+
+      const submitted = (await getEvents(connection, sig)).find((e) => e.name === "WorkSubmitted");
+      expect(submitted).to.not.be.undefined;
+      expect(submitted!.data.escrow.toBase58()).to.equal(escrowPda.toBase58());
+      expect(Array.from(submitted!.data.workHash)).to.deep.equal(workHash);
+    });
+
+    it("only the recipient can submit work", async () => {
+      try {
+        await program.methods
+          .submitWork(Array(32).fill(1))
+          .accounts({ escrowAccount: escrowPda, recipient: creator.publicKey })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("UnauthorizedRecipient");
+      }
+    });
+  });
+
+  describe("delegate", () => {
+    const hotKey = Keypair.generate();
+    const stranger = Keypair.generate();
+    let escrowPda: PublicKey;
+
+    before(async () => {
+      await airdrop(connection, hotKey.publicKey, 1);
+      await airdrop(connection, stranger.publicKey, 1);
+
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+    });
+
+    it("only the recipient can set a delegate", async () => {
+      try {
+        await program.methods
+          .setDelegate(stranger.publicKey)
+          .accounts({ escrowAccount: escrowPda, recipient: stranger.publicKey })
+          .signers([stranger])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("UnauthorizedRecipient");
+      }
+    });
+
+    it("rejects request_release from a key that is not the delegate", async () => {
+      await program.methods
+        .setDelegate(hotKey.publicKey)
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      try {
+        await program.methods
+          .requestRelease()
+          .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey, delegate: stranger.publicKey })
+          .signers([stranger])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InvalidDelegate");
+      }
+    });
+
+    it("delegate requests release without the recipient signing", async () => {
+      await program.methods
+        .requestRelease()
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey, delegate: hotKey.publicKey })
+        .signers([hotKey])
+        .rpc();
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.delegate.toBase58()).to.equal(hotKey.publicKey.toBase58());
+      expect(escrow.releaseRequested).to.equal(true);
+    });
+  });
+
+  describe("migrate_escrow", () => {
+    let escrowPda: PublicKey;
+
+    before(async () => {
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+    });
+
+    it("leaves an escrow on the current layout sized and readable", async () => {
+      const sizeBefore = (await connection.getAccountInfo(escrowPda))!.data.length;
+
+      await program.methods
+        .migrateEscrow()
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      const info = await connection.getAccountInfo(escrowPda);
+      expect(info!.data.length).to.equal(sizeBefore);
+
+      // Appended fields decode to their zero defaults
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.releaseRequested).to.equal(false);
+      expect(escrow.notBefore.toNumber()).to.equal(0);
+      expect(escrow.prevTermsHash).to.deep.equal(Array(32).fill(0));
+      expect(escrow.version).to.equal(CURRENT_ACCOUNT_VERSION);
+    });
+
+    it("moves a pre-version escrow behind the header so it can be used again", async () => {
+      const legacyCreator = Keypair.fromSecretKey(Uint8Array.from(legacyCreatorSecret));
+      const legacyRecipient = Keypair.fromSecretKey(Uint8Array.from(legacyRecipientSecret));
+      await airdrop(connection, legacyCreator.publicKey, 1);
+
+      // Handlers refuse the old layout until it is migrated
+      try {
+        await program.methods
+          .acceptTask(Array(32).fill(7))
+          .accounts({ escrowAccount: LEGACY_ESCROW, recipient: legacyRecipient.publicKey })
+          .signers([legacyRecipient])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("AccountDidNotDeserialize");
+      }
+
+      await program.methods
+        .migrateEscrow()
+        .accounts({
+          escrowAccount: LEGACY_ESCROW,
+          creator: legacyCreator.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([legacyCreator])
+        .rpc();
+
+      const escrow = await program.account.escrowAccount.fetch(LEGACY_ESCROW) as any;
+      expect(escrow.version).to.equal(CURRENT_ACCOUNT_VERSION);
+      expect(escrow.creator.toBase58()).to.equal(legacyCreator.publicKey.toBase58());
+      expect(escrow.recipient.toBase58()).to.equal(legacyRecipient.publicKey.toBase58());
+      expect(escrow.amount.toNumber()).to.equal(0.1 * LAMPORTS_PER_SOL);
+      expect(escrow.status).to.have.property("created");
+      expect(escrow.termsHash).to.deep.equal(Array(32).fill(7));
+      expect(escrow.refundDestination.toBase58()).to.equal(legacyCreator.publicKey.toBase58());
+
+      await program.methods
+        .acceptTask(Array(32).fill(7))
+        .accounts({ escrowAccount: LEGACY_ESCROW, recipient: legacyRecipient.publicKey })
+        .signers([legacyRecipient])
+        .rpc();
+
+      const accepted = await program.account.escrowAccount.fetch(LEGACY_ESCROW) as any;
+      expect(accepted.status).to.have.property("active");
+    });
+
+    it("rejects an escrow stamped with an unknown version", async () => {
+      const legacyRecipient = Keypair.fromSecretKey(Uint8Array.from(legacyRecipientSecret));
+
+      try {
+        await program.methods
+          .acceptTask(Array(32).fill(7))
+          .accounts({ escrowAccount: FORGED_VERSION_ESCROW, recipient: legacyRecipient.publicKey })
+          .signers([legacyRecipient])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("UnsupportedVersion");
+      }
+    });
+
+    it("only the creator can migrate", async () => {
+      try {
+        await program.methods
+          .migrateEscrow()
+          .accounts({
+            escrowAccount: escrowPda,
+            creator: recipient.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([recipient])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("UnauthorizedCreator");
+      }
+    });
+  });
+
+  describe("split_escrow", () => {
+    const totalAmount = 0.3 * LAMPORTS_PER_SOL;
+    const splitAmount = 0.1 * LAMPORTS_PER_SOL;
+
+    async function createUnaccepted(): Promise<PublicKey> {
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(totalAmount), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      return escrowPda;
+    }
+
+    async function split(escrowPda: PublicKey, amount: number): Promise<PublicKey> {
+      const newEscrowId = randomEscrowId();
+      const [newEscrowPda] = deriveEscrowPda(creator.publicKey, newEscrowId);
+
+      await program.methods
+        .splitEscrow(newEscrowId, new anchor.BN(amount))
+        .accounts({
+          escrowAccount: escrowPda,
+          newEscrowAccount: newEscrowPda,
+          creator: creator.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      return newEscrowPda;
+    }
+
+    it("divides the escrowed amount between the original and a new escrow", async () => {
+      const escrowPda = await createUnaccepted();
+      const balanceBefore = await connection.getBalance(escrowPda);
+
+      const newEscrowPda = await split(escrowPda, splitAmount);
+
+      const original = await program.account.escrowAccount.fetch(escrowPda) as any;
+      const created = await program.account.escrowAccount.fetch(newEscrowPda) as any;
+      expect(original.amount.toNumber()).to.equal(totalAmount - splitAmount);
+      expect(created.amount.toNumber()).to.equal(splitAmount);
+      expect(original.amount.toNumber() + created.amount.toNumber()).to.equal(totalAmount);
+
+      expect(created.creator.toBase58()).to.equal(creator.publicKey.toBase58());
+      expect(created.recipient.toBase58()).to.equal(recipient.publicKey.toBase58());
+      expect(created.deadline.toNumber()).to.equal(original.deadline.toNumber());
+      expect(created.status).to.have.property("created");
+
+      // Lamports follow the amounts; both accounts stay rent-exempt
+      const rent = await connection.getMinimumBalanceForRentExemption((await connection.getAccountInfo(escrowPda))!.data.length);
+      expect(balanceBefore - (await connection.getBalance(escrowPda))).to.equal(splitAmount);
+      expect(await connection.getBalance(newEscrowPda)).to.equal(rent + splitAmount);
+    });
+
+    it("rejects a split of the whole amount", async () => {
+      const escrowPda = await createUnaccepted();
+
+      try {
+        await split(escrowPda, totalAmount);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("SplitAmountTooLarge");
+      }
+    });
+
+    it("cannot split an accepted escrow", async () => {
+      const escrowPda = await createUnaccepted();
+
+      await program.methods
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      try {
+        await split(escrowPda, splitAmount);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InvalidStatus");
+      }
+    });
+  });
+
+  describe("deadline boundary", () => {
+    // The deadline second itself counts as expired
+    const termsHash = Array(32).fill(0);
+    let deadline: anchor.BN;
+    let createdPda: PublicKey;
+    let refundPda: PublicKey;
+    let disputePda: PublicKey;
+
+    async function create(): Promise<PublicKey> {
+      const escrowId = randomEscrowId();
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, termsHash, feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      return escrowPda;
+    }
+
+    async function accept(escrowPda: PublicKey) {
+      await program.methods
+        .acceptTask(termsHash)
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+    }
+
+    before(async () => {
+      deadline = new anchor.BN((await chainTime(connection)) + 4);
+      createdPda = await create();
+      refundPda = await create();
+      disputePda = await create();
+      await accept(refundPda);
+      await accept(disputePda);
+
+      // Wait until the validator clock reaches the deadline
+      while ((await chainTime(connection)) < deadline.toNumber()) {
+        await new Promise((resolve) => setTimeout(resolve, 500));
+      }
+    });
+
+    it("rejects creating an escrow whose deadline is now", async () => {
+      deadline = new anchor.BN(await chainTime(connection));
+
+      try {
+        await create();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("DeadlineExpired");
+      }
+    });
+
+    it("rejects accept once the deadline is reached", async () => {
+      try {
+        await accept(createdPda);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("DeadlineExpired");
+      }
+    });
+
+    it("allows refunding an active escrow once the deadline is reached", async () => {
+      await program.methods
+        .requestRefund()
+        .accounts({ escrowAccount: refundPda, creator: creator.publicKey, escrowCounter: deriveCounterPda(creator.publicKey)[0] })
+        .signers([creator])
+        .rpc();
+
+      expect(await connection.getAccountInfo(refundPda)).to.be.null;
+    });
+
+    it("still allows disputing an active escrow once the deadline is reached", async () => {
+      await program.methods
+        .dispute(Array(64).fill(0))
+        .accounts({ escrowAccount: disputePda, disputer: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      const escrow = await program.account.escrowAccount.fetch(disputePda);
+      expect(escrow.status).to.have.property("disputed");
+    });
+  });
+
+  describe("dispute", () => {
+    let escrowPda: PublicKey;
+
+    before(async () => {
+      const escrowId = randomEscrowId();
+      const amount = new anchor.BN(1 * LAMPORTS_PER_SOL);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const termsHash = Array(32).fill(0);
+
+      [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTask(termsHash)
+        .accounts({
+          escrowAccount: escrowPda,
+          recipient: recipient.publicKey,
+        })
+        .signers([recipient])
+        .rpc();
+    });
+
+    it("creator can open a dispute", async () => {
+      const reason = "Work not delivered";
+      const reasonBytes = Buffer.alloc(64);
+      reasonBytes.write(reason.substring(0, 64));
+
+      await program.methods
+        .dispute(Array.from(reasonBytes))
+        .accounts({
+          escrowAccount: escrowPda,
+          disputer: creator.publicKey,
+        })
+        .signers([creator])
+        .rpc();
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda);
+      expect(escrow.status).to.have.property("disputed");
+
+      // Verify reason stored
+      const storedReason = Buffer.from(escrow.disputeReason as number[])
+        .toString("utf8")
+        .replace(/\0+$/, "");
+      expect(storedReason).to.equal(reason);
+    });
+
+    it("cannot dispute an escrow that is already disputed", async () => {
+      try {
+        await program.methods
+          .dispute(Array(64).fill(0))
+          .accounts({
+            escrowAccount: escrowPda,
+            disputer: recipient.publicKey,
+          })
+          .signers([recipient])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InvalidStatus");
+      }
+    });
+
+    describe("before acceptance", () => {
+      async function createUnaccepted(): Promise<PublicKey> {
+        const escrowId = randomEscrowId();
+        const [pda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+        await program.methods
+          .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), new anchor.BN(Math.floor(Date.now() / 1000) + 3600), Array(32).fill(7), feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+          .accounts({
+            escrowAccount: pda,
+            creator: creator.publicKey,
+            recipient: recipient.publicKey,
+            arbiter: arbiter.publicKey,
+            feeRecipient: treasury.publicKey,
+            systemProgram: SystemProgram.programId,
+            config: configPda,
+          })
+          .signers([creator])
+          .rpc();
+
+        return pda;
+      }
+
+      async function acknowledge(pda: PublicKey, termsAck: number[]) {
+        await program.methods
+          .acknowledgeTerms(termsAck)
+          .accounts({ escrowAccount: pda, recipient: recipient.publicKey })
+          .signers([recipient])
+          .rpc();
+      }
+
+      it("recipient cannot dispute without acknowledging the terms", async () => {
+        const pda = await createUnaccepted();
+
+        try {
+          await program.methods
+            .dispute(Array(64).fill(0))
+            .accounts({ escrowAccount: pda, disputer: recipient.publicKey })
+            .signers([recipient])
+            .rpc();
+          expect.fail("Should have thrown");
+        } catch (err: any) {
+          expect(err.error?.errorCode?.code || err.message).to.include("TermsNotAcknowledged");
+        }
+      });
+
+      it("rejects an acknowledgement of different terms", async () => {
+        const pda = await createUnaccepted();
+
+        try {
+          await acknowledge(pda, Array(32).fill(8));
+          expect.fail("Should have thrown");
+        } catch (err: any) {
+          expect(err.error?.errorCode?.code || err.message).to.include("TermsHashMismatch");
+        }
+      });
+
+      it("recipient can dispute after acknowledging the terms", async () => {
+        const pda = await createUnaccepted();
+        await acknowledge(pda, Array(32).fill(7));
+
+        const acked = await program.account.escrowAccount.fetch(pda) as any;
+        expect(acked.termsAckedAt.toNumber()).to.be.greaterThan(0);
+        expect(acked.status).to.have.property("created");
+
+        await program.methods
+          .dispute(Array(64).fill(0))
+          .accounts({ escrowAccount: pda, disputer: recipient.publicKey })
+          .signers([recipient])
+          .rpc();
+
+        const escrow = await program.account.escrowAccount.fetch(pda);
+        expect(escrow.status).to.have.property("disputed");
+      });
+
+      it("creator cannot dispute an escrow that was never accepted", async () => {
+        const pda = await createUnaccepted();
+        await acknowledge(pda, Array(32).fill(7));
+
+        try {
+          await program.methods
+            .dispute(Array(64).fill(0))
+            .accounts({ escrowAccount: pda, disputer: creator.publicKey })
+            .signers([creator])
+            .rpc();
+          expect.fail("Should have thrown");
+        } catch (err: any) {
+          expect(err.error?.errorCode?.code || err.message).to.include("UnauthorizedRecipient");
+        }
+      });
+    });
+  });
+
+  describe("dispute window", () => {
+    async function createAccepted(deadlineOffset: number, disputeWindow: number) {
+      const escrowId = randomEscrowId();
+      const now = await chainTime(connection);
+      const deadline = new anchor.BN(now + deadlineOffset);
+      const disputeDeadline = new anchor.BN(now + deadlineOffset + disputeWindow);
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), disputeDeadline)
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      return { escrowPda, disputeDeadline };
+    }
+
+    it("allows a dispute before the dispute deadline", async () => {
+      const { escrowPda, disputeDeadline } = await createAccepted(3600, 86400);
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.disputeDeadline.toNumber()).to.equal(disputeDeadline.toNumber());
+
+      await program.methods
+        .dispute(Array(64).fill(0))
+        .accounts({ escrowAccount: escrowPda, disputer: creator.publicKey })
+        .signers([creator])
+        .rpc();
+
+      const disputed = await program.account.escrowAccount.fetch(escrowPda);
+      expect(disputed.status).to.have.property("disputed");
+    });
+
+    it("rejects a dispute once the dispute deadline has passed", async () => {
+      const { escrowPda, disputeDeadline } = await createAccepted(4, 1);
+
+      while ((await chainTime(connection)) < disputeDeadline.toNumber()) {
+        await new Promise((resolve) => setTimeout(resolve, 500));
+      }
+
+      try {
+        await program.methods
+          .dispute(Array(64).fill(0))
+          .accounts({ escrowAccount: escrowPda, disputer: creator.publicKey })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("DisputeWindowClosed");
+      }
+    });
+
+    it("rejects a dispute deadline before the work deadline", async () => {
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      try {
+        await program.methods
+          .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), deadline.subn(1))
+          .accounts({
+            escrowAccount: escrowPda,
+            creator: creator.publicKey,
+            recipient: recipient.publicKey,
+            arbiter: arbiter.publicKey,
+            feeRecipient: treasury.publicKey,
+            systemProgram: SystemProgram.programId,
+            config: configPda,
+          })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InvalidDisputeDeadline");
+      }
+    });
+  });
+
+  describe("resolve_dispute", () => {
+    it("arbiter collects the arbitration fee even when the depositor loses", async () => {
+      const escrowId = randomEscrowId();
+      const escrowAmount = 0.5 * LAMPORTS_PER_SOL;
+      const arbitrationFee = 0.05 * LAMPORTS_PER_SOL;
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const termsHash = Array(32).fill(0);
+
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(escrowAmount), deadline, termsHash, feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTask(termsHash)
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      await program.methods
+        .dispute(Array(64).fill(0))
+        .accounts({ escrowAccount: escrowPda, disputer: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      const [bondReceiptPda] = deriveBondReceiptPda(escrowPda);
+      await program.methods
+        .requestArbitration(new anchor.BN(arbitrationFee))
+        .accounts({
+          escrowAccount: escrowPda,
+          bondReceipt: bondReceiptPda,
+          payer: recipient.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([recipient])
+        .rpc();
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.arbitrationFee.toNumber()).to.equal(arbitrationFee);
+      expect(escrow.arbitrationFeePayer.toBase58()).to.equal(recipient.publicKey.toBase58());
+
+      // The deposit sits in the bond receipt, not the escrow
+      const receipt = await program.account.bondReceipt.fetch(bondReceiptPda) as any;
+      expect(receipt.escrow.toBase58()).to.equal(escrowPda.toBase58());
+      expect(receipt.amount.toNumber()).to.equal(arbitrationFee);
+      expect(receipt.outcome).to.have.property("pending");
+      const receiptRent = (await connection.getBalance(bondReceiptPda)) - arbitrationFee;
+
+      const arbiterBefore = await connection.getBalance(arbiter.publicKey);
+      const recipientBefore = await connection.getBalance(recipient.publicKey);
+
+      // Recipient deposited the fee but the creator wins
+      await program.methods
+        .resolveDispute({ creator: {} }, Array(32).fill(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          arbiter: arbiter.publicKey,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          feeRecipient: treasury.publicKey,
+          config: configPda,
+          escrowCounter: deriveCounterPda(creator.publicKey)[0],
+          bondReceipt: bondReceiptPda,
+        })
+        .signers([arbiter])
+        .rpc();
+
+      expect((await connection.getBalance(arbiter.publicKey)) - arbiterBefore).to.equal(arbitrationFee);
+      // The ruling closes the receipt and returns its rent to the depositor
+      expect((await connection.getBalance(recipient.publicKey)) - recipientBefore).to.equal(receiptRent);
+      expect(await connection.getAccountInfo(bondReceiptPda)).to.be.null;
+    });
+
+    it("arbiter resolves in favor of recipient (payment released)", async () => {
+      const escrowId = randomEscrowId();
+      const escrowAmount = 1 * LAMPORTS_PER_SOL;
+      const amount = new anchor.BN(escrowAmount);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const termsHash = Array(32).fill(0);
+
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      // Create → Accept → Dispute
+      await program.methods
+        .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTask(termsHash)
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      const reasonBytes = Buffer.alloc(64);
+      reasonBytes.write("Dispute test");
+      await program.methods
+        .dispute(Array.from(reasonBytes))
+        .accounts({ escrowAccount: escrowPda, disputer: creator.publicKey })
+        .signers([creator])
+        .rpc();
+
+      const recipientBefore = await connection.getBalance(recipient.publicKey);
+      const treasuryBefore = await connection.getBalance(treasury.publicKey);
+
+      // Resolve: recipient wins
+      const resolutionHash = Array.from(createHash("sha256").update("Work delivered as specified").digest());
+      const resolveSig = await program.methods
+        .resolveDispute({ recipient: {} }, resolutionHash)
+        .accounts({
+          escrowAccount: escrowPda,
+          arbiter: arbiter.publicKey,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          feeRecipient: treasury.publicKey,
+          config: configPda,
+          escrowCounter: deriveCounterPda(creator.publicKey)[0],
+        })
+        .signers([arbiter])
+        .rpc({ commitment: "confirmed" });
+
+      // Resolution hash is published in the event since the account closes
+      const resolved = (await getEvents(connection, resolveSig)).find((e) => e.name === "DisputeResolved");
+      expect(resolved).to.not.be.undefined;
+      expect(resolved!.data.escrow.toBase58()).to.equal(escrowPda.toBase58());
+      expect(resolved!.data.arbiter.toBase58()).to.equal(arbiter.publicKey.toBase58());
+      expect(resolved!.data.winner).to.have.property("recipient");
+      expect(Array.from(resolved!.data.resolutionHash as number[])).to.deep.equal(resolutionHash);
+
+      const recipientAfter = await connection.getBalance(recipient.publicKey);
+      const treasuryAfter = await connection.getBalance(treasury.publicKey);
+
+      const expectedFee = Math.floor(escrowAmount * feeBasisPoints / 10000);
+      expect(recipientAfter - recipientBefore).to.equal(escrowAmount - expectedFee);
+      expect(treasuryAfter - treasuryBefore).to.equal(expectedFee);
+
+      // Account closed
+      const accountInfo = await connection.getAccountInfo(escrowPda);
+      expect(accountInfo).to.be.null;
+    });
+
+    it("arbiter resolves in favor of creator (full refund)", async () => {
+      const escrowId = randomEscrowId();
+      const escrowAmount = 1 * LAMPORTS_PER_SOL;
+      const amount = new anchor.BN(escrowAmount);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const termsHash = Array(32).fill(0);
+
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      // Create → Accept → Dispute
+      await program.methods
+        .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTask(termsHash)
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      const reasonBytes = Buffer.alloc(64);
+      reasonBytes.write("Creator dispute");
+      await program.methods
+        .dispute(Array.from(reasonBytes))
+        .accounts({ escrowAccount: escrowPda, disputer: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      const creatorBefore = await connection.getBalance(creator.publicKey);
+
+      // Resolve: creator wins → full refund, no fee
+      await program.methods
+        .resolveDispute({ creator: {} }, Array(32).fill(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          arbiter: arbiter.publicKey,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          feeRecipient: treasury.publicKey,
+          config: configPda,
+          escrowCounter: deriveCounterPda(creator.publicKey)[0],
+        })
+        .signers([arbiter])
+        .rpc();
+
+      const creatorAfter = await connection.getBalance(creator.publicKey);
+
+      // Creator gets full amount back + rent
+      expect(creatorAfter - creatorBefore).to.be.greaterThan(escrowAmount - 10000);
+
+      // Account closed
+      const accountInfo = await connection.getAccountInfo(escrowPda);
+      expect(accountInfo).to.be.null;
+    });
+
+    it("non-arbiter cannot resolve dispute", async () => {
+      const escrowId = randomEscrowId();
+      const amount = new anchor.BN(0.1 * LAMPORTS_PER_SOL);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const termsHash = Array(32).fill(0);
+
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTask(termsHash)
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      const reasonBytes = Buffer.alloc(64);
+      reasonBytes.write("Test");
+      await program.methods
+        .dispute(Array.from(reasonBytes))
+        .accounts({ escrowAccount: escrowPda, disputer: creator.publicKey })
+        .signers([creator])
+        .rpc();
+
+      // Creator tries to resolve (not arbiter)
+      try {
+        await program.methods
+          .resolveDispute({ recipient: {} }, Array(32).fill(0))
+          .accounts({
+            escrowAccount: escrowPda,
+            arbiter: creator.publicKey, // wrong person
+            creator: creator.publicKey,
+            recipient: recipient.publicKey,
+            feeRecipient: treasury.publicKey,
+            config: configPda,
+            escrowCounter: deriveCounterPda(creator.publicKey)[0],
+          })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("UnauthorizedArbiter");
+      }
+    });
+
+    it("arbiter who is also a party cannot resolve", async () => {
+      const escrowId = randomEscrowId();
+      const amount = new anchor.BN(0.1 * LAMPORTS_PER_SOL);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const termsHash = Array(32).fill(0);
+
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      // Creator names themselves as arbiter
+      await program.methods
+        .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: creator.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTask(termsHash)
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      const reasonBytes = Buffer.alloc(64);
+      reasonBytes.write("Test");
+      await program.methods
+        .dispute(Array.from(reasonBytes))
+        .accounts({ escrowAccount: escrowPda, disputer: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      try {
+        await program.methods
+          .resolveDispute({ creator: {} }, Array(32).fill(0))
+          .accounts({
+            escrowAccount: escrowPda,
+            arbiter: creator.publicKey,
+            creator: creator.publicKey,
+            recipient: recipient.publicKey,
+            feeRecipient: treasury.publicKey,
+            config: configPda,
+            escrowCounter: deriveCounterPda(creator.publicKey)[0],
+          })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("ConflictedArbiter");
+      }
+    });
+  });
+
+  describe("resolve_disputes_batch", () => {
+    const escrowAmount = 0.2 * LAMPORTS_PER_SOL;
+
+    async function createDisputed(): Promise<PublicKey> {
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(escrowAmount), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      await program.methods
+        .dispute(Array(64).fill(0))
+        .accounts({ escrowAccount: escrowPda, disputer: recipient.publicKey })
         .signers([recipient])
         .rpc();
 
-      // Try refund — should fail (deadline not reached)
+      return escrowPda;
+    }
+
+    function disputeAccounts(escrowPdas: PublicKey[]) {
+      return escrowPdas.flatMap((escrowPda) =>
+        [escrowPda, creator.publicKey, recipient.publicKey, treasury.publicKey, deriveCounterPda(creator.publicKey)[0], creator.publicKey, deriveReputationPda(creator.publicKey)[0], deriveReputationPda(recipient.publicKey)[0], deriveBondReceiptPda(escrowPda)[0]].map((pubkey) => ({
+          pubkey,
+          isWritable: true,
+          isSigner: false,
+        }))
+      );
+    }
+
+    it("resolves two disputes in one call", async () => {
+      const first = await createDisputed();
+      const second = await createDisputed();
+
+      const rent = (await connection.getBalance(first)) - escrowAmount;
+      const creatorBefore = await connection.getBalance(creator.publicKey);
+      const recipientBefore = await connection.getBalance(recipient.publicKey);
+      const treasuryBefore = await connection.getBalance(treasury.publicKey);
+
+      const hashes = [Array(32).fill(1), Array(32).fill(2)];
+      const sig = await program.methods
+        .resolveDisputesBatch([
+          { winner: { recipient: {} }, resolutionHash: hashes[0] },
+          { winner: { creator: {} }, resolutionHash: hashes[1] },
+        ])
+        .accounts({ arbiter: arbiter.publicKey, config: configPda })
+        .remainingAccounts(disputeAccounts([first, second]))
+        .signers([arbiter])
+        .rpc({ commitment: "confirmed" });
+
+      const fee = Math.floor(escrowAmount * feeBasisPoints / 10000);
+      expect((await connection.getBalance(recipient.publicKey)) - recipientBefore).to.equal(escrowAmount - fee);
+      expect((await connection.getBalance(treasury.publicKey)) - treasuryBefore).to.equal(fee);
+      // Second escrow refunded in full, plus rent from both closed accounts
+      expect((await connection.getBalance(creator.publicKey)) - creatorBefore).to.equal(escrowAmount + 2 * rent);
+
+      expect(await connection.getAccountInfo(first)).to.be.null;
+      expect(await connection.getAccountInfo(second)).to.be.null;
+
+      const resolved = (await getEvents(connection, sig)).filter((e) => e.name === "DisputeResolved");
+      expect(resolved.map((e) => e.data.escrow.toBase58())).to.deep.equal([first.toBase58(), second.toBase58()]);
+      expect(resolved.map((e) => e.data.resolutionHash)).to.deep.equal(hashes);
+    });
+
+    it("rejects a batch when any escrow names a different arbiter", async () => {
+      const escrowPda = await createDisputed();
+      const otherArbiter = Keypair.generate();
+      await airdrop(connection, otherArbiter.publicKey, 1);
+
       try {
         await program.methods
-          .requestRefund()
-          .accounts({
-            escrowAccount: escrowPda,
-            creator: creator.publicKey,
-          })
-          .signers([creator])
+          .resolveDisputesBatch([{ winner: { recipient: {} }, resolutionHash: Array(32).fill(0) }])
+          .accounts({ arbiter: otherArbiter.publicKey, config: configPda })
+          .remainingAccounts(disputeAccounts([escrowPda]))
+          .signers([otherArbiter])
           .rpc();
         expect.fail("Should have thrown");
       } catch (err: any) {
-        expect(err.error?.errorCode?.code || err.message).to.include("DeadlineNotReached");
+        expect(err.error?.errorCode?.code || err.message).to.include("UnauthorizedArbiter");
+      }
+    });
+
+    it("rejects resolutions that do not line up with the remaining accounts", async () => {
+      const escrowPda = await createDisputed();
+
+      try {
+        await program.methods
+          .resolveDisputesBatch([
+            { winner: { recipient: {} }, resolutionHash: Array(32).fill(0) },
+            { winner: { creator: {} }, resolutionHash: Array(32).fill(0) },
+          ])
+          .accounts({ arbiter: arbiter.publicKey, config: configPda })
+          .remainingAccounts(disputeAccounts([escrowPda]))
+          .signers([arbiter])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("BatchAccountMismatch");
       }
     });
   });
 
-  describe("dispute", () => {
+  describe("assign_arbiter", () => {
     let escrowPda: PublicKey;
+    const termsHash = Array(32).fill(0);
 
     before(async () => {
       const escrowId = randomEscrowId();
-      const amount = new anchor.BN(1 * LAMPORTS_PER_SOL);
       const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
-      const termsHash = Array(32).fill(0);
-
       [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
 
+      // No arbiter chosen up front
       await program.methods
-        .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0))
+        .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, termsHash, feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
         .accounts({
           escrowAccount: escrowPda,
           creator: creator.publicKey,
           recipient: recipient.publicKey,
-          arbiter: arbiter.publicKey,
+          arbiter: PublicKey.default,
           feeRecipient: treasury.publicKey,
           systemProgram: SystemProgram.programId,
+          config: configPda,
         })
         .signers([creator])
         .rpc();
 
       await program.methods
-        .acceptTask()
-        .accounts({
-          escrowAccount: escrowPda,
-          recipient: recipient.publicKey,
-        })
+        .acceptTask(termsHash)
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
         .signers([recipient])
         .rpc();
     });
 
-    it("creator can open a dispute", async () => {
-      const reason = "Work not delivered";
-      const reasonBytes = Buffer.alloc(64);
-      reasonBytes.write(reason.substring(0, 64));
+    it("creates an escrow with no arbiter", async () => {
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.arbiter.toBase58()).to.equal(PublicKey.default.toBase58());
+    });
+
+    it("cannot assign an arbiter before a dispute opens", async () => {
+      try {
+        await program.methods
+          .assignArbiter(arbiter.publicKey)
+          .accounts({ escrowAccount: escrowPda, creator: creator.publicKey, recipient: recipient.publicKey, config: configPda })
+          .signers([creator, recipient])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InvalidStatus");
+      }
+    });
 
+    it("one party cannot assign the arbiter alone", async () => {
       await program.methods
-        .dispute(Array.from(reasonBytes))
+        .dispute(Array(64).fill(0))
+        .accounts({ escrowAccount: escrowPda, disputer: creator.publicKey })
+        .signers([creator])
+        .rpc();
+
+      try {
+        await program.methods
+          .assignArbiter(arbiter.publicKey)
+          .accounts({ escrowAccount: escrowPda, creator: creator.publicKey, recipient: recipient.publicKey, config: configPda })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.message).to.include("Signature verification failed");
+      }
+    });
+
+    it("both parties assign the arbiter once disputed, who can then resolve", async () => {
+      await program.methods
+        .assignArbiter(arbiter.publicKey)
+        .accounts({ escrowAccount: escrowPda, creator: creator.publicKey, recipient: recipient.publicKey, config: configPda })
+        .signers([creator, recipient])
+        .rpc();
+
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.arbiter.toBase58()).to.equal(arbiter.publicKey.toBase58());
+
+      await program.methods
+        .resolveDispute({ creator: {} }, Array(32).fill(0))
         .accounts({
           escrowAccount: escrowPda,
-          disputer: creator.publicKey,
+          arbiter: arbiter.publicKey,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          feeRecipient: treasury.publicKey,
+          config: configPda,
+          escrowCounter: deriveCounterPda(creator.publicKey)[0],
         })
-        .signers([creator])
+        .signers([arbiter])
         .rpc();
 
-      const escrow = await program.account.escrowAccount.fetch(escrowPda);
-      expect(escrow.status).to.have.property("disputed");
-
-      // Verify reason stored
-      const storedReason = Buffer.from(escrow.disputeReason as number[])
-        .toString("utf8")
-        .replace(/\0+$/, "");
-      expect(storedReason).to.equal(reason);
+      expect(await connection.getAccountInfo(escrowPda)).to.be.null;
     });
   });
 
-  describe("resolve_dispute", () => {
-    it("arbiter resolves in favor of recipient (payment released)", async () => {
+  describe("full lifecycle", () => {
+    it("create → accept → release (happy path)", async () => {
       const escrowId = randomEscrowId();
-      const escrowAmount = 1 * LAMPORTS_PER_SOL;
-      const amount = new anchor.BN(escrowAmount);
+      const amount = new anchor.BN(0.5 * LAMPORTS_PER_SOL);
       const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
-      const termsHash = Array(32).fill(0);
+      const terms = "Deliver the feature by EOD";
+      const termsHash = Array.from(createHash("sha256").update(terms).digest());
 
       const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
 
-      // Create → Accept → Dispute
+      // 1. Create
       await program.methods
-        .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0))
+        .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
         .accounts({
           escrowAccount: escrowPda,
           creator: creator.publicKey,
@@ -490,64 +3685,56 @@ describe("escrow", () => {
           arbiter: arbiter.publicKey,
           feeRecipient: treasury.publicKey,
           systemProgram: SystemProgram.programId,
+          config: configPda,
         })
         .signers([creator])
         .rpc();
 
+      let escrow = await program.account.escrowAccount.fetch(escrowPda);
+      expect(escrow.status).to.have.property("created");
+
+      // 2. Accept
       await program.methods
-        .acceptTask()
+        .acceptTask(termsHash)
         .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
         .signers([recipient])
         .rpc();
 
-      const reasonBytes = Buffer.alloc(64);
-      reasonBytes.write("Dispute test");
-      await program.methods
-        .dispute(Array.from(reasonBytes))
-        .accounts({ escrowAccount: escrowPda, disputer: creator.publicKey })
-        .signers([creator])
-        .rpc();
-
-      const recipientBefore = await connection.getBalance(recipient.publicKey);
-      const treasuryBefore = await connection.getBalance(treasury.publicKey);
+      escrow = await program.account.escrowAccount.fetch(escrowPda);
+      expect(escrow.status).to.have.property("active");
 
-      // Resolve: recipient wins
+      // 3. Release
       await program.methods
-        .resolveDispute({ recipient: {} })
+        .releasePayment()
         .accounts({
           escrowAccount: escrowPda,
-          arbiter: arbiter.publicKey,
           creator: creator.publicKey,
           recipient: recipient.publicKey,
           feeRecipient: treasury.publicKey,
+          config: configPda,
+          escrowCounter: deriveCounterPda(creator.publicKey)[0],
         })
-        .signers([arbiter])
+        .signers([creator])
         .rpc();
 
-      const recipientAfter = await connection.getBalance(recipient.publicKey);
-      const treasuryAfter = await connection.getBalance(treasury.publicKey);
-
-      const expectedFee = Math.floor(escrowAmount * feeBasisPoints / 10000);
-      expect(recipientAfter - recipientBefore).to.equal(escrowAmount - expectedFee);
-      expect(treasuryAfter - treasuryBefore).to.equal(expectedFee);
-
-      // Account closed
-      const accountInfo = await connection.getAccountInfo(escrowPda);
-      expect(accountInfo).to.be.null;
+      // Account closed after release
+      const info = await connection.getAccountInfo(escrowPda);
+      expect(info).to.be.null;
     });
+  });
 
-    it("arbiter resolves in favor of creator (full refund)", async () => {
+  describe("audit log", () => {
+    // Mirrors AuditAction in state.rs
+    const CREATED = 0, ACCEPTED = 1, WORK_SUBMITTED = 2, RELEASE_REQUESTED = 3, RELEASED = 4, DISPUTED = 5, RESOLVED = 6;
+
+    async function createWithLog() {
       const escrowId = randomEscrowId();
-      const escrowAmount = 1 * LAMPORTS_PER_SOL;
-      const amount = new anchor.BN(escrowAmount);
       const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
-      const termsHash = Array(32).fill(0);
-
       const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+      const [auditLogPda] = deriveAuditLogPda(escrowPda);
 
-      // Create → Accept → Dispute
       await program.methods
-        .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0))
+        .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
         .accounts({
           escrowAccount: escrowPda,
           creator: creator.publicKey,
@@ -555,59 +3742,129 @@ describe("escrow", () => {
           arbiter: arbiter.publicKey,
           feeRecipient: treasury.publicKey,
           systemProgram: SystemProgram.programId,
+          config: configPda,
         })
         .signers([creator])
         .rpc();
 
       await program.methods
-        .acceptTask()
-        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .initAuditLog()
+        .accounts({
+          escrowAccount: escrowPda,
+          auditLog: auditLogPda,
+          creator: creator.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey, auditLog: auditLogPda })
         .signers([recipient])
         .rpc();
 
-      const reasonBytes = Buffer.alloc(64);
-      reasonBytes.write("Creator dispute");
+      return { escrowPda, auditLogPda };
+    }
+
+    it("accumulates entries across create → accept → submit → request → release", async () => {
+      const { escrowPda, auditLogPda } = await createWithLog();
+
       await program.methods
-        .dispute(Array.from(reasonBytes))
-        .accounts({ escrowAccount: escrowPda, disputer: recipient.publicKey })
+        .submitWork(Array(32).fill(3))
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey, auditLog: auditLogPda })
         .signers([recipient])
         .rpc();
 
-      const creatorBefore = await connection.getBalance(creator.publicKey);
+      await program.methods
+        .requestRelease()
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey, auditLog: auditLogPda })
+        .signers([recipient])
+        .rpc();
+
+      await program.methods
+        .releasePayment()
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          feeRecipient: treasury.publicKey,
+          auditLog: auditLogPda,
+          config: configPda,
+          escrowCounter: deriveCounterPda(creator.publicKey)[0],
+        })
+        .signers([creator])
+        .rpc();
+
+      // The escrow is closed but its trail stays queryable
+      expect(await connection.getAccountInfo(escrowPda)).to.be.null;
+
+      const log = await program.account.auditLog.fetch(auditLogPda) as any;
+      expect(log.escrow.toBase58()).to.equal(escrowPda.toBase58());
+      expect(log.entries.map((e: any) => e.action)).to.deep.equal([
+        CREATED, ACCEPTED, WORK_SUBMITTED, RELEASE_REQUESTED, RELEASED,
+      ]);
+      expect(log.entries.map((e: any) => e.actor.toBase58())).to.deep.equal([
+        creator.publicKey.toBase58(),
+        recipient.publicKey.toBase58(),
+        recipient.publicKey.toBase58(),
+        recipient.publicKey.toBase58(),
+        creator.publicKey.toBase58(),
+      ]);
+      for (let i = 1; i < log.entries.length; i++) {
+        expect(log.entries[i].timestamp.toNumber()).to.be.at.least(log.entries[i - 1].timestamp.toNumber());
+      }
+    });
+
+    it("records the disputer and arbiter on a disputed escrow", async () => {
+      const { escrowPda, auditLogPda } = await createWithLog();
+
+      await program.methods
+        .dispute(Array(64).fill(0))
+        .accounts({ escrowAccount: escrowPda, disputer: creator.publicKey, auditLog: auditLogPda })
+        .signers([creator])
+        .rpc();
 
-      // Resolve: creator wins → full refund, no fee
       await program.methods
-        .resolveDispute({ creator: {} })
+        .resolveDispute({ recipient: {} }, Array(32).fill(0))
         .accounts({
           escrowAccount: escrowPda,
           arbiter: arbiter.publicKey,
           creator: creator.publicKey,
           recipient: recipient.publicKey,
           feeRecipient: treasury.publicKey,
+          auditLog: auditLogPda,
+          config: configPda,
+          escrowCounter: deriveCounterPda(creator.publicKey)[0],
         })
         .signers([arbiter])
         .rpc();
 
-      const creatorAfter = await connection.getBalance(creator.publicKey);
+      const log = await program.account.auditLog.fetch(auditLogPda) as any;
+      expect(log.entries.map((e: any) => e.action)).to.deep.equal([CREATED, ACCEPTED, DISPUTED, RESOLVED]);
+      expect(log.entries[2].actor.toBase58()).to.equal(creator.publicKey.toBase58());
+      expect(log.entries[3].actor.toBase58()).to.equal(arbiter.publicKey.toBase58());
+    });
 
-      // Creator gets full amount back + rent
-      expect(creatorAfter - creatorBefore).to.be.greaterThan(escrowAmount - 10000);
+    it("grows one entry at a time without draining rent", async () => {
+      const { auditLogPda } = await createWithLog();
 
-      // Account closed
-      const accountInfo = await connection.getAccountInfo(escrowPda);
-      expect(accountInfo).to.be.null;
+      const info = await connection.getAccountInfo(auditLogPda);
+      // discriminator + escrow + vec prefix + bump, plus two 41-byte entries
+      expect(info!.data.length).to.equal(8 + 32 + 4 + 1 + 2 * 41);
+      // Rent for the full 32-entry capacity was deposited at init
+      const maxRent = await connection.getMinimumBalanceForRentExemption(8 + 32 + 4 + 1 + 32 * 41);
+      expect(info!.lamports).to.be.at.least(maxRent);
     });
 
-    it("non-arbiter cannot resolve dispute", async () => {
+    it("only the creator can open an escrow's log", async () => {
       const escrowId = randomEscrowId();
-      const amount = new anchor.BN(0.1 * LAMPORTS_PER_SOL);
       const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
-      const termsHash = Array(32).fill(0);
-
       const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+      const [auditLogPda] = deriveAuditLogPda(escrowPda);
 
       await program.methods
-        .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0))
+        .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
         .accounts({
           escrowAccount: escrowPda,
           creator: creator.publicKey,
@@ -615,111 +3872,64 @@ describe("escrow", () => {
           arbiter: arbiter.publicKey,
           feeRecipient: treasury.publicKey,
           systemProgram: SystemProgram.programId,
+          config: configPda,
         })
         .signers([creator])
         .rpc();
 
-      await program.methods
-        .acceptTask()
-        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
-        .signers([recipient])
-        .rpc();
-
-      const reasonBytes = Buffer.alloc(64);
-      reasonBytes.write("Test");
-      await program.methods
-        .dispute(Array.from(reasonBytes))
-        .accounts({ escrowAccount: escrowPda, disputer: creator.publicKey })
-        .signers([creator])
-        .rpc();
-
-      // Creator tries to resolve (not arbiter)
       try {
         await program.methods
-          .resolveDispute({ recipient: {} })
+          .initAuditLog()
           .accounts({
             escrowAccount: escrowPda,
-            arbiter: creator.publicKey, // wrong person
-            creator: creator.publicKey,
-            recipient: recipient.publicKey,
-            feeRecipient: treasury.publicKey,
+            auditLog: auditLogPda,
+            creator: recipient.publicKey,
+            systemProgram: SystemProgram.programId,
           })
-          .signers([creator])
+          .signers([recipient])
           .rpc();
         expect.fail("Should have thrown");
       } catch (err: any) {
-        expect(err.error?.errorCode?.code || err.message).to.include("UnauthorizedArbiter");
+        expect(err.error?.errorCode?.code || err.message).to.include("UnauthorizedCreator");
       }
     });
   });
 
-  describe("full lifecycle", () => {
-    it("create → accept → release (happy path)", async () => {
+  describe("auto_release", () => {
+    it("creates escrow with auto_release_at and stores it", async () => {
       const escrowId = randomEscrowId();
       const amount = new anchor.BN(0.5 * LAMPORTS_PER_SOL);
-      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
-      const terms = "Deliver the feature by EOD";
-      const termsHash = Array.from(createHash("sha256").update(terms).digest());
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600); // +1h
+      const autoReleaseAt = new anchor.BN(Math.floor(Date.now() / 1000) + 7200); // +2h (after deadline)
+      const termsHash = Array(32).fill(0);
 
       const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
 
-      // 1. Create
-      await program.methods
-        .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0))
-        .accounts({
-          escrowAccount: escrowPda,
-          creator: creator.publicKey,
-          recipient: recipient.publicKey,
-          arbiter: arbiter.publicKey,
-          feeRecipient: treasury.publicKey,
-          systemProgram: SystemProgram.programId,
-        })
-        .signers([creator])
-        .rpc();
-
-      let escrow = await program.account.escrowAccount.fetch(escrowPda);
-      expect(escrow.status).to.have.property("created");
-
-      // 2. Accept
-      await program.methods
-        .acceptTask()
-        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
-        .signers([recipient])
-        .rpc();
-
-      escrow = await program.account.escrowAccount.fetch(escrowPda);
-      expect(escrow.status).to.have.property("active");
-
-      // 3. Release
       await program.methods
-        .releasePayment()
+        .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, autoReleaseAt, new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
         .accounts({
           escrowAccount: escrowPda,
           creator: creator.publicKey,
           recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
           feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
         })
         .signers([creator])
         .rpc();
 
-      // Account closed after release
-      const info = await connection.getAccountInfo(escrowPda);
-      expect(info).to.be.null;
+      const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.autoReleaseAt.toNumber()).to.equal(autoReleaseAt.toNumber());
     });
-  });
 
-  describe("auto_release", () => {
-    it("creates escrow with auto_release_at and stores it", async () => {
+    async function createWithAutoReleaseDelay(autoReleaseAt: anchor.BN, delay: anchor.BN) {
       const escrowId = randomEscrowId();
-      const amount = new anchor.BN(0.5 * LAMPORTS_PER_SOL);
-      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600); // +1h
-      const autoReleaseAt = new anchor.BN(Math.floor(Date.now() / 1000) + 7200); // +2h (after deadline)
-      const termsHash = Array(32).fill(0);
-
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
       const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
 
       await program.methods
-        .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, autoReleaseAt)
+        .createEscrow(escrowId, new anchor.BN(0.5 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), feeBasisPoints, autoReleaseAt, new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", delay, new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
         .accounts({
           escrowAccount: escrowPda,
           creator: creator.publicKey,
@@ -727,12 +3937,40 @@ describe("escrow", () => {
           arbiter: arbiter.publicKey,
           feeRecipient: treasury.publicKey,
           systemProgram: SystemProgram.programId,
+          config: configPda,
         })
         .signers([creator])
         .rpc();
 
+      return { escrowPda, deadline };
+    }
+
+    it("derives auto_release_at from a delay after the deadline", async () => {
+      const sevenDays = 7 * 24 * 60 * 60;
+      const { escrowPda, deadline } = await createWithAutoReleaseDelay(new anchor.BN(0), new anchor.BN(sevenDays));
+
       const escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
-      expect(escrow.autoReleaseAt.toNumber()).to.equal(autoReleaseAt.toNumber());
+      expect(escrow.autoReleaseAt.toNumber()).to.equal(deadline.toNumber() + sevenDays);
+    });
+
+    it("rejects a non-positive auto-release delay", async () => {
+      try {
+        await createWithAutoReleaseDelay(new anchor.BN(0), new anchor.BN(-60));
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InvalidAutoRelease");
+      }
+    });
+
+    it("rejects passing both an absolute auto_release_at and a delay", async () => {
+      const autoReleaseAt = new anchor.BN(Math.floor(Date.now() / 1000) + 7200);
+
+      try {
+        await createWithAutoReleaseDelay(autoReleaseAt, new anchor.BN(3600));
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InvalidAutoRelease");
+      }
     });
 
     it("fails auto-release when disabled (auto_release_at = 0)", async () => {
@@ -745,7 +3983,7 @@ describe("escrow", () => {
 
       // Create with auto_release_at = 0 (disabled)
       await program.methods
-        .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0))
+        .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
         .accounts({
           escrowAccount: escrowPda,
           creator: creator.publicKey,
@@ -753,13 +3991,14 @@ describe("escrow", () => {
           arbiter: arbiter.publicKey,
           feeRecipient: treasury.publicKey,
           systemProgram: SystemProgram.programId,
+          config: configPda,
         })
         .signers([creator])
         .rpc();
 
       // Accept
       await program.methods
-        .acceptTask()
+        .acceptTask(termsHash)
         .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
         .signers([recipient])
         .rpc();
@@ -774,6 +4013,8 @@ describe("escrow", () => {
             creator: creator.publicKey,
             recipient: recipient.publicKey,
             feeRecipient: treasury.publicKey,
+            config: configPda,
+            escrowCounter: deriveCounterPda(creator.publicKey)[0],
           })
           .signers([creator])
           .rpc();
@@ -793,7 +4034,7 @@ describe("escrow", () => {
       const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
 
       await program.methods
-        .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, autoReleaseAt)
+        .createEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, autoReleaseAt, new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
         .accounts({
           escrowAccount: escrowPda,
           creator: creator.publicKey,
@@ -801,13 +4042,14 @@ describe("escrow", () => {
           arbiter: arbiter.publicKey,
           feeRecipient: treasury.publicKey,
           systemProgram: SystemProgram.programId,
+          config: configPda,
         })
         .signers([creator])
         .rpc();
 
       // Accept
       await program.methods
-        .acceptTask()
+        .acceptTask(termsHash)
         .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
         .signers([recipient])
         .rpc();
@@ -822,6 +4064,8 @@ describe("escrow", () => {
             creator: creator.publicKey,
             recipient: recipient.publicKey,
             feeRecipient: treasury.publicKey,
+            config: configPda,
+            escrowCounter: deriveCounterPda(creator.publicKey)[0],
           })
           .signers([creator])
           .rpc();
@@ -849,7 +4093,7 @@ describe("escrow", () => {
       const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
 
       await program.methods
-        .createEscrow(escrowId, amount, dl, termsHash, feeBasisPoints, ar)
+        .createEscrow(escrowId, amount, dl, termsHash, feeBasisPoints, ar, new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
         .accounts({
           escrowAccount: escrowPda,
           creator: creator.publicKey,
@@ -857,13 +4101,14 @@ describe("escrow", () => {
           arbiter: arbiter.publicKey,
           feeRecipient: treasury.publicKey,
           systemProgram: SystemProgram.programId,
+          config: configPda,
         })
         .signers([creator])
         .rpc();
 
       // Accept immediately (before deadline)
       await program.methods
-        .acceptTask()
+        .acceptTask(termsHash)
         .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
         .signers([recipient])
         .rpc();
@@ -878,7 +4123,7 @@ describe("escrow", () => {
       const thirdParty = Keypair.generate();
       await airdrop(connection, thirdParty.publicKey, 1);
 
-      await program.methods
+      const sig = await program.methods
         .autoRelease()
         .accounts({
           escrowAccount: escrowPda,
@@ -886,9 +4131,17 @@ describe("escrow", () => {
           creator: creator.publicKey,
           recipient: recipient.publicKey,
           feeRecipient: treasury.publicKey,
+          config: configPda,
+          escrowCounter: deriveCounterPda(creator.publicKey)[0],
         })
         .signers([thirdParty])
-        .rpc();
+        .rpc({ commitment: "confirmed" });
+
+      // Cranker is recorded in the event
+      const released = (await getEvents(connection, sig)).find((e) => e.name === "AutoReleased");
+      expect(released).to.not.be.undefined;
+      expect(released!.data.caller.toBase58()).to.equal(thirdParty.publicKey.toBase58());
+      expect(released!.data.amount.toNumber()).to.equal(escrowAmount);
 
       const recipientAfter = await connection.getBalance(recipient.publicKey);
       const treasuryAfter = await connection.getBalance(treasury.publicKey);
@@ -902,4 +4155,307 @@ describe("escrow", () => {
       expect(accountInfo).to.be.null;
     });
   });
+
+  describe("claim_auto_release", () => {
+    async function createAutoReleasable(): Promise<PublicKey> {
+      const escrowId = randomEscrowId();
+      const amount = new anchor.BN(1 * LAMPORTS_PER_SOL);
+      const now = Math.floor(Date.now() / 1000);
+      const dl = new anchor.BN(now + 2);
+      const ar = new anchor.BN(now + 3);
+      const termsHash = Array(32).fill(0);
+
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createEscrow(escrowId, amount, dl, termsHash, feeBasisPoints, ar, new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTask(termsHash)
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      return escrowPda;
+    }
+
+    it("recipient claims after the auto-release timestamp", async () => {
+      const escrowAmount = 1 * LAMPORTS_PER_SOL;
+      const escrowPda = await createAutoReleasable();
+
+      // Wait for auto-release timestamp to pass
+      await new Promise((resolve) => setTimeout(resolve, 5000));
+
+      const recipientBefore = await connection.getBalance(recipient.publicKey);
+      const treasuryBefore = await connection.getBalance(treasury.publicKey);
+
+      await program.methods
+        .claimAutoRelease()
+        .accounts({
+          escrowAccount: escrowPda,
+          recipient: recipient.publicKey,
+          creator: creator.publicKey,
+          feeRecipient: treasury.publicKey,
+          config: configPda,
+          escrowCounter: deriveCounterPda(creator.publicKey)[0],
+        })
+        .signers([recipient])
+        .rpc();
+
+      const recipientAfter = await connection.getBalance(recipient.publicKey);
+      const treasuryAfter = await connection.getBalance(treasury.publicKey);
+
+      const expectedFee = Math.floor(escrowAmount * feeBasisPoints / 10000);
+      expect(recipientAfter - recipientBefore).to.equal(escrowAmount - expectedFee);
+      expect(treasuryAfter - treasuryBefore).to.equal(expectedFee);
+
+      const accountInfo = await connection.getAccountInfo(escrowPda);
+      expect(accountInfo).to.be.null;
+    });
+
+    it("fails to claim before the auto-release timestamp", async () => {
+      const escrowPda = await createAutoReleasable();
+
+      try {
+        await program.methods
+          .claimAutoRelease()
+          .accounts({
+            escrowAccount: escrowPda,
+            recipient: recipient.publicKey,
+            creator: creator.publicKey,
+            feeRecipient: treasury.publicKey,
+            config: configPda,
+            escrowCounter: deriveCounterPda(creator.publicKey)[0],
+          })
+          .signers([recipient])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("AutoReleaseNotReady");
+      }
+    });
+
+    it("delegate claims on the recipient's behalf and the recipient is paid", async () => {
+      const escrowAmount = 1 * LAMPORTS_PER_SOL;
+      const hotKey = Keypair.generate();
+      await airdrop(connection, hotKey.publicKey, 1);
+
+      const escrowPda = await createAutoReleasable();
+      await program.methods
+        .setDelegate(hotKey.publicKey)
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      await new Promise((resolve) => setTimeout(resolve, 5000));
+
+      const recipientBefore = await connection.getBalance(recipient.publicKey);
+      const hotKeyBefore = await connection.getBalance(hotKey.publicKey);
+
+      await program.methods
+        .claimAutoRelease()
+        .accounts({
+          escrowAccount: escrowPda,
+          recipient: recipient.publicKey,
+          delegate: hotKey.publicKey,
+          creator: creator.publicKey,
+          feeRecipient: treasury.publicKey,
+          config: configPda,
+          escrowCounter: deriveCounterPda(creator.publicKey)[0],
+        })
+        .signers([hotKey])
+        .rpc();
+
+      const expectedFee = Math.floor(escrowAmount * feeBasisPoints / 10000);
+      expect((await connection.getBalance(recipient.publicKey)) - recipientBefore).to.equal(escrowAmount - expectedFee);
+      expect(await connection.getBalance(hotKey.publicKey)).to.equal(hotKeyBefore);
+      expect(await connection.getAccountInfo(escrowPda)).to.be.null;
+    });
+  });
+
+  describe("post-release window", () => {
+    async function createAndAutoRelease(windowSeconds: number): Promise<PublicKey> {
+      const escrowId = randomEscrowId();
+      const amount = new anchor.BN(0.5 * LAMPORTS_PER_SOL);
+      const now = Math.floor(Date.now() / 1000);
+      const dl = new anchor.BN(now + 2);
+      const ar = new anchor.BN(now + 3);
+      const termsHash = Array(32).fill(0);
+
+      const [escrowPda] = deriveEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createEscrow(escrowId, amount, dl, termsHash, feeBasisPoints, ar, new anchor.BN(0), 0, new anchor.BN(windowSeconds), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTask(termsHash)
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      await new Promise((resolve) => setTimeout(resolve, 5000));
+
+      await program.methods
+        .autoRelease()
+        .accounts({
+          escrowAccount: escrowPda,
+          caller: recipient.publicKey,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          feeRecipient: treasury.publicKey,
+          config: configPda,
+          escrowCounter: deriveCounterPda(creator.publicKey)[0],
+        })
+        .signers([recipient])
+        .rpc();
+
+      return escrowPda;
+    }
+
+    it("holds funds after auto-release and allows creator dispute within the window", async () => {
+      const escrowPda = await createAndAutoRelease(3600);
+
+      let escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.status).to.have.property("completed");
+      expect(escrow.releasedAt.toNumber()).to.be.greaterThan(0);
+
+      const reasonBytes = Buffer.alloc(64);
+      reasonBytes.write("Fraud discovered after release");
+      await program.methods
+        .dispute(Array.from(reasonBytes))
+        .accounts({ escrowAccount: escrowPda, disputer: creator.publicKey })
+        .signers([creator])
+        .rpc();
+
+      escrow = await program.account.escrowAccount.fetch(escrowPda) as any;
+      expect(escrow.status).to.have.property("disputed");
+
+      // Finalize is no longer possible once disputed
+      try {
+        await program.methods
+          .finalizeEscrow()
+          .accounts({
+            escrowAccount: escrowPda,
+            caller: recipient.publicKey,
+            creator: creator.publicKey,
+            recipient: recipient.publicKey,
+            feeRecipient: treasury.publicKey,
+            config: configPda,
+            escrowCounter: deriveCounterPda(creator.publicKey)[0],
+          })
+          .signers([recipient])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InvalidStatus");
+      }
+    });
+
+    it("a retried release on a held escrow returns AlreadyReleased", async () => {
+      const escrowPda = await createAndAutoRelease(3600);
+
+      try {
+        await program.methods
+          .autoRelease()
+          .accounts({
+            escrowAccount: escrowPda,
+            caller: recipient.publicKey,
+            creator: creator.publicKey,
+            recipient: recipient.publicKey,
+            feeRecipient: treasury.publicKey,
+            config: configPda,
+            escrowCounter: deriveCounterPda(creator.publicKey)[0],
+          })
+          .signers([recipient])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("AlreadyReleased");
+      }
+
+      try {
+        await program.methods
+          .releasePayment()
+          .accounts({
+            escrowAccount: escrowPda,
+            creator: creator.publicKey,
+            recipient: recipient.publicKey,
+            feeRecipient: treasury.publicKey,
+            config: configPda,
+            escrowCounter: deriveCounterPda(creator.publicKey)[0],
+          })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("AlreadyReleased");
+      }
+    });
+
+    it("rejects dispute after the window and finalizes payout", async () => {
+      const escrowAmount = 0.5 * LAMPORTS_PER_SOL;
+      const recipientBefore = await connection.getBalance(recipient.publicKey);
+      const escrowPda = await createAndAutoRelease(1);
+
+      // Wait for the post-release window to elapse
+      await new Promise((resolve) => setTimeout(resolve, 3000));
+
+      const reasonBytes = Buffer.alloc(64);
+      reasonBytes.write("Too late");
+      try {
+        await program.methods
+          .dispute(Array.from(reasonBytes))
+          .accounts({ escrowAccount: escrowPda, disputer: creator.publicKey })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("PostReleaseWindowClosed");
+      }
+
+      await program.methods
+        .finalizeEscrow()
+        .accounts({
+          escrowAccount: escrowPda,
+          caller: creator.publicKey,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          feeRecipient: treasury.publicKey,
+          config: configPda,
+          escrowCounter: deriveCounterPda(creator.publicKey)[0],
+        })
+        .signers([creator])
+        .rpc();
+
+      const recipientAfter = await connection.getBalance(recipient.publicKey);
+      const expectedFee = Math.floor(escrowAmount * feeBasisPoints / 10000);
+      // Recipient paid the accept + auto-release tx fees (5000 lamports each)
+      expect(recipientAfter - recipientBefore).to.equal(escrowAmount - expectedFee - 10000);
+
+      const accountInfo = await connection.getAccountInfo(escrowPda);
+      expect(accountInfo).to.be.null;
+    });
+  });
 });
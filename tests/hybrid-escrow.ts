@@ -0,0 +1,249 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+} from "@solana/web3.js";
+import {
+  createMint,
+  createAccount,
+  mintTo,
+  getAccount,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import { expect } from "chai";
+
+import idl from "../target/idl/escrow.json";
+
+const PROGRAM_ID = new PublicKey("EFnubV4grWUCFRPkRTTNVxEdetxYb8VJtAAqQQmxmw8X");
+const BPF_LOADER_UPGRADEABLE_ID = new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111");
+
+function deriveHybridEscrowPda(
+  creator: PublicKey,
+  escrowId: anchor.BN,
+): [PublicKey, number] {
+  const idBuffer = Buffer.alloc(8);
+  idBuffer.writeBigUInt64LE(BigInt(escrowId.toString()));
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("hybrid_escrow"), creator.toBuffer(), idBuffer],
+    PROGRAM_ID
+  );
+}
+
+function deriveHybridVaultPda(escrowPda: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("hybrid_vault"), escrowPda.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+async function airdrop(
+  connection: anchor.web3.Connection,
+  pubkey: PublicKey,
+  amount: number
+) {
+  const sig = await connection.requestAirdrop(pubkey, amount * LAMPORTS_PER_SOL);
+  await connection.confirmTransaction(sig, "confirmed");
+}
+
+function randomEscrowId(): anchor.BN {
+  const bytes = new Uint8Array(8);
+  for (let i = 0; i < 8; i++) bytes[i] = Math.floor(Math.random() * 256);
+  return new anchor.BN(Buffer.from(bytes), "le");
+}
+
+function deriveConfigPda(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("config")], PROGRAM_ID);
+}
+
+// Upgradeable loader account naming the program's upgrade authority, which
+// init_config requires to sign
+function deriveProgramDataPda(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([PROGRAM_ID.toBuffer()], BPF_LOADER_UPGRADEABLE_ID);
+}
+
+// These tests pass the config to create and release instructions. Initialize it
+// with every limit disabled unless another test file already has; the
+// provider wallet is the admin.
+async function ensureConfig(program: Program, configPda: PublicKey) {
+  if (await program.provider.connection.getAccountInfo(configPda)) return;
+
+  await program.methods
+    .initConfig({
+      maxAutoReleaseHorizon: new anchor.BN(0),
+      minArbiterStake: new anchor.BN(0),
+      feeDiscountMinTasks: 0,
+      minFeeBps: 0,
+      maxDeadlineHorizon: new anchor.BN(0),
+      crankRewardLamports: new anchor.BN(0),
+      minMilestoneAmount: new anchor.BN(0),
+      trustedMinTasks: 0,
+      forceCompleteDelay: new anchor.BN(0),
+      creatorInactivityWindow: new anchor.BN(0),
+      maxActivePerCreator: 0,
+      minArbiterCases: 0,
+      bondToArbiterBps: 0,
+    })
+    .accounts({
+      config: configPda,
+      admin: program.provider.publicKey,
+      program: PROGRAM_ID,
+      programData: deriveProgramDataPda()[0],
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+}
+
+describe("hybrid-escrow", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = new Program(idl as any, PROGRAM_ID, provider);
+  const connection = provider.connection;
+  const [configPda] = deriveConfigPda();
+
+  const creator = Keypair.generate();
+  const recipient = Keypair.generate();
+  const arbiter = Keypair.generate();
+  const treasury = Keypair.generate();
+
+  let mint: PublicKey;
+  let creatorAta: PublicKey;
+  let recipientAta: PublicKey;
+  let treasuryAta: PublicKey;
+
+  const feeBasisPoints = 150;
+  const lamportAmount = 1 * LAMPORTS_PER_SOL;
+  const tokenAmount = 1_000_000;
+
+  before(async () => {
+    await ensureConfig(program, configPda);
+    await airdrop(connection, creator.publicKey, 10);
+    await airdrop(connection, recipient.publicKey, 2);
+    await airdrop(connection, treasury.publicKey, 1);
+
+    mint = await createMint(connection, creator, creator.publicKey, null, 6);
+    creatorAta = await createAccount(connection, creator, mint, creator.publicKey);
+    recipientAta = await createAccount(connection, recipient, mint, recipient.publicKey);
+    treasuryAta = await createAccount(connection, treasury, mint, treasury.publicKey);
+    await mintTo(connection, creator, mint, creatorAta, creator, 10_000_000);
+  });
+
+  async function createHybrid(lamports: number, tokens: number) {
+    const escrowId = randomEscrowId();
+    const [escrowPda] = deriveHybridEscrowPda(creator.publicKey, escrowId);
+    const [vaultPda] = deriveHybridVaultPda(escrowPda);
+    const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+    await program.methods
+      .createHybridEscrow(escrowId, new anchor.BN(lamports), new anchor.BN(tokens), deadline, Array(32).fill(0), feeBasisPoints)
+      .accounts({
+        escrowAccount: escrowPda,
+        vault: vaultPda,
+        creator: creator.publicKey,
+        recipient: recipient.publicKey,
+        arbiter: arbiter.publicKey,
+        feeRecipient: treasury.publicKey,
+        mint,
+        creatorTokenAccount: creatorAta,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        config: configPda,
+      })
+      .signers([creator])
+      .rpc();
+
+    return { escrowPda, vaultPda };
+  }
+
+  it("creates a hybrid escrow holding both legs", async () => {
+    const { escrowPda, vaultPda } = await createHybrid(lamportAmount, tokenAmount);
+
+    const escrow = await program.account.hybridEscrowAccount.fetch(escrowPda) as any;
+    expect(escrow.lamportAmount.toNumber()).to.equal(lamportAmount);
+    expect(escrow.tokenAmount.toNumber()).to.equal(tokenAmount);
+    expect(escrow.status).to.have.property("created");
+    expect(escrow.version).to.equal(1);
+
+    const vault = await getAccount(connection, vaultPda);
+    expect(Number(vault.amount)).to.equal(tokenAmount);
+  });
+
+  it("rejects a zero token leg", async () => {
+    try {
+      await createHybrid(lamportAmount, 0);
+      expect.fail("Should have thrown");
+    } catch (err: any) {
+      expect(err.error?.errorCode?.code || err.message).to.include("ZeroAmount");
+    }
+  });
+
+  it("release settles both legs to the recipient atomically", async () => {
+    const { escrowPda, vaultPda } = await createHybrid(lamportAmount, tokenAmount);
+
+    await program.methods
+      .acceptHybridTask()
+      .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+      .signers([recipient])
+      .rpc();
+
+    const recipientLamportsBefore = await connection.getBalance(recipient.publicKey);
+    const treasuryLamportsBefore = await connection.getBalance(treasury.publicKey);
+    const recipientTokensBefore = Number((await getAccount(connection, recipientAta)).amount);
+    const treasuryTokensBefore = Number((await getAccount(connection, treasuryAta)).amount);
+
+    await program.methods
+      .releaseHybrid()
+      .accounts({
+        escrowAccount: escrowPda,
+        vault: vaultPda,
+        creator: creator.publicKey,
+        recipient: recipient.publicKey,
+        recipientTokenAccount: recipientAta,
+        feeRecipient: treasury.publicKey,
+        feeTokenAccount: treasuryAta,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        config: configPda,
+      })
+      .signers([creator])
+      .rpc();
+
+    const lamportFee = Math.floor(lamportAmount * feeBasisPoints / 10000);
+    const tokenFee = Math.floor(tokenAmount * feeBasisPoints / 10000);
+
+    expect((await connection.getBalance(recipient.publicKey)) - recipientLamportsBefore).to.equal(lamportAmount - lamportFee);
+    expect((await connection.getBalance(treasury.publicKey)) - treasuryLamportsBefore).to.equal(lamportFee);
+    expect(Number((await getAccount(connection, recipientAta)).amount) - recipientTokensBefore).to.equal(tokenAmount - tokenFee);
+    expect(Number((await getAccount(connection, treasuryAta)).amount) - treasuryTokensBefore).to.equal(tokenFee);
+
+    expect(await connection.getAccountInfo(escrowPda)).to.be.null;
+    expect(await connection.getAccountInfo(vaultPda)).to.be.null;
+  });
+
+  it("creator refunds both legs before acceptance", async () => {
+    const { escrowPda, vaultPda } = await createHybrid(lamportAmount, tokenAmount);
+
+    const creatorTokensBefore = Number((await getAccount(connection, creatorAta)).amount);
+    const creatorLamportsBefore = await connection.getBalance(creator.publicKey);
+
+    await program.methods
+      .refundHybrid()
+      .accounts({
+        escrowAccount: escrowPda,
+        vault: vaultPda,
+        creator: creator.publicKey,
+        creatorTokenAccount: creatorAta,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([creator])
+      .rpc();
+
+    expect(Number((await getAccount(connection, creatorAta)).amount) - creatorTokensBefore).to.equal(tokenAmount);
+    // SOL leg plus rent comes back with the closed accounts
+    expect((await connection.getBalance(creator.publicKey)) - creatorLamportsBefore).to.be.greaterThan(lamportAmount);
+    expect(await connection.getAccountInfo(escrowPda)).to.be.null;
+  });
+});
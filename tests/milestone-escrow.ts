@@ -10,8 +10,18 @@ import { expect } from "chai";
 import { createHash } from "crypto";
 
 import idl from "../target/idl/escrow.json";
+import legacyCreatorSecret from "./fixtures/legacy-creator.json";
+import legacyRecipientSecret from "./fixtures/legacy-recipient.json";
 
 const PROGRAM_ID = new PublicKey("EFnubV4grWUCFRPkRTTNVxEdetxYb8VJtAAqQQmxmw8X");
+const BPF_LOADER_UPGRADEABLE_ID = new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111");
+
+// Mirrors ACCOUNT_VERSION in state.rs
+const CURRENT_ACCOUNT_VERSION = 1;
+
+// Loaded by the test validator from tests/fixtures (see Anchor.toml): a
+// milestone escrow written before the version header
+const LEGACY_MILESTONE_ESCROW = new PublicKey("BWXzYbACDmK3kAHWT6PBZK9763tMYEd72hUJvuuQBMQ4");
 
 function deriveMilestoneEscrowPda(
   creator: PublicKey,
@@ -25,6 +35,32 @@ function deriveMilestoneEscrowPda(
   );
 }
 
+function deriveCompactMilestoneEscrowPda(
+  creator: PublicKey,
+  escrowId: anchor.BN,
+): [PublicKey, number] {
+  const idBuffer = Buffer.alloc(8);
+  idBuffer.writeBigUInt64LE(BigInt(escrowId.toString()));
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("compact_milestone_escrow"), creator.toBuffer(), idBuffer],
+    PROGRAM_ID
+  );
+}
+
+function deriveReputationPda(agent: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("reputation"), agent.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+function deriveTombstonePda(agent: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("reputation_tombstone"), agent.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
 async function airdrop(
   connection: anchor.web3.Connection,
   pubkey: PublicKey,
@@ -40,12 +76,67 @@ function randomEscrowId(): anchor.BN {
   return new anchor.BN(Buffer.from(bytes), "le");
 }
 
+async function getEvents(
+  connection: anchor.web3.Connection,
+  signature: string
+): Promise<anchor.Event[]> {
+  const tx = await connection.getTransaction(signature, {
+    commitment: "confirmed",
+    maxSupportedTransactionVersion: 0,
+  });
+  const parser = new anchor.EventParser(PROGRAM_ID, new anchor.BorshCoder(idl as any));
+  return Array.from(parser.parseLogs(tx?.meta?.logMessages ?? []));
+}
+
+function deriveConfigPda(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("config")], PROGRAM_ID);
+}
+
+// Upgradeable loader account naming the program's upgrade authority, which
+// init_config requires to sign
+function deriveProgramDataPda(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([PROGRAM_ID.toBuffer()], BPF_LOADER_UPGRADEABLE_ID);
+}
+
+// These tests pass the config to create and release instructions. Initialize it
+// with every limit disabled unless another test file already has; the
+// provider wallet is the admin.
+async function ensureConfig(program: Program, configPda: PublicKey) {
+  if (await program.provider.connection.getAccountInfo(configPda)) return;
+
+  await program.methods
+    .initConfig({
+      maxAutoReleaseHorizon: new anchor.BN(0),
+      minArbiterStake: new anchor.BN(0),
+      feeDiscountMinTasks: 0,
+      minFeeBps: 0,
+      maxDeadlineHorizon: new anchor.BN(0),
+      crankRewardLamports: new anchor.BN(0),
+      minMilestoneAmount: new anchor.BN(0),
+      trustedMinTasks: 0,
+      forceCompleteDelay: new anchor.BN(0),
+      creatorInactivityWindow: new anchor.BN(0),
+      maxActivePerCreator: 0,
+      minArbiterCases: 0,
+      bondToArbiterBps: 0,
+    })
+    .accounts({
+      config: configPda,
+      admin: program.provider.publicKey,
+      program: PROGRAM_ID,
+      programData: deriveProgramDataPda()[0],
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+}
+
 describe("milestone-escrow", () => {
   const provider = anchor.AnchorProvider.env();
   anchor.setProvider(provider);
 
   const program = new Program(idl as any, PROGRAM_ID, provider);
   const connection = provider.connection;
+  const [configPda] = deriveConfigPda();
 
   const creator = Keypair.generate();
   const recipient = Keypair.generate();
@@ -55,6 +146,7 @@ describe("milestone-escrow", () => {
   const feeBasisPoints = 150;
 
   before(async () => {
+    await ensureConfig(program, configPda);
     await airdrop(connection, creator.publicKey, 10);
     await airdrop(connection, recipient.publicKey, 2);
     await airdrop(connection, arbiter.publicKey, 1);
@@ -82,6 +174,7 @@ describe("milestone-escrow", () => {
         arbiter: arbiter.publicKey,
         feeRecipient: treasury.publicKey,
         systemProgram: SystemProgram.programId,
+        config: configPda,
       })
       .signers([creator])
       .rpc();
@@ -91,6 +184,7 @@ describe("milestone-escrow", () => {
     expect(escrow.totalAmount.toNumber()).to.equal(1 * LAMPORTS_PER_SOL);
     expect(escrow.releasedAmount.toNumber()).to.equal(0);
     expect(escrow.status).to.have.property("created");
+    expect(escrow.version).to.equal(1);
   });
 
   it("releases milestone 0, verifies partial payment", async () => {
@@ -117,6 +211,7 @@ describe("milestone-escrow", () => {
         arbiter: arbiter.publicKey,
         feeRecipient: treasury.publicKey,
         systemProgram: SystemProgram.programId,
+        config: configPda,
       })
       .signers([creator])
       .rpc();
@@ -139,6 +234,7 @@ describe("milestone-escrow", () => {
         creator: creator.publicKey,
         recipient: recipient.publicKey,
         feeRecipient: treasury.publicKey,
+        config: configPda,
       })
       .signers([creator])
       .rpc();
@@ -154,6 +250,65 @@ describe("milestone-escrow", () => {
     const escrow = await program.account.milestoneEscrowAccount.fetch(escrowPda) as any;
     expect(escrow.status).to.have.property("active");
     expect(escrow.releasedAmount.toNumber()).to.equal(m0Amount);
+
+    // Claimable figure reflects the remaining milestone
+    const claimable = await program.methods
+      .getClaimable()
+      .accounts({ escrowAccount: escrowPda })
+      .view();
+    expect(claimable.claimable.toNumber()).to.equal(m1Amount);
+    expect(claimable.released.toNumber()).to.equal(m0Amount);
+  });
+
+  it("releases a zero-fee milestone in full", async () => {
+    const escrowId = randomEscrowId();
+    const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+    const m0Amount = 0.5 * LAMPORTS_PER_SOL;
+
+    const milestones = [
+      { amount: new anchor.BN(m0Amount), descriptionHash: Array(32).fill(0) },
+      { amount: new anchor.BN(0.5 * LAMPORTS_PER_SOL), descriptionHash: Array(32).fill(1) },
+    ];
+
+    const [escrowPda] = deriveMilestoneEscrowPda(creator.publicKey, escrowId);
+
+    await program.methods
+      .createMilestoneEscrow(escrowId, deadline, Array(32).fill(0), 0, milestones)
+      .accounts({
+        escrowAccount: escrowPda,
+        creator: creator.publicKey,
+        recipient: recipient.publicKey,
+        arbiter: arbiter.publicKey,
+        feeRecipient: treasury.publicKey,
+        systemProgram: SystemProgram.programId,
+        config: configPda,
+      })
+      .signers([creator])
+      .rpc();
+
+    await program.methods
+      .acceptMilestoneTask()
+      .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+      .signers([recipient])
+      .rpc();
+
+    const recipientBefore = await connection.getBalance(recipient.publicKey);
+    const treasuryBefore = await connection.getBalance(treasury.publicKey);
+
+    await program.methods
+      .releaseMilestone(0)
+      .accounts({
+        escrowAccount: escrowPda,
+        creator: creator.publicKey,
+        recipient: recipient.publicKey,
+        feeRecipient: treasury.publicKey,
+        config: configPda,
+      })
+      .signers([creator])
+      .rpc();
+
+    expect((await connection.getBalance(recipient.publicKey)) - recipientBefore).to.equal(m0Amount);
+    expect(await connection.getBalance(treasury.publicKey)).to.equal(treasuryBefore);
   });
 
   it("release all milestones → account marked completed", async () => {
@@ -177,6 +332,7 @@ describe("milestone-escrow", () => {
         arbiter: arbiter.publicKey,
         feeRecipient: treasury.publicKey,
         systemProgram: SystemProgram.programId,
+        config: configPda,
       })
       .signers([creator])
       .rpc();
@@ -195,6 +351,7 @@ describe("milestone-escrow", () => {
         creator: creator.publicKey,
         recipient: recipient.publicKey,
         feeRecipient: treasury.publicKey,
+        config: configPda,
       })
       .signers([creator])
       .rpc();
@@ -206,12 +363,15 @@ describe("milestone-escrow", () => {
         creator: creator.publicKey,
         recipient: recipient.publicKey,
         feeRecipient: treasury.publicKey,
+        config: configPda,
       })
       .signers([creator])
       .rpc();
 
     const escrow = await program.account.milestoneEscrowAccount.fetch(escrowPda) as any;
     expect(escrow.status).to.have.property("completed");
+    expect(escrow.releasedAmount.toNumber()).to.equal(escrow.totalAmount.toNumber());
+    expect(escrow.releasedAmount.toNumber()).to.equal(1 * LAMPORTS_PER_SOL);
   });
 
   it("dispute single milestone → arbiter resolves", async () => {
@@ -236,6 +396,7 @@ describe("milestone-escrow", () => {
         arbiter: arbiter.publicKey,
         feeRecipient: treasury.publicKey,
         systemProgram: SystemProgram.programId,
+        config: configPda,
       })
       .signers([creator])
       .rpc();
@@ -247,14 +408,23 @@ describe("milestone-escrow", () => {
       .rpc();
 
     // Dispute milestone 0
+    const reasonBytes = Buffer.alloc(64);
+    reasonBytes.write("Phase 1 incomplete");
     await program.methods
-      .disputeMilestone(0)
+      .disputeMilestone(0, Array.from(reasonBytes))
       .accounts({ escrowAccount: escrowPda, disputer: creator.publicKey })
       .signers([creator])
       .rpc();
 
     let escrow = await program.account.milestoneEscrowAccount.fetch(escrowPda) as any;
-    expect(escrow.status).to.have.property("disputed");
+    expect(escrow.status).to.have.property("active"); // Only the milestone is frozen
+    expect(escrow.milestones[0].status).to.have.property("disputed");
+
+    // Verify reason stored
+    const storedReason = Buffer.from(escrow.disputeReason as number[])
+      .toString("utf8")
+      .replace(/\0+$/, "");
+    expect(storedReason).to.equal("Phase 1 incomplete");
 
     const recipientBefore = await connection.getBalance(recipient.publicKey);
 
@@ -276,6 +446,635 @@ describe("milestone-escrow", () => {
     expect(recipientAfter - recipientBefore).to.equal(m0Amount - expectedFee);
 
     escrow = await program.account.milestoneEscrowAccount.fetch(escrowPda) as any;
-    expect(escrow.status).to.have.property("active"); // Back to active, milestone 1 still pending
+    expect(escrow.status).to.have.property("active"); // Milestone 1 still pending
+  });
+
+  it("milestone dispute resolution updates both parties' reputation", async () => {
+    const [creatorRep] = deriveReputationPda(creator.publicKey);
+    const [recipientRep] = deriveReputationPda(recipient.publicKey);
+    for (const [agent, rep] of [[creator, creatorRep], [recipient, recipientRep]] as [Keypair, PublicKey][]) {
+      await program.methods
+        .initReputation()
+        .accounts({
+          reputationAccount: rep,
+          agent: agent.publicKey,
+          tombstone: deriveTombstonePda(agent.publicKey)[0],
+          payer: agent.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent])
+        .rpc();
+    }
+
+    const escrowId = randomEscrowId();
+    const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+    const milestones = [
+      { amount: new anchor.BN(0.2 * LAMPORTS_PER_SOL), descriptionHash: Array(32).fill(0) },
+      { amount: new anchor.BN(0.2 * LAMPORTS_PER_SOL), descriptionHash: Array(32).fill(1) },
+    ];
+
+    const [escrowPda] = deriveMilestoneEscrowPda(creator.publicKey, escrowId);
+
+    await program.methods
+      .createMilestoneEscrow(escrowId, deadline, Array(32).fill(0), feeBasisPoints, milestones)
+      .accounts({
+        escrowAccount: escrowPda,
+        creator: creator.publicKey,
+        recipient: recipient.publicKey,
+        arbiter: arbiter.publicKey,
+        feeRecipient: treasury.publicKey,
+        systemProgram: SystemProgram.programId,
+        config: configPda,
+      })
+      .signers([creator])
+      .rpc();
+
+    await program.methods
+      .acceptMilestoneTask()
+      .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+      .signers([recipient])
+      .rpc();
+
+    await program.methods
+      .disputeMilestone(0, Array(64).fill(0))
+      .accounts({ escrowAccount: escrowPda, disputer: creator.publicKey })
+      .signers([creator])
+      .rpc();
+
+    await program.methods
+      .resolveMilestoneDispute(0, { recipient: {} })
+      .accounts({
+        escrowAccount: escrowPda,
+        arbiter: arbiter.publicKey,
+        creator: creator.publicKey,
+        recipient: recipient.publicKey,
+        feeRecipient: treasury.publicKey,
+        creatorReputation: creatorRep,
+        recipientReputation: recipientRep,
+      })
+      .signers([arbiter])
+      .rpc();
+
+    const winner = await program.account.reputationAccount.fetch(recipientRep) as any;
+    const loser = await program.account.reputationAccount.fetch(creatorRep) as any;
+    expect(winner.disputesWon).to.equal(1);
+    expect(winner.disputesLost).to.equal(0);
+    expect(loser.disputesLost).to.equal(1);
+    expect(loser.disputesWon).to.equal(0);
+  });
+
+  it("fails when recipient == creator", async () => {
+    const escrowId = randomEscrowId();
+    const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+    const milestones = [
+      { amount: new anchor.BN(0.1 * LAMPORTS_PER_SOL), descriptionHash: Array(32).fill(0) },
+    ];
+
+    const [escrowPda] = deriveMilestoneEscrowPda(creator.publicKey, escrowId);
+
+    try {
+      await program.methods
+        .createMilestoneEscrow(escrowId, deadline, Array(32).fill(0), feeBasisPoints, milestones)
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: creator.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+      expect.fail("Should have thrown");
+    } catch (err: any) {
+      expect(err.error?.errorCode?.code || err.message).to.include("SelfEscrow");
+    }
+  });
+
+  it("releases milestone 1 while milestone 0 is under dispute", async () => {
+    const escrowId = randomEscrowId();
+    const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+    const m1Amount = 0.4 * LAMPORTS_PER_SOL;
+    const milestones = [
+      { amount: new anchor.BN(0.6 * LAMPORTS_PER_SOL), descriptionHash: Array(32).fill(0) },
+      { amount: new anchor.BN(m1Amount), descriptionHash: Array(32).fill(1) },
+    ];
+
+    const [escrowPda] = deriveMilestoneEscrowPda(creator.publicKey, escrowId);
+
+    await program.methods
+      .createMilestoneEscrow(escrowId, deadline, Array(32).fill(0), feeBasisPoints, milestones)
+      .accounts({
+        escrowAccount: escrowPda,
+        creator: creator.publicKey,
+        recipient: recipient.publicKey,
+        arbiter: arbiter.publicKey,
+        feeRecipient: treasury.publicKey,
+        systemProgram: SystemProgram.programId,
+        config: configPda,
+      })
+      .signers([creator])
+      .rpc();
+
+    await program.methods
+      .acceptMilestoneTask()
+      .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+      .signers([recipient])
+      .rpc();
+
+    await program.methods
+      .disputeMilestone(0, Array(64).fill(0))
+      .accounts({ escrowAccount: escrowPda, disputer: recipient.publicKey })
+      .signers([recipient])
+      .rpc();
+
+    // Disputed milestone cannot be released
+    try {
+      await program.methods
+        .releaseMilestone(0)
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          feeRecipient: treasury.publicKey,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+      expect.fail("Should have thrown");
+    } catch (err: any) {
+      expect(err.error?.errorCode?.code || err.message).to.include("MilestoneDisputed");
+    }
+
+    const recipientBefore = await connection.getBalance(recipient.publicKey);
+
+    // Unrelated milestone releases normally
+    await program.methods
+      .releaseMilestone(1)
+      .accounts({
+        escrowAccount: escrowPda,
+        creator: creator.publicKey,
+        recipient: recipient.publicKey,
+        feeRecipient: treasury.publicKey,
+        config: configPda,
+      })
+      .signers([creator])
+      .rpc();
+
+    const recipientAfter = await connection.getBalance(recipient.publicKey);
+    const expectedFee = Math.floor(m1Amount * feeBasisPoints / 10000);
+    expect(recipientAfter - recipientBefore).to.equal(m1Amount - expectedFee);
+
+    const escrow = await program.account.milestoneEscrowAccount.fetch(escrowPda) as any;
+    expect(escrow.status).to.have.property("active");
+    expect(escrow.milestones[0].status).to.have.property("disputed");
+    expect(escrow.milestones[1].status).to.have.property("released");
+  });
+
+  it("counts each milestone dispute, records each disputer and reports the total in DisputeOpened", async () => {
+    const escrowId = randomEscrowId();
+    const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+    const milestones = [0, 1, 2].map((i) => ({
+      amount: new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+      descriptionHash: Array(32).fill(i),
+    }));
+
+    const [escrowPda] = deriveMilestoneEscrowPda(creator.publicKey, escrowId);
+
+    await program.methods
+      .createMilestoneEscrow(escrowId, deadline, Array(32).fill(0), feeBasisPoints, milestones)
+      .accounts({
+        escrowAccount: escrowPda,
+        creator: creator.publicKey,
+        recipient: recipient.publicKey,
+        arbiter: arbiter.publicKey,
+        feeRecipient: treasury.publicKey,
+        systemProgram: SystemProgram.programId,
+        config: configPda,
+      })
+      .signers([creator])
+      .rpc();
+
+    await program.methods
+      .acceptMilestoneTask()
+      .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+      .signers([recipient])
+      .rpc();
+
+    let escrow = await program.account.milestoneEscrowAccount.fetch(escrowPda) as any;
+    expect(escrow.disputeCount).to.equal(0);
+
+    for (const [index, disputer] of [[0, creator], [1, recipient]] as [number, Keypair][]) {
+      const sig = await program.methods
+        .disputeMilestone(index, Array(64).fill(0))
+        .accounts({ escrowAccount: escrowPda, disputer: disputer.publicKey })
+        .signers([disputer])
+        .rpc({ commitment: "confirmed" });
+
+      const opened = (await getEvents(connection, sig)).find((e) => e.name === "DisputeOpened");
+      expect(opened).to.not.be.undefined;
+      expect(opened!.data.disputer.toBase58()).to.equal(disputer.publicKey.toBase58());
+      expect(opened!.data.disputeCount).to.equal(index + 1);
+    }
+
+    escrow = await program.account.milestoneEscrowAccount.fetch(escrowPda) as any;
+    expect(escrow.disputeCount).to.equal(2);
+
+    // Each milestone records who opened its dispute
+    expect(escrow.milestoneDisputedBy[0].toBase58()).to.equal(creator.publicKey.toBase58());
+    expect(escrow.milestoneDisputedBy[1].toBase58()).to.equal(recipient.publicKey.toBase58());
+    expect(escrow.milestoneDisputedBy[2].toBase58()).to.equal(PublicKey.default.toBase58());
+  });
+
+  it("blocks a new milestone dispute during the cooldown after a resolution", async () => {
+    const escrowId = randomEscrowId();
+    const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+    const milestones = [
+      { amount: new anchor.BN(0.2 * LAMPORTS_PER_SOL), descriptionHash: Array(32).fill(0) },
+      { amount: new anchor.BN(0.2 * LAMPORTS_PER_SOL), descriptionHash: Array(32).fill(1) },
+    ];
+
+    const [escrowPda] = deriveMilestoneEscrowPda(creator.publicKey, escrowId);
+
+    await program.methods
+      .createMilestoneEscrow(escrowId, deadline, Array(32).fill(0), feeBasisPoints, milestones)
+      .accounts({
+        escrowAccount: escrowPda,
+        creator: creator.publicKey,
+        recipient: recipient.publicKey,
+        arbiter: arbiter.publicKey,
+        feeRecipient: treasury.publicKey,
+        systemProgram: SystemProgram.programId,
+        config: configPda,
+      })
+      .signers([creator])
+      .rpc();
+
+    await program.methods
+      .acceptMilestoneTask()
+      .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+      .signers([recipient])
+      .rpc();
+
+    await program.methods
+      .disputeMilestone(0, Array(64).fill(0))
+      .accounts({ escrowAccount: escrowPda, disputer: creator.publicKey })
+      .signers([creator])
+      .rpc();
+
+    await program.methods
+      .resolveMilestoneDispute(0, { creator: {} })
+      .accounts({
+        escrowAccount: escrowPda,
+        arbiter: arbiter.publicKey,
+        creator: creator.publicKey,
+        recipient: recipient.publicKey,
+        feeRecipient: treasury.publicKey,
+      })
+      .signers([arbiter])
+      .rpc();
+
+    const escrow = await program.account.milestoneEscrowAccount.fetch(escrowPda) as any;
+    expect(escrow.lastDisputeTs.toNumber()).to.be.greaterThan(0);
+
+    // Immediately re-dispute the remaining milestone
+    try {
+      await program.methods
+        .disputeMilestone(1, Array(64).fill(0))
+        .accounts({ escrowAccount: escrowPda, disputer: creator.publicKey })
+        .signers([creator])
+        .rpc();
+      expect.fail("Should have thrown");
+    } catch (err: any) {
+      expect(err.error?.errorCode?.code || err.message).to.include("DisputeCooldown");
+    }
+  });
+
+  it("accepts 2 of 4 milestones and refunds the rest to the creator", async () => {
+    const escrowId = randomEscrowId();
+    const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+    const amounts = [0.1, 0.2, 0.3, 0.4].map((sol) => sol * LAMPORTS_PER_SOL);
+
+    const milestones = amounts.map((amount, i) => ({
+      amount: new anchor.BN(amount),
+      descriptionHash: Array(32).fill(i),
+    }));
+
+    const [escrowPda] = deriveMilestoneEscrowPda(creator.publicKey, escrowId);
+
+    await program.methods
+      .createMilestoneEscrow(escrowId, deadline, Array(32).fill(0), feeBasisPoints, milestones)
+      .accounts({
+        escrowAccount: escrowPda,
+        creator: creator.publicKey,
+        recipient: recipient.publicKey,
+        arbiter: arbiter.publicKey,
+        feeRecipient: treasury.publicKey,
+        systemProgram: SystemProgram.programId,
+        config: configPda,
+      })
+      .signers([creator])
+      .rpc();
+
+    const creatorBefore = await connection.getBalance(creator.publicKey);
+
+    // Recipient commits to milestones 1 and 3 only
+    await program.methods
+      .acceptMilestones(Buffer.from([1, 3]))
+      .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey, creator: creator.publicKey })
+      .signers([recipient])
+      .rpc();
+
+    expect((await connection.getBalance(creator.publicKey)) - creatorBefore).to.equal(amounts[0] + amounts[2]);
+
+    let escrow = await program.account.milestoneEscrowAccount.fetch(escrowPda) as any;
+    expect(escrow.status).to.have.property("active");
+    expect(escrow.totalAmount.toNumber()).to.equal(amounts[1] + amounts[3]);
+    expect(escrow.milestones[0].status).to.have.property("refunded");
+    expect(escrow.milestones[1].status).to.have.property("accepted");
+    expect(escrow.milestones[2].status).to.have.property("refunded");
+    expect(escrow.milestones[3].status).to.have.property("accepted");
+
+    // Declined milestones can't be released
+    try {
+      await program.methods
+        .releaseMilestone(0)
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          feeRecipient: treasury.publicKey,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+      expect.fail("Should have thrown");
+    } catch (err: any) {
+      expect(err.error?.errorCode?.code || err.message).to.include("MilestoneAlreadyReleased");
+    }
+
+    // Releasing both accepted milestones completes the escrow
+    for (const index of [1, 3]) {
+      await program.methods
+        .releaseMilestone(index)
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          feeRecipient: treasury.publicKey,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+    }
+
+    escrow = await program.account.milestoneEscrowAccount.fetch(escrowPda) as any;
+    expect(escrow.status).to.have.property("completed");
+    expect(escrow.releasedAmount.toNumber()).to.equal(escrow.totalAmount.toNumber());
+  });
+
+  it("rejects duplicate indices in accept_milestones", async () => {
+    const escrowId = randomEscrowId();
+    const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+    const milestones = [0, 1, 2].map((i) => ({
+      amount: new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+      descriptionHash: Array(32).fill(i),
+    }));
+
+    const [escrowPda] = deriveMilestoneEscrowPda(creator.publicKey, escrowId);
+
+    await program.methods
+      .createMilestoneEscrow(escrowId, deadline, Array(32).fill(0), feeBasisPoints, milestones)
+      .accounts({
+        escrowAccount: escrowPda,
+        creator: creator.publicKey,
+        recipient: recipient.publicKey,
+        arbiter: arbiter.publicKey,
+        feeRecipient: treasury.publicKey,
+        systemProgram: SystemProgram.programId,
+        config: configPda,
+      })
+      .signers([creator])
+      .rpc();
+
+    try {
+      await program.methods
+        .acceptMilestones(Buffer.from([1, 1, 2]))
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey, creator: creator.publicKey })
+        .signers([recipient])
+        .rpc();
+      expect.fail("Should have thrown");
+    } catch (err: any) {
+      expect(err.error?.errorCode?.code || err.message).to.include("DuplicateMilestoneIndex");
+    }
+
+    const escrow = await program.account.milestoneEscrowAccount.fetch(escrowPda) as any;
+    expect(escrow.status).to.have.property("created");
+  });
+
+  describe("compact layout", () => {
+    // Milestone::SPACE in state.rs
+    const MILESTONE_SPACE = 73;
+
+    function inputs(count: number) {
+      return Array.from({ length: count }, (_, i) => ({
+        amount: new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+        descriptionHash: Array(32).fill(i),
+      }));
+    }
+
+    async function createCompact(count: number): Promise<PublicKey> {
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const [escrowPda] = deriveCompactMilestoneEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createMilestoneEscrowCompact(escrowId, deadline, Array(32).fill(0), feeBasisPoints, inputs(count))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      return escrowPda;
+    }
+
+    it("sizes the account to the number of milestones", async () => {
+      const twoPda = await createCompact(2);
+      const tenPda = await createCompact(10);
+
+      const twoSize = (await connection.getAccountInfo(twoPda))!.data.length;
+      const tenSize = (await connection.getAccountInfo(tenPda))!.data.length;
+      expect(tenSize - twoSize).to.equal(8 * MILESTONE_SPACE);
+
+      const escrow = await program.account.compactMilestoneEscrowAccount.fetch(twoPda) as any;
+      expect(escrow.milestones).to.have.length(2);
+      expect(escrow.totalAmount.toNumber()).to.equal(2 * 0.1 * LAMPORTS_PER_SOL);
+    });
+
+    it("is smaller than the fixed layout for a two-milestone escrow", async () => {
+      const compactPda = await createCompact(2);
+
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const [fixedPda] = deriveMilestoneEscrowPda(creator.publicKey, escrowId);
+
+      await program.methods
+        .createMilestoneEscrow(escrowId, deadline, Array(32).fill(0), feeBasisPoints, inputs(2))
+        .accounts({
+          escrowAccount: fixedPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      const compact = await connection.getAccountInfo(compactPda);
+      const fixed = await connection.getAccountInfo(fixedPda);
+      expect(compact!.data.length).to.be.lessThan(fixed!.data.length);
+      expect(compact!.lamports).to.be.lessThan(fixed!.lamports);
+    });
+
+    it("releases every milestone and completes", async () => {
+      const escrowPda = await createCompact(2);
+
+      await program.methods
+        .acceptCompactMilestoneTask()
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      for (const index of [0, 1]) {
+        await program.methods
+          .releaseCompactMilestone(index)
+          .accounts({
+            escrowAccount: escrowPda,
+            creator: creator.publicKey,
+            recipient: recipient.publicKey,
+            feeRecipient: treasury.publicKey,
+            config: configPda,
+          })
+          .signers([creator])
+          .rpc();
+      }
+
+      const escrow = await program.account.compactMilestoneEscrowAccount.fetch(escrowPda) as any;
+      expect(escrow.status).to.have.property("completed");
+      expect(escrow.releasedAmount.toNumber()).to.equal(escrow.totalAmount.toNumber());
+
+      try {
+        await program.methods
+          .releaseCompactMilestone(2)
+          .accounts({
+            escrowAccount: escrowPda,
+            creator: creator.publicKey,
+            recipient: recipient.publicKey,
+            feeRecipient: treasury.publicKey,
+            config: configPda,
+          })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        // Completed escrows reject any further release
+        expect(err.error?.errorCode?.code || err.message).to.include("InvalidStatus");
+      }
+    });
+
+    it("creator cancels an unaccepted compact escrow", async () => {
+      const escrowPda = await createCompact(3);
+
+      await program.methods
+        .refundCompactMilestoneEscrow()
+        .accounts({ escrowAccount: escrowPda, creator: creator.publicKey })
+        .signers([creator])
+        .rpc();
+
+      expect(await connection.getAccountInfo(escrowPda)).to.be.null;
+    });
+  });
+
+  describe("migrate_milestone_escrow", () => {
+    const legacyCreator = Keypair.fromSecretKey(Uint8Array.from(legacyCreatorSecret));
+    const legacyRecipient = Keypair.fromSecretKey(Uint8Array.from(legacyRecipientSecret));
+
+    before(async () => {
+      await airdrop(connection, legacyCreator.publicKey, 1);
+    });
+
+    it("only the creator can migrate", async () => {
+      try {
+        await program.methods
+          .migrateMilestoneEscrow()
+          .accounts({
+            escrowAccount: LEGACY_MILESTONE_ESCROW,
+            creator: legacyRecipient.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([legacyRecipient])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("UnauthorizedCreator");
+      }
+    });
+
+    it("moves a pre-version milestone escrow behind the header so it can be used again", async () => {
+      // Handlers refuse the old layout until it is migrated
+      try {
+        await program.methods
+          .acceptMilestoneTask()
+          .accounts({ escrowAccount: LEGACY_MILESTONE_ESCROW, recipient: legacyRecipient.publicKey })
+          .signers([legacyRecipient])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("AccountDidNotDeserialize");
+      }
+
+      await program.methods
+        .migrateMilestoneEscrow()
+        .accounts({
+          escrowAccount: LEGACY_MILESTONE_ESCROW,
+          creator: legacyCreator.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([legacyCreator])
+        .rpc();
+
+      const escrow = await program.account.milestoneEscrowAccount.fetch(LEGACY_MILESTONE_ESCROW) as any;
+      expect(escrow.version).to.equal(CURRENT_ACCOUNT_VERSION);
+      expect(escrow.creator.toBase58()).to.equal(legacyCreator.publicKey.toBase58());
+      expect(escrow.totalAmount.toNumber()).to.equal(0.3 * LAMPORTS_PER_SOL);
+      expect(escrow.milestoneCount).to.equal(3);
+      // Every milestone, not just the first, survives the shift intact
+      escrow.milestones.slice(0, 3).forEach((m: any, i: number) => {
+        expect(m.amount.toNumber()).to.equal(0.1 * LAMPORTS_PER_SOL);
+        expect(m.status).to.have.property("pending");
+        expect(m.descriptionHash).to.deep.equal(Array(32).fill(i + 1));
+      });
+      expect(escrow.milestones[3].amount.toNumber()).to.equal(0);
+      expect(escrow.disputeReason).to.deep.equal(Array(64).fill(0));
+      expect(escrow.milestoneDisputedBy.every((k: PublicKey) => k.equals(PublicKey.default))).to.be.true;
+
+      await program.methods
+        .acceptMilestoneTask()
+        .accounts({ escrowAccount: LEGACY_MILESTONE_ESCROW, recipient: legacyRecipient.publicKey })
+        .signers([legacyRecipient])
+        .rpc();
+
+      const accepted = await program.account.milestoneEscrowAccount.fetch(LEGACY_MILESTONE_ESCROW) as any;
+      expect(accepted.status).to.have.property("active");
+    });
   });
 });
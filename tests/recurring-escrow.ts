@@ -0,0 +1,290 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+} from "@solana/web3.js";
+import { expect } from "chai";
+
+import idl from "../target/idl/escrow.json";
+
+const PROGRAM_ID = new PublicKey("EFnubV4grWUCFRPkRTTNVxEdetxYb8VJtAAqQQmxmw8X");
+const BPF_LOADER_UPGRADEABLE_ID = new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111");
+
+function deriveRecurringEscrowPda(
+  creator: PublicKey,
+  escrowId: anchor.BN,
+): [PublicKey, number] {
+  const idBuffer = Buffer.alloc(8);
+  idBuffer.writeBigUInt64LE(BigInt(escrowId.toString()));
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("recurring_escrow"), creator.toBuffer(), idBuffer],
+    PROGRAM_ID
+  );
+}
+
+async function airdrop(
+  connection: anchor.web3.Connection,
+  pubkey: PublicKey,
+  amount: number
+) {
+  const sig = await connection.requestAirdrop(pubkey, amount * LAMPORTS_PER_SOL);
+  await connection.confirmTransaction(sig, "confirmed");
+}
+
+function randomEscrowId(): anchor.BN {
+  const bytes = new Uint8Array(8);
+  for (let i = 0; i < 8; i++) bytes[i] = Math.floor(Math.random() * 256);
+  return new anchor.BN(Buffer.from(bytes), "le");
+}
+
+function deriveConfigPda(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("config")], PROGRAM_ID);
+}
+
+// Upgradeable loader account naming the program's upgrade authority, which
+// init_config requires to sign
+function deriveProgramDataPda(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([PROGRAM_ID.toBuffer()], BPF_LOADER_UPGRADEABLE_ID);
+}
+
+// These tests pass the config to create and release instructions. Initialize it
+// with every limit disabled unless another test file already has; the
+// provider wallet is the admin.
+async function ensureConfig(program: Program, configPda: PublicKey) {
+  if (await program.provider.connection.getAccountInfo(configPda)) return;
+
+  await program.methods
+    .initConfig({
+      maxAutoReleaseHorizon: new anchor.BN(0),
+      minArbiterStake: new anchor.BN(0),
+      feeDiscountMinTasks: 0,
+      minFeeBps: 0,
+      maxDeadlineHorizon: new anchor.BN(0),
+      crankRewardLamports: new anchor.BN(0),
+      minMilestoneAmount: new anchor.BN(0),
+      trustedMinTasks: 0,
+      forceCompleteDelay: new anchor.BN(0),
+      creatorInactivityWindow: new anchor.BN(0),
+      maxActivePerCreator: 0,
+      minArbiterCases: 0,
+      bondToArbiterBps: 0,
+    })
+    .accounts({
+      config: configPda,
+      admin: program.provider.publicKey,
+      program: PROGRAM_ID,
+      programData: deriveProgramDataPda()[0],
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+}
+
+describe("recurring-escrow", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = new Program(idl as any, PROGRAM_ID, provider);
+  const connection = provider.connection;
+  const [configPda] = deriveConfigPda();
+
+  const creator = Keypair.generate();
+  const recipient = Keypair.generate();
+  const treasury = Keypair.generate();
+
+  const feeBasisPoints = 150;
+  const amountPerPeriod = 0.1 * LAMPORTS_PER_SOL;
+
+  before(async () => {
+    await ensureConfig(program, configPda);
+    await airdrop(connection, creator.publicKey, 10);
+    await airdrop(connection, recipient.publicKey, 2);
+    await airdrop(connection, treasury.publicKey, 1);
+  });
+
+  async function createRecurring(periodSeconds: number, periods: number): Promise<PublicKey> {
+    const escrowId = randomEscrowId();
+    const [escrowPda] = deriveRecurringEscrowPda(creator.publicKey, escrowId);
+
+    await program.methods
+      .createRecurringEscrow(escrowId, new anchor.BN(amountPerPeriod), new anchor.BN(periodSeconds), new anchor.BN(amountPerPeriod * periods), feeBasisPoints)
+      .accounts({
+        escrowAccount: escrowPda,
+        creator: creator.publicKey,
+        recipient: recipient.publicKey,
+        feeRecipient: treasury.publicKey,
+        systemProgram: SystemProgram.programId,
+        config: configPda,
+      })
+      .signers([creator])
+      .rpc();
+
+    return escrowPda;
+  }
+
+  async function releasePeriod(escrowPda: PublicKey) {
+    await program.methods
+      .releasePeriod()
+      .accounts({
+        escrowAccount: escrowPda,
+        recipient: recipient.publicKey,
+        feeRecipient: treasury.publicKey,
+        config: configPda,
+      })
+      .signers([recipient])
+      .rpc();
+  }
+
+  it("creates a subscription holding the prepaid balance", async () => {
+    const escrowPda = await createRecurring(3600, 3);
+
+    const escrow = await program.account.recurringEscrowAccount.fetch(escrowPda) as any;
+    expect(escrow.amountPerPeriod.toNumber()).to.equal(amountPerPeriod);
+    expect(escrow.balance.toNumber()).to.equal(amountPerPeriod * 3);
+    expect(escrow.periodsReleased).to.equal(0);
+    expect(escrow.nextReleaseTs.toNumber()).to.equal(escrow.createdAt.toNumber() + 3600);
+  });
+
+  it("rejects a zero-length period", async () => {
+    try {
+      await createRecurring(0, 3);
+      expect.fail("Should have thrown");
+    } catch (err: any) {
+      expect(err.error?.errorCode?.code || err.message).to.include("InvalidPeriod");
+    }
+  });
+
+  it("recipient cranks one payment per period", async () => {
+    const periodSeconds = 2;
+    const escrowPda = await createRecurring(periodSeconds, 3);
+    const fee = Math.floor(amountPerPeriod * feeBasisPoints / 10000);
+
+    // Too early for the first period
+    try {
+      await releasePeriod(escrowPda);
+      expect.fail("Should have thrown");
+    } catch (err: any) {
+      expect(err.error?.errorCode?.code || err.message).to.include("PeriodNotReached");
+    }
+
+    const recipientBefore = await connection.getBalance(recipient.publicKey);
+    const treasuryBefore = await connection.getBalance(treasury.publicKey);
+
+    for (let period = 1; period <= 2; period++) {
+      await new Promise((resolve) => setTimeout(resolve, (periodSeconds + 2) * 1000));
+      await releasePeriod(escrowPda);
+
+      const escrow = await program.account.recurringEscrowAccount.fetch(escrowPda) as any;
+      expect(escrow.periodsReleased).to.equal(period);
+      expect(escrow.balance.toNumber()).to.equal(amountPerPeriod * (3 - period));
+    }
+
+    // Recipient pays the transaction fees, so compare the treasury exactly
+    expect((await connection.getBalance(treasury.publicKey)) - treasuryBefore).to.equal(2 * fee);
+    expect((await connection.getBalance(recipient.publicKey)) - recipientBefore).to.be.greaterThan(2 * (amountPerPeriod - fee) - 0.001 * LAMPORTS_PER_SOL);
+  });
+
+  it("stops when the balance runs out until the creator tops up", async () => {
+    const periodSeconds = 2;
+    const escrowPda = await createRecurring(periodSeconds, 1);
+
+    await new Promise((resolve) => setTimeout(resolve, (periodSeconds + 2) * 1000));
+    await releasePeriod(escrowPda);
+
+    await new Promise((resolve) => setTimeout(resolve, (periodSeconds + 2) * 1000));
+    try {
+      await releasePeriod(escrowPda);
+      expect.fail("Should have thrown");
+    } catch (err: any) {
+      expect(err.error?.errorCode?.code || err.message).to.include("RecurringBalanceExhausted");
+    }
+
+    await program.methods
+      .topUpRecurring(new anchor.BN(amountPerPeriod))
+      .accounts({
+        escrowAccount: escrowPda,
+        creator: creator.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([creator])
+      .rpc();
+
+    await releasePeriod(escrowPda);
+
+    const escrow = await program.account.recurringEscrowAccount.fetch(escrowPda) as any;
+    expect(escrow.periodsReleased).to.equal(2);
+    expect(escrow.balance.toNumber()).to.equal(0);
+  });
+
+  it("creator cancels mid-subscription and reclaims the unreleased balance", async () => {
+    const escrowPda = await createRecurring(3600, 3);
+
+    const creatorBefore = await connection.getBalance(creator.publicKey);
+    const recipientBefore = await connection.getBalance(recipient.publicKey);
+
+    await program.methods
+      .cancelRecurring()
+      .accounts({
+        escrowAccount: escrowPda,
+        creator: creator.publicKey,
+        recipient: recipient.publicKey,
+        feeRecipient: treasury.publicKey,
+      })
+      .signers([creator])
+      .rpc();
+
+    // No period was due, so the whole balance plus rent returns to the creator
+    expect((await connection.getBalance(creator.publicKey)) - creatorBefore).to.be.greaterThan(amountPerPeriod * 3);
+    expect(await connection.getBalance(recipient.publicKey)).to.equal(recipientBefore);
+    expect(await connection.getAccountInfo(escrowPda)).to.be.null;
+  });
+
+  it("pays periods already due to the recipient on cancel", async () => {
+    const periodSeconds = 2;
+    const escrowPda = await createRecurring(periodSeconds, 3);
+    const fee = Math.floor(amountPerPeriod * feeBasisPoints / 10000);
+
+    await new Promise((resolve) => setTimeout(resolve, (periodSeconds + 1) * 1000));
+    const escrow = await program.account.recurringEscrowAccount.fetch(escrowPda) as any;
+    const recipientBefore = await connection.getBalance(recipient.publicKey);
+
+    await program.methods
+      .cancelRecurring()
+      .accounts({
+        escrowAccount: escrowPda,
+        creator: creator.publicKey,
+        recipient: recipient.publicKey,
+        feeRecipient: treasury.publicKey,
+      })
+      .signers([creator])
+      .rpc();
+
+    // At least the first period is owed; how many more depends on slot timing
+    const received = (await connection.getBalance(recipient.publicKey)) - recipientBefore;
+    expect(received % (amountPerPeriod - fee)).to.equal(0);
+    expect(received).to.be.at.least(amountPerPeriod - fee);
+    expect(received).to.be.at.most(escrow.balance.toNumber());
+  });
+
+  it("only the recipient can release a period", async () => {
+    const escrowPda = await createRecurring(3600, 1);
+
+    try {
+      await program.methods
+        .releasePeriod()
+        .accounts({
+          escrowAccount: escrowPda,
+          recipient: creator.publicKey,
+          feeRecipient: treasury.publicKey,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+      expect.fail("Should have thrown");
+    } catch (err: any) {
+      expect(err.error?.errorCode?.code || err.message).to.include("UnauthorizedRecipient");
+    }
+  });
+});
@@ -7,10 +7,25 @@ import {
   SystemProgram,
 } from "@solana/web3.js";
 import { expect } from "chai";
+import { createHash } from "crypto";
 
 import idl from "../target/idl/escrow.json";
+import legacyRecipientSecret from "./fixtures/legacy-recipient.json";
 
 const PROGRAM_ID = new PublicKey("EFnubV4grWUCFRPkRTTNVxEdetxYb8VJtAAqQQmxmw8X");
+const BPF_LOADER_UPGRADEABLE_ID = new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111");
+
+function deriveEscrowPda(
+  creator: PublicKey,
+  escrowId: anchor.BN,
+): [PublicKey, number] {
+  const idBuffer = Buffer.alloc(8);
+  idBuffer.writeBigUInt64LE(BigInt(escrowId.toString()));
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("escrow"), creator.toBuffer(), idBuffer],
+    PROGRAM_ID
+  );
+}
 
 function deriveReputationPda(agent: PublicKey): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
@@ -19,6 +34,20 @@ function deriveReputationPda(agent: PublicKey): [PublicKey, number] {
   );
 }
 
+function deriveTombstonePda(agent: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("reputation_tombstone"), agent.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+function deriveBondReceiptPda(escrowPda: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("bond"), escrowPda.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
 async function airdrop(
   connection: anchor.web3.Connection,
   pubkey: PublicKey,
@@ -28,17 +57,83 @@ async function airdrop(
   await connection.confirmTransaction(sig, "confirmed");
 }
 
+function randomEscrowId(): anchor.BN {
+  const bytes = new Uint8Array(8);
+  for (let i = 0; i < 8; i++) bytes[i] = Math.floor(Math.random() * 256);
+  return new anchor.BN(Buffer.from(bytes), "le");
+}
+
+async function getEvents(
+  connection: anchor.web3.Connection,
+  signature: string
+): Promise<anchor.Event[]> {
+  const tx = await connection.getTransaction(signature, {
+    commitment: "confirmed",
+    maxSupportedTransactionVersion: 0,
+  });
+  const parser = new anchor.EventParser(PROGRAM_ID, new anchor.BorshCoder(idl as any));
+  return Array.from(parser.parseLogs(tx?.meta?.logMessages ?? []));
+}
+
+function deriveConfigPda(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("config")], PROGRAM_ID);
+}
+
+// Upgradeable loader account naming the program's upgrade authority, which
+// init_config requires to sign
+function deriveProgramDataPda(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([PROGRAM_ID.toBuffer()], BPF_LOADER_UPGRADEABLE_ID);
+}
+
+function deriveCounterPda(creator: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("counter"), creator.toBuffer()], PROGRAM_ID);
+}
+
+// These tests pass the config to create and release instructions. Initialize it
+// with every limit disabled unless another test file already has; the
+// provider wallet is the admin.
+async function ensureConfig(program: Program, configPda: PublicKey) {
+  if (await program.provider.connection.getAccountInfo(configPda)) return;
+
+  await program.methods
+    .initConfig({
+      maxAutoReleaseHorizon: new anchor.BN(0),
+      minArbiterStake: new anchor.BN(0),
+      feeDiscountMinTasks: 0,
+      minFeeBps: 0,
+      maxDeadlineHorizon: new anchor.BN(0),
+      crankRewardLamports: new anchor.BN(0),
+      minMilestoneAmount: new anchor.BN(0),
+      trustedMinTasks: 0,
+      forceCompleteDelay: new anchor.BN(0),
+      creatorInactivityWindow: new anchor.BN(0),
+      maxActivePerCreator: 0,
+      minArbiterCases: 0,
+      bondToArbiterBps: 0,
+    })
+    .accounts({
+      config: configPda,
+      admin: program.provider.publicKey,
+      program: PROGRAM_ID,
+      programData: deriveProgramDataPda()[0],
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+}
+
 describe("reputation", () => {
   const provider = anchor.AnchorProvider.env();
   anchor.setProvider(provider);
 
   const program = new Program(idl as any, PROGRAM_ID, provider);
   const connection = provider.connection;
+  const [configPda] = deriveConfigPda();
 
   const agent1 = Keypair.generate();
   const agent2 = Keypair.generate();
 
   before(async () => {
+    await ensureConfig(program, configPda);
     await airdrop(connection, agent1.publicKey, 5);
     await airdrop(connection, agent2.publicKey, 2);
   });
@@ -51,6 +146,7 @@ describe("reputation", () => {
       .accounts({
         reputationAccount: repPda,
         agent: agent1.publicKey,
+        tombstone: deriveTombstonePda(agent1.publicKey)[0],
         payer: agent1.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -77,6 +173,7 @@ describe("reputation", () => {
         .accounts({
           reputationAccount: repPda,
           agent: agent1.publicKey,
+          tombstone: deriveTombstonePda(agent1.publicKey)[0],
           payer: agent1.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -97,6 +194,7 @@ describe("reputation", () => {
       .accounts({
         reputationAccount: repPda,
         agent: agent2.publicKey,
+        tombstone: deriveTombstonePda(agent2.publicKey)[0],
         payer: agent1.publicKey, // agent1 pays rent for agent2's reputation
         systemProgram: SystemProgram.programId,
       })
@@ -106,4 +204,879 @@ describe("reputation", () => {
     const rep = await program.account.reputationAccount.fetch(repPda) as any;
     expect(rep.agent.toBase58()).to.equal(agent2.publicKey.toBase58());
   });
+
+  it("reputation_commitment matches an off-chain recomputation", async () => {
+    const [repPda] = deriveReputationPda(agent1.publicKey);
+    const rep = await program.account.reputationAccount.fetch(repPda) as any;
+
+    const u32 = (n: number) => {
+      const b = Buffer.alloc(4);
+      b.writeUInt32LE(n);
+      return b;
+    };
+    const u64 = (n: anchor.BN) => n.toArrayLike(Buffer, "le", 8);
+
+    const expected = createHash("sha256")
+      .update(Buffer.concat([
+        rep.agent.toBuffer(),
+        u32(rep.escrowsCreated),
+        u32(rep.escrowsCompleted),
+        u32(rep.escrowsReceived),
+        u32(rep.tasksCompleted),
+        u32(rep.disputesInitiated),
+        u32(rep.disputesWon),
+        u32(rep.disputesLost),
+        u64(rep.totalVolumeLamports),
+        u64(rep.tipsReceived),
+      ]))
+      .digest();
+
+    const commitment = await program.methods
+      .reputationCommitment()
+      .accounts({ reputationAccount: repPda })
+      .view();
+    expect(Buffer.from(commitment as number[]).equals(expected)).to.equal(true);
+  });
+
+  it("initializes a cohort of agents in one transaction", async () => {
+    const cohort = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+    const repPdas = cohort.map((a) => deriveReputationPda(a.publicKey)[0]);
+    const batchAccounts = cohort.flatMap((a, i) => [repPdas[i], deriveTombstonePda(a.publicKey)[0]]);
+
+    await program.methods
+      .initReputationBatch(cohort.map((a) => a.publicKey))
+      .accounts({
+        payer: agent1.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(batchAccounts.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false })))
+      .signers([agent1])
+      .rpc();
+
+    for (let i = 0; i < cohort.length; i++) {
+      const rep = await program.account.reputationAccount.fetch(repPdas[i]) as any;
+      expect(rep.agent.toBase58()).to.equal(cohort[i].publicKey.toBase58());
+      expect(rep.escrowsCreated).to.equal(0);
+      expect(rep.totalVolumeLamports.toNumber()).to.equal(0);
+    }
+  });
+
+  it("rejects a batch whose accounts don't match the agents", async () => {
+    const cohort = [Keypair.generate(), Keypair.generate()];
+    // Second pair points at the wrong agent's PDA
+    const batchAccounts = [
+      deriveReputationPda(cohort[0].publicKey)[0],
+      deriveTombstonePda(cohort[0].publicKey)[0],
+      deriveReputationPda(Keypair.generate().publicKey)[0],
+      deriveTombstonePda(cohort[1].publicKey)[0],
+    ];
+
+    try {
+      await program.methods
+        .initReputationBatch(cohort.map((a) => a.publicKey))
+        .accounts({
+          payer: agent1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(batchAccounts.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false })))
+        .signers([agent1])
+        .rpc();
+      expect.fail("Should have thrown");
+    } catch (err: any) {
+      expect(err.error?.errorCode?.code || err.message).to.include("BatchAccountMismatch");
+    }
+  });
+
+  it("agent can close reputation and reclaim rent", async () => {
+    const agent3 = Keypair.generate();
+    await airdrop(connection, agent3.publicKey, 1);
+    const [repPda] = deriveReputationPda(agent3.publicKey);
+
+    await program.methods
+      .initReputation()
+      .accounts({
+        reputationAccount: repPda,
+        agent: agent3.publicKey,
+        tombstone: deriveTombstonePda(agent3.publicKey)[0],
+        payer: agent3.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([agent3])
+      .rpc();
+
+    const rent = await connection.getBalance(repPda);
+    const agentBefore = await connection.getBalance(agent3.publicKey);
+
+    await program.methods
+      .closeReputation()
+      .accounts({
+        reputationAccount: repPda,
+        agent: agent3.publicKey,
+        tombstone: deriveTombstonePda(agent3.publicKey)[0],
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([agent3])
+      .rpc();
+
+    const agentAfter = await connection.getBalance(agent3.publicKey);
+    const tombstoneRent = await connection.getBalance(deriveTombstonePda(agent3.publicKey)[0]);
+    // Rent returned minus the tombstone's rent and the tx fee
+    expect(agentAfter - agentBefore).to.be.greaterThan(rent - tombstoneRent - 10000);
+
+    const accountInfo = await connection.getAccountInfo(repPda);
+    expect(accountInfo).to.be.null;
+
+    // Closing must not be a way to wipe the record and start over
+    try {
+      await program.methods
+        .initReputation()
+        .accounts({
+          reputationAccount: repPda,
+          agent: agent3.publicKey,
+          tombstone: deriveTombstonePda(agent3.publicKey)[0],
+          payer: agent3.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent3])
+        .rpc();
+      expect.fail("Should have thrown");
+    } catch (err: any) {
+      expect(err.error?.errorCode?.code || err.message).to.include("ReputationClosed");
+    }
+
+    // Nor can a batch initialization
+    try {
+      await program.methods
+        .initReputationBatch([agent3.publicKey])
+        .accounts({
+          payer: agent1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([repPda, deriveTombstonePda(agent3.publicKey)[0]].map((pubkey) => ({ pubkey, isWritable: true, isSigner: false })))
+        .signers([agent1])
+        .rpc();
+      expect.fail("Should have thrown");
+    } catch (err: any) {
+      expect(err.error?.errorCode?.code || err.message).to.include("ReputationClosed");
+    }
+  });
+
+  it("other signers cannot close an agent's reputation", async () => {
+    const [repPda] = deriveReputationPda(agent2.publicKey);
+
+    try {
+      await program.methods
+        .closeReputation()
+        .accounts({
+          reputationAccount: repPda,
+          agent: agent1.publicKey,
+          tombstone: deriveTombstonePda(agent1.publicKey)[0],
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent1])
+        .rpc();
+      expect.fail("Should have thrown");
+    } catch (err: any) {
+      expect(err.error?.errorCode?.code || err.message).to.include("ConstraintSeeds");
+    }
+  });
+
+  it("emits ReputationUpdated on escrow create and release", async () => {
+    const [creatorRep] = deriveReputationPda(agent1.publicKey);
+    const [recipientRep] = deriveReputationPda(agent2.publicKey);
+    const treasury = Keypair.generate();
+
+    const escrowId = randomEscrowId();
+    const amount = new anchor.BN(0.1 * LAMPORTS_PER_SOL);
+    const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+    const [escrowPda] = deriveEscrowPda(agent1.publicKey, escrowId);
+
+    const createSig = await program.methods
+      .createEscrow(escrowId, amount, deadline, Array(32).fill(0), 0, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        escrowAccount: escrowPda,
+        creator: agent1.publicKey,
+        recipient: agent2.publicKey,
+        arbiter: treasury.publicKey,
+        feeRecipient: treasury.publicKey,
+        systemProgram: SystemProgram.programId,
+        creatorReputation: creatorRep,
+        recipientReputation: recipientRep,
+        config: configPda,
+      })
+      .signers([agent1])
+      .rpc({ commitment: "confirmed" });
+
+    let events = (await getEvents(connection, createSig)).filter((e) => e.name === "ReputationUpdated");
+    expect(events).to.have.length(2);
+    expect(events[0].data.agent.toBase58()).to.equal(agent1.publicKey.toBase58());
+    expect(events[0].data.reason).to.have.property("escrowCreated");
+    expect(events[1].data.agent.toBase58()).to.equal(agent2.publicKey.toBase58());
+    expect(events[1].data.reason).to.have.property("escrowReceived");
+
+    await program.methods
+      .acceptTask(Array(32).fill(0))
+      .accounts({ escrowAccount: escrowPda, recipient: agent2.publicKey })
+      .signers([agent2])
+      .rpc();
+
+    const releaseSig = await program.methods
+      .releasePayment()
+      .accounts({
+        escrowAccount: escrowPda,
+        creator: agent1.publicKey,
+        recipient: agent2.publicKey,
+        feeRecipient: treasury.publicKey,
+        creatorReputation: creatorRep,
+        recipientReputation: recipientRep,
+        config: configPda,
+        escrowCounter: deriveCounterPda(agent1.publicKey)[0],
+      })
+      .signers([agent1])
+      .rpc({ commitment: "confirmed" });
+
+    events = (await getEvents(connection, releaseSig)).filter((e) => e.name === "ReputationUpdated");
+    expect(events).to.have.length(2);
+    expect(events[0].data.agent.toBase58()).to.equal(agent1.publicKey.toBase58());
+    expect(events[0].data.reason).to.have.property("escrowCompleted");
+    expect(events[1].data.agent.toBase58()).to.equal(agent2.publicKey.toBase58());
+    expect(events[1].data.reason).to.have.property("taskCompleted");
+    expect(events[1].data.tasksCompleted).to.equal(1);
+  });
+
+  it("release_with_tip pays payout + tip and records tips_received", async () => {
+    const [creatorRep] = deriveReputationPda(agent1.publicKey);
+    const [recipientRep] = deriveReputationPda(agent2.publicKey);
+    const treasury = Keypair.generate();
+    const feeBasisPoints = 150;
+
+    const escrowId = randomEscrowId();
+    const escrowAmount = 0.5 * LAMPORTS_PER_SOL;
+    const tip = 0.05 * LAMPORTS_PER_SOL;
+    const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+    const [escrowPda] = deriveEscrowPda(agent1.publicKey, escrowId);
+
+    await program.methods
+      .createEscrow(escrowId, new anchor.BN(escrowAmount), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        escrowAccount: escrowPda,
+        creator: agent1.publicKey,
+        recipient: agent2.publicKey,
+        arbiter: treasury.publicKey,
+        feeRecipient: treasury.publicKey,
+        systemProgram: SystemProgram.programId,
+        config: configPda,
+      })
+      .signers([agent1])
+      .rpc();
+
+    await program.methods
+      .acceptTask(Array(32).fill(0))
+      .accounts({ escrowAccount: escrowPda, recipient: agent2.publicKey })
+      .signers([agent2])
+      .rpc();
+
+    const repBefore = await program.account.reputationAccount.fetch(recipientRep) as any;
+    const recipientBefore = await connection.getBalance(agent2.publicKey);
+
+    await program.methods
+      .releaseWithTip(new anchor.BN(tip))
+      .accounts({
+        escrowAccount: escrowPda,
+        creator: agent1.publicKey,
+        recipient: agent2.publicKey,
+        feeRecipient: treasury.publicKey,
+        creatorReputation: creatorRep,
+        recipientReputation: recipientRep,
+        systemProgram: SystemProgram.programId,
+        config: configPda,
+        escrowCounter: deriveCounterPda(agent1.publicKey)[0],
+      })
+      .signers([agent1])
+      .rpc();
+
+    const recipientAfter = await connection.getBalance(agent2.publicKey);
+    const expectedFee = Math.floor(escrowAmount * feeBasisPoints / 10000);
+    expect(recipientAfter - recipientBefore).to.equal(escrowAmount - expectedFee + tip);
+
+    const repAfter = await program.account.reputationAccount.fetch(recipientRep) as any;
+    expect(repAfter.tipsReceived.toNumber() - repBefore.tipsReceived.toNumber()).to.equal(tip);
+  });
+
+  it("claim_auto_release credits completion like auto_release", async () => {
+    const [creatorRep] = deriveReputationPda(agent1.publicKey);
+    const [recipientRep] = deriveReputationPda(agent2.publicKey);
+    const treasury = Keypair.generate();
+
+    const escrowId = randomEscrowId();
+    const escrowAmount = 0.1 * LAMPORTS_PER_SOL;
+    const now = Math.floor(Date.now() / 1000);
+    const [escrowPda] = deriveEscrowPda(agent1.publicKey, escrowId);
+
+    await program.methods
+      .createEscrow(escrowId, new anchor.BN(escrowAmount), new anchor.BN(now + 2), Array(32).fill(0), 150, new anchor.BN(now + 3), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        escrowAccount: escrowPda,
+        creator: agent1.publicKey,
+        recipient: agent2.publicKey,
+        arbiter: treasury.publicKey,
+        feeRecipient: treasury.publicKey,
+        systemProgram: SystemProgram.programId,
+        config: configPda,
+      })
+      .signers([agent1])
+      .rpc();
+
+    await program.methods
+      .acceptTask(Array(32).fill(0))
+      .accounts({ escrowAccount: escrowPda, recipient: agent2.publicKey })
+      .signers([agent2])
+      .rpc();
+
+    await new Promise((resolve) => setTimeout(resolve, 5000));
+
+    const creatorBefore = await program.account.reputationAccount.fetch(creatorRep) as any;
+    const recipientBefore = await program.account.reputationAccount.fetch(recipientRep) as any;
+
+    await program.methods
+      .claimAutoRelease()
+      .accounts({
+        escrowAccount: escrowPda,
+        recipient: agent2.publicKey,
+        creator: agent1.publicKey,
+        feeRecipient: treasury.publicKey,
+        creatorReputation: creatorRep,
+        recipientReputation: recipientRep,
+        config: configPda,
+        escrowCounter: deriveCounterPda(agent1.publicKey)[0],
+      })
+      .signers([agent2])
+      .rpc();
+
+    const creatorAfter = await program.account.reputationAccount.fetch(creatorRep) as any;
+    const recipientAfter = await program.account.reputationAccount.fetch(recipientRep) as any;
+    expect(creatorAfter.escrowsCompleted).to.equal(creatorBefore.escrowsCompleted + 1);
+    expect(recipientAfter.tasksCompleted).to.equal(recipientBefore.tasksCompleted + 1);
+    expect(recipientAfter.totalVolumeLamports.toNumber() - recipientBefore.totalVolumeLamports.toNumber()).to.equal(escrowAmount);
+  });
+
+  it("resolve_disputes_batch records the outcome like resolve_dispute", async () => {
+    const [creatorRep] = deriveReputationPda(agent1.publicKey);
+    const [recipientRep] = deriveReputationPda(agent2.publicKey);
+    const treasury = Keypair.generate();
+    const arbiter = Keypair.generate();
+    await airdrop(connection, arbiter.publicKey, 1);
+
+    const escrowId = randomEscrowId();
+    const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+    const [escrowPda] = deriveEscrowPda(agent1.publicKey, escrowId);
+
+    await program.methods
+      .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), 150, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        escrowAccount: escrowPda,
+        creator: agent1.publicKey,
+        recipient: agent2.publicKey,
+        arbiter: arbiter.publicKey,
+        feeRecipient: treasury.publicKey,
+        systemProgram: SystemProgram.programId,
+        config: configPda,
+      })
+      .signers([agent1])
+      .rpc();
+
+    await program.methods
+      .acceptTask(Array(32).fill(0))
+      .accounts({ escrowAccount: escrowPda, recipient: agent2.publicKey })
+      .signers([agent2])
+      .rpc();
+
+    await program.methods
+      .dispute(Array(64).fill(0))
+      .accounts({ escrowAccount: escrowPda, disputer: agent2.publicKey })
+      .signers([agent2])
+      .rpc();
+
+    const creatorBefore = await program.account.reputationAccount.fetch(creatorRep) as any;
+    const recipientBefore = await program.account.reputationAccount.fetch(recipientRep) as any;
+    const resolutionHash = Array.from(createHash("sha256").update("ruling").digest());
+
+    const sig = await program.methods
+      .resolveDisputesBatch([{ winner: { recipient: {} }, resolutionHash }])
+      .accounts({ arbiter: arbiter.publicKey, config: configPda })
+      .remainingAccounts(
+        [escrowPda, agent1.publicKey, agent2.publicKey, treasury.publicKey, deriveCounterPda(agent1.publicKey)[0], agent1.publicKey, creatorRep, recipientRep, deriveBondReceiptPda(escrowPda)[0]].map((pubkey) => ({
+          pubkey,
+          isWritable: true,
+          isSigner: false,
+        }))
+      )
+      .signers([arbiter])
+      .rpc({ commitment: "confirmed" });
+
+    const creatorAfter = await program.account.reputationAccount.fetch(creatorRep) as any;
+    const recipientAfter = await program.account.reputationAccount.fetch(recipientRep) as any;
+    expect(recipientAfter.disputesWon).to.equal(recipientBefore.disputesWon + 1);
+    expect(creatorAfter.disputesLost).to.equal(creatorBefore.disputesLost + 1);
+
+    const events = await getEvents(connection, sig);
+    const resolved = events.find((e) => e.name === "DisputeResolved");
+    expect(resolved?.data.resolutionHash).to.deep.equal(resolutionHash);
+    const reasons = events.filter((e) => e.name === "ReputationUpdated").map((e) => Object.keys(e.data.reason)[0]);
+    expect(reasons).to.deep.equal(["disputeWon", "disputeLost"]);
+  });
+
+  describe("migrate_reputation", () => {
+    // Loaded by the test validator from tests/fixtures (see Anchor.toml): the
+    // agent's reputation as written before tips, ratings and missed deadlines
+    const legacyAgent = Keypair.fromSecretKey(Uint8Array.from(legacyRecipientSecret)).publicKey;
+    const [legacyRep] = deriveReputationPda(legacyAgent);
+
+    it("grows a pre-tips reputation account and keeps its history", async () => {
+      // Too short to decode until it is migrated
+      const decoded = await program.account.reputationAccount.fetch(legacyRep).then(() => true, () => false);
+      expect(decoded).to.be.false;
+
+      await program.methods
+        .migrateReputation()
+        .accounts({
+          reputationAccount: legacyRep,
+          agent: legacyAgent,
+          payer: agent1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent1])
+        .rpc();
+
+      const rep = await program.account.reputationAccount.fetch(legacyRep) as any;
+      expect(rep.agent.toBase58()).to.equal(legacyAgent.toBase58());
+      expect(rep.escrowsCreated).to.equal(3);
+      expect(rep.escrowsCompleted).to.equal(2);
+      expect(rep.escrowsReceived).to.equal(5);
+      expect(rep.tasksCompleted).to.equal(4);
+      expect(rep.disputesWon).to.equal(1);
+      expect(rep.totalVolumeLamports.toNumber()).to.equal(2 * LAMPORTS_PER_SOL);
+      expect(rep.tipsReceived.toNumber()).to.equal(0);
+      expect(rep.ratingCount).to.equal(0);
+      expect(rep.deadlinesMissed).to.equal(0);
+    });
+
+    it("is a no-op on a current reputation account", async () => {
+      const [repPda] = deriveReputationPda(agent1.publicKey);
+      const before = await connection.getAccountInfo(repPda);
+
+      await program.methods
+        .migrateReputation()
+        .accounts({
+          reputationAccount: repPda,
+          agent: agent1.publicKey,
+          payer: agent1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent1])
+        .rpc();
+
+      const after = await connection.getAccountInfo(repPda);
+      expect(after!.data.equals(before!.data)).to.be.true;
+    });
+  });
+
+  describe("rate_recipient", () => {
+    const [recipientRep] = deriveReputationPda(agent2.publicKey);
+    let receiptPda: PublicKey;
+
+    before(async () => {
+      const treasury = Keypair.generate();
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const [escrowPda] = deriveEscrowPda(agent1.publicKey, escrowId);
+      [receiptPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("rating_receipt"), escrowPda.toBuffer()],
+        PROGRAM_ID
+      );
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), 0, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: agent1.publicKey,
+          recipient: agent2.publicKey,
+          arbiter: treasury.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([agent1])
+        .rpc();
+
+      await program.methods
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: escrowPda, recipient: agent2.publicKey })
+        .signers([agent2])
+        .rpc();
+
+      await program.methods
+        .releasePayment()
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: agent1.publicKey,
+          recipient: agent2.publicKey,
+          feeRecipient: treasury.publicKey,
+          ratingReceipt: receiptPda,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+          escrowCounter: deriveCounterPda(agent1.publicKey)[0],
+        })
+        .signers([agent1])
+        .rpc();
+    });
+
+    async function rate(stars: number) {
+      await program.methods
+        .rateRecipient(stars)
+        .accounts({
+          ratingReceipt: receiptPda,
+          creator: agent1.publicKey,
+          recipientReputation: recipientRep,
+        })
+        .signers([agent1])
+        .rpc();
+    }
+
+    it("rejects out-of-range stars", async () => {
+      for (const stars of [0, 6]) {
+        try {
+          await rate(stars);
+          expect.fail("Should have thrown");
+        } catch (err: any) {
+          expect(err.error?.errorCode?.code || err.message).to.include("InvalidRating");
+        }
+      }
+    });
+
+    it("records a valid rating on the recipient's reputation", async () => {
+      const before = await program.account.reputationAccount.fetch(recipientRep) as any;
+
+      await rate(4);
+
+      const after = await program.account.reputationAccount.fetch(recipientRep) as any;
+      expect(after.ratingSum.toNumber() - before.ratingSum.toNumber()).to.equal(4);
+      expect(after.ratingCount - before.ratingCount).to.equal(1);
+      expect(await connection.getAccountInfo(receiptPda)).to.be.null;
+    });
+
+    it("cannot rate the same escrow twice", async () => {
+      try {
+        await rate(5);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("AccountNotInitialized");
+      }
+    });
+  });
+
+  describe("release_and_rate", () => {
+    const [creatorRep] = deriveReputationPda(agent1.publicKey);
+    const [recipientRep] = deriveReputationPda(agent2.publicKey);
+    const treasury = Keypair.generate();
+    const escrowAmount = 0.1 * LAMPORTS_PER_SOL;
+    const feeBps = 100;
+
+    it("pays the recipient and records completion and rating in one call", async () => {
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const [escrowPda] = deriveEscrowPda(agent1.publicKey, escrowId);
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(escrowAmount), deadline, Array(32).fill(0), feeBps, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: agent1.publicKey,
+          recipient: agent2.publicKey,
+          arbiter: treasury.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([agent1])
+        .rpc();
+
+      await program.methods
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: escrowPda, recipient: agent2.publicKey })
+        .signers([agent2])
+        .rpc();
+
+      const repBefore = await program.account.reputationAccount.fetch(recipientRep) as any;
+      const balanceBefore = await connection.getBalance(agent2.publicKey);
+
+      await program.methods
+        .releaseAndRate(5)
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: agent1.publicKey,
+          recipient: agent2.publicKey,
+          feeRecipient: treasury.publicKey,
+          creatorReputation: creatorRep,
+          recipientReputation: recipientRep,
+          config: configPda,
+          escrowCounter: deriveCounterPda(agent1.publicKey)[0],
+        })
+        .signers([agent1])
+        .rpc();
+
+      const fee = Math.floor(escrowAmount * feeBps / 10000);
+      expect((await connection.getBalance(agent2.publicKey)) - balanceBefore).to.equal(escrowAmount - fee);
+      expect(await connection.getAccountInfo(escrowPda)).to.be.null;
+
+      const repAfter = await program.account.reputationAccount.fetch(recipientRep) as any;
+      expect(repAfter.tasksCompleted - repBefore.tasksCompleted).to.equal(1);
+      expect(repAfter.ratingSum.toNumber() - repBefore.ratingSum.toNumber()).to.equal(5);
+      expect(repAfter.ratingCount - repBefore.ratingCount).to.equal(1);
+    });
+  });
+
+  describe("reputation_score", () => {
+    // Mirrors ReputationAccount::score
+    function expectedScore(rep: any): number {
+      const earned = 100 * (rep.escrowsCompleted + rep.tasksCompleted)
+        + Math.floor(rep.totalVolumeLamports.toNumber() / 10_000_000);
+      return Math.max(0, earned - 500 * rep.disputesLost);
+    }
+
+    async function score(repPda: PublicKey): Promise<number> {
+      const result = await program.methods
+        .reputationScore()
+        .accounts({ reputationAccount: repPda })
+        .view();
+      return (result as anchor.BN).toNumber();
+    }
+
+    it("scores a fresh agent at zero", async () => {
+      const agent = Keypair.generate();
+      const [repPda] = deriveReputationPda(agent.publicKey);
+
+      await program.methods
+        .initReputation()
+        .accounts({
+          reputationAccount: repPda,
+          agent: agent.publicKey,
+          tombstone: deriveTombstonePda(agent.publicKey)[0],
+          payer: agent1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent1])
+        .rpc();
+
+      expect(await score(repPda)).to.equal(0);
+    });
+
+    it("scores the creator and recipient profiles by the documented weighting", async () => {
+      for (const agent of [agent1, agent2]) {
+        const [repPda] = deriveReputationPda(agent.publicKey);
+        const rep = await program.account.reputationAccount.fetch(repPda) as any;
+
+        const onChain = await score(repPda);
+        expect(onChain).to.equal(expectedScore(rep));
+        // Earlier tests completed escrows on both sides
+        expect(onChain).to.be.greaterThan(0);
+      }
+    });
+
+    it("ranks a recipient with more completed tasks higher", async () => {
+      const [creatorRep] = deriveReputationPda(agent1.publicKey);
+      const [recipientRep] = deriveReputationPda(agent2.publicKey);
+      const before = await score(recipientRep);
+
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const [escrowPda] = deriveEscrowPda(agent1.publicKey, escrowId);
+      const treasury = Keypair.generate();
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), 0, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: agent1.publicKey,
+          recipient: agent2.publicKey,
+          arbiter: treasury.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          config: configPda,
+        })
+        .signers([agent1])
+        .rpc();
+
+      await program.methods
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: escrowPda, recipient: agent2.publicKey })
+        .signers([agent2])
+        .rpc();
+
+      await program.methods
+        .releasePayment()
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: agent1.publicKey,
+          recipient: agent2.publicKey,
+          feeRecipient: treasury.publicKey,
+          creatorReputation: creatorRep,
+          recipientReputation: recipientRep,
+          config: configPda,
+          escrowCounter: deriveCounterPda(agent1.publicKey)[0],
+        })
+        .signers([agent1])
+        .rpc();
+
+      // One completion plus whatever volume the release recorded
+      expect((await score(recipientRep)) - before).to.be.at.least(100);
+    });
+  });
+
+  describe("merge_reputation", () => {
+    const oldWallet = Keypair.generate();
+    const newWallet = Keypair.generate();
+    const [oldRep] = deriveReputationPda(oldWallet.publicKey);
+    const [newRep] = deriveReputationPda(newWallet.publicKey);
+
+    // Runs one released escrow from agent1 to `recipient`, crediting the recipient's reputation
+    async function completeTaskFor(recipient: Keypair, recipientRep: PublicKey) {
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const [escrowPda] = deriveEscrowPda(agent1.publicKey, escrowId);
+      const treasury = Keypair.generate();
+
+      await program.methods
+        .createEscrow(escrowId, new anchor.BN(0.1 * LAMPORTS_PER_SOL), deadline, Array(32).fill(0), 0, new anchor.BN(0), new anchor.BN(0), 0, new anchor.BN(0), new anchor.BN(0), 0, "", new anchor.BN(0), new anchor.BN(0), false, new anchor.BN(0), new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: agent1.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: treasury.publicKey,
+          feeRecipient: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+          recipientReputation: recipientRep,
+          config: configPda,
+        })
+        .signers([agent1])
+        .rpc();
+
+      await program.methods
+        .acceptTask(Array(32).fill(0))
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      await program.methods
+        .releasePayment()
+        .accounts({
+          escrowAccount: escrowPda,
+          creator: agent1.publicKey,
+          recipient: recipient.publicKey,
+          feeRecipient: treasury.publicKey,
+          recipientReputation: recipientRep,
+          config: configPda,
+          escrowCounter: deriveCounterPda(agent1.publicKey)[0],
+        })
+        .signers([agent1])
+        .rpc();
+    }
+
+    before(async () => {
+      await airdrop(connection, oldWallet.publicKey, 1);
+      await airdrop(connection, newWallet.publicKey, 1);
+
+      for (const [agent, rep] of [[oldWallet, oldRep], [newWallet, newRep]] as [Keypair, PublicKey][]) {
+        await program.methods
+          .initReputation()
+          .accounts({
+            reputationAccount: rep,
+            agent: agent.publicKey,
+            tombstone: deriveTombstonePda(agent.publicKey)[0],
+            payer: agent.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([agent])
+          .rpc();
+      }
+
+      await completeTaskFor(oldWallet, oldRep);
+      await completeTaskFor(oldWallet, oldRep);
+      await completeTaskFor(newWallet, newRep);
+    });
+
+    it("rejects merging an account into itself", async () => {
+      try {
+        await program.methods
+          .mergeReputation()
+          .accounts({
+            oldReputation: oldRep,
+            newReputation: oldRep,
+            oldAgent: oldWallet.publicKey,
+            newAgent: oldWallet.publicKey,
+            tombstone: deriveTombstonePda(oldWallet.publicKey)[0],
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([oldWallet])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("ReputationSelfMerge");
+      }
+    });
+
+    it("sums the counters into the new account and closes the old one", async () => {
+      const oldBefore = await program.account.reputationAccount.fetch(oldRep) as any;
+      const newBefore = await program.account.reputationAccount.fetch(newRep) as any;
+      const rent = await connection.getBalance(oldRep);
+      const oldWalletBefore = await connection.getBalance(oldWallet.publicKey);
+
+      await program.methods
+        .mergeReputation()
+        .accounts({
+          oldReputation: oldRep,
+          newReputation: newRep,
+          oldAgent: oldWallet.publicKey,
+          newAgent: newWallet.publicKey,
+          tombstone: deriveTombstonePda(oldWallet.publicKey)[0],
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([oldWallet, newWallet])
+        .rpc();
+
+      const merged = await program.account.reputationAccount.fetch(newRep) as any;
+      expect(merged.agent.toBase58()).to.equal(newWallet.publicKey.toBase58());
+      expect(merged.tasksCompleted).to.equal(oldBefore.tasksCompleted + newBefore.tasksCompleted);
+      expect(merged.tasksCompleted).to.equal(3);
+      expect(merged.escrowsReceived).to.equal(oldBefore.escrowsReceived + newBefore.escrowsReceived);
+      expect(merged.totalVolumeLamports.toNumber()).to.equal(
+        oldBefore.totalVolumeLamports.toNumber() + newBefore.totalVolumeLamports.toNumber()
+      );
+
+      expect(await connection.getAccountInfo(oldRep)).to.be.null;
+      // The provider pays the tx fee, so the old wallet gets the rent back
+      // exactly, less the tombstone it leaves behind
+      const tombstoneRent = await connection.getBalance(deriveTombstonePda(oldWallet.publicKey)[0]);
+      expect((await connection.getBalance(oldWallet.publicKey)) - oldWalletBefore).to.equal(rent - tombstoneRent);
+    });
+
+    it("old wallet cannot start a fresh record after merging", async () => {
+      try {
+        await program.methods
+          .initReputation()
+          .accounts({
+            reputationAccount: oldRep,
+            agent: oldWallet.publicKey,
+            tombstone: deriveTombstonePda(oldWallet.publicKey)[0],
+            payer: oldWallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([oldWallet])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("ReputationClosed");
+      }
+    });
+  });
 });
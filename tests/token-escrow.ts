@@ -11,14 +11,27 @@ import {
   createAccount,
   mintTo,
   getAccount,
+  transfer,
+  freezeAccount,
+  closeAccount,
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
 import { expect } from "chai";
 import { createHash } from "crypto";
 
 import idl from "../target/idl/escrow.json";
+import legacyCreatorSecret from "./fixtures/legacy-creator.json";
+import legacyRecipientSecret from "./fixtures/legacy-recipient.json";
 
 const PROGRAM_ID = new PublicKey("EFnubV4grWUCFRPkRTTNVxEdetxYb8VJtAAqQQmxmw8X");
+const BPF_LOADER_UPGRADEABLE_ID = new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111");
+
+// Mirrors ACCOUNT_VERSION in state.rs
+const CURRENT_ACCOUNT_VERSION = 1;
+
+// Loaded by the test validator from tests/fixtures (see Anchor.toml): a token
+// escrow written before the version header
+const LEGACY_TOKEN_ESCROW = new PublicKey("HoSQ6jSjEeS3ptb8GJk3vtCmGo448SvvBmqumsvsEWq5");
 
 function deriveTokenEscrowPda(
   creator: PublicKey,
@@ -43,6 +56,20 @@ function deriveVaultPda(
   );
 }
 
+function deriveReputationPda(agent: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("reputation"), agent.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+function deriveTombstonePda(agent: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("reputation_tombstone"), agent.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
 async function airdrop(
   connection: anchor.web3.Connection,
   pubkey: PublicKey,
@@ -60,12 +87,67 @@ function randomEscrowId(): anchor.BN {
   return new anchor.BN(Buffer.from(bytes), "le");
 }
 
+async function getEvents(
+  connection: anchor.web3.Connection,
+  signature: string
+): Promise<anchor.Event[]> {
+  const tx = await connection.getTransaction(signature, {
+    commitment: "confirmed",
+    maxSupportedTransactionVersion: 0,
+  });
+  const parser = new anchor.EventParser(PROGRAM_ID, new anchor.BorshCoder(idl as any));
+  return Array.from(parser.parseLogs(tx?.meta?.logMessages ?? []));
+}
+
+function deriveConfigPda(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("config")], PROGRAM_ID);
+}
+
+// Upgradeable loader account naming the program's upgrade authority, which
+// init_config requires to sign
+function deriveProgramDataPda(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([PROGRAM_ID.toBuffer()], BPF_LOADER_UPGRADEABLE_ID);
+}
+
+// These tests pass the config to create and release instructions. Initialize it
+// with every limit disabled unless another test file already has; the
+// provider wallet is the admin.
+async function ensureConfig(program: Program, configPda: PublicKey) {
+  if (await program.provider.connection.getAccountInfo(configPda)) return;
+
+  await program.methods
+    .initConfig({
+      maxAutoReleaseHorizon: new anchor.BN(0),
+      minArbiterStake: new anchor.BN(0),
+      feeDiscountMinTasks: 0,
+      minFeeBps: 0,
+      maxDeadlineHorizon: new anchor.BN(0),
+      crankRewardLamports: new anchor.BN(0),
+      minMilestoneAmount: new anchor.BN(0),
+      trustedMinTasks: 0,
+      forceCompleteDelay: new anchor.BN(0),
+      creatorInactivityWindow: new anchor.BN(0),
+      maxActivePerCreator: 0,
+      minArbiterCases: 0,
+      bondToArbiterBps: 0,
+    })
+    .accounts({
+      config: configPda,
+      admin: program.provider.publicKey,
+      program: PROGRAM_ID,
+      programData: deriveProgramDataPda()[0],
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+}
+
 describe("token-escrow", () => {
   const provider = anchor.AnchorProvider.env();
   anchor.setProvider(provider);
 
   const program = new Program(idl as any, PROGRAM_ID, provider);
   const connection = provider.connection;
+  const [configPda] = deriveConfigPda();
 
   // Actors
   const creator = Keypair.generate();
@@ -83,6 +165,8 @@ describe("token-escrow", () => {
   const tokenAmount = 1_000_000; // 1 token with 6 decimals
 
   before(async () => {
+    await ensureConfig(program, configPda);
+
     // Fund actors
     await airdrop(connection, creator.publicKey, 10);
     await airdrop(connection, recipient.publicKey, 2);
@@ -131,6 +215,7 @@ describe("token-escrow", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          config: configPda,
         })
         .signers([creator])
         .rpc();
@@ -141,11 +226,44 @@ describe("token-escrow", () => {
       expect(escrow.mint.toBase58()).to.equal(mint.toBase58());
       expect(escrow.amount.toNumber()).to.equal(tokenAmount);
       expect(escrow.status).to.have.property("created");
+      expect(escrow.version).to.equal(1);
 
       // Verify tokens in vault
       const vaultAccount = await getAccount(connection, vaultPda);
       expect(Number(vaultAccount.amount)).to.equal(tokenAmount);
     });
+
+    it("fails when recipient == creator", async () => {
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+      const [escrowPda] = deriveTokenEscrowPda(creator.publicKey, escrowId);
+      const [vaultPda] = deriveVaultPda(escrowPda);
+
+      try {
+        await program.methods
+          .createTokenEscrow(escrowId, new anchor.BN(tokenAmount), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0))
+          .accounts({
+            escrowAccount: escrowPda,
+            vault: vaultPda,
+            creator: creator.publicKey,
+            recipient: creator.publicKey,
+            arbiter: arbiter.publicKey,
+            feeRecipient: treasury.publicKey,
+            mint: mint,
+            creatorTokenAccount: creatorAta,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+            config: configPda,
+          })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("SelfEscrow");
+      }
+    });
   });
 
   describe("full token lifecycle", () => {
@@ -173,6 +291,7 @@ describe("token-escrow", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          config: configPda,
         })
         .signers([creator])
         .rpc();
@@ -205,6 +324,7 @@ describe("token-escrow", () => {
           recipientTokenAccount: recipientAta,
           feeTokenAccount: treasuryAta,
           tokenProgram: TOKEN_PROGRAM_ID,
+          config: configPda,
         })
         .signers([creator])
         .rpc();
@@ -221,6 +341,185 @@ describe("token-escrow", () => {
       const info = await connection.getAccountInfo(escrowPda);
       expect(info).to.be.null;
     });
+
+    // A short vault can't be produced with the classic token program on
+    // localnet, so this covers the other side of the balance check
+    it("pays tokens sent directly to the vault to the recipient", async () => {
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const surplus = 5_000;
+
+      const [escrowPda] = deriveTokenEscrowPda(creator.publicKey, escrowId);
+      const [vaultPda] = deriveVaultPda(escrowPda);
+
+      await program.methods
+        .createTokenEscrow(escrowId, new anchor.BN(tokenAmount), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          vault: vaultPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          mint: mint,
+          creatorTokenAccount: creatorAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTokenTask()
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      await transfer(connection, creator, creatorAta, vaultPda, creator, surplus);
+
+      const recipientBefore = Number((await getAccount(connection, recipientAta)).amount);
+      const treasuryBefore = Number((await getAccount(connection, treasuryAta)).amount);
+
+      await program.methods
+        .releaseTokenPayment()
+        .accounts({
+          escrowAccount: escrowPda,
+          vault: vaultPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          recipientTokenAccount: recipientAta,
+          feeTokenAccount: treasuryAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      // Fee is still charged on the escrowed amount only
+      const expectedFee = Math.floor(tokenAmount * feeBasisPoints / 10000);
+      expect(Number((await getAccount(connection, recipientAta)).amount) - recipientBefore).to.equal(tokenAmount - expectedFee + surplus);
+      expect(Number((await getAccount(connection, treasuryAta)).amount) - treasuryBefore).to.equal(expectedFee);
+      expect(await connection.getAccountInfo(vaultPda)).to.be.null;
+    });
+
+    it("pays the full amount with no fee transfer at zero bps", async () => {
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+      const [escrowPda] = deriveTokenEscrowPda(creator.publicKey, escrowId);
+      const [vaultPda] = deriveVaultPda(escrowPda);
+
+      await program.methods
+        .createTokenEscrow(escrowId, new anchor.BN(tokenAmount), deadline, Array(32).fill(0), 0, new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          vault: vaultPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          mint: mint,
+          creatorTokenAccount: creatorAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTokenTask()
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      const recipientBefore = Number((await getAccount(connection, recipientAta)).amount);
+      const treasuryBefore = Number((await getAccount(connection, treasuryAta)).amount);
+
+      await program.methods
+        .releaseTokenPayment()
+        .accounts({
+          escrowAccount: escrowPda,
+          vault: vaultPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          recipientTokenAccount: recipientAta,
+          feeTokenAccount: treasuryAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      expect(Number((await getAccount(connection, recipientAta)).amount) - recipientBefore).to.equal(tokenAmount);
+      expect(Number((await getAccount(connection, treasuryAta)).amount)).to.equal(treasuryBefore);
+    });
+  });
+
+  describe("creator as fee recipient", () => {
+    it("releases with the creator's own token account as the fee account", async () => {
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+      const [escrowPda] = deriveTokenEscrowPda(creator.publicKey, escrowId);
+      const [vaultPda] = deriveVaultPda(escrowPda);
+
+      await program.methods
+        .createTokenEscrow(escrowId, new anchor.BN(tokenAmount), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          vault: vaultPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: creator.publicKey,
+          mint: mint,
+          creatorTokenAccount: creatorAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTokenTask()
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      const creatorTokensBefore = Number((await getAccount(connection, creatorAta)).amount);
+      const recipientTokensBefore = Number((await getAccount(connection, recipientAta)).amount);
+      const creatorLamportsBefore = await connection.getBalance(creator.publicKey);
+
+      await program.methods
+        .releaseTokenPayment()
+        .accounts({
+          escrowAccount: escrowPda,
+          vault: vaultPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          recipientTokenAccount: recipientAta,
+          feeTokenAccount: creatorAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      const fee = Math.floor(tokenAmount * feeBasisPoints / 10000);
+      expect(Number((await getAccount(connection, creatorAta)).amount) - creatorTokensBefore).to.equal(fee);
+      expect(Number((await getAccount(connection, recipientAta)).amount) - recipientTokensBefore).to.equal(tokenAmount - fee);
+
+      // Rent from both the vault and the escrow account comes back too
+      expect(await connection.getBalance(creator.publicKey)).to.be.greaterThan(creatorLamportsBefore);
+      expect(await connection.getAccountInfo(escrowPda)).to.be.null;
+      expect(await connection.getAccountInfo(vaultPda)).to.be.null;
+    });
   });
 
   describe("refund_token_escrow", () => {
@@ -250,6 +549,7 @@ describe("token-escrow", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          config: configPda,
         })
         .signers([creator])
         .rpc();
@@ -275,21 +575,16 @@ describe("token-escrow", () => {
       const info = await connection.getAccountInfo(escrowPda);
       expect(info).to.be.null;
     });
-  });
 
-  describe("dispute_token + resolve_token_dispute", () => {
-    it("dispute and resolve in favor of recipient", async () => {
+    async function createTokenEscrow(deadlineIn: number, accept: boolean) {
       const escrowId = randomEscrowId();
-      const amount = new anchor.BN(tokenAmount);
-      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
-      const termsHash = Array(32).fill(0);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + deadlineIn);
 
       const [escrowPda] = deriveTokenEscrowPda(creator.publicKey, escrowId);
       const [vaultPda] = deriveVaultPda(escrowPda);
 
-      // Create → Accept → Dispute
       await program.methods
-        .createTokenEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0))
+        .createTokenEscrow(escrowId, new anchor.BN(tokenAmount), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0))
         .accounts({
           escrowAccount: escrowPda,
           vault: vaultPda,
@@ -302,54 +597,841 @@ describe("token-escrow", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          config: configPda,
         })
         .signers([creator])
         .rpc();
 
+      if (accept) {
+        await program.methods
+          .acceptTokenTask()
+          .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+          .signers([recipient])
+          .rpc();
+      }
+
+      return { escrowPda, vaultPda };
+    }
+
+    function refund(escrowPda: PublicKey, vaultPda: PublicKey, recipientReputation: PublicKey | null) {
+      return program.methods
+        .refundTokenEscrow()
+        .accounts({
+          escrowAccount: escrowPda,
+          vault: vaultPda,
+          creator: creator.publicKey,
+          creatorTokenAccount: creatorAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          recipientReputation,
+        })
+        .signers([creator])
+        .rpc({ commitment: "confirmed" });
+    }
+
+    it("cancelling before acceptance emits Cancelled and leaves reputation alone", async () => {
+      const [recipientRep] = deriveReputationPda(recipient.publicKey);
       await program.methods
-        .acceptTokenTask()
-        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .initReputation()
+        .accounts({
+          reputationAccount: recipientRep,
+          agent: recipient.publicKey,
+          tombstone: deriveTombstonePda(recipient.publicKey)[0],
+          payer: recipient.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
         .signers([recipient])
         .rpc();
 
-      const reasonBytes = Buffer.alloc(64);
-      reasonBytes.write("Token dispute test");
-      await program.methods
-        .disputeToken(Array.from(reasonBytes))
-        .accounts({ escrowAccount: escrowPda, disputer: creator.publicKey })
-        .signers([creator])
-        .rpc();
+      const { escrowPda, vaultPda } = await createTokenEscrow(3600, false);
+      const sig = await refund(escrowPda, vaultPda, recipientRep);
 
-      const recipientBefore = Number((await getAccount(connection, recipientAta)).amount);
-      const treasuryBefore = Number((await getAccount(connection, treasuryAta)).amount);
+      const event = (await getEvents(connection, sig)).find((e) => e.name === "TokenEscrowRefunded");
+      expect(event).to.exist;
+      expect(event!.data.amount.toNumber()).to.equal(tokenAmount);
+      expect(event!.data.status).to.have.property("cancelled");
+
+      const rep = await program.account.reputationAccount.fetch(recipientRep) as any;
+      expect(rep.deadlinesMissed).to.equal(0);
+    });
+
+    it("refunding after a missed deadline emits Refunded and counts against the recipient", async () => {
+      const [recipientRep] = deriveReputationPda(recipient.publicKey);
+      const before = await program.account.reputationAccount.fetch(recipientRep) as any;
+
+      const { escrowPda, vaultPda } = await createTokenEscrow(2, true);
+      await new Promise((resolve) => setTimeout(resolve, 3000));
+
+      const creatorBefore = Number((await getAccount(connection, creatorAta)).amount);
+      const sig = await refund(escrowPda, vaultPda, recipientRep);
+
+      expect(Number((await getAccount(connection, creatorAta)).amount) - creatorBefore).to.equal(tokenAmount);
+
+      const event = (await getEvents(connection, sig)).find((e) => e.name === "TokenEscrowRefunded");
+      expect(event).to.exist;
+      expect(event!.data.status).to.have.property("refunded");
+
+      const after = await program.account.reputationAccount.fetch(recipientRep) as any;
+      expect(after.deadlinesMissed - before.deadlinesMissed).to.equal(1);
+    });
+  });
+
+  describe("create reputation tracking", () => {
+    const largeAmount = 10_000_000; // MIN_REPUTATION_AMOUNT base units
+
+    async function ensureReputation(agent: Keypair): Promise<PublicKey> {
+      const [rep] = deriveReputationPda(agent.publicKey);
+      if (!(await connection.getAccountInfo(rep))) {
+        await program.methods
+          .initReputation()
+          .accounts({
+            reputationAccount: rep,
+            agent: agent.publicKey,
+            tombstone: deriveTombstonePda(agent.publicKey)[0],
+            payer: agent.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([agent])
+          .rpc();
+      }
+      return rep;
+    }
+
+    async function createTracked(amount: number, creatorRep: PublicKey, recipientRep: PublicKey) {
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const [escrowPda] = deriveTokenEscrowPda(creator.publicKey, escrowId);
+      const [vaultPda] = deriveVaultPda(escrowPda);
 
-      // Resolve: recipient wins
       await program.methods
-        .resolveTokenDispute({ recipient: {} })
+        .createTokenEscrow(escrowId, new anchor.BN(amount), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0))
         .accounts({
           escrowAccount: escrowPda,
           vault: vaultPda,
-          arbiter: arbiter.publicKey,
           creator: creator.publicKey,
           recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          mint: mint,
           creatorTokenAccount: creatorAta,
-          recipientTokenAccount: recipientAta,
-          feeTokenAccount: treasuryAta,
           tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          creatorReputation: creatorRep,
+          recipientReputation: recipientRep,
+          config: configPda,
         })
-        .signers([arbiter])
+        .signers([creator])
         .rpc();
+    }
 
-      const recipientAfter = Number((await getAccount(connection, recipientAta)).amount);
-      const treasuryAfter = Number((await getAccount(connection, treasuryAta)).amount);
+    it("a large token escrow bumps both parties' counters", async () => {
+      const creatorRep = await ensureReputation(creator);
+      const recipientRep = await ensureReputation(recipient);
+      await mintTo(connection, creator, mint, creatorAta, creator, largeAmount);
 
-      const expectedFee = Math.floor(tokenAmount * feeBasisPoints / 10000);
-      expect(recipientAfter - recipientBefore).to.equal(tokenAmount - expectedFee);
-      expect(treasuryAfter - treasuryBefore).to.equal(expectedFee);
+      const creatorBefore = await program.account.reputationAccount.fetch(creatorRep) as any;
+      const recipientBefore = await program.account.reputationAccount.fetch(recipientRep) as any;
 
-      // PDA closed
-      const info = await connection.getAccountInfo(escrowPda);
-      expect(info).to.be.null;
+      await createTracked(largeAmount, creatorRep, recipientRep);
+
+      const creatorAfter = await program.account.reputationAccount.fetch(creatorRep) as any;
+      const recipientAfter = await program.account.reputationAccount.fetch(recipientRep) as any;
+      expect(creatorAfter.escrowsCreated - creatorBefore.escrowsCreated).to.equal(1);
+      expect(recipientAfter.escrowsReceived - recipientBefore.escrowsReceived).to.equal(1);
+    });
+
+    it("a token escrow below the threshold leaves the counters alone", async () => {
+      const creatorRep = await ensureReputation(creator);
+      const recipientRep = await ensureReputation(recipient);
+      await mintTo(connection, creator, mint, creatorAta, creator, tokenAmount);
+
+      const creatorBefore = await program.account.reputationAccount.fetch(creatorRep) as any;
+      const recipientBefore = await program.account.reputationAccount.fetch(recipientRep) as any;
+
+      await createTracked(tokenAmount, creatorRep, recipientRep);
+
+      const creatorAfter = await program.account.reputationAccount.fetch(creatorRep) as any;
+      const recipientAfter = await program.account.reputationAccount.fetch(recipientRep) as any;
+      expect(creatorAfter.escrowsCreated).to.equal(creatorBefore.escrowsCreated);
+      expect(recipientAfter.escrowsReceived).to.equal(recipientBefore.escrowsReceived);
+    });
+  });
+
+  describe("mint mismatch", () => {
+    let otherMint: PublicKey;
+    let creatorOtherAta: PublicKey;
+    let recipientOtherAta: PublicKey;
+    let treasuryOtherAta: PublicKey;
+
+    before(async () => {
+      otherMint = await createMint(connection, creator, creator.publicKey, null, 6);
+      creatorOtherAta = await createAccount(connection, creator, otherMint, creator.publicKey);
+      recipientOtherAta = await createAccount(connection, recipient, otherMint, recipient.publicKey);
+      treasuryOtherAta = await createAccount(connection, treasury, otherMint, treasury.publicKey);
+    });
+
+    async function createTokenEscrow(accept: boolean) {
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+      const [escrowPda] = deriveTokenEscrowPda(creator.publicKey, escrowId);
+      const [vaultPda] = deriveVaultPda(escrowPda);
+
+      await program.methods
+        .createTokenEscrow(escrowId, new anchor.BN(tokenAmount), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          vault: vaultPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          mint: mint,
+          creatorTokenAccount: creatorAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      if (accept) {
+        await program.methods
+          .acceptTokenTask()
+          .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+          .signers([recipient])
+          .rpc();
+      }
+
+      return { escrowPda, vaultPda };
+    }
+
+    function release(escrowPda: PublicKey, vaultPda: PublicKey, recipientTokenAccount: PublicKey, feeTokenAccount: PublicKey) {
+      return program.methods
+        .releaseTokenPayment()
+        .accounts({
+          escrowAccount: escrowPda,
+          vault: vaultPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          recipientTokenAccount,
+          feeTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+    }
+
+    it("release rejects a recipient token account of another mint", async () => {
+      const { escrowPda, vaultPda } = await createTokenEscrow(true);
+
+      try {
+        await release(escrowPda, vaultPda, recipientOtherAta, treasuryAta);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("MintMismatch");
+      }
+    });
+
+    it("release rejects a fee token account of another mint", async () => {
+      const { escrowPda, vaultPda } = await createTokenEscrow(true);
+
+      try {
+        await release(escrowPda, vaultPda, recipientAta, treasuryOtherAta);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("MintMismatch");
+      }
+    });
+
+    it("refund rejects a creator token account of another mint", async () => {
+      const { escrowPda, vaultPda } = await createTokenEscrow(false);
+
+      try {
+        await program.methods
+          .refundTokenEscrow()
+          .accounts({
+            escrowAccount: escrowPda,
+            vault: vaultPda,
+            creator: creator.publicKey,
+            creatorTokenAccount: creatorOtherAta,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("MintMismatch");
+      }
+    });
+  });
+
+  describe("staged release", () => {
+    const stagedRecipient = Keypair.generate();
+    let stagedRecipientAta: PublicKey;
+
+    before(async () => {
+      await airdrop(connection, stagedRecipient.publicKey, 1);
+      stagedRecipientAta = await createAccount(connection, stagedRecipient, mint, stagedRecipient.publicKey);
+    });
+
+    async function createAndStage() {
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+      const [escrowPda] = deriveTokenEscrowPda(creator.publicKey, escrowId);
+      const [vaultPda] = deriveVaultPda(escrowPda);
+
+      await program.methods
+        .createTokenEscrow(escrowId, new anchor.BN(tokenAmount), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          vault: vaultPda,
+          creator: creator.publicKey,
+          recipient: stagedRecipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          mint: mint,
+          creatorTokenAccount: creatorAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTokenTask()
+        .accounts({ escrowAccount: escrowPda, recipient: stagedRecipient.publicKey })
+        .signers([stagedRecipient])
+        .rpc();
+
+      await program.methods
+        .releaseTokenStaged()
+        .accounts({
+          escrowAccount: escrowPda,
+          vault: vaultPda,
+          creator: creator.publicKey,
+          feeTokenAccount: treasuryAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      return { escrowPda, vaultPda };
+    }
+
+    function finalize(escrowPda: PublicKey, vaultPda: PublicKey, recipientTokenAccount: PublicKey) {
+      return program.methods
+        .finalizeTokenRelease()
+        .accounts({
+          escrowAccount: escrowPda,
+          vault: vaultPda,
+          creator: creator.publicKey,
+          recipientTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          config: configPda,
+        })
+        .rpc();
+    }
+
+    it("staging pays the fee and parks the recipient share in the vault", async () => {
+      const treasuryBefore = Number((await getAccount(connection, treasuryAta)).amount);
+      const { escrowPda, vaultPda } = await createAndStage();
+
+      const fee = Math.floor(tokenAmount * feeBasisPoints / 10000);
+      expect(Number((await getAccount(connection, treasuryAta)).amount) - treasuryBefore).to.equal(fee);
+      expect(Number((await getAccount(connection, vaultPda)).amount)).to.equal(tokenAmount - fee);
+
+      const escrow = await program.account.tokenEscrowAccount.fetch(escrowPda) as any;
+      expect(escrow.status).to.have.property("completing");
+      expect(escrow.stagedAmount.toNumber()).to.equal(tokenAmount - fee);
+
+      const recipientBefore = Number((await getAccount(connection, stagedRecipientAta)).amount);
+      await finalize(escrowPda, vaultPda, stagedRecipientAta);
+
+      expect(Number((await getAccount(connection, stagedRecipientAta)).amount) - recipientBefore).to.equal(tokenAmount - fee);
+      expect(await connection.getAccountInfo(escrowPda)).to.be.null;
+      expect(await connection.getAccountInfo(vaultPda)).to.be.null;
+    });
+
+    it("a failed recipient leg keeps the staged state and finalize can be retried", async () => {
+      const treasuryBefore = Number((await getAccount(connection, treasuryAta)).amount);
+      const { escrowPda, vaultPda } = await createAndStage();
+      const fee = Math.floor(tokenAmount * feeBasisPoints / 10000);
+
+      // Recipient closes their token account, so the payout leg cannot land
+      const balance = Number((await getAccount(connection, stagedRecipientAta)).amount);
+      if (balance > 0) {
+        await transfer(connection, stagedRecipient, stagedRecipientAta, creatorAta, stagedRecipient, balance);
+      }
+      await closeAccount(connection, stagedRecipient, stagedRecipientAta, stagedRecipient.publicKey, stagedRecipient);
+
+      try {
+        await finalize(escrowPda, vaultPda, stagedRecipientAta);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("AccountNotInitialized");
+      }
+
+      // Fee stays paid exactly once and the recipient's share is untouched
+      expect(Number((await getAccount(connection, treasuryAta)).amount) - treasuryBefore).to.equal(fee);
+      expect(Number((await getAccount(connection, vaultPda)).amount)).to.equal(tokenAmount - fee);
+      const escrow = await program.account.tokenEscrowAccount.fetch(escrowPda) as any;
+      expect(escrow.status).to.have.property("completing");
+
+      // Recipient reopens an account and the release resumes
+      stagedRecipientAta = await createAccount(connection, stagedRecipient, mint, stagedRecipient.publicKey);
+      await finalize(escrowPda, vaultPda, stagedRecipientAta);
+
+      expect(Number((await getAccount(connection, stagedRecipientAta)).amount)).to.equal(tokenAmount - fee);
+      expect(Number((await getAccount(connection, treasuryAta)).amount) - treasuryBefore).to.equal(fee);
+      expect(await connection.getAccountInfo(escrowPda)).to.be.null;
+    });
+
+    it("a staged escrow can no longer be refunded", async () => {
+      const { escrowPda, vaultPda } = await createAndStage();
+
+      try {
+        await program.methods
+          .refundTokenEscrow()
+          .accounts({
+            escrowAccount: escrowPda,
+            vault: vaultPda,
+            creator: creator.publicKey,
+            creatorTokenAccount: creatorAta,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([creator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InvalidStatus");
+      }
+    });
+  });
+
+  describe("recover_frozen_token_escrow", () => {
+    // Separate mint with the creator as freeze authority
+    let freezableMint: PublicKey;
+    let creatorFreezableAta: PublicKey;
+
+    before(async () => {
+      freezableMint = await createMint(connection, creator, creator.publicKey, creator.publicKey, 6);
+      creatorFreezableAta = await createAccount(connection, creator, freezableMint, creator.publicKey);
+      await mintTo(connection, creator, freezableMint, creatorFreezableAta, creator, 10_000_000);
+    });
+
+    // Creates and accepts an escrow paying into a fresh recipient token account
+    async function createActive() {
+      const escrowId = randomEscrowId();
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const [escrowPda] = deriveTokenEscrowPda(creator.publicKey, escrowId);
+      const [vaultPda] = deriveVaultPda(escrowPda);
+      const recipientFreezableAta = await createAccount(connection, recipient, freezableMint, recipient.publicKey, Keypair.generate());
+
+      await program.methods
+        .createTokenEscrow(escrowId, new anchor.BN(tokenAmount), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          vault: vaultPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          mint: freezableMint,
+          creatorTokenAccount: creatorFreezableAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTokenTask()
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      return { escrowPda, vaultPda, recipientFreezableAta };
+    }
+
+    function recover(escrowPda: PublicKey, vaultPda: PublicKey, recipientTokenAccount: PublicKey) {
+      return program.methods
+        .recoverFrozenTokenEscrow()
+        .accounts({
+          escrowAccount: escrowPda,
+          vault: vaultPda,
+          creator: creator.publicKey,
+          creatorTokenAccount: creatorFreezableAta,
+          recipientTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([creator])
+        .rpc();
+    }
+
+    it("rejects recovery while the recipient account is not frozen", async () => {
+      const { escrowPda, vaultPda, recipientFreezableAta } = await createActive();
+
+      try {
+        await recover(escrowPda, vaultPda, recipientFreezableAta);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("RecipientAccountNotFrozen");
+      }
+    });
+
+    it("records a frozen recipient account and holds funds until the timeout", async () => {
+      const { escrowPda, vaultPda, recipientFreezableAta } = await createActive();
+      await freezeAccount(connection, creator, recipientFreezableAta, freezableMint, creator);
+
+      // First call only records the freeze
+      await recover(escrowPda, vaultPda, recipientFreezableAta);
+
+      const escrow = await program.account.tokenEscrowAccount.fetch(escrowPda) as any;
+      expect(escrow.frozenReportedAt.toNumber()).to.be.greaterThan(0);
+      expect(escrow.status).to.have.property("active");
+      expect(Number((await getAccount(connection, vaultPda)).amount)).to.equal(tokenAmount);
+
+      // FROZEN_RECOVERY_SECONDS (24h) has not elapsed yet
+      try {
+        await recover(escrowPda, vaultPda, recipientFreezableAta);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("FrozenRecoveryNotReady");
+      }
+    });
+  });
+
+  describe("dispute_token + resolve_token_dispute", () => {
+    it("dispute and resolve in favor of recipient", async () => {
+      const escrowId = randomEscrowId();
+      const amount = new anchor.BN(tokenAmount);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const termsHash = Array(32).fill(0);
+
+      const [escrowPda] = deriveTokenEscrowPda(creator.publicKey, escrowId);
+      const [vaultPda] = deriveVaultPda(escrowPda);
+
+      // Create → Accept → Dispute
+      await program.methods
+        .createTokenEscrow(escrowId, amount, deadline, termsHash, feeBasisPoints, new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          vault: vaultPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          mint: mint,
+          creatorTokenAccount: creatorAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTokenTask()
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      const reasonBytes = Buffer.alloc(64);
+      reasonBytes.write("Token dispute test");
+      await program.methods
+        .disputeToken(Array.from(reasonBytes))
+        .accounts({ escrowAccount: escrowPda, disputer: creator.publicKey })
+        .signers([creator])
+        .rpc();
+
+      const recipientBefore = Number((await getAccount(connection, recipientAta)).amount);
+      const treasuryBefore = Number((await getAccount(connection, treasuryAta)).amount);
+
+      // Resolve: recipient wins
+      await program.methods
+        .resolveTokenDispute({ recipient: {} })
+        .accounts({
+          escrowAccount: escrowPda,
+          vault: vaultPda,
+          arbiter: arbiter.publicKey,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          creatorTokenAccount: creatorAta,
+          recipientTokenAccount: recipientAta,
+          feeTokenAccount: treasuryAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([arbiter])
+        .rpc();
+
+      const recipientAfter = Number((await getAccount(connection, recipientAta)).amount);
+      const treasuryAfter = Number((await getAccount(connection, treasuryAta)).amount);
+
+      const expectedFee = Math.floor(tokenAmount * feeBasisPoints / 10000);
+      expect(recipientAfter - recipientBefore).to.equal(tokenAmount - expectedFee);
+      expect(treasuryAfter - treasuryBefore).to.equal(expectedFee);
+
+      // PDA closed
+      const info = await connection.getAccountInfo(escrowPda);
+      expect(info).to.be.null;
+    });
+
+    it("arbiter splits a token dispute 60/40", async () => {
+      const escrowId = randomEscrowId();
+      const amount = new anchor.BN(tokenAmount);
+      const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+      const [escrowPda] = deriveTokenEscrowPda(creator.publicKey, escrowId);
+      const [vaultPda] = deriveVaultPda(escrowPda);
+
+      await program.methods
+        .createTokenEscrow(escrowId, amount, deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0))
+        .accounts({
+          escrowAccount: escrowPda,
+          vault: vaultPda,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          arbiter: arbiter.publicKey,
+          feeRecipient: treasury.publicKey,
+          mint: mint,
+          creatorTokenAccount: creatorAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          config: configPda,
+        })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .acceptTokenTask()
+        .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+
+      await program.methods
+        .disputeToken(Array(64).fill(0))
+        .accounts({ escrowAccount: escrowPda, disputer: creator.publicKey })
+        .signers([creator])
+        .rpc();
+
+      const creatorBefore = Number((await getAccount(connection, creatorAta)).amount);
+      const recipientBefore = Number((await getAccount(connection, recipientAta)).amount);
+      const treasuryBefore = Number((await getAccount(connection, treasuryAta)).amount);
+
+      await program.methods
+        .resolveTokenDisputeSplit(6000)
+        .accounts({
+          escrowAccount: escrowPda,
+          vault: vaultPda,
+          arbiter: arbiter.publicKey,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          creatorTokenAccount: creatorAta,
+          recipientTokenAccount: recipientAta,
+          feeTokenAccount: treasuryAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([arbiter])
+        .rpc();
+
+      const expectedFee = Math.floor(tokenAmount * feeBasisPoints / 10000);
+      const net = tokenAmount - expectedFee;
+      const expectedRecipient = Math.floor(net * 6000 / 10000);
+
+      const creatorAfter = Number((await getAccount(connection, creatorAta)).amount);
+      const recipientAfter = Number((await getAccount(connection, recipientAta)).amount);
+      const treasuryAfter = Number((await getAccount(connection, treasuryAta)).amount);
+
+      expect(recipientAfter - recipientBefore).to.equal(expectedRecipient);
+      expect(creatorAfter - creatorBefore).to.equal(net - expectedRecipient);
+      expect(treasuryAfter - treasuryBefore).to.equal(expectedFee);
+
+      const info = await connection.getAccountInfo(escrowPda);
+      expect(info).to.be.null;
+    });
+
+    describe("resolve_token_dispute_advanced", () => {
+      const recipientShare = tokenAmount * 0.7;
+      const refundShare = tokenAmount - recipientShare;
+
+      async function createDisputedTokenEscrow() {
+        const escrowId = randomEscrowId();
+        const deadline = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+        const [escrowPda] = deriveTokenEscrowPda(creator.publicKey, escrowId);
+        const [vaultPda] = deriveVaultPda(escrowPda);
+
+        await program.methods
+          .createTokenEscrow(escrowId, new anchor.BN(tokenAmount), deadline, Array(32).fill(0), feeBasisPoints, new anchor.BN(0))
+          .accounts({
+            escrowAccount: escrowPda,
+            vault: vaultPda,
+            creator: creator.publicKey,
+            recipient: recipient.publicKey,
+            arbiter: arbiter.publicKey,
+            feeRecipient: treasury.publicKey,
+            mint: mint,
+            creatorTokenAccount: creatorAta,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+            config: configPda,
+          })
+          .signers([creator])
+          .rpc();
+
+        await program.methods
+          .acceptTokenTask()
+          .accounts({ escrowAccount: escrowPda, recipient: recipient.publicKey })
+          .signers([recipient])
+          .rpc();
+
+        await program.methods
+          .disputeToken(Array(64).fill(0))
+          .accounts({ escrowAccount: escrowPda, disputer: creator.publicKey })
+          .signers([creator])
+          .rpc();
+
+        return { escrowPda, vaultPda };
+      }
+
+      // Resolves with the given amounts, returning [creator, recipient, treasury] token deltas
+      async function resolveAdvanced(recipientAmount: number, refundAmount: number, chargeFee: boolean) {
+        const { escrowPda, vaultPda } = await createDisputedTokenEscrow();
+
+        const creatorBefore = Number((await getAccount(connection, creatorAta)).amount);
+        const recipientBefore = Number((await getAccount(connection, recipientAta)).amount);
+        const treasuryBefore = Number((await getAccount(connection, treasuryAta)).amount);
+
+        await program.methods
+          .resolveTokenDisputeAdvanced(new anchor.BN(recipientAmount), new anchor.BN(refundAmount), chargeFee)
+          .accounts({
+            escrowAccount: escrowPda,
+            vault: vaultPda,
+            arbiter: arbiter.publicKey,
+            creator: creator.publicKey,
+            recipient: recipient.publicKey,
+            creatorTokenAccount: creatorAta,
+            recipientTokenAccount: recipientAta,
+            feeTokenAccount: treasuryAta,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([arbiter])
+          .rpc();
+
+        expect(await connection.getAccountInfo(vaultPda)).to.be.null;
+
+        return [
+          Number((await getAccount(connection, creatorAta)).amount) - creatorBefore,
+          Number((await getAccount(connection, recipientAta)).amount) - recipientBefore,
+          Number((await getAccount(connection, treasuryAta)).amount) - treasuryBefore,
+        ];
+      }
+
+      it("charges the fee on the recipient's portion only", async () => {
+        const [creatorDelta, recipientDelta, treasuryDelta] = await resolveAdvanced(recipientShare, refundShare, true);
+
+        const expectedFee = Math.floor(recipientShare * feeBasisPoints / 10000);
+        expect(recipientDelta).to.equal(recipientShare - expectedFee);
+        expect(treasuryDelta).to.equal(expectedFee);
+        expect(creatorDelta).to.equal(refundShare);
+      });
+
+      it("waives the fee entirely when charge_fee is false", async () => {
+        const [creatorDelta, recipientDelta, treasuryDelta] = await resolveAdvanced(recipientShare, refundShare, false);
+
+        expect(recipientDelta).to.equal(recipientShare);
+        expect(treasuryDelta).to.equal(0);
+        expect(creatorDelta).to.equal(refundShare);
+      });
+
+      it("rejects amounts that don't add up to the escrow", async () => {
+        try {
+          await resolveAdvanced(recipientShare, refundShare - 1, true);
+          expect.fail("Should have thrown");
+        } catch (err: any) {
+          expect(err.error?.errorCode?.code || err.message).to.include("InvalidSplit");
+        }
+      });
+    });
+  });
+
+  describe("migrate_token_escrow", () => {
+    const legacyCreator = Keypair.fromSecretKey(Uint8Array.from(legacyCreatorSecret));
+    const legacyRecipient = Keypair.fromSecretKey(Uint8Array.from(legacyRecipientSecret));
+
+    before(async () => {
+      await airdrop(connection, legacyCreator.publicKey, 1);
+    });
+
+    it("only the creator can migrate", async () => {
+      try {
+        await program.methods
+          .migrateTokenEscrow()
+          .accounts({
+            escrowAccount: LEGACY_TOKEN_ESCROW,
+            creator: legacyRecipient.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([legacyRecipient])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("UnauthorizedCreator");
+      }
+    });
+
+    it("moves a pre-version token escrow behind the header so it can be used again", async () => {
+      // Handlers refuse the old layout until it is migrated
+      try {
+        await program.methods
+          .acceptTokenTask()
+          .accounts({ escrowAccount: LEGACY_TOKEN_ESCROW, recipient: legacyRecipient.publicKey })
+          .signers([legacyRecipient])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("AccountDidNotDeserialize");
+      }
+
+      await program.methods
+        .migrateTokenEscrow()
+        .accounts({
+          escrowAccount: LEGACY_TOKEN_ESCROW,
+          creator: legacyCreator.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([legacyCreator])
+        .rpc();
+
+      const escrow = await program.account.tokenEscrowAccount.fetch(LEGACY_TOKEN_ESCROW) as any;
+      expect(escrow.version).to.equal(CURRENT_ACCOUNT_VERSION);
+      expect(escrow.creator.toBase58()).to.equal(legacyCreator.publicKey.toBase58());
+      expect(escrow.recipient.toBase58()).to.equal(legacyRecipient.publicKey.toBase58());
+      expect(escrow.mint.toBase58()).to.equal("9NYJcggFTP8iecxDTW2UamFXA44imKzCmPMKSxm1oNAa");
+      expect(escrow.amount.toNumber()).to.equal(1_000_000);
+      expect(escrow.feeBasisPoints).to.equal(10);
+      expect(escrow.escrowId.toNumber()).to.equal(2);
+      expect(escrow.stagedAmount.toNumber()).to.equal(0);
+
+      await program.methods
+        .acceptTokenTask()
+        .accounts({ escrowAccount: LEGACY_TOKEN_ESCROW, recipient: legacyRecipient.publicKey })
+        .signers([legacyRecipient])
+        .rpc();
+
+      const accepted = await program.account.tokenEscrowAccount.fetch(LEGACY_TOKEN_ESCROW) as any;
+      expect(accepted.status).to.have.property("active");
     });
   });
 });